@@ -408,7 +408,7 @@ fn run_with_service_with_extra_inputs(
                 let tx_id =
                     tx.id(&shared.config.chain_conf.consensus_parameters.chain_id);
 
-                let mut sub = shared.block_importer.block_importer.subscribe();
+                let mut sub = shared.block_importer.events();
                 shared
                     .txpool
                     .insert(vec![std::sync::Arc::new(tx)])
@@ -417,7 +417,9 @@ fn run_with_service_with_extra_inputs(
                     .next()
                     .expect("Should be at least 1 element")
                     .expect("Should include transaction successfully");
-                let res = sub.recv().await.expect("Should produce a block");
+                let res = futures::StreamExt::next(&mut sub)
+                    .await
+                    .expect("Should produce a block");
                 assert_eq!(res.tx_status.len(), 2, "res.tx_status: {:?}", res.tx_status);
                 assert_eq!(res.sealed_block.entity.transactions().len(), 2);
                 assert_eq!(res.tx_status[0].id, tx_id);
@@ -33,6 +33,22 @@ pub fn get_state_size() -> u64 {
     state_size
 }
 
+/// The sizes of the contract state used by the state size dependent benchmarks.
+pub fn get_state_sizes() -> Vec<u64> {
+    // Override state sizes if the env var is set
+    std::env::var_os("STATE_SIZES")
+        .map(|value| {
+            let value = value.to_str().unwrap();
+            let values = value
+                .split(',')
+                .map(|v| v.trim().parse::<u64>().unwrap())
+                .collect::<Vec<_>>();
+            println!("Overriding state sizes with {:?}", values);
+            values
+        })
+        .unwrap_or_else(|| vec![10_000, 100_000, 1_000_000, get_state_size()])
+}
+
 /// Allocates a byte array from heap and initializes it. Then points `reg` to it.
 fn aloc_bytearray<const S: usize>(reg: u8, v: [u8; S]) -> Vec<Instruction> {
     let mut ops = vec![op::movi(reg, S as u32), op::aloc(reg)];
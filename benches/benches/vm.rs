@@ -74,6 +74,7 @@ fn vm(c: &mut Criterion) {
     flow::run(c);
     mem::run(c);
     blockchain::run(c);
+    state_size::run(c);
     contract_root(c);
     state_root(c);
     vm_initialization(c);
@@ -58,14 +58,21 @@ pub struct BenchDb {
 
 impl BenchDb {
     fn new(contract_id: &ContractId) -> anyhow::Result<Self> {
+        Self::with_state_size(contract_id, crate::utils::get_state_size())
+    }
+
+    /// Creates the database where the contract has `state_size` storage slots
+    /// and `state_size` balances.
+    pub fn with_state_size(
+        contract_id: &ContractId,
+        state_size: u64,
+    ) -> anyhow::Result<Self> {
         let tmp_dir = ShallowTempDir::new();
 
         let db = Arc::new(RocksDb::default_open(tmp_dir.path(), None).unwrap());
         let mut storage_key = primitive_types::U256::zero();
         let mut key_bytes = Bytes32::zeroed();
 
-        let state_size = crate::utils::get_state_size();
-
         let mut database = Database::new(db);
         database.init_contract_state(
             contract_id,
@@ -112,7 +119,7 @@ impl BenchDb {
     }
 
     /// Creates a `VmDatabase` instance.
-    fn to_vm_database(&self) -> VmStorage<Database> {
+    pub fn to_vm_database(&self) -> VmStorage<Database> {
         let header = ConsensusHeader {
             prev_root: Default::default(),
             height: 1.into(),
@@ -3,6 +3,7 @@ pub mod blockchain;
 pub mod crypto;
 pub mod flow;
 pub mod mem;
+pub mod state_size;
 
 pub use super::run_group_ref;
 
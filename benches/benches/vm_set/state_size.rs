@@ -0,0 +1,131 @@
+//! The benchmarks of the storage opcodes measured against databases with
+//! different number of entries. The throughput of the benchmarks is the number
+//! of entries in the contract state, so `collect` treats them as dependent costs.
+
+use super::{
+    blockchain::BenchDb,
+    run_group_ref,
+};
+
+use crate::utils::make_receipts;
+use criterion::{
+    Criterion,
+    Throughput,
+};
+use fuel_core_benches::*;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_tx::Word,
+    fuel_types::*,
+    fuel_vm::consts::*,
+};
+use rand::{
+    rngs::StdRng,
+    RngCore,
+    SeedableRng,
+};
+
+/// The size of the code of the called contract.
+const CALL_CODE_SIZE: usize = 100;
+
+pub fn run(c: &mut Criterion) {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let contract: ContractId = VmBench::CONTRACT;
+
+    let dbs = crate::utils::get_state_sizes()
+        .into_iter()
+        .map(|state_size| {
+            let db = BenchDb::with_state_size(&contract, state_size)
+                .expect("Unable to fill contract storage");
+            (state_size, db)
+        })
+        .collect::<Vec<_>>();
+
+    let receipts_ctx = make_receipts(rng);
+
+    let mut sww = c.benchmark_group("sww_state_size");
+    for (state_size, db) in &dbs {
+        let mut start_key = Bytes32::zeroed();
+        // The database was initialized with entries starting `0..state_size`.
+        // We want to write new entry to the database, so the starting key is far.
+        start_key.as_mut()[0] = 255;
+        let data = start_key.iter().copied().collect::<Vec<_>>();
+
+        let post_call = vec![
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            op::addi(0x11, 0x10, ContractId::LEN.try_into().unwrap()),
+            op::addi(0x11, 0x11, WORD_SIZE.try_into().unwrap()),
+            op::addi(0x11, 0x11, WORD_SIZE.try_into().unwrap()),
+            op::addi(0x11, 0x11, AssetId::LEN.try_into().unwrap()),
+        ];
+        let mut bench = VmBench::contract_using_db(
+            rng,
+            db.to_vm_database(),
+            op::sww(0x11, 0x29, RegId::ONE),
+        )
+        .expect("failed to prepare contract")
+        .with_post_call(post_call);
+        bench.data.extend(data);
+
+        sww.throughput(Throughput::Elements(*state_size));
+        run_group_ref(&mut sww, format!("{state_size}"), bench);
+    }
+    sww.finish();
+
+    let mut srw = c.benchmark_group("srw_state_size");
+    for (state_size, db) in &dbs {
+        let bench = VmBench::contract_using_db(
+            rng,
+            db.to_vm_database(),
+            op::srw(0x13, 0x14, 0x10),
+        )
+        .expect("failed to prepare contract");
+
+        srw.throughput(Throughput::Elements(*state_size));
+        run_group_ref(&mut srw, format!("{state_size}"), bench);
+    }
+    srw.finish();
+
+    let mut call = c.benchmark_group("call_state_size");
+    for (state_size, db) in &dbs {
+        let mut code = vec![0u8; CALL_CODE_SIZE];
+        rng.fill_bytes(&mut code);
+
+        let mut code = ContractCode::from(code);
+        code.id = contract;
+
+        let data = code
+            .id
+            .iter()
+            .copied()
+            .chain((0 as Word).to_be_bytes().iter().copied())
+            .chain((0 as Word).to_be_bytes().iter().copied())
+            .chain(AssetId::default().iter().copied())
+            .collect();
+
+        let prepare_script = vec![
+            op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+            op::addi(0x11, 0x10, ContractId::LEN.try_into().unwrap()),
+            op::addi(0x11, 0x11, WORD_SIZE.try_into().unwrap()),
+            op::addi(0x11, 0x11, WORD_SIZE.try_into().unwrap()),
+            op::movi(0x12, 100_000),
+        ];
+
+        call.throughput(Throughput::Elements(*state_size));
+        run_group_ref(
+            &mut call,
+            format!("{state_size}"),
+            VmBench::new(op::call(0x10, RegId::ZERO, 0x11, RegId::CGAS))
+                .with_db(db.to_vm_database())
+                .with_contract_code(code)
+                .with_data(data)
+                .with_prepare_script(prepare_script)
+                .with_call_receipts(receipts_ctx.clone()),
+        );
+    }
+    call.finish();
+}
@@ -51,14 +51,170 @@ struct Args {
     /// The format the output should be written to.
     #[arg(short, long, value_enum, default_value_t = OutputFormat::Yaml)]
     format: OutputFormat,
+
+    /// The number of segments of the piecewise-linear model used for
+    /// dependent measurements that are not linear.
+    #[arg(long, default_value_t = DEFAULT_SEGMENTS)]
+    segments: usize,
+
+    /// Fail instead of printing a warning when a dependent measurement is not linear
+    /// or the quality of its linear fit is below `min_r_squared`.
+    #[arg(long)]
+    strict: bool,
+
+    /// The minimal coefficient of determination(R²) of the linear fit
+    /// of dependent measurements. The costs with a lower value are flagged.
+    #[arg(long, default_value_t = DEFAULT_MIN_R_SQUARED)]
+    min_r_squared: f64,
+
+    /// Path to store the machine-readable report of the dependent cost estimation:
+    /// the points, the fitted model and the residuals per cost.
+    /// The report is written in the CSV format if the extension is `csv`
+    /// and in the JSON format otherwise.
+    #[arg(long)]
+    report: Option<PathBuf>,
+
+    #[command(flatten)]
+    profile: HardwareProfileArgs,
+
+    /// Path to the reference "unit machine" to normalize the costs against.
+    /// The costs are calculated as if they were measured on the unit machine.
+    #[arg(long)]
+    unit_machine: Option<PathBuf>,
+
+    /// Save the current run as the reference "unit machine" to this path.
+    #[arg(long)]
+    save_unit_machine: Option<PathBuf>,
+
+    /// Benchmark id used to compare the speed of the machine with the unit machine.
+    /// It must be a benchmark without throughput. Defaults to the base line.
+    #[arg(long)]
+    calibration: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Compare two sets of gas costs and report the changes per opcode.
+    Compare(CompareArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct CompareArgs {
+    /// Path to the gas costs to compare against in the yaml or json format.
+    /// The `ChainConfig` JSON is also supported.
+    old: PathBuf,
+
+    /// Path to the new gas costs in the same formats as `old`.
+    new: PathBuf,
+
+    /// Fail if any cost increases by more than this percent.
+    #[arg(long)]
+    max_increase: Option<f64>,
+
+    /// Fail if any cost decreases by more than this percent.
+    #[arg(long)]
+    max_decrease: Option<f64>,
 }
 
+#[derive(clap::Args, Debug, Clone)]
+/// The hardware the benchmarks were run on.
+struct HardwareProfileArgs {
+    /// The model of the CPU. Detected from `/proc/cpuinfo` if not set.
+    #[arg(long)]
+    cpu_model: Option<String>,
+
+    /// The frequency of the CPU in MHz. Detected from `/proc/cpuinfo` if not set.
+    #[arg(long)]
+    cpu_frequency_mhz: Option<u64>,
+
+    /// The kind of the storage used by the database.
+    #[arg(long, value_enum, default_value_t = StorageKind::Unknown)]
+    storage: StorageKind,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+/// The kind of the storage used by the database.
+enum StorageKind {
+    Nvme,
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// The hardware profile the run is tagged with.
+struct HardwareProfile {
+    cpu_model: Option<String>,
+    cpu_frequency_mhz: Option<u64>,
+    storage: StorageKind,
+}
+
+impl HardwareProfile {
+    /// Creates the profile from the arguments and detects the missing CPU
+    /// information from `/proc/cpuinfo`.
+    fn detect(args: HardwareProfileArgs) -> Self {
+        let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+        let cpuinfo_value = |key: &str| {
+            cpuinfo.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                (name.trim() == key).then(|| value.trim().to_string())
+            })
+        };
+        Self {
+            cpu_model: args.cpu_model.or_else(|| cpuinfo_value("model name")),
+            cpu_frequency_mhz: args.cpu_frequency_mhz.or_else(|| {
+                cpuinfo_value("cpu MHz")?
+                    .parse::<f64>()
+                    .ok()
+                    .map(|mhz| mhz as u64)
+            }),
+            storage: args.storage,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// The reference machine the costs are normalized against.
+struct UnitMachine {
+    profile: HardwareProfile,
+    /// The id of the base line benchmark.
+    baseline: String,
+    /// The mean time of the base line benchmark in nanoseconds.
+    baseline_ns: u64,
+    /// The id of the calibration benchmark.
+    calibration: String,
+    /// The mean time of the calibration benchmark in nanoseconds.
+    calibration_ns: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// The tag written next to the output.
+struct RunTag {
+    /// The hardware the benchmarks were run on.
+    profile: HardwareProfile,
+    /// The hardware of the unit machine if the costs were normalized.
+    unit_machine: Option<HardwareProfile>,
+}
+
+/// The default number of segments of the piecewise-linear model.
+const DEFAULT_SEGMENTS: usize = 4;
+
+/// The default minimal R² of the linear fit of dependent measurements.
+const DEFAULT_MIN_R_SQUARED: f64 = 0.9;
+
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
 /// The format the output should be written to.
 enum OutputFormat {
     Yaml,
     Json,
     Rust,
+    /// The `gas_costs` section of the `ChainConfig` JSON. The costs that were not
+    /// measured are taken from the defaults.
+    ChainConfig,
 }
 
 #[derive(Debug)]
@@ -98,6 +254,14 @@ struct State {
     baseline: String,
     /// Should all measurements be included.
     all: bool,
+    /// The number of segments of the piecewise-linear model.
+    segments: usize,
+    /// Should the non-linear dependent measurements be treated as errors.
+    strict: bool,
+    /// The minimal R² of the linear fit of dependent measurements.
+    min_r_squared: f64,
+    /// The unit machine to normalize the costs against.
+    unit_machine: Option<UnitMachine>,
     /// Map of ids to their mean times.
     ids: HashMap<String, Duration>,
     /// Map of ids to their throughput.
@@ -110,6 +274,10 @@ impl Default for State {
     fn default() -> Self {
         State {
             all: false,
+            segments: DEFAULT_SEGMENTS,
+            strict: false,
+            min_r_squared: DEFAULT_MIN_R_SQUARED,
+            unit_machine: None,
             ids: HashMap::new(),
             throughput: HashMap::new(),
             groups: HashMap::new(),
@@ -148,11 +316,31 @@ fn main() {
         debug,
         format,
         all,
+        segments,
+        strict,
+        min_r_squared,
+        report,
+        profile,
+        unit_machine,
+        save_unit_machine,
+        calibration,
+        command,
     } = Args::parse();
-    if all && matches!(format, OutputFormat::Rust) {
+    if let Some(Command::Compare(args)) = command {
+        compare(args);
+        return
+    }
+    if all && matches!(format, OutputFormat::Rust | OutputFormat::ChainConfig) {
         panic!("The flag `all` cannot be used with {format:?}");
     }
     let mut output = output.unwrap_or_else(|| std::env::current_dir().unwrap());
+    let profile = HardwareProfile::detect(profile);
+    let unit_machine = unit_machine.map(|path| {
+        let file = std::fs::File::open(&path).unwrap_or_else(|e| {
+            panic!("Could not open the unit machine {}: {e}", path.display())
+        });
+        serde_yaml::from_reader::<_, UnitMachine>(file).unwrap()
+    });
 
     let (tx, rx) = channel();
 
@@ -187,6 +375,10 @@ fn main() {
     let mut line = String::new();
     let mut state = State {
         all,
+        segments,
+        strict,
+        min_r_squared,
+        unit_machine,
         ids: HashMap::new(),
         throughput: HashMap::new(),
         groups: HashMap::new(),
@@ -218,11 +410,30 @@ fn main() {
     if debug {
         eprintln!("{state}");
     }
+    if let Some(path) = save_unit_machine {
+        let calibration = calibration.unwrap_or_else(|| state.baseline.clone());
+        let unit_machine = UnitMachine {
+            profile: profile.clone(),
+            baseline: state.baseline.clone(),
+            baseline_ns: state.mean_ns(&state.baseline),
+            calibration_ns: state.mean_ns(&calibration),
+            calibration,
+        };
+        let file = std::fs::File::create(&path).unwrap();
+        serde_yaml::to_writer(file, &unit_machine).unwrap();
+        println!("Successfully wrote the unit machine to {}", path.display());
+    }
+    if let Some(path) = report {
+        let (_, reports) = state.clone().into_costs_with_reports();
+        write_reports(&path, &reports);
+        println!("Successfully wrote the report to {}", path.display());
+    }
     if output.is_dir() {
         match format {
             OutputFormat::Yaml => output.push("gas-costs.yaml"),
             OutputFormat::Json => output.push("gas-costs.json"),
             OutputFormat::Rust => output.push("gas-costs.rs"),
+            OutputFormat::ChainConfig => output.push("chain-config-gas-costs.json"),
         }
     }
 
@@ -237,8 +448,37 @@ fn main() {
             serde_json::to_writer(writer, &state.to_json()).unwrap();
         }
         OutputFormat::Rust => write!(&mut writer, "{}", state.to_rust_code()).unwrap(),
+        OutputFormat::ChainConfig => {
+            let gas_costs = state.to_chain_config_gas_costs();
+            let diff = gas_costs_diff(&GasCostsValues::default(), &gas_costs);
+            if diff.is_empty() {
+                eprintln!("The gas costs are the same as the defaults");
+            } else {
+                eprintln!("The gas costs differ from the defaults:");
+                for line in diff {
+                    eprintln!("  {line}");
+                }
+            }
+            serde_json::to_writer_pretty(
+                writer,
+                &serde_json::json!({ "gas_costs": gas_costs }),
+            )
+            .unwrap();
+        }
     }
     println!("Successfully wrote output to {}", output.display());
+
+    let tag = RunTag {
+        profile,
+        unit_machine: state.unit_machine.map(|unit_machine| unit_machine.profile),
+    };
+    let tag_path = output.with_extension("profile.yaml");
+    let file = std::fs::File::create(&tag_path).unwrap();
+    serde_yaml::to_writer(file, &tag).unwrap();
+    println!(
+        "Successfully wrote the hardware profile to {}",
+        tag_path.display()
+    );
 }
 
 fn extract_state(line: &str, state: &mut State, debug: bool) {
@@ -364,15 +604,18 @@ pub fn default_gas_costs() -> GasCostsValues {
 "##,
 ];
 
+/// The names of the costs that differ between the serialized
+/// and the Rust representation of the `GasCostsValues`.
+const RUST_NAMES: [(&str, &str); 5] = [
+    ("mod", "mod_op"),
+    ("move", "move_op"),
+    ("ret_contract", "ret"),
+    ("rvrt_contract", "rvrt"),
+    ("retd_contract", "retd"),
+];
+
 impl State {
     fn to_rust_code(&self) -> String {
-        let remaps = [
-            ("mod", "mod_op"),
-            ("move", "move_op"),
-            ("ret_contract", "ret"),
-            ("rvrt_contract", "rvrt"),
-            ("retd_contract", "retd"),
-        ];
         let removes = [("ret_script"), ("rvrt_script"), ("retd_script")]
             .into_iter()
             .collect::<HashSet<_>>();
@@ -384,7 +627,7 @@ impl State {
             for k in &removes {
                 m.remove(k);
             }
-            for (old, new) in remaps {
+            for (old, new) in RUST_NAMES {
                 let v = m.remove(old);
                 if let Some(v) = v {
                     m.insert(serde_yaml::Value::String(new.to_string()), v);
@@ -482,6 +725,36 @@ impl State {
         )
     }
 
+    /// Returns the gas costs in the format of the `ChainConfig`. The costs
+    /// that were not measured by the benchmarks are taken from the defaults.
+    fn to_chain_config_gas_costs(&self) -> GasCostsValues {
+        let mut gas_costs = match serde_json::to_value(GasCostsValues::default()) {
+            Ok(Value::Object(m)) => m,
+            _ => unreachable!(),
+        };
+        let measured = match self.to_json() {
+            Value::Object(m) => m,
+            _ => unreachable!(),
+        };
+
+        let mut missing = gas_costs
+            .keys()
+            .filter(|k| !measured.contains_key(*k))
+            .cloned()
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+        if !missing.is_empty() {
+            eprintln!("Warning the following keys were not set by this bench and use the defaults:\n{missing:?}");
+        }
+
+        for (name, cost) in measured {
+            if let Some(value) = gas_costs.get_mut(&name) {
+                *value = cost;
+            }
+        }
+        serde_json::from_value(Value::Object(gas_costs)).unwrap()
+    }
+
     fn to_gas_costs(&self) -> GasCostsValues {
         serde_yaml::from_value(self.to_yaml()).unwrap()
     }
@@ -502,29 +775,48 @@ impl State {
     }
 
     fn into_costs(self) -> Costs {
-        let (state, costs) = self.into_dependent_costs();
-        state.into_relative_costs(costs)
+        self.into_costs_with_reports().0
+    }
+
+    fn into_costs_with_reports(self) -> (Costs, Vec<CostReport>) {
+        let (state, costs, reports) = self.into_dependent_costs();
+        (state.into_relative_costs(costs), reports)
     }
 
+    /// Returns the time of the base line in nanoseconds. If the unit machine is set,
+    /// it is the time of the base line on the unit machine scaled by the ratio of
+    /// the calibration benchmark times on this machine and the unit machine.
     fn get_baseline(&self) -> u64 {
+        let Some(unit_machine) = &self.unit_machine else {
+            return self.mean_ns(&self.baseline)
+        };
+        let calibration = self.mean_ns(&unit_machine.calibration) as u128;
+        let baseline = (unit_machine.baseline_ns as u128 * calibration)
+            .checked_div(unit_machine.calibration_ns as u128)
+            .unwrap_or_else(|| panic!("The calibration of the unit machine is zero"));
+        baseline.max(1).try_into().unwrap()
+    }
+
+    fn mean_ns(&self, id: &str) -> u64 {
         self.ids
-            .get(&self.baseline)
+            .get(id)
             .copied()
             .unwrap_or_else(|| {
-                panic!(
-                    "Could not produce output as baseline {} was not found in recording",
-                    self.baseline
-                )
+                panic!("Could not produce output as {id} was not found in recording")
             })
             .as_nanos()
             .try_into()
             .unwrap()
     }
 
-    fn into_dependent_costs(self) -> (Self, Costs) {
+    fn into_dependent_costs(self) -> (Self, Costs, Vec<CostReport>) {
         let baseline = self.get_baseline();
         let State {
             all,
+            segments,
+            strict,
+            min_r_squared,
+            unit_machine,
             mut ids,
             mut groups,
             throughput,
@@ -550,23 +842,30 @@ impl State {
             })
             .collect::<Vec<_>>();
 
-        let iter = dependent_groups.into_iter().map(|(name, x_y)| {
+        let mut reports = Vec::with_capacity(dependent_groups.len());
+        for (name, x_y) in dependent_groups {
             groups.remove(&name);
 
-            let cost = Cost::Dependent(dependent_cost(&name, x_y));
-            (name, cost)
-        });
-        costs.0.extend(iter);
+            let report = dependent_cost(&name, x_y, segments, strict, min_r_squared);
+            costs.0.insert(name, Cost::Dependent(report.cost.clone()));
+            reports.push(report);
+        }
+        reports.sort_unstable_by(|a, b| a.name.cmp(&b.name));
 
         (
             Self {
                 all,
+                segments,
+                strict,
+                min_r_squared,
+                unit_machine,
                 baseline: baseline_name,
                 ids,
                 throughput,
                 groups,
             },
             costs,
+            reports,
         )
     }
 
@@ -596,6 +895,417 @@ impl Costs {
     }
 }
 
+/// Returns the list of the costs that differ between `defaults` and `measured`
+/// in the `name: default -> measured` format.
+fn gas_costs_diff(defaults: &GasCostsValues, measured: &GasCostsValues) -> Vec<String> {
+    let (Value::Object(defaults), Value::Object(measured)) = (
+        serde_json::to_value(defaults).unwrap(),
+        serde_json::to_value(measured).unwrap(),
+    ) else {
+        unreachable!()
+    };
+
+    let mut diff = measured
+        .iter()
+        .filter_map(|(name, cost)| {
+            let default = defaults.get(name)?;
+            (default != cost).then(|| format!("{name}: {default} -> {cost}"))
+        })
+        .collect::<Vec<_>>();
+    diff.sort_unstable();
+    diff
+}
+
+fn compare(args: CompareArgs) {
+    let CompareArgs {
+        old,
+        new,
+        max_increase,
+        max_decrease,
+    } = args;
+    let comparison = Comparison::new(read_costs(&old), read_costs(&new));
+
+    for change in &comparison.changes {
+        println!("{change}");
+    }
+    if !comparison.removed.is_empty() {
+        println!("Removed: {:?}", comparison.removed);
+    }
+    if !comparison.added.is_empty() {
+        println!("Added: {:?}", comparison.added);
+    }
+    if !comparison.kind_changed.is_empty() {
+        println!(
+            "Changed between relative and dependent: {:?}",
+            comparison.kind_changed
+        );
+    }
+
+    let failures = comparison.failures(max_increase, max_decrease);
+    if !failures.is_empty() {
+        eprintln!("The following costs changed more than allowed:");
+        for failure in failures {
+            eprintln!("  {failure}");
+        }
+        std::process::exit(1);
+    }
+}
+
+/// Reads the costs produced by `collect` in the yaml or json format.
+/// The `gas_costs` section of the `ChainConfig` is used if it is present.
+fn read_costs(path: &PathBuf) -> Costs {
+    let input = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {e}", path.display()));
+    let is_json = path.extension().is_some_and(|ext| ext == "json");
+    let costs = if is_json {
+        let mut value: Value = serde_json::from_str(&input).unwrap();
+        for section in ["consensus_parameters", "gas_costs"] {
+            if let Some(inner) = value.get_mut(section) {
+                value = inner.take();
+            }
+        }
+        serde_json::from_value::<Costs>(value).map_err(|e| e.to_string())
+    } else {
+        let mut value: serde_yaml::Value = serde_yaml::from_str(&input).unwrap();
+        for section in ["consensus_parameters", "gas_costs"] {
+            if let Some(inner) = value.get_mut(section) {
+                value = inner.clone();
+            }
+        }
+        // The untagged `Cost` can't be deserialized from the tagged yaml values.
+        serde_yaml::from_value::<HashMap<String, serde_yaml::Value>>(value)
+            .and_then(|costs| {
+                costs
+                    .into_iter()
+                    .map(|(name, cost)| {
+                        let cost = match cost.as_u64() {
+                            Some(cost) => Cost::Relative(cost),
+                            None => Cost::Dependent(serde_yaml::from_value(cost)?),
+                        };
+                        Ok((name, cost))
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(Costs)
+            })
+            .map_err(|e| e.to_string())
+    };
+    let costs = costs
+        .unwrap_or_else(|e| panic!("Could not parse costs from {}: {e}", path.display()));
+
+    Costs(
+        costs
+            .0
+            .into_iter()
+            .map(|(name, cost)| {
+                let name = RUST_NAMES
+                    .iter()
+                    .find(|(_, rust)| *rust == name)
+                    .map(|(serialized, _)| serialized.to_string())
+                    .unwrap_or(name);
+                (name, cost)
+            })
+            .collect(),
+    )
+}
+
+/// The change of a single cost between two sets of gas costs.
+#[derive(Debug, Clone, PartialEq)]
+struct Change {
+    /// The name of the cost. The parts of dependent costs
+    /// are suffixed with `.base` and `.per_unit`.
+    name: String,
+    old: f64,
+    new: f64,
+}
+
+impl Change {
+    /// The change in percent relative to the old cost.
+    fn percent(&self) -> f64 {
+        if self.old == self.new {
+            0.0
+        } else if self.old == 0.0 {
+            f64::INFINITY
+        } else {
+            (self.new - self.old) / self.old * 100.0
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} ({:+.2}%)",
+            self.name,
+            self.old,
+            self.new,
+            self.percent()
+        )
+    }
+}
+
+/// The result of the comparison of two sets of gas costs.
+#[derive(Debug, Default)]
+struct Comparison {
+    /// The changes of the costs present in both sets, sorted by name.
+    changes: Vec<Change>,
+    /// The costs present only in the old set.
+    removed: Vec<String>,
+    /// The costs present only in the new set.
+    added: Vec<String>,
+    /// The costs that are relative in one set and dependent in the other.
+    kind_changed: Vec<String>,
+}
+
+impl Comparison {
+    fn new(old: Costs, mut new: Costs) -> Self {
+        let mut comparison = Comparison::default();
+        for (name, old_cost) in old.0 {
+            let Some(new_cost) = new.0.remove(&name) else {
+                comparison.removed.push(name);
+                continue
+            };
+            match (old_cost, new_cost) {
+                (Cost::Relative(old), Cost::Relative(new)) => {
+                    comparison.changes.push(Change {
+                        name,
+                        old: old as f64,
+                        new: new as f64,
+                    });
+                }
+                (Cost::Dependent(old), Cost::Dependent(new)) => {
+                    comparison.changes.push(Change {
+                        name: format!("{name}.base"),
+                        old: old.base() as f64,
+                        new: new.base() as f64,
+                    });
+                    comparison.changes.push(Change {
+                        name: format!("{name}.per_unit"),
+                        old: old.gas_per_unit(),
+                        new: new.gas_per_unit(),
+                    });
+                }
+                _ => comparison.kind_changed.push(name),
+            }
+        }
+        comparison.added = new.0.into_keys().collect();
+
+        comparison
+            .changes
+            .sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        comparison.removed.sort_unstable();
+        comparison.added.sort_unstable();
+        comparison.kind_changed.sort_unstable();
+        comparison
+    }
+
+    /// Returns the changes that exceed the thresholds. The costs that changed
+    /// between relative and dependent fail if any threshold is set.
+    fn failures(
+        &self,
+        max_increase: Option<f64>,
+        max_decrease: Option<f64>,
+    ) -> Vec<String> {
+        let mut failures = self
+            .changes
+            .iter()
+            .filter(|change| {
+                let percent = change.percent();
+                max_increase.is_some_and(|max| percent > max)
+                    || max_decrease.is_some_and(|max| -percent > max)
+            })
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if max_increase.is_some() || max_decrease.is_some() {
+            failures.extend(
+                self.kind_changed.iter().map(|name| {
+                    format!("{name}: changed between relative and dependent")
+                }),
+            );
+        }
+        failures
+    }
+}
+
+impl DependentCost {
+    fn base(&self) -> u64 {
+        match self {
+            DependentCost::LightOperation { base, .. }
+            | DependentCost::HeavyOperation { base, .. } => *base,
+        }
+    }
+
+    /// The gas charged per one unit.
+    fn gas_per_unit(&self) -> f64 {
+        match self {
+            DependentCost::LightOperation { units_per_gas, .. } => {
+                if *units_per_gas == 0 {
+                    0.0
+                } else {
+                    1.0 / *units_per_gas as f64
+                }
+            }
+            DependentCost::HeavyOperation { gas_per_unit, .. } => *gas_per_unit as f64,
+        }
+    }
+}
+
+/// The measured points of the dependent cost: the number of elements
+/// and the time in `noop`s.
+type Points = Vec<(u64, u64)>;
+
+/// The multiplier of the median absolute deviation of the residuals
+/// after which the point is considered an outlier.
+const OUTLIER_MADS: f64 = 3.0;
+
+/// Splits the points into inliers and outliers. The outliers are the points whose
+/// residuals of the linear fit are further than `OUTLIER_MADS` median absolute
+/// deviations from the median residual.
+fn reject_outliers(name: &str, x_y: Points) -> (Points, Points) {
+    // The scale factor makes MAD consistent with the standard deviation.
+    const MAD_SCALE: f64 = 1.4826;
+
+    let Some(fit) = LinearFit::new(&x_y) else {
+        return (x_y, vec![])
+    };
+    if x_y.len() < 3 {
+        return (x_y, vec![])
+    }
+    let residuals = x_y.iter().map(|p| fit.residual(*p)).collect::<Vec<_>>();
+    let median_residual = median(residuals.clone());
+    let mad = median(
+        residuals
+            .iter()
+            .map(|r| (r - median_residual).abs())
+            .collect(),
+    );
+    if mad == 0.0 {
+        return (x_y, vec![])
+    }
+
+    let limit = OUTLIER_MADS * MAD_SCALE * mad;
+    let (inliers, outliers): (Vec<_>, Vec<_>) = x_y
+        .iter()
+        .zip(residuals)
+        .partition(|(_, r)| (r - median_residual).abs() <= limit);
+    if inliers.len() < 2 {
+        return (x_y, vec![])
+    }
+    let outliers = outliers.into_iter().map(|(p, _)| *p).collect::<Vec<_>>();
+    if !outliers.is_empty() {
+        eprintln!("Rejected outliers of the {name}: {outliers:?}");
+    }
+    (inliers.into_iter().map(|(p, _)| *p).collect(), outliers)
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        (values[mid - 1] + values[mid]) / 2.0
+    }
+}
+
+/// The least squares fit of `y = intercept + slope * x` with the 95%
+/// confidence intervals of the coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+struct LinearFit {
+    /// Time in `noop`s per one element.
+    slope: f64,
+    /// Time in `noop`s without elements.
+    intercept: f64,
+    /// The coefficient of determination.
+    r_squared: f64,
+    /// The half-width of the confidence interval of the slope.
+    slope_interval: f64,
+    /// The half-width of the confidence interval of the intercept.
+    intercept_interval: f64,
+}
+
+impl LinearFit {
+    /// Returns `None` if there are less than two distinct `x`.
+    fn new(x_y: &[(u64, u64)]) -> Option<Self> {
+        let n = x_y.len() as f64;
+        let avg_x = x_y.iter().map(|(x, _)| *x as f64).sum::<f64>() / n;
+        let avg_y = x_y.iter().map(|(_, y)| *y as f64).sum::<f64>() / n;
+        let sq_x: f64 = x_y.iter().map(|(x, _)| (*x as f64 - avg_x).powi(2)).sum();
+        if x_y.len() < 2 || sq_x == 0.0 {
+            return None
+        }
+        let sum_x_y: f64 = x_y
+            .iter()
+            .map(|(x, y)| (*x as f64 - avg_x) * (*y as f64 - avg_y))
+            .sum();
+        let slope = sum_x_y / sq_x;
+        let intercept = avg_y - slope * avg_x;
+
+        let sq_residuals: f64 = x_y
+            .iter()
+            .map(|(x, y)| (*y as f64 - intercept - slope * *x as f64).powi(2))
+            .sum();
+        let sq_total: f64 = x_y.iter().map(|(_, y)| (*y as f64 - avg_y).powi(2)).sum();
+        let r_squared = if sq_total == 0.0 {
+            1.0
+        } else {
+            1.0 - sq_residuals / sq_total
+        };
+
+        let degrees_of_freedom = x_y.len() - 2;
+        let (slope_interval, intercept_interval) = if degrees_of_freedom == 0 {
+            (f64::INFINITY, f64::INFINITY)
+        } else {
+            let variance = sq_residuals / degrees_of_freedom as f64;
+            let t = t_critical_95(degrees_of_freedom);
+            (
+                t * (variance / sq_x).sqrt(),
+                t * (variance * (1.0 / n + avg_x.powi(2) / sq_x)).sqrt(),
+            )
+        };
+
+        Some(Self {
+            slope,
+            intercept,
+            r_squared,
+            slope_interval,
+            intercept_interval,
+        })
+    }
+
+    fn residual(&self, (x, y): (u64, u64)) -> f64 {
+        y as f64 - self.intercept - self.slope * x as f64
+    }
+}
+
+impl Display for LinearFit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "R² = {:.4}, slope = {:.6} ± {:.6}, intercept = {:.2} ± {:.2}",
+            self.r_squared,
+            self.slope,
+            self.slope_interval,
+            self.intercept,
+            self.intercept_interval
+        )
+    }
+}
+
+/// The two-sided 95% critical value of the Student's t-distribution.
+fn t_critical_95(degrees_of_freedom: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201,
+        2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074,
+        2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+    ];
+    match degrees_of_freedom {
+        0 => f64::INFINITY,
+        df if df <= TABLE.len() => TABLE[df - 1],
+        _ => 1.96,
+    }
+}
+
 fn linear_regression(x_y: Vec<(u64, u64)>) -> f64 {
     let avg_x =
         x_y.iter().map(|(x, _)| x).copied().sum::<u64>() as f64 / x_y.len() as f64;
@@ -628,43 +1338,177 @@ fn linear_regression(x_y: Vec<(u64, u64)>) -> f64 {
     sq_x / sum_x_y
 }
 
-fn dependent_cost(name: &String, x_y: Vec<(u64, u64)>) -> DependentCost {
-    const NEAR_LINEAR: f64 = 0.1;
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    /// Number of elements for the opcode.
+    x: u64,
+    /// Time in `noop`s required to process `x` elements.
+    ///
+    /// Note: If the time to process `noop` opcode is `20` nanoseconds
+    /// and the time required to process `x` elements is `140`,
+    /// the `y` is 7 in this case.
+    y: u64,
+}
+
+impl Point {
+    /// The price in `noop` time to process a single element.
+    fn price(&self) -> f64 {
+        (self.y as f64) / (self.x as f64)
+    }
 
-    #[derive(PartialEq, Eq)]
-    enum Type {
-        /// The points have a linear property. The first point
-        /// and the last points are almost the same(The difference is < 0.1).
-        Linear,
-        /// When the delta of the last point is much lower than
-        /// the first point, it is a logarithmic chart.
-        Logarithm,
-        /// When the delta of the last point is much more than
-        /// the first point, it is an exponential chart.
-        Exp,
+    /// The amount that this operation costs per increase in one `noop` time.
+    fn amount(&self) -> f64 {
+        (self.x as f64) / (self.y as f64)
     }
+}
+
+/// Splits the points into `segments` parts with the same number of points and
+/// returns the breakpoints of the piecewise-linear model. The first and the last
+/// breakpoints are the first and the last points.
+fn piecewise_linear_fit(points: &[Point], segments: usize) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_unstable_by_key(|p| p.x);
+    let last = points.len().saturating_sub(1);
+    let segments = segments.clamp(1, last.max(1));
+
+    let mut breakpoints = (0..=segments)
+        .map(|i| points[i * last / segments])
+        .collect::<Vec<_>>();
+    breakpoints.dedup_by_key(|p| p.x);
+    breakpoints
+}
+
+/// The amount of elements per one `noop` time between two breakpoints.
+fn segment_amount(start: Point, end: Point) -> f64 {
+    let dx = end.x.saturating_sub(start.x) as f64;
+    let dy = end.y.saturating_sub(start.y) as f64;
+    dx / dy
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+/// The type of the chart of the dependent measurements.
+enum Type {
+    /// The points have a linear property. The first point
+    /// and the last points are almost the same(The difference is < 0.1).
+    Linear,
+    /// When the delta of the last point is much lower than
+    /// the first point, it is a logarithmic chart.
+    Logarithm,
+    /// When the delta of the last point is much more than
+    /// the first point, it is an exponential chart. It is approximated
+    /// by the piecewise-linear model.
+    Exp,
+}
+
+#[derive(Debug, Clone, Serialize)]
+/// The estimation of the dependent cost for the machine-readable report.
+struct CostReport {
+    name: String,
+    model: Type,
+    cost: DependentCost,
+    /// The linear fit of the points without outliers.
+    fit: Option<LinearFit>,
+    points: Vec<PointReport>,
+    /// The breakpoints of the piecewise-linear model of the exponential chart.
+    breakpoints: Vec<(u64, u64)>,
+}
 
-    #[derive(Debug, Clone, Copy)]
-    struct Point {
-        /// Number of elements for the opcode.
-        x: u64,
-        /// Time in `noop`s required to process `x` elements.
-        ///
-        /// Note: If the time to process `noop` opcode is `20` nanoseconds
-        /// and the time required to process `x` elements is `140`,
-        /// the `y` is 7 in this case.
-        y: u64,
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+/// The measured point of the dependent cost.
+struct PointReport {
+    /// Number of elements for the opcode.
+    x: u64,
+    /// Time in `noop`s required to process `x` elements.
+    y: u64,
+    /// The residual of the linear fit.
+    residual: Option<f64>,
+    /// Whether the point was rejected as an outlier.
+    outlier: bool,
+}
+
+/// Writes the reports in the CSV format if the extension of the `path`
+/// is `csv` and in the JSON format otherwise.
+fn write_reports(path: &PathBuf, reports: &[CostReport]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut writer = BufWriter::new(file);
+    if path.extension().is_some_and(|ext| ext == "csv") {
+        write!(&mut writer, "{}", reports_to_csv(reports)).unwrap();
+    } else {
+        serde_json::to_writer_pretty(writer, reports).unwrap();
     }
+}
 
-    impl Point {
-        /// The price in `noop` time to process a single element.
-        fn price(&self) -> f64 {
-            (self.y as f64) / (self.x as f64)
+/// Returns one row per measured point with the fitted model of its cost.
+fn reports_to_csv(reports: &[CostReport]) -> String {
+    let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let mut csv = String::from(
+        "name,model,x,y,residual,outlier,r_squared,slope,slope_interval,intercept,intercept_interval\n",
+    );
+    for report in reports {
+        let model = serde_json::to_value(report.model).unwrap();
+        let fit = report.fit;
+        for point in &report.points {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{}\n",
+                report.name,
+                model.as_str().unwrap(),
+                point.x,
+                point.y,
+                optional(point.residual),
+                point.outlier,
+                optional(fit.map(|fit| fit.r_squared)),
+                optional(fit.map(|fit| fit.slope)),
+                optional(fit.map(|fit| fit.slope_interval)),
+                optional(fit.map(|fit| fit.intercept)),
+                optional(fit.map(|fit| fit.intercept_interval)),
+            ));
         }
+    }
+    csv
+}
 
-        /// The amount that this operation costs per increase in one `noop` time.
-        fn amount(&self) -> f64 {
-            (self.x as f64) / (self.y as f64)
+fn dependent_cost(
+    name: &String,
+    x_y: Vec<(u64, u64)>,
+    segments: usize,
+    strict: bool,
+    min_r_squared: f64,
+) -> CostReport {
+    const NEAR_LINEAR: f64 = 0.1;
+
+    let (x_y, outliers) = reject_outliers(name, x_y);
+    let fit = LinearFit::new(&x_y);
+    let mut points = x_y
+        .iter()
+        .map(|&(x, y)| PointReport {
+            x,
+            y,
+            residual: fit.map(|fit| fit.residual((x, y))),
+            outlier: false,
+        })
+        .chain(outliers.into_iter().map(|(x, y)| PointReport {
+            x,
+            y,
+            residual: fit.map(|fit| fit.residual((x, y))),
+            outlier: true,
+        }))
+        .collect::<Vec<_>>();
+    points.sort_unstable_by_key(|p| p.x);
+    let mut breakpoints = vec![];
+
+    if let Some(fit) = fit {
+        eprintln!("{name}: {fit}");
+        if fit.r_squared < min_r_squared {
+            let message = format!(
+                "The linear fit of the {} has R² {:.4} below {}. \
+                The measurements are too noisy to be used as a cost:\n {:?}",
+                name, fit.r_squared, min_r_squared, x_y
+            );
+            if strict {
+                panic!("{message}");
+            }
+            eprintln!("{message}");
         }
     }
 
@@ -703,15 +1547,31 @@ fn dependent_cost(name: &String, x_y: Vec<(u64, u64)>) -> DependentCost {
                 .unwrap();
             (base, amount)
         }
-        Type::Logarithm | Type::Exp => {
-            if expected_type == Type::Exp {
-                println!(
-                    "The {} is exponential. We don't support exponential chart. \
-                The opcode should be limited with upper bound. \n {:?}",
-                    name, x_y
-                );
+        Type::Exp => {
+            let piecewise = piecewise_linear_fit(&x_y, segments);
+            breakpoints = piecewise.iter().map(|p| (p.x, p.y)).collect();
+            let message = format!(
+                "The {} is not linear. We don't support non-linear charts. \
+                The opcode should be limited with upper bound. \
+                The breakpoints of the piecewise-linear model:\n {:?}",
+                name, piecewise
+            );
+            if strict {
+                panic!("{message}");
             }
-
+            eprintln!("{message}");
+
+            // The steepest segment is the worst scenario,
+            // and we use it to charge every element.
+            let base = piecewise.first().unwrap().y;
+            let amount = piecewise
+                .windows(2)
+                .map(|segment| segment_amount(segment[0], segment[1]))
+                .min_by(|a, b| a.partial_cmp(b).unwrap())
+                .unwrap_or(last.amount());
+            (base, amount)
+        }
+        Type::Logarithm => {
             // The logarithm function slows down fast, and the point where it becomes more
             // linear is the base point. After this point we use linear strategy.
             let last = x_y.last().unwrap().amount();
@@ -735,7 +1595,7 @@ fn dependent_cost(name: &String, x_y: Vec<(u64, u64)>) -> DependentCost {
         }
     };
 
-    if amount > 1.0 {
+    let cost = if amount > 1.0 {
         DependentCost::LightOperation {
             base,
             units_per_gas: amount as u64,
@@ -743,8 +1603,17 @@ fn dependent_cost(name: &String, x_y: Vec<(u64, u64)>) -> DependentCost {
     } else {
         DependentCost::HeavyOperation {
             base,
-            gas_per_unit: (1.0 / amount) as u64,
+            gas_per_unit: ((1.0 / amount) as u64).max(1),
         }
+    };
+
+    CostReport {
+        name: name.clone(),
+        model: expected_type,
+        cost,
+        fit,
+        points,
+        breakpoints,
     }
 }
 
@@ -802,6 +1671,10 @@ mod tests {
 
         let mut state = State {
             all: true,
+            segments: DEFAULT_SEGMENTS,
+            strict: false,
+            min_r_squared: DEFAULT_MIN_R_SQUARED,
+            unit_machine: None,
             baseline: "".into(),
             ids: Default::default(),
             throughput: Default::default(),
@@ -842,6 +1715,10 @@ mod tests {
 
         let mut state = State {
             all: false,
+            segments: DEFAULT_SEGMENTS,
+            strict: false,
+            min_r_squared: DEFAULT_MIN_R_SQUARED,
+            unit_machine: None,
             baseline: "noop".into(),
             ids: Default::default(),
             throughput: Default::default(),
@@ -876,6 +1753,260 @@ mod tests {
         assert!(output.status.success());
     }
 
+    #[test]
+    fn chain_config_gas_costs_use_defaults_for_missing_keys() {
+        let input = r#"
+        {"reason":"benchmark-complete","id":"noop","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[],"mean":{"estimate":10.0,"lower_bound":10.0,"upper_bound":10.0,"unit":"ns"},"median":{"estimate":10.0,"lower_bound":10.0,"upper_bound":10.0,"unit":"ns"}}
+        {"reason":"group-complete","group_name":"noop","benchmarks":["noop"],"report_directory":""}
+        {"reason":"benchmark-complete","id":"add","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[],"mean":{"estimate":70.0,"lower_bound":70.0,"upper_bound":70.0,"unit":"ns"},"median":{"estimate":70.0,"lower_bound":70.0,"upper_bound":70.0,"unit":"ns"}}
+        {"reason":"group-complete","group_name":"add","benchmarks":["add"],"report_directory":""}
+        {"reason":"benchmark-complete","id":"mcp/10000","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[{"per_iteration":10000,"unit":"bytes"}], "mean":{"estimate":200.0,"lower_bound":200.0,"upper_bound":200.0,"unit":"ns"},"median":{"estimate":200.0,"lower_bound":200.0,"upper_bound":200.0,"unit":"ns"}}
+        {"reason":"benchmark-complete","id":"mcp/100000","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[{"per_iteration":100000,"unit":"bytes"}], "mean":{"estimate":2000.0,"lower_bound":2000.0,"upper_bound":2000.0,"unit":"ns"},"median":{"estimate":2000.0,"lower_bound":2000.0,"upper_bound":2000.0,"unit":"ns"}}
+        {"reason":"group-complete","group_name":"mcp","benchmarks":["mcp/10000","mcp/100000"],"report_directory":""}
+        "#;
+
+        let mut state = State {
+            all: false,
+            segments: DEFAULT_SEGMENTS,
+            strict: false,
+            min_r_squared: DEFAULT_MIN_R_SQUARED,
+            unit_machine: None,
+            baseline: "noop".into(),
+            ids: Default::default(),
+            throughput: Default::default(),
+            groups: Default::default(),
+        };
+        for line in input.lines() {
+            extract_state(line, &mut state, false);
+        }
+
+        let defaults = GasCostsValues::default();
+        let gas_costs = state.to_chain_config_gas_costs();
+
+        assert_eq!(gas_costs.add, 7);
+        assert!(matches!(
+            gas_costs.mcp,
+            fuel_core_types::fuel_tx::DependentCost::LightOperation { base: 20, .. }
+        ));
+        assert_eq!(gas_costs.call, defaults.call);
+        assert_eq!(gas_costs.bal, defaults.bal);
+
+        let diff = gas_costs_diff(&defaults, &gas_costs);
+        assert!(diff.contains(&format!("add: {} -> 7", defaults.add)));
+        assert!(diff.iter().any(|line| line.starts_with("mcp: ")));
+        assert!(!diff.iter().any(|line| line.starts_with("call: ")));
+    }
+
+    const EXPONENTIAL: [(u64, u64); 5] = [(1, 1), (2, 2), (3, 4), (4, 8), (5, 16)];
+
+    #[test]
+    fn piecewise_linear_fit_returns_breakpoints() {
+        let points = EXPONENTIAL.map(|(x, y)| Point { x, y });
+
+        let breakpoints = piecewise_linear_fit(&points, 2)
+            .into_iter()
+            .map(|p| (p.x, p.y))
+            .collect::<Vec<_>>();
+
+        assert_eq!(breakpoints, vec![(1, 1), (3, 4), (5, 16)]);
+    }
+
+    #[test]
+    fn exponential_dependent_cost_uses_the_steepest_segment() {
+        let cost =
+            dependent_cost(&"exp".to_string(), EXPONENTIAL.to_vec(), 2, false, 0.0).cost;
+
+        assert_eq!(
+            cost,
+            DependentCost::HeavyOperation {
+                base: 1,
+                gas_per_unit: 6,
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The exp is not linear")]
+    fn exponential_dependent_cost_fails_in_strict_mode() {
+        dependent_cost(&"exp".to_string(), EXPONENTIAL.to_vec(), 2, true, 0.0);
+    }
+
+    #[test]
+    fn compare_reports_changes_and_failures() {
+        let old = r#"
+        add: 2
+        mod: 4
+        bal: 100
+        mcp: !LightOperation
+          base: 10
+          units_per_gas: 100
+        "#;
+        let new = r#"
+        {
+          "gas_costs": {
+            "add": 3,
+            "mod_op": 4,
+            "sub": 2,
+            "mcp": { "HeavyOperation": { "base": 10, "gas_per_unit": 2 } }
+          }
+        }
+        "#;
+        let dir = tempfile::TempDir::new().unwrap();
+        let old_path = dir.path().join("old.yaml");
+        let new_path = dir.path().join("new.json");
+        std::fs::write(&old_path, old).unwrap();
+        std::fs::write(&new_path, new).unwrap();
+
+        let comparison = Comparison::new(read_costs(&old_path), read_costs(&new_path));
+
+        assert_eq!(
+            comparison
+                .changes
+                .iter()
+                .map(|c| (c.name.as_str(), c.percent()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add", 50.0),
+                ("mcp.base", 0.0),
+                ("mcp.per_unit", 19900.0),
+                ("mod", 0.0),
+            ]
+        );
+        assert_eq!(comparison.removed, vec!["bal".to_string()]);
+        assert_eq!(comparison.added, vec!["sub".to_string()]);
+        assert!(comparison.failures(None, None).is_empty());
+        assert_eq!(
+            comparison.failures(Some(60.0), None),
+            vec!["mcp.per_unit: 0.01 -> 2 (+19900.00%)".to_string()]
+        );
+        assert_eq!(comparison.failures(Some(20.0), None).len(), 2);
+        assert!(comparison.failures(None, Some(1.0)).is_empty());
+    }
+
+    #[test]
+    fn linear_fit_reports_quality_and_intervals() {
+        let exact = LinearFit::new(&[(1, 12), (2, 14), (3, 16), (4, 18)]).unwrap();
+        assert_eq!(exact.slope, 2.0);
+        assert_eq!(exact.intercept, 10.0);
+        assert_eq!(exact.r_squared, 1.0);
+        assert_eq!(exact.slope_interval, 0.0);
+
+        let noisy =
+            LinearFit::new(&[(1, 10), (2, 30), (3, 5), (4, 40), (5, 12)]).unwrap();
+        assert!(noisy.r_squared < DEFAULT_MIN_R_SQUARED);
+        assert!(noisy.slope_interval > noisy.slope.abs());
+
+        assert!(LinearFit::new(&[(1, 10), (1, 20)]).is_none());
+    }
+
+    #[test]
+    fn reject_outliers_removes_far_points() {
+        let x_y = vec![
+            (1000, 10),
+            (2000, 21),
+            (3000, 30),
+            (4000, 39),
+            (5000, 500),
+            (6000, 60),
+            (7000, 71),
+        ];
+
+        let (inliers, outliers) = reject_outliers("test", x_y);
+
+        assert_eq!(outliers, vec![(5000, 500)]);
+        assert_eq!(inliers.len(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "The linear fit of the noisy has R²")]
+    fn noisy_dependent_cost_fails_in_strict_mode() {
+        let x_y = vec![(1, 10), (2, 30), (3, 5), (4, 40), (5, 12)];
+        dependent_cost(
+            &"noisy".to_string(),
+            x_y,
+            DEFAULT_SEGMENTS,
+            true,
+            DEFAULT_MIN_R_SQUARED,
+        );
+    }
+
+    #[test]
+    fn costs_are_normalized_against_unit_machine() {
+        let input = r#"
+        {"reason":"benchmark-complete","id":"noop","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[],"mean":{"estimate":10.0,"lower_bound":10.0,"upper_bound":10.0,"unit":"ns"},"median":{"estimate":10.0,"lower_bound":10.0,"upper_bound":10.0,"unit":"ns"}}
+        {"reason":"group-complete","group_name":"noop","benchmarks":["noop"],"report_directory":""}
+        {"reason":"benchmark-complete","id":"add","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[],"mean":{"estimate":70.0,"lower_bound":70.0,"upper_bound":70.0,"unit":"ns"},"median":{"estimate":70.0,"lower_bound":70.0,"upper_bound":70.0,"unit":"ns"}}
+        {"reason":"group-complete","group_name":"add","benchmarks":["add"],"report_directory":""}
+        {"reason":"benchmark-complete","id":"calibration","report_directory":"","iteration_count":[],"measured_values":[],"unit":"ns","throughput":[],"mean":{"estimate":200.0,"lower_bound":200.0,"upper_bound":200.0,"unit":"ns"},"median":{"estimate":200.0,"lower_bound":200.0,"upper_bound":200.0,"unit":"ns"}}
+        "#;
+        let unit_machine = UnitMachine {
+            profile: HardwareProfile {
+                cpu_model: Some("unit".to_string()),
+                cpu_frequency_mhz: Some(1000),
+                storage: StorageKind::Nvme,
+            },
+            baseline: "noop".to_string(),
+            baseline_ns: 20,
+            calibration: "calibration".to_string(),
+            calibration_ns: 100,
+        };
+
+        let mut state = State {
+            baseline: "noop".into(),
+            ..Default::default()
+        };
+        for line in input.lines() {
+            extract_state(line, &mut state, false);
+        }
+        let local = state.clone().into_costs();
+        state.unit_machine = Some(unit_machine);
+        let normalized = state.into_costs();
+
+        // The machine is twice slower than the unit machine,
+        // so the base line is `20 * 2 = 40` nanoseconds.
+        assert!(matches!(local.0.get("add"), Some(Cost::Relative(7))));
+        assert!(matches!(normalized.0.get("add"), Some(Cost::Relative(2))));
+    }
+
+    #[test]
+    fn report_contains_points_model_and_residuals() {
+        let x_y = vec![
+            (1000, 10),
+            (2000, 20),
+            (3000, 30),
+            (4000, 40),
+            (5000, 500),
+            (6000, 60),
+        ];
+
+        let report = dependent_cost(
+            &"mcp".to_string(),
+            x_y,
+            DEFAULT_SEGMENTS,
+            false,
+            DEFAULT_MIN_R_SQUARED,
+        );
+
+        assert_eq!(report.model, Type::Linear);
+        assert_eq!(report.fit.unwrap().r_squared, 1.0);
+        assert_eq!(report.points.len(), 6);
+        assert!(report.points.iter().all(|p| p.outlier == (p.x == 5000)));
+        assert_eq!(report.points[4].residual, Some(450.0));
+
+        let csv = reports_to_csv(std::slice::from_ref(&report));
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("name,model,x,y,residual,outlier"));
+        assert_eq!(lines.count(), 6);
+        assert!(csv.contains("mcp,linear,5000,500,450,true,1,"));
+
+        let json = serde_json::to_value([report]).unwrap();
+        assert_eq!(json[0]["model"], "linear");
+        assert_eq!(json[0]["points"][4]["outlier"], true);
+    }
+
     #[test]
     fn test_linear_regression() {
         assert_eq!(
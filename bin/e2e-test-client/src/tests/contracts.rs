@@ -23,6 +23,7 @@ pub async fn deploy_large_contract(ctx: &TestContext) -> Result<(), Failed> {
     let mut contract_config = ContractConfig {
         contract_id: Default::default(),
         code: bytecode,
+        code_hash: None,
         salt: rng.gen(),
         state: None,
         balances: None,
@@ -31,7 +32,7 @@ pub async fn deploy_large_contract(ctx: &TestContext) -> Result<(), Failed> {
         tx_pointer_block_height: None,
         tx_pointer_tx_idx: None,
     };
-    contract_config.calculate_contract_id();
+    contract_config.calculate_contract_id()?;
 
     let deployment_request = ctx.bob.deploy_contract(contract_config);
 
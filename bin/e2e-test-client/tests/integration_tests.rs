@@ -4,7 +4,10 @@ use fuel_core::service::{
 };
 
 // Add methods on commands
-use fuel_core::txpool::types::ContractId;
+use fuel_core::{
+    producer::CoinbaseRecipient,
+    txpool::types::ContractId,
+};
 use fuel_core_chain_config::ChainConfig;
 use fuel_core_e2e_client::config::SuiteConfig;
 use std::{
@@ -108,7 +111,7 @@ fn dev_config() -> Config {
     );
 
     config.chain_conf = chain_config;
-    config.block_producer.coinbase_recipient = Some(
+    config.block_producer.coinbase_recipient = CoinbaseRecipient::Static(
         ContractId::from_str(
             "0x7777777777777777777777777777777777777777777777777777777777777777",
         )
@@ -1,13 +1,20 @@
-use clap::Parser;
+use clap::{
+    CommandFactory,
+    Parser,
+    ValueEnum,
+};
 use std::{
     env,
     path::PathBuf,
     str::FromStr,
+    sync::OnceLock,
 };
 use tracing_subscriber::{
     filter::EnvFilter,
+    fmt::format::JsonFields,
     layer::SubscriberExt,
     registry,
+    reload,
     Layer,
 };
 
@@ -15,12 +22,19 @@ use tracing_subscriber::{
 use dotenvy::dotenv;
 
 lazy_static::lazy_static! {
-    pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
+    pub static ref DEFAULT_DATA_DIR: PathBuf = dirs::home_dir().unwrap().join(".fuel");
+    pub static ref DEFAULT_DB_PATH: PathBuf = DEFAULT_DATA_DIR.join("db");
 }
 
+pub mod chain_config;
+pub mod config;
 pub mod fee_contract;
+mod json_format;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 pub mod run;
 pub mod snapshot;
+pub mod status;
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -39,11 +53,47 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Config(config::Command),
+    ChainConfig(chain_config::Command),
+    Status(status::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
 pub const HUMAN_LOGGING: &str = "HUMAN_LOGGING";
+/// The log filter used when neither `RUST_LOG` nor `--log-filter` is set.
+pub const DEFAULT_LOG_FILTER: &str = "info";
+
+/// The format of the logs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Colored logs for the terminal.
+    Human,
+    /// One JSON object per line with the timestamp, level, target, fields and spans.
+    Json,
+}
+
+impl LogFormat {
+    /// Returns the format set by the `HUMAN_LOGGING` environment variable.
+    fn from_env() -> Self {
+        let human_logging = env::var_os(HUMAN_LOGGING)
+            .map(|s| {
+                bool::from_str(s.to_str().unwrap()).expect(
+                    "Expected `true` or `false` to be provided for `HUMAN_LOGGING`",
+                )
+            })
+            .unwrap_or(true);
+        if human_logging {
+            LogFormat::Human
+        } else {
+            LogFormat::Json
+        }
+    }
+}
+
+/// The handle to change the log filter after the initialization of the logging.
+static LOG_FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, registry::Registry>> =
+    OnceLock::new();
 
 #[cfg(feature = "env")]
 fn init_environment() -> Option<PathBuf> {
@@ -55,70 +105,119 @@ fn init_environment() -> Option<PathBuf> {
     None
 }
 
-pub async fn init_logging() -> anyhow::Result<()> {
+/// Initializes the logging in the `format`. If the `format` is not set, it is defined
+/// by the `HUMAN_LOGGING` environment variable.
+pub async fn init_logging(format: Option<LogFormat>) -> anyhow::Result<()> {
     let filter = match env::var_os(LOG_FILTER) {
         Some(_) => {
             EnvFilter::try_from_default_env().expect("Invalid `RUST_LOG` provided")
         }
-        None => EnvFilter::new("info"),
+        None => EnvFilter::new(DEFAULT_LOG_FILTER),
     };
-
-    let human_logging = env::var_os(HUMAN_LOGGING)
-        .map(|s| {
-            bool::from_str(s.to_str().unwrap())
-                .expect("Expected `true` or `false` to be provided for `HUMAN_LOGGING`")
-        })
-        .unwrap_or(true);
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    let _ = LOG_FILTER_HANDLE.set(filter_handle);
 
     let layer = tracing_subscriber::fmt::Layer::default().with_writer(std::io::stderr);
 
-    let fmt = if human_logging {
+    let fmt = match format.unwrap_or_else(LogFormat::from_env) {
         // use pretty logs
-        layer
+        LogFormat::Human => layer
             .with_ansi(true)
             .with_level(true)
             .with_line_number(true)
-            .boxed()
-    } else {
+            .boxed(),
         // use machine parseable structured logs
-        layer
+        LogFormat::Json => layer
             // disable terminal colors
             .with_ansi(false)
-            .with_level(true)
-            .with_line_number(true)
-            // use json
-            .json()
-            .boxed()
+            .fmt_fields(JsonFields::new())
+            .event_format(json_format::JsonFormat)
+            .boxed(),
     };
 
     let subscriber = registry::Registry::default() // provide underlying span data store
         .with(filter) // filter out low-level debug tracing (eg tokio executor)
         .with(fmt); // log to stdout
+    #[cfg(feature = "otlp")]
+    let subscriber = subscriber.with(otlp::layer());
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("setting global default failed");
     Ok(())
 }
 
-pub async fn run_cli() -> anyhow::Result<()> {
-    init_logging().await?;
-    if let Some(path) = init_environment() {
+/// Replaces the log filter of the initialized logging with the `filter` in
+/// the `RUST_LOG` format.
+pub fn set_log_filter(filter: &str) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(filter)?;
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("The logging is not initialized"))?;
+    handle.reload(filter)?;
+    Ok(())
+}
+
+/// Returns the log filter in the `RUST_LOG` format with the `module_levels` directives
+/// on top of the `filter`. Returns `None` if nothing is set.
+pub fn log_filter_directives(
+    filter: Option<&str>,
+    module_levels: &[String],
+) -> Option<String> {
+    if filter.is_none() && module_levels.is_empty() {
+        return None
+    }
+    let directives = std::iter::once(filter.unwrap_or(DEFAULT_LOG_FILTER))
+        .chain(module_levels.iter().map(String::as_str))
+        .filter(|directive| !directive.is_empty())
+        .collect::<Vec<_>>();
+    Some(directives.join(","))
+}
+
+async fn start_logging(
+    format: Option<LogFormat>,
+    environment: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    init_logging(format).await?;
+    if let Some(path) = environment {
         let path = path.display();
         tracing::info!("Loading environment variables from {path}");
     }
-    let opt = Opt::try_parse();
+    Ok(())
+}
+
+pub async fn run_cli() -> anyhow::Result<()> {
+    // The environment is loaded before the logging, because it may configure the logging.
+    let environment = init_environment();
+    let args = config::args_with_config_file(Opt::command(), env::args_os().collect())?;
+    let opt = Opt::try_parse_from(args);
     if opt.is_err() {
-        let command = run::Command::try_parse();
+        let args = config::args_with_config_file(
+            run::Command::command(),
+            env::args_os().collect(),
+        )?;
+        let command = run::Command::try_parse_from(args);
         if let Ok(command) = command {
+            start_logging(command.log_format, environment).await?;
             tracing::warn!("This cli format for running `fuel-core` is deprecated and will be removed. Please use `fuel-core run` or use `--help` for more information");
             return run::exec(command).await
         }
     }
 
+    let log_format = match &opt {
+        Ok(Opt {
+            command: Fuel::Run(command),
+        }) => command.log_format,
+        _ => None,
+    };
+    start_logging(log_format, environment).await?;
+
     match opt {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Config(command) => config::exec(command),
+            Fuel::ChainConfig(command) => chain_config::exec(command),
+            Fuel::Status(command) => status::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
         },
         Err(e) => {
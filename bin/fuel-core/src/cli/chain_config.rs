@@ -0,0 +1,601 @@
+//! Generation of the chain configurations, so a new network doesn't require copying
+//! and hand-editing of the bundled configs.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use clap::{
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use fuel_core::{
+    chain_config::{
+        address,
+        ChainConfig,
+        CoinConfig,
+        ConsensusConfig,
+        StateConfig,
+        TESTNET_INITIAL_BALANCE,
+    },
+    types::{
+        fuel_crypto::{
+            rand::{
+                rngs::StdRng,
+                SeedableRng,
+            },
+            SecretKey,
+        },
+        fuel_tx::{
+            Address,
+            GasCosts,
+            Input,
+        },
+        fuel_types::ChainId,
+    },
+};
+use std::{
+    fmt,
+    io::{
+        self,
+        BufRead,
+        Write,
+    },
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Generate the chain configurations.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the chain config operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a new chain config from the parameters.
+    New(NewArgs),
+    /// Prints the JSON Schema of the config files for the validation and the autocomplete.
+    Schema(SchemaArgs),
+}
+
+/// The config file described by the JSON Schema.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConfigKind {
+    /// The chain config.
+    Chain,
+    /// The state config.
+    State,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct SchemaArgs {
+    /// The config file described by the schema.
+    #[clap(long = "config", value_enum, default_value = "chain")]
+    pub config: ConfigKind,
+
+    /// The path of the schema file. The schema is printed to stdout if it is not set.
+    #[clap(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+}
+
+/// The gas costs of the VM instructions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GasCostsPreset {
+    /// The benchmarked gas costs.
+    Default,
+    /// All instructions are free.
+    Free,
+    /// All instructions cost one unit of gas.
+    Unit,
+}
+
+impl GasCostsPreset {
+    fn gas_costs(self) -> GasCosts {
+        match self {
+            GasCostsPreset::Default => GasCosts::default(),
+            GasCostsPreset::Free => GasCosts::free(),
+            GasCostsPreset::Unit => GasCosts::unit(),
+        }
+    }
+}
+
+impl fmt::Display for GasCostsPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.to_possible_value().expect("No skipped variants");
+        f.write_str(value.get_name())
+    }
+}
+
+/// The account funded with the base asset at the genesis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub address: Address,
+    pub amount: u64,
+}
+
+impl FromStr for Account {
+    type Err = anyhow::Error;
+
+    /// Parses the account in the `ADDRESS:AMOUNT` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, amount) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Expected `ADDRESS:AMOUNT`, got `{s}`"))?;
+        let address = address::parse(address)?;
+        let amount = amount
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid amount `{amount}`"))?;
+        Ok(Self { address, amount })
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct NewArgs {
+    /// The name of the chain.
+    #[clap(long = "chain-name", default_value = "local")]
+    pub chain_name: String,
+
+    /// The id of the chain, used in the signatures of the transactions.
+    #[clap(long = "chain-id", default_value = "0")]
+    pub chain_id: u64,
+
+    /// The address of the PoA block producer. A new key is generated and printed
+    /// if it is not set.
+    #[clap(long = "consensus-address")]
+    pub consensus_address: Option<Address>,
+
+    /// The gas costs of the VM instructions.
+    #[clap(long = "gas-costs", value_enum, default_value_t = GasCostsPreset::Default)]
+    pub gas_costs: GasCostsPreset,
+
+    /// The maximum gas used by all transactions of the block.
+    #[clap(long = "block-gas-limit", default_value_t = ChainConfig::default().block_gas_limit)]
+    pub block_gas_limit: u64,
+
+    /// The maximum gas used by one transaction.
+    #[clap(long = "max-gas-per-tx", default_value_t = ChainConfig::default().consensus_parameters.tx_params.max_gas_per_tx)]
+    pub max_gas_per_tx: u64,
+
+    /// The account funded with the base asset at the genesis, in the
+    /// `ADDRESS:AMOUNT` format. Can be repeated.
+    #[clap(long = "account")]
+    pub accounts: Vec<Account>,
+
+    /// The number of new accounts funded with the `--account-balance` at the
+    /// genesis. The secret keys of the accounts are printed.
+    #[clap(long = "generate-accounts", default_value = "0")]
+    pub generate_accounts: usize,
+
+    /// The balance of the generated accounts.
+    #[clap(long = "account-balance", default_value_t = TESTNET_INITIAL_BALANCE)]
+    pub account_balance: u64,
+
+    /// The CSV file with the `address,amount,asset_id` rows of the coins
+    /// funded at the genesis in addition to the accounts.
+    #[clap(long = "allocations")]
+    pub allocations: Option<PathBuf>,
+
+    /// The contract deployed at the genesis from the `forc build` outputs: the binary
+    /// or the directory with it. Can be repeated.
+    #[clap(long = "contract")]
+    pub contracts: Vec<PathBuf>,
+
+    /// Asks for the values of the parameters in the terminal. The values of the
+    /// other options are used as the defaults.
+    #[clap(short = 'i', long = "interactive")]
+    pub interactive: bool,
+
+    /// The path of the created chain config.
+    #[clap(short = 'o', long = "output", default_value = "chain_config.json")]
+    pub output: PathBuf,
+
+    /// Overwrites the output file if it exists.
+    #[clap(short = 'f', long = "force")]
+    pub force: bool,
+}
+
+/// The key generated for the chain config.
+#[derive(Debug, Clone)]
+pub struct GeneratedKey {
+    /// What the key is used for.
+    pub role: &'static str,
+    pub secret: SecretKey,
+    pub address: Address,
+}
+
+impl GeneratedKey {
+    fn new(role: &'static str, rng: &mut StdRng) -> Self {
+        let secret = SecretKey::random(rng);
+        let address = Input::owner(&secret.public_key());
+        Self {
+            role,
+            secret,
+            address,
+        }
+    }
+}
+
+pub fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::New(mut args) => {
+            if args.interactive {
+                let stdin = io::stdin();
+                prompt_args(&mut args, &mut stdin.lock(), &mut io::stderr())?;
+            }
+            if args.output.exists() && !args.force {
+                return Err(anyhow!(
+                    "The file {} already exists, use `--force` to overwrite it",
+                    args.output.display()
+                ))
+            }
+
+            let (config, keys) = new_chain_config(&args, &mut StdRng::from_entropy())?;
+            let json = serde_json::to_string_pretty(&config)?;
+            std::fs::write(&args.output, json).with_context(|| {
+                format!(
+                    "Failed to write the chain config to {}",
+                    args.output.display()
+                )
+            })?;
+
+            println!("Chain config is written to {}", args.output.display());
+            for key in keys {
+                println!(
+                    "{}: address {}, secret {:#x}",
+                    key.role,
+                    address::to_checksum(&key.address),
+                    key.secret
+                );
+            }
+            Ok(())
+        }
+        SubCommands::Schema(args) => {
+            let schema = match args.config {
+                ConfigKind::Chain => ChainConfig::json_schema(),
+                ConfigKind::State => StateConfig::json_schema(),
+            };
+            let json = serde_json::to_string_pretty(&schema)?;
+            match &args.output {
+                Some(output) => std::fs::write(output, json).with_context(|| {
+                    format!("Failed to write the schema to {}", output.display())
+                })?,
+                None => println!("{json}"),
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Creates the chain config from the `args`. Returns the config and the keys generated
+/// for it.
+pub fn new_chain_config(
+    args: &NewArgs,
+    rng: &mut StdRng,
+) -> anyhow::Result<(ChainConfig, Vec<GeneratedKey>)> {
+    if args.block_gas_limit < args.max_gas_per_tx {
+        return Err(anyhow!(
+            "The block gas limit {} is less than the max gas per transaction {}",
+            args.block_gas_limit,
+            args.max_gas_per_tx
+        ))
+    }
+
+    let mut keys = vec![];
+    let signing_key = match args.consensus_address {
+        Some(address) => address,
+        None => {
+            let key = GeneratedKey::new("consensus key", rng);
+            let address = key.address;
+            keys.push(key);
+            address
+        }
+    };
+
+    let mut accounts = args.accounts.clone();
+    for _ in 0..args.generate_accounts {
+        let key = GeneratedKey::new("account", rng);
+        accounts.push(Account {
+            address: key.address,
+            amount: args.account_balance,
+        });
+        keys.push(key);
+    }
+
+    let mut config = ChainConfig {
+        chain_name: args.chain_name.clone(),
+        block_gas_limit: args.block_gas_limit,
+        consensus: ConsensusConfig::PoA {
+            signing_key,
+            block_time: None,
+        },
+        ..ChainConfig::default()
+    };
+    let params = &mut config.consensus_parameters;
+    params.chain_id = ChainId::new(args.chain_id);
+    params.gas_costs = args.gas_costs.gas_costs();
+    params.tx_params.max_gas_per_tx = args.max_gas_per_tx;
+
+    let mut coins = accounts
+        .into_iter()
+        .map(|account| CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: account.address,
+            amount: account.amount,
+            asset_id: ChainConfig::BASE_ASSET,
+        })
+        .collect::<Vec<_>>();
+    if let Some(allocations) = &args.allocations {
+        let state = StateConfig::from_csv_allocations(allocations)?;
+        coins.extend(state.coins.into_iter().flatten());
+    }
+
+    let mut state = StateConfig::default();
+    if !coins.is_empty() {
+        state.coins = Some(coins);
+    }
+    for contract in &args.contracts {
+        state.add_contract_artifact(contract).with_context(|| {
+            format!("Failed to add the contract {}", contract.display())
+        })?;
+    }
+    if state != StateConfig::default() {
+        config.initial_state = Some(state);
+    }
+
+    Ok((config, keys))
+}
+
+/// Asks for the values of the `args` line by line. An empty answer keeps the current
+/// value.
+fn prompt_args(
+    args: &mut NewArgs,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> anyhow::Result<()> {
+    args.chain_name = prompt(input, output, "Chain name", &args.chain_name)?;
+    args.chain_id = prompt(input, output, "Chain id", &args.chain_id)?;
+
+    let consensus_address = args
+        .consensus_address
+        .map(|address| format!("{address:#x}"))
+        .unwrap_or_else(|| "generate".to_string());
+    let consensus_address: String =
+        prompt(input, output, "Consensus address", &consensus_address)?;
+    args.consensus_address = match consensus_address.as_str() {
+        "generate" => None,
+        consensus_address => Some(address::parse(consensus_address)?),
+    };
+
+    let gas_costs: String = prompt(
+        input,
+        output,
+        "Gas costs (default, free, unit)",
+        &args.gas_costs,
+    )?;
+    args.gas_costs =
+        GasCostsPreset::from_str(&gas_costs, true).map_err(|e| anyhow!(e))?;
+    args.block_gas_limit =
+        prompt(input, output, "Block gas limit", &args.block_gas_limit)?;
+    args.max_gas_per_tx = prompt(input, output, "Max gas per tx", &args.max_gas_per_tx)?;
+
+    let accounts = args
+        .accounts
+        .iter()
+        .map(|account| format!("{:#x}:{}", account.address, account.amount))
+        .collect::<Vec<_>>()
+        .join(",");
+    let accounts: String = prompt(
+        input,
+        output,
+        "Funded accounts (comma-separated ADDRESS:AMOUNT)",
+        &accounts,
+    )?;
+    args.accounts = accounts
+        .split(',')
+        .filter(|account| !account.trim().is_empty())
+        .map(Account::from_str)
+        .collect::<anyhow::Result<_>>()?;
+
+    args.generate_accounts = prompt(
+        input,
+        output,
+        "Number of generated accounts",
+        &args.generate_accounts,
+    )?;
+    if args.generate_accounts > 0 {
+        args.account_balance = prompt(
+            input,
+            output,
+            "Balance of generated accounts",
+            &args.account_balance,
+        )?;
+    }
+    Ok(())
+}
+
+fn prompt<T>(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    label: &str,
+    default: &impl fmt::Display,
+) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let default = default.to_string();
+    write!(output, "{label} [{default}]: ")?;
+    output.flush()?;
+
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    let answer = match line.trim() {
+        "" => default.as_str(),
+        answer => answer,
+    };
+    answer
+        .parse()
+        .map_err(|e| anyhow!("Invalid value `{answer}` of `{label}`: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core::types::fuel_tx::AssetId;
+
+    fn args(cli: &[&str]) -> NewArgs {
+        #[derive(Parser)]
+        struct Cli {
+            #[clap(flatten)]
+            args: NewArgs,
+        }
+        let cli = [&["chain-config"], cli].concat();
+        Cli::parse_from(cli).args
+    }
+
+    #[test]
+    fn chain_config_contains_the_parameters() {
+        let address = Address::from([1; 32]);
+        let args = args(&[
+            "--chain-name",
+            "devnet",
+            "--chain-id",
+            "7",
+            "--consensus-address",
+            &format!("{address:#x}"),
+            "--gas-costs",
+            "free",
+            "--block-gas-limit",
+            "2000",
+            "--max-gas-per-tx",
+            "1000",
+            "--account",
+            &format!("{address:#x}:500"),
+            "--generate-accounts",
+            "2",
+            "--account-balance",
+            "100",
+        ]);
+
+        let (config, keys) =
+            new_chain_config(&args, &mut StdRng::seed_from_u64(0)).unwrap();
+
+        assert_eq!(config.chain_name, "devnet");
+        assert_eq!(config.block_gas_limit, 2000);
+        assert_eq!(
+            config.consensus,
+            ConsensusConfig::PoA {
+                signing_key: address,
+                block_time: None,
+            }
+        );
+        let params = &config.consensus_parameters;
+        assert_eq!(params.chain_id, ChainId::new(7));
+        assert_eq!(params.gas_costs, GasCosts::free());
+        assert_eq!(params.tx_params.max_gas_per_tx, 1000);
+        let coins = config.initial_state.unwrap().coins.unwrap();
+        let balances = coins
+            .iter()
+            .map(|coin| (coin.owner, coin.amount))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            balances,
+            vec![
+                (address, 500),
+                (keys[0].address, 100),
+                (keys[1].address, 100)
+            ]
+        );
+    }
+
+    #[test]
+    fn chain_config_contains_allocations_from_csv() {
+        let address = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+        std::fs::write(
+            file.path(),
+            format!("address,amount,asset_id\n{address:#x},7,{asset_id:#x}\n"),
+        )
+        .unwrap();
+
+        let result = new_chain_config(
+            &args(&["--allocations", file.path().to_str().unwrap()]),
+            &mut StdRng::seed_from_u64(0),
+        );
+
+        let coins = result.unwrap().0.initial_state.unwrap().coins.unwrap();
+        assert_eq!(coins.len(), 1);
+        assert_eq!(
+            (coins[0].owner, coins[0].amount, coins[0].asset_id),
+            (address, 7, asset_id)
+        );
+    }
+
+    #[test]
+    fn chain_config_contains_contracts_from_artifacts() {
+        let file = tempfile::Builder::new().suffix(".bin").tempfile().unwrap();
+        std::fs::write(file.path(), [1, 2, 3, 4]).unwrap();
+
+        let result = new_chain_config(
+            &args(&["--contract", file.path().to_str().unwrap()]),
+            &mut StdRng::seed_from_u64(0),
+        );
+
+        let contracts = result.unwrap().0.initial_state.unwrap().contracts.unwrap();
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].code, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn consensus_key_is_generated_if_not_set() {
+        let (config, keys) =
+            new_chain_config(&args(&[]), &mut StdRng::seed_from_u64(0)).unwrap();
+
+        assert_eq!(keys.len(), 1);
+        assert_eq!(
+            config.consensus,
+            ConsensusConfig::PoA {
+                signing_key: keys[0].address,
+                block_time: None,
+            }
+        );
+        assert_eq!(config.initial_state, None);
+    }
+
+    #[test]
+    fn block_gas_limit_below_max_gas_per_tx_is_rejected() {
+        let args = args(&["--block-gas-limit", "10", "--max-gas-per-tx", "20"]);
+
+        assert!(new_chain_config(&args, &mut StdRng::seed_from_u64(0)).is_err());
+    }
+
+    #[test]
+    fn interactive_answers_override_the_defaults() {
+        let mut args = args(&["--chain-name", "devnet"]);
+        let mut input = "\n3\n\nunit\n\n\n0x0101010101010101010101010101010101010101010101010101010101010101:5\n0\n".as_bytes();
+
+        prompt_args(&mut args, &mut input, &mut io::sink()).unwrap();
+
+        assert_eq!(args.chain_name, "devnet");
+        assert_eq!(args.chain_id, 3);
+        assert_eq!(args.consensus_address, None);
+        assert_eq!(args.gas_costs, GasCostsPreset::Unit);
+        assert_eq!(
+            args.accounts,
+            vec![Account {
+                address: Address::from([1; 32]),
+                amount: 5
+            }]
+        );
+    }
+}
@@ -0,0 +1,417 @@
+//! The TOML config file with the values of the CLI options. The keys of the file are
+//! the long names of the options without the `--` prefix, for example:
+//!
+//! ```toml
+//! port = 4001
+//! utxo-validation = true
+//! reserved-nodes = ["/dns4/node-1/tcp/30333", "/dns4/node-2/tcp/30333"]
+//! ```
+//!
+//! The value from the config file is used only when the option is not set in the
+//! environment or on the command line, so the precedence is
+//! `config file < environment < command line`.
+
+use crate::cli::{
+    run,
+    Opt,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use clap::{
+    parser::ValueSource,
+    Arg,
+    ArgMatches,
+    CommandFactory,
+    Parser,
+    Subcommand,
+};
+use std::{
+    env,
+    ffi::OsString,
+    fmt,
+    path::PathBuf,
+};
+
+/// The id of the option with the path to the config file.
+const CONFIG_ARG: &str = "config";
+
+/// The options with values hidden in the output of `print-effective`.
+const SENSITIVE_ARGS: &[&str] = &["consensus-key", "keypair", "admin-token"];
+
+/// Inspect the configuration of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the config operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Prints the effective values of the options of the `run` command after merging
+    /// the config file, the environment and the command line.
+    PrintEffective(run::Command),
+}
+
+pub fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::PrintEffective(_) => {
+            let values = effective_values(Opt::command(), env::args_os().collect())?;
+            for value in values {
+                println!("{value}");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns `args` extended with the values from the config file passed via `--config`
+/// for the options which are not set in the environment or on the command line.
+/// The `args` are returned unchanged if they can't be parsed by the `cmd`, so the
+/// caller reports the error of the parsing.
+pub fn args_with_config_file(
+    cmd: clap::Command,
+    args: Vec<OsString>,
+) -> anyhow::Result<Vec<OsString>> {
+    merge_config_file(cmd, args).map(|(args, _)| args)
+}
+
+/// Returns the merged arguments and the names of the options set by the config file.
+fn merge_config_file(
+    cmd: clap::Command,
+    mut args: Vec<OsString>,
+) -> anyhow::Result<(Vec<OsString>, Vec<String>)> {
+    let Some((cmd, matches)) = leaf_command(cmd, &args) else {
+        return Ok((args, vec![]))
+    };
+    let Some(path) = matches.try_get_one::<PathBuf>(CONFIG_ARG).ok().flatten() else {
+        return Ok((args, vec![]))
+    };
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+    let table: toml::value::Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse the config file {}", path.display()))?;
+
+    let mut from_config = vec![];
+    for (name, value) in table {
+        let arg = cmd
+            .get_arguments()
+            .find(|arg| {
+                arg.get_long() == Some(name.as_str()) && arg.get_id() != CONFIG_ARG
+            })
+            .ok_or_else(|| anyhow!("Unknown option `{name}` in the config file"))?;
+        let source = matches.value_source(arg.get_id().as_str());
+        if matches!(
+            source,
+            Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+        ) {
+            continue
+        }
+        args.extend(config_value_to_args(arg, &name, value)?);
+        from_config.push(name);
+    }
+
+    Ok((args, from_config))
+}
+
+/// Parses the `args` and returns the innermost sub-command with its matches.
+fn leaf_command(
+    mut cmd: clap::Command,
+    args: &[OsString],
+) -> Option<(clap::Command, ArgMatches)> {
+    let mut matches = cmd.clone().try_get_matches_from(args).ok()?;
+    while let Some((name, sub_matches)) = matches.remove_subcommand() {
+        cmd = cmd.find_subcommand(&name)?.clone();
+        matches = sub_matches;
+    }
+    cmd.build();
+    Some((cmd, matches))
+}
+
+fn config_value_to_args(
+    arg: &Arg,
+    name: &str,
+    value: toml::Value,
+) -> anyhow::Result<Vec<OsString>> {
+    let flag = format!("--{name}");
+    if !arg.get_action().takes_values() {
+        let toml::Value::Boolean(enabled) = value else {
+            return Err(anyhow!(
+                "The option `{name}` in the config file must be a boolean"
+            ))
+        };
+        return Ok(enabled.then(|| flag.into()).into_iter().collect())
+    }
+
+    let values = match value {
+        toml::Value::Array(values) => values
+            .into_iter()
+            .map(|value| config_value_to_string(name, value))
+            .collect::<anyhow::Result<Vec<_>>>()?,
+        value => vec![config_value_to_string(name, value)?],
+    };
+    if values.is_empty() {
+        return Ok(vec![])
+    }
+
+    let args = match arg.get_value_delimiter() {
+        Some(delimiter) => {
+            vec![format!("{flag}={}", values.join(&delimiter.to_string()))]
+        }
+        None => values
+            .into_iter()
+            .map(|value| format!("{flag}={value}"))
+            .collect(),
+    };
+    Ok(args.into_iter().map(Into::into).collect())
+}
+
+fn config_value_to_string(name: &str, value: toml::Value) -> anyhow::Result<String> {
+    match value {
+        toml::Value::String(value) => Ok(value),
+        toml::Value::Integer(value) => Ok(value.to_string()),
+        toml::Value::Float(value) => Ok(value.to_string()),
+        toml::Value::Boolean(value) => Ok(value.to_string()),
+        toml::Value::Datetime(value) => Ok(value.to_string()),
+        toml::Value::Array(_) | toml::Value::Table(_) => Err(anyhow!(
+            "The option `{name}` in the config file has a nested value"
+        )),
+    }
+}
+
+/// The effective value of the option with the source of the value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveValue {
+    pub name: String,
+    pub value: toml::Value,
+    pub source: &'static str,
+}
+
+impl fmt::Display for EffectiveValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {} # {}", self.name, self.value, self.source)
+    }
+}
+
+/// Returns the effective values of the options of the `run` command from the `args`
+/// of the process. Both `fuel-core run` and the deprecated format without the
+/// sub-command are supported.
+pub fn run_effective_values(args: Vec<OsString>) -> anyhow::Result<Vec<EffectiveValue>> {
+    effective_values(Opt::command(), args.clone())
+        .or_else(|_| effective_values(run::Command::command(), args))
+}
+
+/// Returns the effective values of the options of the innermost sub-command after
+/// merging the config file, the environment and the command line.
+fn effective_values(
+    cmd: clap::Command,
+    args: Vec<OsString>,
+) -> anyhow::Result<Vec<EffectiveValue>> {
+    let (args, from_config) = merge_config_file(cmd.clone(), args)?;
+    let (cmd, matches) = leaf_command(cmd, &args)
+        .ok_or_else(|| anyhow!("Failed to parse the arguments"))?;
+
+    let mut values = vec![];
+    for arg in cmd.get_arguments() {
+        let (Some(name), id) = (arg.get_long(), arg.get_id().as_str()) else {
+            continue
+        };
+        if id == CONFIG_ARG {
+            continue
+        }
+        let Some(raw) = matches.try_get_raw(id).ok().flatten() else {
+            continue
+        };
+        let raw = raw
+            .map(|value| value.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        let value = if SENSITIVE_ARGS.contains(&name) {
+            toml::Value::String("<redacted>".to_string())
+        } else if !arg.get_action().takes_values() {
+            toml::Value::Boolean(raw.iter().any(|value| value == "true"))
+        } else if arg
+            .get_num_args()
+            .is_some_and(|range| range.max_values() > 1)
+            || matches!(arg.get_action(), clap::ArgAction::Append)
+        {
+            toml::Value::Array(raw.into_iter().map(toml::Value::String).collect())
+        } else {
+            toml::Value::String(raw.concat())
+        };
+
+        let source = if from_config.iter().any(|config_name| config_name == name) {
+            "config file"
+        } else {
+            match matches.value_source(id) {
+                Some(ValueSource::CommandLine) => "command line",
+                Some(ValueSource::EnvVariable) => "environment",
+                _ => "default",
+            }
+        };
+        values.push(EffectiveValue {
+            name: name.to_string(),
+            value,
+            source,
+        });
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[derive(Debug, Parser)]
+    struct TestCommand {
+        #[clap(long = "port", default_value = "4000")]
+        port: u16,
+        #[clap(long = "utxo-validation")]
+        utxo_validation: bool,
+        #[clap(long = "reserved-nodes", value_delimiter = ',')]
+        reserved_nodes: Vec<String>,
+        #[clap(long = "consensus-key")]
+        consensus_key: Option<String>,
+        #[clap(long = "config")]
+        config: Option<PathBuf>,
+    }
+
+    /// The command with the option read from the environment. Only one test uses it,
+    /// so the environment variable isn't shared between the concurrent tests.
+    #[derive(Debug, Parser)]
+    struct EnvTestCommand {
+        #[clap(long = "port", default_value = "4000", env = "FUEL_CORE_TEST_ENV_PORT")]
+        port: u16,
+        #[clap(long = "config")]
+        config: Option<PathBuf>,
+    }
+
+    fn config_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        std::iter::once("fuel-core")
+            .chain(args.iter().copied())
+            .map(Into::into)
+            .collect()
+    }
+
+    fn parse(args: Vec<OsString>) -> TestCommand {
+        let args = args_with_config_file(TestCommand::command(), args).unwrap();
+        TestCommand::try_parse_from(args).unwrap()
+    }
+
+    #[test]
+    fn config_file_sets_the_options() {
+        let file = config_file(
+            r#"
+                port = 4001
+                utxo-validation = true
+                reserved-nodes = ["a", "b"]
+            "#,
+        );
+
+        let path = file.path();
+
+        let command = parse(args(&["--config", path.to_str().unwrap()]));
+
+        assert_eq!(command.port, 4001);
+        assert!(command.utxo_validation);
+        assert_eq!(command.reserved_nodes, vec!["a", "b"]);
+        assert_eq!(command.config.as_deref(), Some(path));
+    }
+
+    #[test]
+    fn command_line_overrides_config_file() {
+        let file = config_file("port = 4001\nreserved-nodes = [\"a\"]");
+
+        let command = parse(args(&[
+            "--config",
+            file.path().to_str().unwrap(),
+            "--port",
+            "4002",
+        ]));
+
+        assert_eq!(command.port, 4002);
+        assert_eq!(command.reserved_nodes, vec!["a"]);
+    }
+
+    #[test]
+    fn environment_overrides_config_file() {
+        let file = config_file("port = 4001");
+        env::set_var("FUEL_CORE_TEST_ENV_PORT", "4003");
+
+        let args = args_with_config_file(
+            EnvTestCommand::command(),
+            args(&["--config", file.path().to_str().unwrap()]),
+        )
+        .unwrap();
+        let command = EnvTestCommand::try_parse_from(args).unwrap();
+        env::remove_var("FUEL_CORE_TEST_ENV_PORT");
+
+        assert_eq!(command.port, 4003);
+    }
+
+    #[test]
+    fn unknown_option_in_config_file_is_an_error() {
+        let file = config_file("unknown-option = 1");
+
+        let result = args_with_config_file(
+            TestCommand::command(),
+            args(&["--config", file.path().to_str().unwrap()]),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn effective_values_show_the_sources_and_hide_secrets() {
+        let file = config_file("utxo-validation = true\nconsensus-key = \"secret\"");
+
+        let values = effective_values(
+            TestCommand::command(),
+            args(&[
+                "--config",
+                file.path().to_str().unwrap(),
+                "--reserved-nodes",
+                "a,b",
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            values.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "port = \"4000\" # default",
+                "utxo-validation = true # config file",
+                "reserved-nodes = [\"a\", \"b\"] # command line",
+                "consensus-key = \"<redacted>\" # config file",
+            ]
+        );
+    }
+
+    #[test]
+    fn admin_token_of_run_command_is_redacted() {
+        let values =
+            run_effective_values(args(&["run", "--admin-token", "secret"])).unwrap();
+
+        let admin_token = values
+            .iter()
+            .find(|value| value.name == "admin-token")
+            .unwrap();
+        assert_eq!(
+            admin_token.to_string(),
+            "admin-token = \"<redacted>\" # command line"
+        );
+    }
+}
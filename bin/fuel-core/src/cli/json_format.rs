@@ -0,0 +1,189 @@
+//! The JSON format of the logs for the ingestion into Loki or Elastic. It follows the
+//! format of the [`tracing_subscriber::fmt::format::Json`], but also contains the ids
+//! of the spans, so the logs of the same request or block can be grouped together.
+//!
+//! ```json
+//! {
+//!   "timestamp": "2024-01-01T00:00:00.000000Z",
+//!   "level": "INFO",
+//!   "target": "fuel_core_importer::importer",
+//!   "fields": { "message": "Committed block" },
+//!   "span": { "id": 2, "name": "execute_and_commit", "height": 10 },
+//!   "spans": [{ "id": 1, "name": "run" }, { "id": 2, "name": "execute_and_commit", "height": 10 }]
+//! }
+//! ```
+
+use serde_json::{
+    Map,
+    Value,
+};
+use std::fmt;
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    Event,
+    Subscriber,
+};
+use tracing_subscriber::{
+    fmt::{
+        format::{
+            JsonFields,
+            Writer,
+        },
+        time::{
+            FormatTime,
+            SystemTime,
+        },
+        FmtContext,
+        FormatEvent,
+        FormattedFields,
+    },
+    registry::LookupSpan,
+};
+
+/// Formats each event as one line of JSON.
+pub struct JsonFormat;
+
+impl<S> FormatEvent<S, JsonFields> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, JsonFields>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut timestamp = String::new();
+        SystemTime.format_time(&mut Writer::new(&mut timestamp))?;
+
+        let metadata = event.metadata();
+        let mut fields = FieldsVisitor::default();
+        event.record(&mut fields);
+
+        let mut log = Map::new();
+        log.insert("timestamp".to_string(), timestamp.into());
+        log.insert("level".to_string(), metadata.level().as_str().into());
+        log.insert("target".to_string(), metadata.target().into());
+        log.insert("fields".to_string(), fields.0.into());
+
+        if let Some(scope) = ctx.event_scope() {
+            let spans = scope
+                .from_root()
+                .map(|span| {
+                    let mut json = Map::new();
+                    json.insert("id".to_string(), span.id().into_u64().into());
+                    json.insert("name".to_string(), span.name().into());
+                    let extensions = span.extensions();
+                    let span_fields = extensions
+                        .get::<FormattedFields<JsonFields>>()
+                        .and_then(|fields| {
+                            serde_json::from_str::<Map<String, Value>>(fields).ok()
+                        });
+                    json.extend(span_fields.unwrap_or_default());
+                    Value::Object(json)
+                })
+                .collect::<Vec<_>>();
+            if let Some(span) = spans.last() {
+                log.insert("span".to_string(), span.clone());
+            }
+            log.insert("spans".to_string(), spans.into());
+        }
+
+        writeln!(writer, "{}", Value::Object(log))
+    }
+}
+
+/// Collects the fields of the event into the JSON object.
+#[derive(Default)]
+struct FieldsVisitor(Map<String, Value>);
+
+impl Visit for FieldsVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}").into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io,
+        sync::{
+            Arc,
+            Mutex,
+        },
+    };
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn log_contains_fields_and_span_ids() {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .fmt_fields(JsonFields::new())
+            .event_format(JsonFormat)
+            .with_writer(buffer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("import", height = 10);
+            let _entered = span.enter();
+            tracing::info!(peers = 3, "Committed block");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let log: Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(log["level"], "INFO");
+        assert_eq!(log["target"], module_path!());
+        assert_eq!(log["fields"]["message"], "Committed block");
+        assert_eq!(log["fields"]["peers"], 3);
+        assert_eq!(log["span"]["name"], "import");
+        assert_eq!(log["span"]["height"], 10);
+        assert!(log["span"]["id"].is_u64());
+        assert_eq!(log["spans"].as_array().unwrap().len(), 1);
+    }
+}
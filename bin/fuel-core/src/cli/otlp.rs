@@ -0,0 +1,98 @@
+//! Export of the tracing spans to the OpenTelemetry collector via OTLP, so the
+//! timelines of the requests and blocks can be inspected in Jaeger or Tempo.
+
+use anyhow::Context;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    runtime,
+    trace::{
+        self,
+        Sampler,
+        Tracer,
+    },
+    Resource,
+};
+use std::sync::OnceLock;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{
+    filter::EnvFilter,
+    layer::Layered,
+    registry::Registry,
+    reload,
+    Layer,
+};
+
+/// The subscriber below the OTLP layer: the registry with the log filter and the
+/// formatting layers.
+type Subscriber = Layered<
+    Box<dyn Layer<Layered<reload::Layer<EnvFilter, Registry>, Registry>> + Send + Sync>,
+    Layered<reload::Layer<EnvFilter, Registry>, Registry>,
+>;
+
+type OtlpLayer = Option<OpenTelemetryLayer<Subscriber, Tracer>>;
+
+/// The handle to install the exporter after the initialization of the logging.
+static OTLP_HANDLE: OnceLock<reload::Handle<OtlpLayer, Subscriber>> = OnceLock::new();
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct OtlpArgs {
+    /// The gRPC endpoint of the OpenTelemetry collector, for example
+    /// `http://localhost:4317`. The spans are not exported if it is not set.
+    #[clap(long = "otlp-endpoint", env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+
+    /// The ratio of the traces exported to the collector, from `0.0` to `1.0`.
+    #[clap(long = "otlp-sampling-ratio", default_value = "1.0", env)]
+    pub otlp_sampling_ratio: f64,
+}
+
+impl OtlpArgs {
+    /// Starts the export of the spans if the endpoint is set. The `service_name` is
+    /// used to distinguish the nodes in the collector.
+    pub fn install(&self, service_name: &str) -> anyhow::Result<()> {
+        let Some(endpoint) = &self.otlp_endpoint else {
+            return Ok(())
+        };
+
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint),
+            )
+            .with_trace_config(
+                trace::config()
+                    .with_sampler(Sampler::ParentBased(Box::new(
+                        Sampler::TraceIdRatioBased(self.otlp_sampling_ratio),
+                    )))
+                    .with_resource(Resource::new(vec![KeyValue::new(
+                        "service.name",
+                        service_name.to_string(),
+                    )])),
+            )
+            .install_batch(runtime::Tokio)
+            .context("Failed to install the OTLP exporter")?;
+
+        let handle = OTLP_HANDLE
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("The logging is not initialized"))?;
+        handle.reload(Some(tracing_opentelemetry::layer().with_tracer(tracer)))?;
+        tracing::info!("Exporting the spans to the OTLP collector at {endpoint}");
+        Ok(())
+    }
+}
+
+/// Returns the layer of the subscriber which exports the spans after the
+/// [`OtlpArgs::install`].
+pub fn layer() -> reload::Layer<OtlpLayer, Subscriber> {
+    let (layer, handle) = reload::Layer::new(None);
+    let _ = OTLP_HANDLE.set(handle);
+    layer
+}
+
+/// Flushes the spans which are not exported yet.
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
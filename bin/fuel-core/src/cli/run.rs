@@ -1,8 +1,17 @@
 #![allow(unused_variables)]
 use crate::{
     cli::{
-        run::consensus::PoATriggerArgs,
-        DEFAULT_DB_PATH,
+        log_filter_directives,
+        run::{
+            consensus::{
+                GasTargetAutotuningArgs,
+                PoATriggerArgs,
+            },
+            keystore::KeystorePassword,
+        },
+        set_log_filter,
+        LogFormat,
+        DEFAULT_DATA_DIR,
     },
     FuelService,
 };
@@ -16,21 +25,54 @@ use fuel_core::{
         default_consensus_dev_key,
         ChainConfig,
     },
-    producer::Config as ProducerConfig,
+    database::migration::MigrationConfig,
+    fuel_core_graphql_api::dry_run_limiter::DryRunLimits,
+    producer::{
+        CoinbaseRecipient,
+        Config as ProducerConfig,
+    },
     service::{
-        config::Trigger,
+        admin::{
+            Config as AdminConfig,
+            LogFilterSetter,
+        },
+        config::{
+            RestartPolicy,
+            RestartableService,
+            SystemTime,
+            Trigger,
+        },
+        disk_usage::Config as DiskUsageConfig,
         Config,
         DbType,
+        ExecutionProfiler,
+        NodeMode,
         RelayerConsensusConfig,
         ServiceTrait,
         VMConfig,
     },
-    txpool::Config as TxPoolConfig,
+    txpool::{
+        policy::{
+            AllowedOwners,
+            Policy,
+        },
+        Config as TxPoolConfig,
+    },
     types::{
-        blockchain::primitives::SecretKeyWrapper,
-        fuel_tx::ContractId,
+        blockchain::primitives::{
+            BlockId,
+            SecretKeyWrapper,
+        },
+        fuel_tx::{
+            Address,
+            ContractId,
+        },
         fuel_vm::SecretKey,
         secrecy::Secret,
+        services::{
+            block_importer::Finality,
+            executor::ReceiptsLimits,
+        },
     },
 };
 use pyroscope::{
@@ -46,6 +88,7 @@ use std::{
     net,
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
 };
 use tracing::{
     info,
@@ -54,16 +97,29 @@ use tracing::{
 };
 
 pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
+/// The name of the file inside of the RocksDB directory with the transactions of
+/// the `TxPool` saved during the shutdown.
+const TXPOOL_PERSISTENCE_FILE: &str = "txpool.json";
+/// The name of the file inside of the RocksDB directory with the addresses of
+/// the peers saved during the shutdown.
+#[cfg(feature = "p2p")]
+const PEERS_PERSISTENCE_FILE: &str = "peers.json";
 // Default database cache is 1 GB
 const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
+// Default number of the cached contract state slots and balances
+const DEFAULT_STATE_CACHE_CAPACITY: usize = 100_000;
 
 #[cfg(feature = "p2p")]
 mod p2p;
 
 mod consensus;
+mod data_dir;
+mod dev;
+mod keystore;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod reload;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -86,14 +142,29 @@ pub struct Command {
     )]
     pub max_database_cache_size: usize,
 
+    /// The number of the contract state slots and balances cached in memory
+    /// in front of the database. The zero disables the cache.
+    #[arg(
+        long = "state-cache-capacity",
+        default_value_t = DEFAULT_STATE_CACHE_CAPACITY,
+        env
+    )]
+    pub state_cache_capacity: usize,
+
+    /// The directory with the data of the node. The data of each chain is stored
+    /// in the `<DATA_DIR>/<CHAIN_ID>` directory.
     #[clap(
-        name = "DB_PATH",
-        long = "db-path",
+        long = "data-dir",
         value_parser,
-        default_value = (*DEFAULT_DB_PATH).to_str().unwrap(),
+        default_value = (*DEFAULT_DATA_DIR).to_str().unwrap(),
         env
     )]
-    pub database_path: PathBuf,
+    pub data_dir: PathBuf,
+
+    /// The path to the database. By default, the database is stored in
+    /// the `<DATA_DIR>/<CHAIN_ID>/db` directory.
+    #[clap(name = "DB_PATH", long = "db-path", value_parser, env)]
+    pub database_path: Option<PathBuf>,
 
     #[clap(
         long = "db-type",
@@ -104,6 +175,15 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// Runs the migrations of the existing database without committing the
+    /// changes and exits. The node isn't started.
+    #[arg(long = "db-migration-dry-run", env)]
+    pub database_migration_dry_run: bool,
+
+    /// The directory for the backup of the database made before the migrations.
+    #[arg(long = "db-backup-path", value_parser, env)]
+    pub database_backup_path: Option<PathBuf>,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -120,10 +200,83 @@ pub struct Command {
     #[arg(long = "debug", env)]
     pub debug: bool,
 
+    /// Runs a one-command local development node: the in-memory database, the
+    /// `local_testnet` chain with funded accounts, instant block production, and
+    /// the debug mode. The endpoint, the consensus key and the funded accounts
+    /// are printed on the start.
+    #[arg(
+        long = "dev",
+        env,
+        conflicts_with_all = ["CHAIN_CONFIG", "DB_PATH", "database_type", "instant", "period"]
+    )]
+    pub dev: bool,
+
+    /// The role of the node. The `read-only` node only synchronizes the blocks and
+    /// serves the queries: it doesn't produce blocks, rejects the submitted
+    /// transactions, and doesn't gossip anything.
+    #[arg(
+        long = "mode",
+        default_value = "full",
+        value_enum,
+        ignore_case = true,
+        conflicts_with = "dev",
+        env
+    )]
+    pub mode: NodeMode,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// The number of threads used to validate non-conflicting transactions
+    /// of the imported blocks in parallel. The signatures of all transactions
+    /// of the block are recovered in parallel on the rayon thread pool. `1` means
+    /// sequential execution.
+    #[arg(long = "execution-threads", default_value = "1", env)]
+    pub execution_threads: usize,
+
+    /// Record the changes made to the state by each block(created and spent coins,
+    /// modified contracts' storage slots and balances). The diff is available via
+    /// the `stateDiff` GraphQL query.
+    #[arg(long = "record-state-diff", env)]
+    pub record_state_diff: bool,
+
+    /// Dump the gas and wall-time spent per opcode and per called contract for each
+    /// executed block into logs. The gas per opcode and contract is available only
+    /// if the node is built with the `profile-gas` feature.
+    #[arg(long = "vm-profiling", env)]
+    pub vm_profiling: bool,
+
+    /// The max number of receipts produced by one transaction. Transactions above
+    /// the limit are not included into produced blocks and fail the dry run.
+    #[arg(long = "vm-max-tx-receipts", env)]
+    pub vm_max_tx_receipts: Option<usize>,
+
+    /// The max total number of bytes logged by one transaction with the `LOGD`
+    /// instruction. Transactions above the limit are not included into produced
+    /// blocks and fail the dry run.
+    #[arg(long = "vm-max-tx-log-data", env)]
+    pub vm_max_tx_log_data: Option<u64>,
+
+    /// Skips the verification of the signatures of the inputs while replaying the
+    /// blocks up to the trusted finalized checkpoint, set as `HEIGHT:BLOCK_ID`.
+    /// The signatures are skipped only when the stored block at the checkpoint
+    /// height has the same id, and the validation fails if the ids differ.
+    /// Use only with the checkpoint of the trusted source.
+    #[arg(
+        long = "skip-signatures-until",
+        value_name = "HEIGHT:BLOCK_ID",
+        value_parser = parse_checkpoint,
+        env
+    )]
+    pub skip_signatures_until: Option<(u32, BlockId)>,
+
+    /// The number of the predicates evaluated successfully during the insertion
+    /// into the `TxPool`, which are not evaluated again in the produced and
+    /// imported blocks. `0` disables the cache.
+    #[arg(long = "predicate-cache-size", default_value = "10000", env)]
+    pub predicate_cache_size: usize,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
@@ -138,15 +291,48 @@ pub struct Command {
     #[arg(long = "consensus-key", env)]
     pub consensus_key: Option<String>,
 
+    /// The path to the password-encrypted keystore file with the signing key used when
+    /// producing blocks. The keystore is generated by `fuel-core-keygen new --keystore`.
+    #[arg(long = "consensus-keystore", env, conflicts_with = "consensus_key")]
+    pub consensus_keystore: Option<PathBuf>,
+
+    /// The file with the password of the keystore files. If not set, the password is
+    /// read from the `FUEL_KEYSTORE_PASSWORD` env var or prompted.
+    #[arg(long = "keystore-password-file", env)]
+    pub keystore_password_file: Option<PathBuf>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
 
-    /// The block's fee recipient public key.
-    ///
-    /// If not set, `consensus_key` is used as the provider of the `Address`.
-    #[arg(long = "coinbase-recipient", env)]
-    pub coinbase_recipient: Option<String>,
+    #[clap(flatten)]
+    pub gas_target_autotuning: GasTargetAutotuningArgs,
+
+    /// Adds the randomness beacon to the produced blocks. The beacon is the hash of
+    /// the signature of the block height made by the consensus key. It is verified
+    /// by all nodes and gives applications the random value that the block producer
+    /// can't choose. The producer knows the future values, so it is suitable for devnets.
+    #[arg(long = "poa-beacon", env)]
+    pub poa_beacon: bool,
+
+    /// The recipient of the fees of the produced blocks: the `ContractId`, the comma
+    /// separated list of `ContractId`s receiving the fees of the blocks in turn,
+    /// or `signer` for the contract with the id equal to the address of the consensus key.
+    /// With the `--utxo-validation`, the contracts must be deployed, otherwise
+    /// the node fails to start.
+    #[arg(long = "coinbase-recipient", value_parser = parse_coinbase_recipient, env)]
+    pub coinbase_recipient: Option<CoinbaseRecipient>,
+
+    /// The soft gas target of the produced blocks. The block producer selects the
+    /// transactions up to the target, while the blocks of other producers are only
+    /// limited by the block gas limit of the chain.
+    #[arg(long = "block-gas-target", env)]
+    pub block_gas_target: Option<u64>,
+
+    /// The max number of cached results of the dry run. The result is reused for
+    /// the same transactions until the next block. The zero disables the cache.
+    #[arg(long = "dry-run-cache-size", default_value = "1024", env)]
+    pub dry_run_cache_size: usize,
 
     #[cfg_attr(feature = "relayer", clap(flatten))]
     #[cfg(feature = "relayer")]
@@ -185,6 +371,21 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// How long the transaction submitted after another transaction waits for it
+    /// to enter the `TxPool`, if the inputs of the transaction are not found.
+    #[clap(long = "tx-dependency-wait-timeout", default_value = "10s", env)]
+    pub tx_dependency_wait_timeout: humantime::Duration,
+
+    /// The owners allowed to spend coins and messages in the transactions accepted
+    /// by the `TxPool`. All owners are allowed if not set.
+    #[clap(long = "tx-allowed-owners", value_delimiter = ',', env)]
+    pub tx_allowed_owners: Vec<String>,
+
+    /// Enables the static checks of the submitted scripts, like the jumps outside
+    /// of the script and the infinite loops. The failed scripts are rejected by the `TxPool`.
+    #[arg(long = "tx-script-static-checks", env)]
+    pub tx_script_static_checks: bool,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -197,12 +398,132 @@ pub struct Command {
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
 
-    /// Timeout before drop the request.
+    /// How long the final statuses of the transactions not included in the blocks,
+    /// like squeezed out ones, are available via the GraphQL API.
+    #[clap(long = "tx-status-archive-retention", default_value = "24h", env)]
+    pub tx_status_archive_retention: humantime::Duration,
+
+    /// The number of the latest blocks with the transactions and receipts available.
+    /// The bodies of the older blocks are pruned, while the headers and the data
+    /// required for the message proofs are kept. The pruned node can't serve
+    /// the transactions of the old blocks to the peers syncing the history.
+    /// Nothing is pruned if not set.
+    #[clap(long = "block-body-retention", env)]
+    pub block_body_retention: Option<u32>,
+
+    /// The number of the blocks on top of the block after which it is final.
+    /// The bodies of the non-final blocks are never pruned, and the message proofs
+    /// are only generated against the final blocks.
+    #[clap(long = "finality-confirmations", default_value = "0", env)]
+    pub finality_confirmations: u32,
+
+    /// How often the disk usage of the databases is measured.
+    #[clap(long = "disk-usage-interval", default_value = "60s", env)]
+    pub disk_usage_interval: humantime::Duration,
+
+    /// The node warns about the low disk space when the available space of the volume
+    /// with the databases falls below the threshold in bytes.
+    #[clap(long = "low-disk-space-threshold", default_value = "1073741824", env)]
+    pub low_disk_space_threshold: u64,
+
+    /// Pauses the non-critical writes, like the DA-compressed blocks and the archived
+    /// transaction statuses, while the disk space is low.
+    #[clap(long = "pause-writes-on-low-disk-space", env)]
+    pub pause_writes_on_low_disk_space: bool,
+
+    /// Disables the write-ahead log of the databases until the block at the height of
+    /// the trusted finalized checkpoint is imported. It speeds up the resync of the node,
+    /// but the crash of the node before the checkpoint may corrupt the databases.
+    #[clap(long = "fast-sync-unsafe", value_name = "CHECKPOINT_HEIGHT", env)]
+    pub fast_sync_unsafe: Option<u32>,
+
+    /// The number of blocks after which the pagination cursors of the GraphQL API
+    /// expire. The following pages of the expired pagination are rejected.
+    #[clap(long = "graphql-cursor-expiry", default_value = "10000", env)]
+    pub graphql_cursor_expiry: u32,
+
+    /// The maximum number of the dry runs of the GraphQL API executed at the same time.
+    #[clap(long = "dry-run-max-concurrent", default_value = "8", env)]
+    pub dry_run_max_concurrent: usize,
+
+    /// The maximum script gas of all dry runs in progress.
+    #[clap(long = "dry-run-max-gas", default_value = "1000000000", env)]
+    pub dry_run_max_gas: u64,
+
+    /// The maximum script gas of the dry runs in progress requested from one IP address.
+    #[clap(long = "dry-run-max-gas-per-ip", default_value = "300000000", env)]
+    pub dry_run_max_gas_per_ip: u64,
+
+    /// The maximum time of the dry run, including the waiting for the turn.
+    #[clap(long = "dry-run-timeout", default_value = "30s", env)]
+    pub dry_run_timeout: humantime::Duration,
+
+    /// The address of the admin API with the operational endpoints, like the peer
+    /// management and the manual block production.
+    #[clap(long = "admin-addr", default_value = "127.0.0.1:4001", env)]
+    pub admin_addr: net::SocketAddr,
+
+    /// The bearer token of the admin API. The admin API is disabled if not set.
+    #[clap(long = "admin-token", env)]
+    pub admin_token: Option<String>,
+
+    /// Timeout before drop the request. The request also stops iterating over
+    /// the database after the timeout, and its queued dry runs are skipped.
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The maximum time to wait for the graceful shutdown of the node after `SIGTERM`
+    /// or `SIGINT`. The process exits without waiting for the services after the timeout.
+    #[clap(long = "shutdown-timeout", default_value = "30s", env)]
+    pub shutdown_timeout: humantime::Duration,
+
+    /// The services restarted with the backoff after a panic instead of stopping
+    /// the node. The number of restarts is reported by the `<service>_restarts` metric.
+    #[clap(long = "restart-on-panic", value_enum, value_delimiter = ',', env)]
+    pub restart_on_panic: Vec<RestartableService>,
+
+    /// The backoff before the restart of the service after the first panic.
+    /// It doubles after each consecutive panic up to the `restart-max-backoff`.
+    #[clap(long = "restart-initial-backoff", default_value = "1s", env)]
+    pub restart_initial_backoff: humantime::Duration,
+
+    /// The max backoff between the restarts of the service after panics.
+    #[clap(long = "restart-max-backoff", default_value = "1m", env)]
+    pub restart_max_backoff: humantime::Duration,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
+
+    #[cfg_attr(feature = "otlp", clap(flatten))]
+    #[cfg(feature = "otlp")]
+    pub otlp_args: crate::cli::otlp::OtlpArgs,
+
+    /// The filter of the logs in the `RUST_LOG` format, for example `info,fuel_core=debug`.
+    #[clap(long = "log-filter", env = "RUST_LOG")]
+    pub log_filter: Option<String>,
+
+    /// The log levels of the specific modules on top of the `log-filter`, for
+    /// example `fuel_core_txpool=debug,fuel_core_p2p=warn`.
+    #[clap(
+        long = "log-module-level",
+        value_delimiter = ',',
+        value_parser = parse_module_level,
+        env
+    )]
+    pub log_module_levels: Vec<String>,
+
+    /// The format of the logs. If not set, the logs are human-readable unless the
+    /// `HUMAN_LOGGING` environment variable is `false`.
+    #[clap(long = "log-format", value_enum, env)]
+    pub log_format: Option<LogFormat>,
+
+    /// The path to the TOML file with the values of the options. The keys of the file
+    /// are the long names of the options without the `--` prefix. The values from the
+    /// environment and the command line take precedence over the file. The node
+    /// re-reads the file on `SIGHUP` and applies the options which are safe to change
+    /// at runtime: `log-filter` and `min-gas-price`.
+    #[clap(long = "config", env = "FUEL_CORE_CONFIG")]
+    pub config: Option<PathBuf>,
 }
 
 impl Command {
@@ -212,16 +533,35 @@ impl Command {
             port,
             service_name: name,
             max_database_cache_size,
+            state_cache_capacity,
+            data_dir,
             database_path,
             database_type,
+            database_migration_dry_run,
+            database_backup_path,
             chain_config,
             vm_backtrace,
+            execution_threads,
+            record_state_diff,
+            vm_profiling,
+            vm_max_tx_receipts,
+            vm_max_tx_log_data,
+            skip_signatures_until,
+            predicate_cache_size,
             debug,
+            dev,
+            mode,
             utxo_validation,
             min_gas_price,
             consensus_key,
+            consensus_keystore,
+            keystore_password_file,
             poa_trigger,
+            gas_target_autotuning,
+            poa_beacon,
             coinbase_recipient,
+            block_gas_target,
+            dry_run_cache_size,
             #[cfg(feature = "relayer")]
             relayer_args,
             #[cfg(feature = "p2p")]
@@ -235,24 +575,74 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_dependency_wait_timeout,
+            tx_allowed_owners,
+            tx_script_static_checks,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
+            tx_status_archive_retention,
+            block_body_retention,
+            finality_confirmations,
+            disk_usage_interval,
+            low_disk_space_threshold,
+            pause_writes_on_low_disk_space,
+            fast_sync_unsafe,
+            graphql_cursor_expiry,
+            dry_run_max_concurrent,
+            dry_run_max_gas,
+            dry_run_max_gas_per_ip,
+            dry_run_timeout,
+            admin_addr,
+            admin_token,
             api_request_timeout,
+            shutdown_timeout: _,
+            restart_on_panic,
+            restart_initial_backoff,
+            restart_max_backoff,
             profiling: _,
+            #[cfg(feature = "otlp")]
+                otlp_args: _,
+            log_filter: _,
+            log_module_levels: _,
+            log_format: _,
+            config: _,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
+        let (database_type, debug) = if dev {
+            (DbType::InMemory, true)
+        } else {
+            (database_type, debug)
+        };
+
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let database_path = database_path.unwrap_or_else(|| {
+            data_dir::database_path(&data_dir, &chain_conf.consensus_parameters.chain_id)
+        });
 
         #[cfg(feature = "relayer")]
         let relayer_cfg = relayer_args.into_config();
 
+        let mut keystore_password = KeystorePassword::new(keystore_password_file);
+
+        #[cfg(feature = "p2p")]
+        let persist_peers = p2p_args.persist_peers;
         #[cfg(feature = "p2p")]
-        let p2p_cfg = p2p_args.into_config(chain_conf.chain_name.clone(), metrics)?;
+        let p2p_cfg = p2p_args.into_config(
+            chain_conf.chain_name.clone(),
+            metrics,
+            &mut keystore_password,
+        )?;
 
-        let trigger: Trigger = poa_trigger.into();
+        let trigger: Trigger = if mode.is_read_only() {
+            Trigger::Never
+        } else if dev {
+            Trigger::Instant
+        } else {
+            poa_trigger.into()
+        };
 
         if trigger != Trigger::Never {
             info!("Block production mode: {:?}", &trigger);
@@ -260,7 +650,10 @@ impl Command {
             info!("Block production disabled");
         }
 
-        let consensus_key = load_consensus_key(consensus_key)?;
+        let consensus_key = match consensus_keystore {
+            Some(path) => Some(Secret::new(keystore_password.decrypt(&path)?.into())),
+            None => load_consensus_key(consensus_key)?,
+        };
         if consensus_key.is_some() && trigger == Trigger::Never {
             warn!("Consensus key configured but block production is disabled!");
         }
@@ -279,14 +672,26 @@ impl Command {
             }
         });
 
-        let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
-            Some(
-                ContractId::from_str(coinbase_recipient.as_str())
-                    .map_err(|err| anyhow!(err))?,
-            )
-        } else {
+        let coinbase_recipient = coinbase_recipient.unwrap_or_else(|| {
             tracing::warn!("The coinbase recipient `ContractId` is not set!");
+            CoinbaseRecipient::None
+        });
+
+        if let Some((height, block_id)) = skip_signatures_until {
+            warn!(
+                "The signatures of the inputs are not verified for the blocks up to \
+                the trusted checkpoint {block_id:#x} at the height {height}"
+            );
+        }
+
+        let txpool_policy = if tx_allowed_owners.is_empty() {
             None
+        } else {
+            let owners = tx_allowed_owners
+                .iter()
+                .map(|owner| Address::from_str(owner).map_err(|err| anyhow!(err)))
+                .collect::<anyhow::Result<_>>()?;
+            Some(Policy::new(AllowedOwners(owners)))
         };
 
         let verifier = RelayerConsensusConfig {
@@ -294,36 +699,73 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
-        let block_importer =
+        let mut block_importer =
             fuel_core::service::config::fuel_core_importer::Config::new(&chain_conf);
+        block_importer.finality = Finality::Confirmations(finality_confirmations);
+
+        let txpool_persistence_path = (database_type == DbType::RocksDb)
+            .then(|| database_path.join(TXPOOL_PERSISTENCE_FILE));
+        #[cfg(feature = "p2p")]
+        let peers_persistence_path = (persist_peers && database_type == DbType::RocksDb)
+            .then(|| database_path.join(PEERS_PERSISTENCE_FILE));
 
         let config = Config {
             addr,
             api_request_timeout: api_request_timeout.into(),
             max_database_cache_size,
+            state_cache_capacity,
             database_path,
             database_type,
+            txpool_persistence_path,
+            database_migration: MigrationConfig {
+                dry_run: database_migration_dry_run,
+                backup_path: database_backup_path,
+            },
             chain_conf: chain_conf.clone(),
+            state_reader: None,
             debug,
             utxo_validation,
+            mode,
             block_production: trigger,
+            clock: Arc::new(SystemTime),
+            gas_target_autotuning: gas_target_autotuning.into_config(),
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                execution_threads,
+                record_state_diff,
+                profiler: vm_profiling.then(|| ExecutionProfiler::new(true)),
+                receipts_limits: ReceiptsLimits {
+                    max_receipts: vm_max_tx_receipts,
+                    max_log_data_bytes: vm_max_tx_log_data,
+                },
+                skip_signatures_until: skip_signatures_until
+                    .map(|(height, block_id)| (height.into(), block_id)),
+                predicate_cache_size,
+                #[cfg(feature = "fault-injection")]
+                fault_injector: None,
+            },
+            txpool: TxPoolConfig {
+                policy: txpool_policy,
+                script_static_checks: tx_script_static_checks,
+                ..TxPoolConfig::new(
+                    tx_max_number,
+                    tx_max_depth,
+                    chain_conf,
+                    min_gas_price,
+                    utxo_validation,
+                    metrics,
+                    tx_pool_ttl.into(),
+                    tx_number_active_subscriptions,
+                    tx_dependency_wait_timeout.into(),
+                )
             },
-            txpool: TxPoolConfig::new(
-                tx_max_number,
-                tx_max_depth,
-                chain_conf,
-                min_gas_price,
-                utxo_validation,
-                metrics,
-                tx_pool_ttl.into(),
-                tx_number_active_subscriptions,
-            ),
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
                 metrics,
+                dry_run_cache_size,
+                block_gas_target,
+                ..Default::default()
             },
             block_importer,
             #[cfg(feature = "relayer")]
@@ -332,20 +774,60 @@ impl Command {
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
+            #[cfg(feature = "p2p")]
+            peers_persistence_path,
             consensus_key,
+            poa_beacon,
             name,
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            tx_status_archive_retention: tx_status_archive_retention.into(),
+            block_body_retention,
+            disk_usage: DiskUsageConfig {
+                interval: disk_usage_interval.into(),
+                low_space_threshold: low_disk_space_threshold,
+                pause_non_critical_writes: pause_writes_on_low_disk_space,
+            },
+            fast_sync_unsafe_until: fast_sync_unsafe.map(Into::into),
+            cursor_expiry: graphql_cursor_expiry,
+            dry_run_limits: DryRunLimits {
+                max_concurrent: dry_run_max_concurrent,
+                max_gas: dry_run_max_gas,
+                max_gas_per_ip: dry_run_max_gas_per_ip,
+                timeout: dry_run_timeout.into(),
+            },
+            admin: admin_token.map(|token| AdminConfig {
+                addr: admin_addr,
+                token: Secret::new(token),
+                log_filter: Some(LogFilterSetter(Arc::new(set_log_filter))),
+            }),
+            restart_on_panic,
+            restart_policy: RestartPolicy::OnPanic {
+                initial_backoff: restart_initial_backoff.into(),
+                max_backoff: restart_max_backoff.into(),
+            },
         };
         Ok(config)
     }
 }
 
 pub async fn exec(command: Command) -> anyhow::Result<()> {
+    if let Some(log_filter) =
+        log_filter_directives(command.log_filter.as_deref(), &command.log_module_levels)
+    {
+        set_log_filter(&log_filter)?;
+    }
+    #[cfg(feature = "otlp")]
+    command.otlp_args.install(&command.service_name)?;
     let profiling = command.profiling.clone();
+    let shutdown_timeout = command.shutdown_timeout;
+    let dev = command.dev;
     let config = command.get_config()?;
+    if config.database_migration.dry_run {
+        return dry_run_migrations(&config)
+    }
 
     // start profiling agent if url is configured
     let _profiling_agent = start_pyroscope_agent(profiling, &config)?;
@@ -354,20 +836,112 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     info!("Fuel Core version v{}", env!("CARGO_PKG_VERSION"));
     trace!("Initializing in TRACE mode.");
     // initialize the server
+    let consensus_public_key = dev::consensus_public_key(&config);
     let server = FuelService::new_node(config).await?;
+    if dev {
+        println!(
+            "{}",
+            dev::banner(server.bound_address, consensus_public_key)
+        );
+    }
     // pause the main task while service is running
     tokio::select! {
         result = server.await_stop() => {
             result?;
         }
         _ = shutdown_signal() => {}
+        result = reload::reload_on_signal(&server) => {
+            result?;
+        }
+    }
+
+    match tokio::time::timeout(shutdown_timeout.into(), server.stop_and_await()).await {
+        Ok(result) => {
+            result?;
+        }
+        Err(_) => {
+            warn!("The node is not stopped within {shutdown_timeout}, exiting without waiting");
+        }
     }
+    #[cfg(feature = "otlp")]
+    crate::cli::otlp::shutdown();
 
-    server.stop_and_await().await?;
+    Ok(())
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+fn dry_run_migrations(config: &Config) -> anyhow::Result<()> {
+    Err(anyhow!(
+        "Rocksdb must be enabled to migrate the database at {}",
+        config.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn dry_run_migrations(config: &Config) -> anyhow::Result<()> {
+    use fuel_core::combined_database::CombinedDatabase;
 
+    if !config.database_path.exists() {
+        info!(
+            "There is no database at {}, nothing to migrate",
+            config.database_path.display()
+        );
+        return Ok(())
+    }
+    let database =
+        CombinedDatabase::open(&config.database_path, config.max_database_cache_size)?;
+    let migrations = database.migrate(&config.database_migration)?;
+    if migrations.is_empty() {
+        info!("The database is up to date, nothing to migrate");
+    } else {
+        info!("The migrations succeeded: {}", migrations.join(", "));
+    }
     Ok(())
 }
 
+/// Parses the log level of the module in the `target=level` format.
+fn parse_module_level(directive: &str) -> anyhow::Result<String> {
+    let (target, level) = directive
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected `target=level`, got `{directive}`"))?;
+    if target.is_empty() {
+        return Err(anyhow!("The target of `{directive}` is empty"))
+    }
+    tracing::level_filters::LevelFilter::from_str(level)
+        .map_err(|_| anyhow!("Unknown log level `{level}` of `{directive}`"))?;
+    Ok(directive.to_string())
+}
+
+/// Parses the trusted checkpoint in the format of the `--skip-signatures-until`.
+fn parse_checkpoint(value: &str) -> anyhow::Result<(u32, BlockId)> {
+    let (height, block_id) = value
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Expected `HEIGHT:BLOCK_ID`, got `{value}`"))?;
+    let height = height
+        .parse()
+        .map_err(|_| anyhow!("Invalid checkpoint height `{height}`"))?;
+    let block_id = BlockId::from_str(block_id)
+        .map_err(|_| anyhow!("Invalid checkpoint block id `{block_id}`"))?;
+    Ok((height, block_id))
+}
+
+/// Parses the recipient of the fees in the format of the `--coinbase-recipient`.
+fn parse_coinbase_recipient(value: &str) -> anyhow::Result<CoinbaseRecipient> {
+    if value == "signer" {
+        return Ok(CoinbaseRecipient::Signer)
+    }
+    let contract_ids = value
+        .split(',')
+        .map(|contract_id| {
+            ContractId::from_str(contract_id.trim()).map_err(|err| anyhow!(err))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    match contract_ids.as_slice() {
+        [contract_id] => Ok(CoinbaseRecipient::Static(*contract_id)),
+        _ => Ok(CoinbaseRecipient::Rotating(contract_ids)),
+    }
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
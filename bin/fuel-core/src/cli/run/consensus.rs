@@ -4,7 +4,10 @@ use clap::{
     ArgGroup,
     ValueEnum,
 };
-use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core::service::config::{
+    GasTargetAutotuning,
+    Trigger as PoATrigger,
+};
 use humantime::Duration;
 
 #[derive(Debug, Clone, clap::Args)]
@@ -63,6 +66,40 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+pub struct GasTargetAutotuningArgs {
+    /// Enables the automatic adjustment of the gas target of produced blocks.
+    /// The target is decreased when the production of the block takes more time
+    /// than the budget and increased when full blocks are produced within half
+    /// of the budget. The target never exceeds the block gas limit of the chain.
+    #[clap(long = "block-execution-time-budget", env)]
+    pub execution_time_budget: Option<Duration>,
+
+    /// The lowest gas target of produced blocks with enabled autotuning.
+    #[clap(long = "min-block-gas-target", default_value = "0", env)]
+    pub min_gas_target: u64,
+
+    /// The percent of the gas target by which it changes after each produced block.
+    #[clap(
+        long = "block-gas-target-adjustment-percent",
+        default_value = "10",
+        value_parser = clap::value_parser!(u8).range(1..=100),
+        env
+    )]
+    pub adjustment_percent: u8,
+}
+
+impl GasTargetAutotuningArgs {
+    pub fn into_config(self) -> Option<GasTargetAutotuning> {
+        self.execution_time_budget
+            .map(|execution_time_budget| GasTargetAutotuning {
+                min_gas_target: self.min_gas_target,
+                execution_time_budget: execution_time_budget.into(),
+                adjustment_percent: self.adjustment_percent,
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,6 +112,8 @@ mod tests {
     pub struct Command {
         #[clap(flatten)]
         trigger: PoATriggerArgs,
+        #[clap(flatten)]
+        gas_target_autotuning: GasTargetAutotuningArgs,
     }
 
     #[test_case(&[] => Ok(Trigger::Instant); "defaults to instant trigger")]
@@ -86,4 +125,19 @@ mod tests {
             .map_err(|_| ())
             .map(|c| c.trigger.into())
     }
+
+    #[test_case(&[] => Ok(None); "autotuning is disabled by default")]
+    #[test_case(&["", "--block-execution-time-budget=500ms", "--min-block-gas-target=1000"] => Ok(Some(GasTargetAutotuning {
+        min_gas_target: 1000,
+        execution_time_budget: StdDuration::from_millis(500),
+        adjustment_percent: 10,
+    })); "autotuning is enabled by the budget")]
+    #[test_case(&["", "--block-execution-time-budget=1s", "--block-gas-target-adjustment-percent=0"] => Err(()); "zero adjustment is not allowed")]
+    fn parse_gas_target_autotuning(
+        args: &[&str],
+    ) -> Result<Option<GasTargetAutotuning>, ()> {
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.gas_target_autotuning.into_config())
+    }
 }
@@ -0,0 +1,66 @@
+//! The layout of the data directory. The data of each chain is stored in its own
+//! `<DATA_DIR>/<CHAIN_ID>` directory, so several networks can run on one host.
+
+use fuel_core::types::fuel_types::ChainId;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use tracing::warn;
+
+/// The name of the database directory inside of the chain directory.
+const DATABASE_DIR: &str = "db";
+
+/// Returns the path of the database of the `chain_id` inside of the `data_dir`.
+///
+/// The database from the layout without chain directories, `<DATA_DIR>/db`, is
+/// still used if it exists. The chain id recorded in it prevents opening it
+/// for another chain.
+pub fn database_path(data_dir: &Path, chain_id: &ChainId) -> PathBuf {
+    let legacy_path = data_dir.join(DATABASE_DIR);
+    if legacy_path.exists() {
+        warn!(
+            "Using the database at {} shared by all chains, move it to {} to isolate the chain",
+            legacy_path.display(),
+            chain_dir(data_dir, chain_id).join(DATABASE_DIR).display()
+        );
+        return legacy_path
+    }
+    chain_dir(data_dir, chain_id).join(DATABASE_DIR)
+}
+
+fn chain_dir(data_dir: &Path, chain_id: &ChainId) -> PathBuf {
+    data_dir.join(u64::from(*chain_id).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn database_path_is_namespaced_by_chain_id() {
+        let dir = TempDir::new().unwrap();
+        let data_dir = dir.path();
+
+        assert_eq!(
+            database_path(data_dir, &ChainId::new(0)),
+            data_dir.join("0").join("db")
+        );
+        assert_eq!(
+            database_path(data_dir, &ChainId::new(7)),
+            data_dir.join("7").join("db")
+        );
+    }
+
+    #[test]
+    fn existing_legacy_database_is_used() {
+        let dir = TempDir::new().unwrap();
+        let data_dir = dir.path();
+        std::fs::create_dir_all(data_dir.join("db")).unwrap();
+
+        let path = database_path(data_dir, &ChainId::new(7));
+
+        assert_eq!(path, data_dir.join("db"));
+    }
+}
@@ -0,0 +1,82 @@
+//! The startup output of the node running with `--dev`.
+
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        TESTNET_INITIAL_BALANCE,
+        TESTNET_WALLET_SECRETS,
+    },
+    service::Config,
+    types::{
+        fuel_crypto::PublicKey,
+        fuel_tx::Address,
+        fuel_vm::SecretKey,
+        secrecy::ExposeSecret,
+    },
+};
+use std::{
+    fmt::Write,
+    net::SocketAddr,
+    ops::Deref,
+    str::FromStr,
+};
+
+/// Returns the public key of the block producer configured for the node.
+pub fn consensus_public_key(config: &Config) -> Option<PublicKey> {
+    config
+        .consensus_key
+        .as_ref()
+        .map(|key| key.expose_secret().deref().public_key())
+}
+
+/// Returns the human-readable summary of the dev node with the endpoint and the
+/// funded accounts of the `local_testnet` chain.
+pub fn banner(
+    bound_address: SocketAddr,
+    consensus_public_key: Option<PublicKey>,
+) -> String {
+    let mut banner = String::new();
+    let _ = writeln!(banner, "Fuel Core dev node");
+    let _ = writeln!(banner, "==================");
+    let _ = writeln!(banner, "GraphQL endpoint:  http://{bound_address}/graphql");
+    let _ = writeln!(
+        banner,
+        "Playground:        http://{bound_address}/playground"
+    );
+    if let Some(public_key) = consensus_public_key {
+        let _ = writeln!(banner, "Consensus key:     {public_key}");
+    }
+    let _ = writeln!(banner, "Block production:  instant");
+    let _ = writeln!(banner);
+    let _ = writeln!(
+        banner,
+        "Funded accounts ({TESTNET_INITIAL_BALANCE} of the base asset {:#x} each):",
+        ChainConfig::BASE_ASSET
+    );
+    for (i, secret) in TESTNET_WALLET_SECRETS.iter().enumerate() {
+        let secret = SecretKey::from_str(secret).expect("Expected valid secret");
+        let address = Address::from(*secret.public_key().hash());
+        let _ = writeln!(banner, "({i}) Address:     {address:#x}");
+        let _ = writeln!(banner, "    Private key: {secret:#x}");
+    }
+    let _ = write!(
+        banner,
+        "\nThe keys are publicly known, never use them outside of the local development."
+    );
+    banner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn banner_lists_the_funded_accounts() {
+        let banner = banner("127.0.0.1:4000".parse().unwrap(), None);
+
+        assert!(banner.contains("http://127.0.0.1:4000/graphql"));
+        for secret in TESTNET_WALLET_SECRETS {
+            assert!(banner.contains(secret));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use fuel_core::types::fuel_crypto::SecretKey;
+use fuel_core_keygen::{
+    decrypt_key,
+    read_password,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// The password of the encrypted keystore files. It is read from the password file,
+/// the environment or the terminal only once, when the first keystore is decrypted.
+pub struct KeystorePassword {
+    password_file: Option<PathBuf>,
+    password: Option<String>,
+}
+
+impl KeystorePassword {
+    pub fn new(password_file: Option<PathBuf>) -> Self {
+        Self {
+            password_file,
+            password: None,
+        }
+    }
+
+    /// Decrypts the secret key from the keystore file at the `path`.
+    pub fn decrypt(&mut self, path: &Path) -> anyhow::Result<SecretKey> {
+        let password = match &self.password {
+            Some(password) => password,
+            None => self
+                .password
+                .insert(read_password(self.password_file.as_deref())?),
+        };
+        decrypt_key(path, password)
+    }
+}
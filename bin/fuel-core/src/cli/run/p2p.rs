@@ -1,3 +1,4 @@
+use super::keystore::KeystorePassword;
 use anyhow::anyhow;
 use clap::{
     builder::ArgPredicate::IsPresent,
@@ -11,6 +12,10 @@ use fuel_core::{
             NotInitialized,
             MAX_RESPONSE_SIZE,
         },
+        gossipsub::{
+            messages::TxGossipVersion,
+            topics::TxGossipSharding,
+        },
         gossipsub_config::default_gossipsub_builder,
         heartbeat,
         Multiaddr,
@@ -20,6 +25,7 @@ use fuel_core::{
         fuel_crypto::SecretKey,
     },
 };
+use fuel_core_keygen::is_keystore;
 use std::{
     net::{
         IpAddr,
@@ -40,7 +46,8 @@ pub struct P2PArgs {
     #[clap(long = "enable-p2p", action)]
     pub enable_p2p: bool,
 
-    /// Peering secret key. Supports either a hex encoded secret key inline or a path to bip32 mnemonic encoded secret file.
+    /// Peering secret key. Supports either a hex encoded secret key inline, a path to bip32 mnemonic encoded secret file,
+    /// or a path to the password-encrypted keystore file generated by `fuel-core-keygen new --keystore`.
     #[clap(long = "keypair", env, value_parser = KeypairArg::try_from_string)]
     #[arg(required_if_eq("enable_p2p", "true"))]
     #[arg(requires_if(IsPresent, "enable_p2p"))]
@@ -76,6 +83,12 @@ pub struct P2PArgs {
     #[clap(long = "reserved-nodes", value_delimiter = ',', env)]
     pub reserved_nodes: Vec<Multiaddr>,
 
+    /// Save the addresses of the peers with the good reputation during the shutdown
+    /// and connect to them at the next start, along with the bootstrap nodes.
+    /// The peers are saved only with the RocksDB database.
+    #[clap(long = "persist-peers", env)]
+    pub persist_peers: bool,
+
     /// With this set to `true` you create a guarded node that is only ever connected to trusted, reserved nodes.    
     #[clap(long = "reserved-nodes-only-mode", env)]
     pub reserved_nodes_only_mode: bool,
@@ -144,6 +157,32 @@ pub struct P2PArgs {
     #[clap(long = "gossip-heartbeat-interval", default_value = "500ms", env)]
     pub gossip_heartbeat_interval: humantime::Duration,
 
+    /// How long `Gossipsub` remembers the ids of the seen messages to filter duplicates
+    #[clap(long = "gossip-seen-cache-ttl", default_value = "60s", env)]
+    pub gossip_seen_cache_ttl: humantime::Duration,
+
+    /// The number of the latest gossip messages remembered to ignore their duplicates
+    /// arriving after `gossip-seen-cache-ttl`
+    #[clap(long = "gossip-seen-cache-size", default_value = "10000", env)]
+    pub gossip_seen_cache_size: usize,
+
+    /// The encoding version of the published transactions. All versions are
+    /// accepted from the peers. Switch to the newer version once all nodes
+    /// of the network support it.
+    #[clap(long = "tx-gossip-version", default_value = "0", env)]
+    pub tx_gossip_version: u8,
+
+    /// The number of the shards of the transaction gossip topic. The transactions
+    /// are split between the shards by the first byte of their id. All nodes
+    /// of the network should use the same number of the shards.
+    #[clap(long = "tx-gossip-shards", default_value = "1", env)]
+    pub tx_gossip_shards: u8,
+
+    /// The shards of the transaction gossip topic relayed by the node.
+    /// The node relays all shards by default.
+    #[clap(long = "tx-gossip-subscribed-shards", value_delimiter = ',', env)]
+    pub tx_gossip_subscribed_shards: Vec<u8>,
+
     /// The maximum byte size for each gossip (default is 18 MiB)
     #[clap(long = "max-transmit-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_transmit_size: usize,
@@ -232,6 +271,7 @@ impl P2PArgs {
         self,
         network_name: String,
         metrics: bool,
+        keystore_password: &mut KeystorePassword,
     ) -> anyhow::Result<Option<Config<NotInitialized>>> {
         if !self.enable_p2p {
             tracing::info!("P2P service disabled");
@@ -240,6 +280,10 @@ impl P2PArgs {
 
         let local_keypair = {
             match self.keypair.expect("mandatory value") {
+                KeypairArg::Path(path) if is_keystore(&path) => {
+                    let secret_key = keystore_password.decrypt(&path)?;
+                    convert_to_libp2p_keypair(&mut secret_key.to_vec())?
+                }
                 KeypairArg::Path(path) => {
                     let phrase = std::fs::read_to_string(path)?;
                     let secret_key =
@@ -263,6 +307,7 @@ impl P2PArgs {
             .history_length(self.history_length)
             .history_gossip(self.history_gossip)
             .heartbeat_interval(self.gossip_heartbeat_interval.into())
+            .duplicate_cache_time(self.gossip_seen_cache_ttl.into())
             .max_transmit_size(self.max_transmit_size)
             .build()
             .expect("valid gossipsub configuration");
@@ -306,6 +351,15 @@ impl P2PArgs {
                 self.connection_idle_timeout,
             )),
             gossipsub_config,
+            gossip_seen_cache_size: self.gossip_seen_cache_size,
+            tx_gossip_version: TxGossipVersion::try_from(self.tx_gossip_version)
+                .map_err(|e| anyhow!(e))?,
+            tx_gossip_sharding: TxGossipSharding::new(
+                self.tx_gossip_shards,
+                (!self.tx_gossip_subscribed_shards.is_empty())
+                    .then_some(self.tx_gossip_subscribed_shards),
+            )
+            .map_err(|e| anyhow!(e))?,
             heartbeat_config,
             set_request_timeout: Duration::from_secs(self.request_timeout),
             set_connection_keep_alive: Duration::from_secs(self.connection_keep_alive),
@@ -0,0 +1,235 @@
+//! Reload of the configuration of the running node on `SIGHUP`. The node re-reads
+//! the config file passed via `--config` and applies the new values of the
+//! [`RELOADABLE_OPTIONS`] without a restart. The changes of other options are only
+//! reported, because they require a restart of the node.
+
+use crate::{
+    cli::{
+        config::{
+            run_effective_values,
+            EffectiveValue,
+        },
+        log_filter_directives,
+        set_log_filter,
+        DEFAULT_LOG_FILTER,
+    },
+    FuelService,
+};
+use std::env;
+use tracing::{
+    info,
+    warn,
+};
+
+/// The options which can be changed without a restart of the node.
+pub const RELOADABLE_OPTIONS: &[&str] = &[
+    LOG_FILTER_OPTION,
+    LOG_MODULE_LEVEL_OPTION,
+    MIN_GAS_PRICE_OPTION,
+];
+
+const LOG_FILTER_OPTION: &str = "log-filter";
+const LOG_MODULE_LEVEL_OPTION: &str = "log-module-level";
+const MIN_GAS_PRICE_OPTION: &str = "min-gas-price";
+
+/// The change of the configuration found during the reload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reload {
+    LogFilter(String),
+    MinGasPrice(u64),
+    RequiresRestart(String),
+}
+
+/// Reloads the configuration each time the process receives `SIGHUP`.
+/// Never returns unless the signal handler can't be installed.
+pub async fn reload_on_signal(server: &FuelService) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut sighup =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())?;
+        let mut previous = run_effective_values(env::args_os().collect())?;
+        loop {
+            sighup.recv().await;
+            info!("sighup received, reloading the configuration");
+            match reload(server, &previous) {
+                Ok(current) => previous = current,
+                Err(err) => warn!("Failed to reload the configuration: {err:?}"),
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = server;
+        std::future::pending().await
+    }
+}
+
+fn reload(
+    server: &FuelService,
+    previous: &[EffectiveValue],
+) -> anyhow::Result<Vec<EffectiveValue>> {
+    let current = run_effective_values(env::args_os().collect())?;
+    let reloads = reloads(previous, &current)?;
+    if reloads.is_empty() {
+        info!("The configuration is not changed");
+    }
+
+    for reload in reloads {
+        match reload {
+            Reload::LogFilter(filter) => {
+                set_log_filter(&filter)?;
+                info!("The log filter is changed to `{filter}`");
+            }
+            Reload::MinGasPrice(min_gas_price) => {
+                server.shared.txpool.set_min_gas_price(min_gas_price);
+                info!("The min gas price is changed to {min_gas_price}");
+            }
+            Reload::RequiresRestart(name) => {
+                warn!(
+                    "The option `{name}` is changed, but only {RELOADABLE_OPTIONS:?} \
+                    can be reloaded. Restart the node to apply it"
+                );
+            }
+        }
+    }
+
+    Ok(current)
+}
+
+/// Returns the changes between the `previous` and the `current` effective values.
+fn reloads(
+    previous: &[EffectiveValue],
+    current: &[EffectiveValue],
+) -> anyhow::Result<Vec<Reload>> {
+    let find = |values: &[EffectiveValue], name: &str| {
+        values
+            .iter()
+            .find(|value| value.name == name)
+            .map(|value| value.value.clone())
+    };
+    let removed = previous
+        .iter()
+        .filter(|value| find(current, &value.name).is_none())
+        .map(|value| (value.name.as_str(), None));
+    let changed = current
+        .iter()
+        .filter(|value| find(previous, &value.name).as_ref() != Some(&value.value))
+        .map(|value| (value.name.as_str(), Some(&value.value)));
+
+    let mut reloads = vec![];
+    let mut log_filter_changed = false;
+    for (name, value) in changed.chain(removed) {
+        let reload = match (name, value) {
+            (LOG_FILTER_OPTION | LOG_MODULE_LEVEL_OPTION, _) => {
+                log_filter_changed = true;
+                continue
+            }
+            (MIN_GAS_PRICE_OPTION, Some(toml::Value::String(min_gas_price))) => {
+                Reload::MinGasPrice(min_gas_price.parse()?)
+            }
+            (name, _) => Reload::RequiresRestart(name.to_string()),
+        };
+        reloads.push(reload);
+    }
+
+    // The log filter is built from both options, so it is reloaded once.
+    if log_filter_changed {
+        let filter = match find(current, LOG_FILTER_OPTION) {
+            Some(toml::Value::String(filter)) => Some(filter),
+            _ => None,
+        };
+        let module_levels = match find(current, LOG_MODULE_LEVEL_OPTION) {
+            Some(toml::Value::Array(levels)) => levels
+                .into_iter()
+                .filter_map(|level| level.as_str().map(str::to_string))
+                .collect(),
+            _ => vec![],
+        };
+        let filter = log_filter_directives(filter.as_deref(), &module_levels)
+            .unwrap_or_else(|| DEFAULT_LOG_FILTER.to_string());
+        reloads.push(Reload::LogFilter(filter));
+    }
+
+    Ok(reloads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(name: &str, value: &str) -> EffectiveValue {
+        EffectiveValue {
+            name: name.to_string(),
+            value: toml::Value::String(value.to_string()),
+            source: "config file",
+        }
+    }
+
+    #[test]
+    fn unchanged_configuration_has_no_reloads() {
+        let values = vec![value("min-gas-price", "1"), value("port", "4000")];
+
+        assert_eq!(reloads(&values, &values).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn reloadable_options_are_applied() {
+        let previous = vec![value("min-gas-price", "1"), value("log-filter", "info")];
+        let current = vec![value("min-gas-price", "5"), value("log-filter", "debug")];
+
+        assert_eq!(
+            reloads(&previous, &current).unwrap(),
+            vec![
+                Reload::MinGasPrice(5),
+                Reload::LogFilter("debug".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn removed_log_filter_is_reset_to_default() {
+        let previous = vec![value("log-filter", "debug")];
+
+        assert_eq!(
+            reloads(&previous, &[]).unwrap(),
+            vec![Reload::LogFilter(DEFAULT_LOG_FILTER.to_string())]
+        );
+    }
+
+    #[test]
+    fn module_levels_are_added_to_log_filter() {
+        let previous = vec![value("log-filter", "info")];
+        let current = vec![
+            value("log-filter", "info"),
+            EffectiveValue {
+                name: "log-module-level".to_string(),
+                value: toml::Value::Array(vec![
+                    toml::Value::String("fuel_core_txpool=debug".to_string()),
+                    toml::Value::String("fuel_core_p2p=warn".to_string()),
+                ]),
+                source: "config file",
+            },
+        ];
+
+        assert_eq!(
+            reloads(&previous, &current).unwrap(),
+            vec![Reload::LogFilter(
+                "info,fuel_core_txpool=debug,fuel_core_p2p=warn".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn other_options_require_restart() {
+        let previous = vec![value("port", "4000"), value("max-peers-connected", "5")];
+        let current = vec![value("port", "4001")];
+
+        assert_eq!(
+            reloads(&previous, &current).unwrap(),
+            vec![
+                Reload::RequiresRestart("port".to_string()),
+                Reload::RequiresRestart("max-peers-connected".to_string())
+            ]
+        );
+    }
+}
@@ -3,8 +3,23 @@ use clap::{
     Parser,
     Subcommand,
 };
-use fuel_core::types::fuel_types::ContractId;
-use std::path::PathBuf;
+use fuel_core::{
+    chain_config::{
+        encryption::{
+            self,
+            SnapshotKey,
+        },
+        ChainConfig,
+    },
+    types::fuel_types::ContractId,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// The name of the snapshot file inside of the snapshot directory.
+const SNAPSHOT_FILE_NAME: &str = "chain_config.json";
 
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
@@ -18,6 +33,16 @@ pub struct Command {
     )]
     database_path: PathBuf,
 
+    /// Encrypts the produced snapshot by AES-256-GCM. The key is read from the
+    /// `--encryption-key-file` or from the `FUEL_SNAPSHOT_KEY`/`FUEL_SNAPSHOT_KEY_FILE`
+    /// environment variables.
+    #[clap(long = "encrypt")]
+    encrypt: bool,
+
+    /// The path to the file with the hex key of the encrypted snapshots.
+    #[clap(long = "encryption-key-file")]
+    encryption_key_file: Option<PathBuf>,
+
     /// The sub-command of the snapshot operation.
     #[command(subcommand)]
     subcommand: SubCommands,
@@ -39,27 +64,149 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Prints the summary of the snapshot without opening the database.
+    #[command(arg_required_else_help = true)]
+    Info {
+        /// The path to the snapshot JSON file or to the directory with the
+        /// `chain_config.json` file.
+        #[clap(name = "SNAPSHOT_PATH")]
+        path: PathBuf,
+        /// The number of the largest owners and contracts to print.
+        #[clap(long = "top", default_value = "10")]
+        top: usize,
+    },
+    /// Generates a new key for the encrypted snapshots and prints it in hex.
+    Keygen,
 }
 
-#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let key_file = command.encryption_key_file.as_deref();
+    match command.subcommand {
+        SubCommands::Info { path, top } => info(&path, top, key_file),
+        SubCommands::Keygen => {
+            println!("{:#x}", SnapshotKey::generate());
+            Ok(())
+        }
+        subcommand => {
+            let key = if command.encrypt {
+                Some(snapshot_key(key_file)?)
+            } else {
+                None
+            };
+            exec_on_database(command.database_path, subcommand, key)
+        }
+    }
+}
+
+/// Returns the key from the `path` or from the environment.
+fn snapshot_key(path: Option<&Path>) -> anyhow::Result<SnapshotKey> {
+    let key = match path {
+        Some(path) => Some(SnapshotKey::from_file(path)?),
+        None => SnapshotKey::from_env()?,
+    };
+    key.ok_or_else(|| {
+        anyhow::anyhow!(
+            "The snapshot key is required, use the `--encryption-key-file` or the `{}`",
+            encryption::SNAPSHOT_KEY_ENV
+        )
+    })
+}
+
+/// Writes the snapshot `json` to the stdout, encrypted by the `key` if it is set.
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn write_snapshot(json: Vec<u8>, key: Option<&SnapshotKey>) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let bytes = match key {
+        Some(key) => key.encrypt(&json)?,
+        None => json,
+    };
+    std::io::stdout().lock().write_all(&bytes)?;
+    Ok(())
+}
+
+fn info(path: &Path, top: usize, key_file: Option<&Path>) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    let path = if path.is_dir() {
+        path.join(SNAPSHOT_FILE_NAME)
+    } else {
+        path.to_path_buf()
+    };
+    let contents = std::fs::read(&path).context(format!(
+        "failed to open snapshot at path {}",
+        path.display()
+    ))?;
+    let size = contents.len();
+    let encrypted = encryption::is_encrypted(&contents);
+    let contents = if encrypted {
+        snapshot_key(key_file)?.decrypt(&contents)?
+    } else {
+        contents
+    };
+    let config: ChainConfig = serde_json::from_slice(&contents).context(format!(
+        "failed to parse snapshot at path {}",
+        path.display()
+    ))?;
+
+    let state = config.initial_state.unwrap_or_default();
+    let summary = state.summary(&config.consensus_parameters.base_asset_id, top);
+
+    println!("Format: JSON");
+    println!("Encrypted: {encrypted}");
+    println!("Size: {size} bytes (uncompressed)");
+    println!("Chain name: {}", config.chain_name);
+    match summary.height {
+        Some(height) => println!("Height: {height}"),
+        None => println!("Height: not set"),
+    }
+    println!("Coins: {}", summary.coins);
+    println!("Messages: {}", summary.messages);
+    println!("Contracts: {}", summary.contracts);
+    println!("Contract state slots: {}", summary.contract_state_slots);
+    println!(
+        "Contract state size: {} bytes",
+        summary.contract_state_bytes
+    );
+    println!("Contract balances: {}", summary.contract_balances);
+    println!("Total supply:");
+    for (asset_id, supply) in &summary.total_supply {
+        println!("  {asset_id:#x}: {supply}");
+    }
+    println!("Top {top} owners:");
+    for (owner, asset_id, amount) in &summary.top_owners {
+        println!("  {owner:#x}: {amount} of {asset_id:#x}");
+    }
+    println!("Top {top} contracts by state size:");
+    for (contract_id, slots, bytes) in &summary.top_contracts {
+        println!("  {contract_id:#x}: {slots} slots, {bytes} bytes");
+    }
+    Ok(())
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+fn exec_on_database(
+    path: PathBuf,
+    _: SubCommands,
+    _: Option<SnapshotKey>,
+) -> anyhow::Result<()> {
     Err(anyhow::anyhow!(
         "Rocksdb must be enabled to use the database at {}",
-        command.database_path.display()
+        path.display()
     ))
 }
 
 #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
-pub async fn exec(command: Command) -> anyhow::Result<()> {
+fn exec_on_database(
+    path: PathBuf,
+    subcommand: SubCommands,
+    key: Option<SnapshotKey>,
+) -> anyhow::Result<()> {
     use anyhow::Context;
     use fuel_core::{
-        chain_config::{
-            ChainConfig,
-            StateConfig,
-        },
+        chain_config::StateConfig,
         database::Database,
     };
-    let path = command.database_path;
     let data_source = fuel_core::state::rocks_db::RocksDb::default_open(&path, None)
         .map_err(Into::<anyhow::Error>::into)
         .context(format!(
@@ -68,7 +215,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         ))?;
     let db = Database::new(std::sync::Arc::new(data_source));
 
-    match command.subcommand {
+    match subcommand {
         SubCommands::Everything { chain_config } => {
             let config: ChainConfig = chain_config.parse()?;
             let state_conf = StateConfig::generate_state_config(db)?;
@@ -78,17 +225,18 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
                 ..config
             };
 
-            let stdout = std::io::stdout().lock();
-
-            serde_json::to_writer_pretty(stdout, &chain_conf)
+            let json = serde_json::to_vec_pretty(&chain_conf)
                 .context("failed to dump snapshot to JSON")?;
+            write_snapshot(json, key.as_ref())?;
         }
         SubCommands::Contract { contract_id } => {
             let config = db.get_contract_config_by_id(contract_id)?;
-            let stdout = std::io::stdout().lock();
-
-            serde_json::to_writer_pretty(stdout, &config)
+            let json = serde_json::to_vec_pretty(&config)
                 .context("failed to dump contract snapshot to JSON")?;
+            write_snapshot(json, key.as_ref())?;
+        }
+        SubCommands::Info { .. } | SubCommands::Keygen => {
+            unreachable!("The command doesn't use the database")
         }
     }
     Ok(())
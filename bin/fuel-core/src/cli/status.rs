@@ -0,0 +1,98 @@
+use clap::Parser;
+use fuel_core_client::client::FuelClient;
+use std::{
+    fmt,
+    time::Duration,
+};
+
+/// Prints the status of the running node.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The URL of the GraphQL API of the node.
+    #[clap(long = "url", default_value = "http://127.0.0.1:4000")]
+    pub url: String,
+}
+
+/// The status of the node, printed as `name: value` lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Status {
+    height: u32,
+    /// `None` if the P2P is disabled on the node.
+    peers: Option<usize>,
+    txpool_size: u64,
+    da_height: u64,
+    /// `None` if the block production is disabled on the node.
+    is_synced: Option<bool>,
+    uptime: Duration,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "height: {}", self.height)?;
+        match self.peers {
+            Some(peers) => writeln!(f, "peers: {peers}")?,
+            None => writeln!(f, "peers: unavailable")?,
+        }
+        writeln!(f, "txpool size: {}", self.txpool_size)?;
+        writeln!(f, "da height: {}", self.da_height)?;
+        let sync_state = match self.is_synced {
+            Some(true) => "synced",
+            Some(false) => "not synced",
+            None => "unavailable",
+        };
+        writeln!(f, "sync state: {sync_state}")?;
+        write!(f, "uptime: {}", humantime::format_duration(self.uptime))
+    }
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let client = FuelClient::new(&command.url)?;
+
+    let chain_info = client.chain_info().await?;
+    let node_info = client.node_info().await?;
+    // The peers are not available if the P2P is disabled on the node.
+    let peers = client
+        .connected_peers_info()
+        .await
+        .ok()
+        .map(|peers| peers.len());
+
+    let status = Status {
+        height: chain_info.latest_block.header.height,
+        peers,
+        txpool_size: node_info.tx_pool_size,
+        da_height: chain_info.da_height,
+        is_synced: node_info.is_synced,
+        uptime: Duration::from_secs(node_info.uptime),
+    };
+    println!("{status}");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_is_printed_line_by_line() {
+        let status = Status {
+            height: 10,
+            peers: None,
+            txpool_size: 2,
+            da_height: 5,
+            is_synced: Some(true),
+            uptime: Duration::from_secs(3723),
+        };
+
+        assert_eq!(
+            status.to_string(),
+            "height: 10\n\
+            peers: unavailable\n\
+            txpool size: 2\n\
+            da height: 5\n\
+            sync state: synced\n\
+            uptime: 1h 2m 3s"
+        );
+    }
+}
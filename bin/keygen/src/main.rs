@@ -4,14 +4,19 @@ use clap::Parser;
 use crossterm::terminal;
 use fuel_core_keygen::{
     new_key,
+    new_keystore,
     parse_secret,
+    read_password,
     KeyType,
 };
-use std::io::{
-    stdin,
-    stdout,
-    Read,
-    Write,
+use std::{
+    io::{
+        stdin,
+        stdout,
+        Read,
+        Write,
+    },
+    path::PathBuf,
 };
 use termion::screen::IntoAlternateScreen;
 
@@ -47,6 +52,14 @@ pub struct NewKey {
         default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
     )]
     pub key_type: KeyType,
+    /// Save the key into the password-encrypted keystore file inside of this directory
+    /// instead of printing the secret.
+    #[clap(long = "keystore")]
+    pub keystore: Option<PathBuf>,
+    /// The file with the password of the keystore. If not set, the password is read
+    /// from the `FUEL_KEYSTORE_PASSWORD` env var or prompted.
+    #[clap(long = "password-file", requires = "keystore")]
+    pub password_file: Option<PathBuf>,
 }
 
 /// Key management utilities for configuring fuel-core
@@ -58,14 +71,26 @@ pub(crate) enum Command {
 }
 
 impl Command {
-    pub(crate) fn exec(&self) -> anyhow::Result<(serde_json::Value, bool)> {
+    /// Returns the output, whether it should be pretty-printed, and whether it
+    /// contains a secret.
+    pub(crate) fn exec(&self) -> anyhow::Result<(serde_json::Value, bool, bool)> {
         match self {
-            Command::New(cmd) => {
-                Ok((serde_json::to_value(new_key(cmd.key_type)?)?, cmd.pretty))
-            }
+            Command::New(cmd) => match &cmd.keystore {
+                Some(dir) => {
+                    let password = read_password(cmd.password_file.as_deref())?;
+                    let response = new_keystore(cmd.key_type, dir, &password)?;
+                    Ok((serde_json::to_value(response)?, cmd.pretty, false))
+                }
+                None => Ok((
+                    serde_json::to_value(new_key(cmd.key_type)?)?,
+                    cmd.pretty,
+                    true,
+                )),
+            },
             Command::Parse(cmd) => Ok((
                 serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
                 cmd.pretty,
+                true,
             )),
         }
     }
@@ -73,8 +98,8 @@ impl Command {
 
 fn main() -> anyhow::Result<()> {
     let cmd = Command::parse();
-    let (result, is_pretty) = cmd.exec()?;
-    print_value(result, is_pretty)
+    let (result, is_pretty, is_secret) = cmd.exec()?;
+    print_value(result, is_pretty, is_secret)
 }
 
 fn wait_for_keypress() {
@@ -100,7 +125,11 @@ fn display_string_discreetly(
     Ok(())
 }
 
-fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
+fn print_value(
+    output: serde_json::Value,
+    pretty: bool,
+    secret: bool,
+) -> anyhow::Result<()> {
     let output = if pretty {
         serde_json::to_string_pretty(&output)
     } else {
@@ -108,6 +137,11 @@ fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
     }
     .map_err(anyhow::Error::msg);
 
+    if !secret {
+        println!("{}", output?);
+        return Ok(())
+    }
+
     let _ = display_string_discreetly(
         &output?,
         "### Do not share or lose this private key! Press any key to complete. ###",
@@ -0,0 +1,158 @@
+//! The conversions of the [`Address`] between the text formats used by the Fuel tooling:
+//!
+//! - hex: `0x` followed by 64 lowercase hex digits;
+//! - bech32m: the [`FUEL_BECH32_HRP`] prefix, like `fuel1...`;
+//! - checksummed hex: the hex with the letters uppercased according to the
+//!   SHA-256 hash of the lowercase hex, similar to EIP-55.
+//!
+//! The [`parse`] accepts any of them, so the tools can accept the address
+//! in the format the user has it.
+
+use crate::FUEL_BECH32_HRP;
+use anyhow::anyhow;
+use bech32::{
+    FromBase32,
+    ToBase32,
+    Variant,
+};
+use core::str::FromStr;
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Address,
+};
+
+/// Returns the `0x` prefixed lowercase hex of the `address`.
+pub fn to_hex(address: &Address) -> String {
+    format!("{address:#x}")
+}
+
+/// Returns the bech32m encoding of the `address` with the [`FUEL_BECH32_HRP`] prefix.
+pub fn to_bech32(address: &Address) -> String {
+    bech32::encode(FUEL_BECH32_HRP, address.to_base32(), Variant::Bech32m)
+        .expect("The prefix is valid and the address is short enough")
+}
+
+/// Returns the `0x` prefixed hex of the `address` with the checksum encoded
+/// in the case of the letters. The letter is uppercase if the corresponding
+/// nibble of the SHA-256 hash of the lowercase hex is at least 8.
+pub fn to_checksum(address: &Address) -> String {
+    let hex = format!("{address:x}");
+    let hash = Hasher::hash(hex.as_bytes());
+    let checksummed = hex
+        .chars()
+        .enumerate()
+        .map(|(index, char)| {
+            let byte = hash[index / 2];
+            let nibble = if index % 2 == 0 {
+                byte >> 4
+            } else {
+                byte & 0x0f
+            };
+            if nibble >= 8 {
+                char.to_ascii_uppercase()
+            } else {
+                char
+            }
+        })
+        .collect::<String>();
+    format!("0x{checksummed}")
+}
+
+/// Parses the `address` in any of the supported formats. The hex with the mixed case
+/// is treated as checksummed and is rejected if the checksum doesn't match.
+pub fn parse(address: &str) -> anyhow::Result<Address> {
+    let address = address.trim();
+    if address.starts_with(FUEL_BECH32_HRP) {
+        from_bech32(address)
+    } else {
+        from_hex(address)
+    }
+}
+
+/// Parses the bech32m `address` with the [`FUEL_BECH32_HRP`] prefix.
+pub fn from_bech32(address: &str) -> anyhow::Result<Address> {
+    let (hrp, data, _) = bech32::decode(address)?;
+    if hrp != FUEL_BECH32_HRP {
+        return Err(anyhow!("Unexpected bech32 prefix `{hrp}`"))
+    }
+    let bytes = Vec::<u8>::from_base32(&data)?;
+    let bytes: [u8; Address::LEN] = bytes
+        .try_into()
+        .map_err(|_| anyhow!("The address should be {} bytes", Address::LEN))?;
+    Ok(bytes.into())
+}
+
+/// Parses the hex `address`, with or without the `0x` prefix. Verifies the checksum
+/// if the letters have the mixed case.
+pub fn from_hex(address: &str) -> anyhow::Result<Address> {
+    let parsed = Address::from_str(address)
+        .map_err(|e| anyhow!("Invalid address `{address}`: {e}"))?;
+    let digits = address.trim_start_matches("0x");
+    let has_lowercase = digits.chars().any(|char| char.is_ascii_lowercase());
+    let has_uppercase = digits.chars().any(|char| char.is_ascii_uppercase());
+    if has_lowercase && has_uppercase {
+        let expected = to_checksum(&parsed);
+        if expected.trim_start_matches("0x") != digits {
+            return Err(anyhow!(
+                "Invalid checksum of the address `{address}`, expected `{expected}`"
+            ))
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: Address = Address::new([
+        0x6b, 0x63, 0x80, 0x4c, 0xfb, 0xf9, 0x85, 0x6e, 0x68, 0xe5, 0xb6, 0xe7, 0xae,
+        0xf2, 0x38, 0xdc, 0x8e, 0xfc, 0x96, 0x8f, 0x81, 0x47, 0xd4, 0x1b, 0x7d, 0x13,
+        0x5a, 0x44, 0xc6, 0xa9, 0x1f, 0x7f,
+    ]);
+
+    #[test]
+    fn all_formats_are_parsed_back() {
+        for encoded in [
+            to_hex(&ADDRESS),
+            to_bech32(&ADDRESS),
+            to_checksum(&ADDRESS),
+            to_hex(&ADDRESS).to_uppercase().replace("0X", "0x"),
+            to_hex(&ADDRESS).trim_start_matches("0x").to_string(),
+        ] {
+            assert_eq!(parse(&encoded).unwrap(), ADDRESS, "{encoded}");
+        }
+    }
+
+    #[test]
+    fn bech32_uses_fuel_prefix() {
+        let encoded = to_bech32(&ADDRESS);
+
+        assert!(encoded.starts_with("fuel1"), "{encoded}");
+        assert!(from_bech32(&encoded.replacen("fuel", "eth", 1)).is_err());
+    }
+
+    #[test]
+    fn checksum_has_mixed_case() {
+        let checksummed = to_checksum(&ADDRESS);
+
+        assert_eq!(checksummed.to_lowercase(), to_hex(&ADDRESS));
+        assert_ne!(checksummed, to_hex(&ADDRESS));
+    }
+
+    #[test]
+    fn invalid_checksum_is_rejected() {
+        let checksummed = to_checksum(&ADDRESS);
+        let letter = checksummed
+            .char_indices()
+            .skip(2)
+            .find(|(_, char)| char.is_ascii_alphabetic())
+            .unwrap()
+            .0;
+        let mut corrupted = checksummed.into_bytes();
+        corrupted[letter] ^= 0x20;
+        let corrupted = String::from_utf8(corrupted).unwrap();
+
+        assert!(parse(&corrupted).is_err());
+    }
+}
@@ -1,9 +1,21 @@
+#[cfg(feature = "std")]
+mod allocations;
+#[cfg(feature = "std")]
+mod artifact;
 mod chain;
 mod coin;
 mod consensus;
 mod contract;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+#[cfg(feature = "std")]
+mod json_schema;
 mod message;
 mod state;
+mod state_reader;
+mod summary;
+mod supply;
+mod vesting;
 
 pub use chain::*;
 pub use coin::*;
@@ -11,9 +23,14 @@ pub use consensus::*;
 pub use contract::*;
 pub use message::*;
 pub use state::*;
+pub use state_reader::*;
+pub use summary::*;
+pub use supply::*;
+pub use vesting::*;
 
 #[cfg(test)]
 mod tests {
+    use crate::GenesisCommitment;
     use fuel_core_types::{
         blockchain::primitives::DaBlockHeight,
         fuel_asm::op,
@@ -26,6 +43,7 @@ mod tests {
             Bytes32,
         },
         fuel_vm::Contract,
+        tai64::Tai64,
     };
     use rand::{
         prelude::StdRng,
@@ -43,6 +61,10 @@ mod tests {
     use super::{
         chain::ChainConfig,
         coin::CoinConfig,
+        consensus::{
+            BlockTimeConfig,
+            ConsensusConfig,
+        },
         contract::ContractConfig,
         message::MessageConfig,
         state::StateConfig,
@@ -109,6 +131,62 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn genesis_metadata_is_committed_by_chain_config_hash() {
+        let config = ChainConfig::local_testnet();
+        let with_time = ChainConfig {
+            genesis_time: Some(1_700_000_000),
+            ..config.clone()
+        };
+        let with_extra_data = ChainConfig {
+            genesis_extra_data: Some(b"launch".to_vec()),
+            ..config.clone()
+        };
+
+        let roots =
+            [&config, &with_time, &with_extra_data].map(|config| config.root().unwrap());
+
+        assert_ne!(roots[0], roots[1]);
+        assert_ne!(roots[0], roots[2]);
+        assert_ne!(roots[1], roots[2]);
+        assert_eq!(config.genesis_time().unwrap(), Tai64::UNIX_EPOCH);
+        assert_eq!(
+            with_time.genesis_time().unwrap(),
+            Tai64::from_unix(1_700_000_000)
+        );
+        let json = serde_json::to_string(&with_extra_data).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ChainConfig>(&json).unwrap(),
+            with_extra_data
+        );
+    }
+
+    #[test]
+    fn block_time_rules_are_committed_by_consensus_config_hash() {
+        let consensus = ConsensusConfig::default_poa();
+        let ConsensusConfig::PoA { signing_key, .. } = consensus;
+        let with_block_time = ConsensusConfig::PoA {
+            signing_key,
+            block_time: Some(BlockTimeConfig {
+                max_future_drift: Some(10),
+                min_block_interval: 1,
+            }),
+        };
+
+        // The hash of the config without the `block_time` is the same as before
+        // the `block_time` was introduced.
+        assert_eq!(
+            hex::encode(consensus.root().unwrap()),
+            "0d8bf65564ca3e35d23c2471d0f862f57a47c26124e0a86d975fa7be7574a27c"
+        );
+        assert_ne!(consensus.root().unwrap(), with_block_time.root().unwrap());
+        let json = serde_json::to_string(&with_block_time).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ConsensusConfig>(&json).unwrap(),
+            with_block_time
+        );
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false, false, false);
@@ -125,6 +203,79 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn shared_contract_code_is_stored_once() {
+        let contract = |id: u8, code: Vec<u8>| ContractConfig {
+            contract_id: [id; 32].into(),
+            code,
+            ..test_config_contract(false, false, false, false)
+                .initial_state
+                .unwrap()
+                .contracts
+                .unwrap()
+                .remove(0)
+        };
+        let shared_code = op::ret(0x10).to_bytes().to_vec();
+        let unique_code = op::ret(0x11).to_bytes().to_vec();
+        let mut state = StateConfig {
+            contracts: Some(vec![
+                contract(1, shared_code.clone()),
+                contract(2, unique_code.clone()),
+                contract(3, shared_code.clone()),
+            ]),
+            ..Default::default()
+        };
+
+        state.deduplicate_codes();
+
+        assert_eq!(state.codes, Some(vec![shared_code.clone()]));
+        let contracts = state.contracts.as_ref().unwrap();
+        assert!(contracts[0].code.is_empty());
+        assert_eq!(
+            contracts[0].code_hash,
+            Some(ContractConfig::hash_code(&shared_code))
+        );
+        assert_eq!(contracts[1].code, unique_code);
+        assert_eq!(contracts[1].code_hash, None);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let deserialized: StateConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, state);
+        assert_eq!(
+            deserialized.contract_codes().unwrap(),
+            vec![&shared_code[..], &unique_code[..], &shared_code[..]]
+        );
+    }
+
+    #[test]
+    fn unknown_contract_code_hash_is_rejected() {
+        let mut config = test_config_contract(false, false, false, false)
+            .initial_state
+            .unwrap();
+        let contract = &mut config.contracts.as_mut().unwrap()[0];
+        contract.code = vec![];
+        contract.code_hash = Some(Bytes32::zeroed());
+
+        assert!(config.contract_codes().is_err());
+    }
+
+    #[test]
+    fn contract_id_of_shared_code_is_not_calculated() {
+        let mut contract = test_config_contract(false, false, false, false)
+            .initial_state
+            .unwrap()
+            .contracts
+            .unwrap()
+            .remove(0);
+        contract.calculate_contract_id().unwrap();
+        let contract_id = contract.contract_id;
+        contract.code_hash = Some(ContractConfig::hash_code(&contract.code));
+        contract.code = vec![];
+
+        assert!(contract.calculate_contract_id().is_err());
+        assert_eq!(contract.contract_id, contract_id);
+    }
+
     #[test]
     fn snapshot_contract_with_state() {
         let config = test_config_contract(true, false, false, false);
@@ -260,6 +411,7 @@ mod tests {
                 contracts: Some(vec![ContractConfig {
                     contract_id: Default::default(),
                     code: contract.into(),
+                    code_hash: None,
                     salt: Default::default(),
                     state,
                     balances,
@@ -0,0 +1,193 @@
+use crate::{
+    address,
+    ChainConfig,
+    CoinConfig,
+    StateConfig,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use core::str::FromStr;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{
+        BufRead,
+        BufReader,
+    },
+    path::Path,
+};
+
+/// The number of columns in the row of the allocations: `address,amount,asset_id`.
+const COLUMNS: usize = 3;
+
+impl StateConfig {
+    /// Reads the genesis coins from the CSV file with `address,amount,asset_id` rows.
+    /// See [`StateConfig::from_csv_allocations_reader`] for the format.
+    pub fn from_csv_allocations(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        Self::from_csv_allocations_reader(BufReader::new(file))
+            .with_context(|| format!("Invalid allocations in {}", path.display()))
+    }
+
+    /// Reads the genesis coins from the `address,amount,asset_id` rows.
+    ///
+    /// - The optional first row with the `address` column is the header.
+    /// - The address is either hex or bech32 with the `fuel` prefix.
+    /// - The empty asset id is the base asset.
+    /// - The empty lines and the lines starting with `#` are skipped.
+    ///
+    /// Each row becomes a separate coin. The same owner can't receive
+    /// the same asset twice, because it is usually a mistake in the list.
+    pub fn from_csv_allocations_reader(reader: impl BufRead) -> anyhow::Result<Self> {
+        let mut coins = vec![];
+        let mut allocations = HashMap::<(Address, AssetId), usize>::new();
+        let mut is_first_row = true;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index.saturating_add(1);
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+
+            let columns = line.split(',').map(unquote).collect::<Vec<_>>();
+            if is_first_row {
+                is_first_row = false;
+                if columns[0].eq_ignore_ascii_case("address") {
+                    continue
+                }
+            }
+            if columns.len() != COLUMNS {
+                return Err(anyhow!(
+                    "Line {line_number}: expected {COLUMNS} columns `address,amount,asset_id`, found {}",
+                    columns.len()
+                ))
+            }
+
+            let owner = address::parse(columns[0])
+                .with_context(|| format!("Line {line_number}: invalid address"))?;
+            let amount = u64::from_str(columns[1])
+                .with_context(|| format!("Line {line_number}: invalid amount"))?;
+            if amount == 0 {
+                return Err(anyhow!("Line {line_number}: the amount should not be zero"))
+            }
+            let asset_id = if columns[2].is_empty() {
+                ChainConfig::BASE_ASSET
+            } else {
+                AssetId::from_str(columns[2])
+                    .map_err(|e| anyhow!(e))
+                    .with_context(|| format!("Line {line_number}: invalid asset id"))?
+            };
+
+            if let Some(previous) = allocations.insert((owner, asset_id), line_number) {
+                return Err(anyhow!(
+                    "Line {line_number}: duplicates the allocation of {asset_id} to {owner} from line {previous}"
+                ))
+            }
+
+            coins.push(CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner,
+                amount,
+                asset_id,
+            });
+        }
+
+        Ok(StateConfig {
+            coins: Some(coins),
+            ..Default::default()
+        })
+    }
+}
+
+fn unquote(column: &str) -> &str {
+    let column = column.trim();
+    column
+        .strip_prefix('"')
+        .and_then(|column| column.strip_suffix('"'))
+        .unwrap_or(column)
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(csv: &str) -> anyhow::Result<Vec<CoinConfig>> {
+        StateConfig::from_csv_allocations_reader(csv.as_bytes())
+            .map(|state| state.coins.unwrap())
+    }
+
+    fn owner(byte: u8) -> Address {
+        Address::from([byte; 32])
+    }
+
+    #[test]
+    fn reads_rows_with_header_and_comments() {
+        let asset_id = AssetId::from([9; 32]);
+        let csv = format!(
+            "address,amount,asset_id\n\
+             # team\n\
+             {},100,{asset_id}\n\
+             \n\
+             \"{}\", 200 ,\n",
+            owner(1),
+            owner(2),
+        );
+
+        let coins = parse(&csv).unwrap();
+
+        let allocations = coins
+            .iter()
+            .map(|coin| (coin.owner, coin.amount, coin.asset_id))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            allocations,
+            vec![
+                (owner(1), 100, asset_id),
+                (owner(2), 200, ChainConfig::BASE_ASSET)
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_bech32_addresses() {
+        let address = address::to_bech32(&owner(3));
+
+        let coins = parse(&format!("{address},5,")).unwrap();
+
+        assert_eq!(coins[0].owner, owner(3));
+    }
+
+    #[test]
+    fn rejects_duplicated_allocations() {
+        let csv = format!("{},1,\n{},2,\n", owner(1), owner(1));
+
+        let err = parse(&csv).unwrap_err();
+
+        assert!(err.to_string().contains("Line 2"), "{err}");
+        assert!(err.to_string().contains("line 1"), "{err}");
+    }
+
+    #[test]
+    fn rejects_invalid_rows() {
+        assert!(parse("0x01,1,").is_err());
+        assert!(parse(&format!("{},0,", owner(1))).is_err());
+        assert!(parse(&format!("{},-1,", owner(1))).is_err());
+        assert!(parse(&format!("{},1", owner(1))).is_err());
+        assert!(parse(&format!("{},1,0x02", owner(1))).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{
+    ContractConfig,
+    StateConfig,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use core::str::FromStr;
+use fuel_core_types::fuel_types::{
+    Bytes32,
+    ContractId,
+    Salt,
+};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// The extension of the contract binary generated by `forc build`.
+const BINARY_EXTENSION: &str = "bin";
+/// The suffix of the storage slots file generated by `forc build`.
+const STORAGE_SLOTS_SUFFIX: &str = "-storage_slots.json";
+/// The suffix of the optional file with the hex salt of the contract.
+const SALT_SUFFIX: &str = "-salt";
+
+/// The storage slot in the format of `forc build`.
+#[derive(Deserialize)]
+struct StorageSlotArtifact {
+    key: String,
+    value: String,
+}
+
+impl ContractConfig {
+    /// Creates the contract from the `forc build` outputs. The `path` is either
+    /// the `<name>.bin` binary or the directory with a single binary, like `out/debug`.
+    ///
+    /// Next to the binary, the optional `<name>-storage_slots.json` file defines
+    /// the initial storage, and the optional `<name>-salt` file contains the hex salt.
+    /// The zero salt is used if the file doesn't exist.
+    pub fn from_artifact(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let binary = find_binary(path.as_ref())?;
+        let name = binary
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow!("Invalid file name {}", binary.display()))?;
+        let sibling = |suffix: &str| binary.with_file_name(format!("{name}{suffix}"));
+
+        let code = fs::read(&binary)
+            .with_context(|| format!("Failed to read {}", binary.display()))?;
+
+        let slots_path = sibling(STORAGE_SLOTS_SUFFIX);
+        let state = if slots_path.exists() {
+            let slots = read_storage_slots(&slots_path).with_context(|| {
+                format!("Invalid storage slots {}", slots_path.display())
+            })?;
+            (!slots.is_empty()).then_some(slots)
+        } else {
+            None
+        };
+
+        let salt_path = sibling(SALT_SUFFIX);
+        let salt = if salt_path.exists() {
+            let salt = fs::read_to_string(&salt_path)
+                .with_context(|| format!("Failed to read {}", salt_path.display()))?;
+            Salt::from_str(salt.trim())
+                .map_err(|e| anyhow!(e))
+                .with_context(|| format!("Invalid salt {}", salt_path.display()))?
+        } else {
+            Salt::zeroed()
+        };
+
+        let mut contract = ContractConfig {
+            contract_id: Default::default(),
+            code,
+            code_hash: None,
+            salt,
+            state,
+            balances: None,
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        };
+        contract.calculate_contract_id()?;
+        Ok(contract)
+    }
+}
+
+impl StateConfig {
+    /// Adds the contract from the `forc build` outputs to the genesis state and returns
+    /// its id. See [`ContractConfig::from_artifact`] for the expected files.
+    pub fn add_contract_artifact(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<ContractId> {
+        let contract = ContractConfig::from_artifact(path)?;
+        let contract_id = contract.contract_id;
+        let contracts = self.contracts.get_or_insert_with(Vec::new);
+        if contracts
+            .iter()
+            .any(|existing| existing.contract_id == contract_id)
+        {
+            return Err(anyhow!(
+                "The contract {contract_id} is already in the genesis state"
+            ))
+        }
+        contracts.push(contract);
+        Ok(contract_id)
+    }
+}
+
+fn find_binary(path: &Path) -> anyhow::Result<PathBuf> {
+    if !path.is_dir() {
+        return Ok(path.to_path_buf())
+    }
+    let mut binaries = fs::read_dir(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter(|entry| {
+            entry.as_ref().map_or(true, |path| {
+                path.extension().and_then(|ext| ext.to_str()) == Some(BINARY_EXTENSION)
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match binaries.len() {
+        1 => Ok(binaries.remove(0)),
+        0 => Err(anyhow!("No contract binary in {}", path.display())),
+        _ => Err(anyhow!(
+            "Multiple contract binaries in {}, specify the binary instead",
+            path.display()
+        )),
+    }
+}
+
+fn read_storage_slots(path: &Path) -> anyhow::Result<Vec<(Bytes32, Bytes32)>> {
+    let json = fs::read_to_string(path)?;
+    let slots: Vec<StorageSlotArtifact> = serde_json::from_str(&json)?;
+    slots
+        .into_iter()
+        .map(|slot| {
+            let key = Bytes32::from_str(&slot.key)
+                .map_err(|e| anyhow!("Invalid key `{}`: {e}", slot.key))?;
+            let value = Bytes32::from_str(&slot.value)
+                .map_err(|e| anyhow!("Invalid value `{}`: {e}", slot.value))?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::{
+        Contract,
+        StorageSlot,
+    };
+    use tempfile::TempDir;
+
+    struct ArtifactDir(TempDir);
+
+    impl ArtifactDir {
+        fn new() -> Self {
+            Self(TempDir::new().unwrap())
+        }
+
+        fn path(&self) -> &Path {
+            self.0.path()
+        }
+
+        fn write(&self, file: &str, content: impl AsRef<[u8]>) {
+            fs::write(self.path().join(file), content).unwrap();
+        }
+    }
+
+    const CODE: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    #[test]
+    fn contract_is_read_from_build_outputs() {
+        let dir = ArtifactDir::new();
+        let key = Bytes32::from([1; 32]);
+        let value = Bytes32::from([2; 32]);
+        let salt = Salt::from([3; 32]);
+        dir.write("token.bin", CODE);
+        dir.write(
+            "token-storage_slots.json",
+            format!(r#"[{{"key":"{key:x}","value":"{value:x}"}}]"#),
+        );
+        dir.write("token-salt", format!("{salt:#x}\n"));
+
+        let contract = ContractConfig::from_artifact(dir.path()).unwrap();
+
+        let slots = [StorageSlot::new(key, value)];
+        let code = Contract::from(CODE.to_vec());
+        let expected_id = code.id(
+            &salt,
+            &code.root(),
+            &Contract::initial_state_root(slots.iter()),
+        );
+        assert_eq!(contract.code, CODE.to_vec());
+        assert_eq!(contract.salt, salt);
+        assert_eq!(contract.state, Some(vec![(key, value)]));
+        assert_eq!(contract.contract_id, expected_id);
+    }
+
+    #[test]
+    fn contract_without_storage_and_salt_uses_defaults() {
+        let dir = ArtifactDir::new();
+        dir.write("token.bin", CODE);
+
+        let contract =
+            ContractConfig::from_artifact(dir.path().join("token.bin")).unwrap();
+
+        assert_eq!(contract.salt, Salt::zeroed());
+        assert_eq!(contract.state, None);
+    }
+
+    #[test]
+    fn directory_with_multiple_binaries_is_rejected() {
+        let dir = ArtifactDir::new();
+        dir.write("a.bin", CODE);
+        dir.write("b.bin", CODE);
+
+        assert!(ContractConfig::from_artifact(dir.path()).is_err());
+    }
+
+    #[test]
+    fn same_contract_is_added_once() {
+        let dir = ArtifactDir::new();
+        dir.write("token.bin", CODE);
+        let mut state = StateConfig::default();
+
+        let contract_id = state.add_contract_artifact(dir.path()).unwrap();
+
+        assert_eq!(
+            state.contracts.as_ref().unwrap()[0].contract_id,
+            contract_id
+        );
+        assert!(state.add_contract_artifact(dir.path()).is_err());
+    }
+}
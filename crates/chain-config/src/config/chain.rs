@@ -1,7 +1,4 @@
-use bech32::{
-    ToBase32,
-    Variant::Bech32m,
-};
+use anyhow::anyhow;
 use core::str::FromStr;
 use fuel_core_storage::MerkleRoot;
 use fuel_core_types::{
@@ -15,9 +12,9 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         AssetId,
-        Bytes32,
     },
     fuel_vm::SecretKey,
+    tai64::Tai64,
 };
 use itertools::Itertools;
 use serde::{
@@ -35,11 +32,14 @@ use std::{
 };
 
 use crate::{
+    address,
     config::{
         coin::CoinConfig,
         state::StateConfig,
+        supply::SupplyLimitConfig,
     },
     genesis::GenesisCommitment,
+    serialization::HexType,
     ConsensusConfig,
 };
 
@@ -47,6 +47,14 @@ use crate::{
 pub const FUEL_BECH32_HRP: &str = "fuel";
 pub const LOCAL_TESTNET: &str = "local_testnet";
 pub const TESTNET_INITIAL_BALANCE: u64 = 10_000_000;
+/// The secret keys of the accounts funded by the [`ChainConfig::local_testnet`].
+pub const TESTNET_WALLET_SECRETS: [&str; 5] = [
+    "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c",
+    "0x37fa81c84ccd547c30c176b118d5cb892bdb113e8e80141f266519422ef9eefd",
+    "0x862512a2363db2b3a375c0d4bbbd27172180d89f23f2e259bac850ab02619301",
+    "0x976e5c3fa620092c718d852ca703b6da9e3075b9f2ecb8ed42d9f746bf26aafb",
+    "0x7f8a325504e7315eda997db7861c9447f5c3eff26333b20180475d94443a10c6",
+];
 
 #[serde_as]
 // TODO: Remove not consensus/network fields from `ChainConfig` or create a new config only
@@ -55,11 +63,23 @@ pub const TESTNET_INITIAL_BALANCE: u64 = 10_000_000;
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct ChainConfig {
     pub chain_name: String,
+    /// The hard gas limit of the block. The blocks using more gas are invalid.
     pub block_gas_limit: u64,
     #[serde(default)]
     pub initial_state: Option<StateConfig>,
     pub consensus_parameters: ConsensusParameters,
     pub consensus: ConsensusConfig,
+    /// The maximum supply of the assets in the `initial_state`.
+    #[serde(default)]
+    pub max_supply: Option<Vec<SupplyLimitConfig>>,
+    /// The UNIX timestamp in seconds of the genesis block. The UNIX epoch if not set.
+    #[serde(default)]
+    pub genesis_time: Option<u64>,
+    /// The application-defined data committed into the genesis by the chain config
+    /// hash, like the launch metadata of the network.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub genesis_extra_data: Option<Vec<u8>>,
 }
 
 impl Default for ChainConfig {
@@ -70,6 +90,9 @@ impl Default for ChainConfig {
             consensus_parameters: ConsensusParameters::default(),
             initial_state: None,
             consensus: ConsensusConfig::default_poa(),
+            max_supply: None,
+            genesis_time: None,
+            genesis_extra_data: None,
         }
     }
 }
@@ -80,26 +103,16 @@ impl ChainConfig {
     pub fn local_testnet() -> Self {
         // endow some preset accounts with an initial balance
         tracing::info!("Initial Accounts");
-        let secrets = [
-            "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c",
-            "0x37fa81c84ccd547c30c176b118d5cb892bdb113e8e80141f266519422ef9eefd",
-            "0x862512a2363db2b3a375c0d4bbbd27172180d89f23f2e259bac850ab02619301",
-            "0x976e5c3fa620092c718d852ca703b6da9e3075b9f2ecb8ed42d9f746bf26aafb",
-            "0x7f8a325504e7315eda997db7861c9447f5c3eff26333b20180475d94443a10c6",
-        ];
-        let initial_coins = secrets
+        let initial_coins = TESTNET_WALLET_SECRETS
             .into_iter()
             .map(|secret| {
                 let secret = SecretKey::from_str(secret).expect("Expected valid secret");
                 let address = Address::from(*secret.public_key().hash());
-                let bech32_data = Bytes32::new(*address).to_base32();
-                let bech32_encoding =
-                    bech32::encode(FUEL_BECH32_HRP, bech32_data, Bech32m).unwrap();
                 tracing::info!(
-                    "PrivateKey({:#x}), Address({:#x} [bech32: {}]), Balance({})",
+                    "PrivateKey({:#x}), Address({} [bech32: {}]), Balance({})",
                     secret,
-                    address,
-                    bech32_encoding,
+                    address::to_checksum(&address),
+                    address::to_bech32(&address),
                     TESTNET_INITIAL_BALANCE
                 );
                 Self::initial_coin(secret, TESTNET_INITIAL_BALANCE, None)
@@ -124,14 +137,11 @@ impl ChainConfig {
             .map(|_| {
                 let secret = SecretKey::random(&mut rng);
                 let address = Address::from(*secret.public_key().hash());
-                let bech32_data = Bytes32::new(*address).to_base32();
-                let bech32_encoding =
-                    bech32::encode(FUEL_BECH32_HRP, bech32_data, Bech32m).unwrap();
                 tracing::info!(
-                    "PrivateKey({:#x}), Address({:#x} [bech32: {}]), Balance({})",
+                    "PrivateKey({:#x}), Address({} [bech32: {}]), Balance({})",
                     secret,
-                    address,
-                    bech32_encoding,
+                    address::to_checksum(&address),
+                    address::to_bech32(&address),
                     TESTNET_INITIAL_BALANCE
                 );
                 Self::initial_coin(secret, TESTNET_INITIAL_BALANCE, None)
@@ -148,6 +158,16 @@ impl ChainConfig {
         }
     }
 
+    /// Returns the time of the genesis block.
+    pub fn genesis_time(&self) -> anyhow::Result<Tai64> {
+        let Some(genesis_time) = self.genesis_time else {
+            return Ok(Tai64::UNIX_EPOCH)
+        };
+        let seconds = i64::try_from(genesis_time)
+            .map_err(|_| anyhow!("The genesis time {genesis_time} is out of range"))?;
+        Ok(Tai64::from_unix(seconds))
+    }
+
     pub fn initial_coin(
         secret: SecretKey,
         amount: u64,
@@ -179,6 +199,16 @@ impl FromStr for ChainConfig {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
                 let contents = std::fs::read(path)?;
+                #[cfg(feature = "encryption")]
+                let contents =
+                    crate::encryption::decrypt_snapshot(contents).map_err(|e| {
+                        std::io::Error::new(
+                            ErrorKind::InvalidData,
+                            e.context(format!(
+                                "an error occurred while decrypting the chain config file {s}"
+                            )),
+                        )
+                    })?;
                 serde_json::from_slice(&contents).map_err(|e| {
                     std::io::Error::new(
                         ErrorKind::InvalidData,
@@ -204,17 +234,31 @@ impl GenesisCommitment for ChainConfig {
             initial_state: _,
             consensus_parameters,
             consensus,
+            // Skip the `max_supply` because it only validates the `initial_state`.
+            max_supply: _,
+            genesis_time,
+            genesis_extra_data,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(consensus_parameters.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // The optional fields are hashed only if set, so the hash of the configs
+        // without them is the same as before they were introduced.
+        if let Some(genesis_time) = genesis_time {
+            hasher.input(b"genesis_time");
+            hasher.input(genesis_time.to_be_bytes());
+        }
+        if let Some(extra_data) = genesis_extra_data {
+            hasher.input(b"genesis_extra_data");
+            hasher.input((extra_data.len() as u64).to_be_bytes());
+            hasher.input(extra_data);
+        }
 
-        Ok(config_hash)
+        Ok(*hasher.finalize())
     }
 }
 
@@ -240,10 +284,25 @@ impl GenesisCommitment for GasCosts {
 
 impl GenesisCommitment for ConsensusConfig {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
+        /// The `ConsensusConfig` before the `block_time` was introduced. It keeps
+        /// the hash of the configs without the `block_time` the same.
+        #[derive(Serialize)]
+        enum LegacyConsensusConfig<'a> {
+            PoA { signing_key: &'a Address },
+        }
+
         // TODO: Define hash algorithm for `ConsensusConfig`
-        let bytes = postcard::to_allocvec(&self).map_err(anyhow::Error::msg)?;
-        let hash = Hasher::default().chain(bytes).finalize();
+        let ConsensusConfig::PoA {
+            signing_key,
+            block_time,
+        } = self;
+        let bytes = postcard::to_allocvec(&LegacyConsensusConfig::PoA { signing_key })
+            .map_err(anyhow::Error::msg)?;
+        let mut hasher = Hasher::default().chain(bytes);
+        if let Some(block_time) = block_time {
+            hasher.input(postcard::to_allocvec(block_time).map_err(anyhow::Error::msg)?);
+        }
 
-        Ok(hash.into())
+        Ok(hasher.finalize().into())
     }
 }
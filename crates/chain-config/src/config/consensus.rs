@@ -11,13 +11,40 @@ use crate::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+        /// The rules of the block timestamps. The time of the next block can't be
+        /// lower than the time of the previous one if not set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_time: Option<BlockTimeConfig>,
+    },
 }
 
 impl ConsensusConfig {
     pub fn default_poa() -> Self {
         ConsensusConfig::PoA {
             signing_key: Input::owner(&default_consensus_dev_key().public_key()),
+            block_time: None,
         }
     }
+
+    /// Returns the rules of the block timestamps.
+    pub fn block_time(&self) -> BlockTimeConfig {
+        match self {
+            ConsensusConfig::PoA { block_time, .. } => block_time.unwrap_or_default(),
+        }
+    }
+}
+
+/// The rules of the block timestamps, enforced on the production and on the import
+/// of the blocks.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BlockTimeConfig {
+    /// The maximum number of seconds the block time can be ahead of the local clock.
+    /// The time of the block is not limited if not set.
+    #[serde(default)]
+    pub max_future_drift: Option<u64>,
+    /// The minimum number of seconds between the times of the consecutive blocks.
+    #[serde(default)]
+    pub min_block_interval: u64,
 }
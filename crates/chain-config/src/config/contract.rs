@@ -2,7 +2,9 @@ use crate::serialization::{
     HexNumber,
     HexType,
 };
+use anyhow::anyhow;
 use fuel_core_types::{
+    fuel_crypto::Hasher,
     fuel_tx::{
         Contract,
         ContractId,
@@ -30,8 +32,16 @@ use serde_with::{
 pub struct ContractConfig {
     #[serde_as(as = "HexType")]
     pub contract_id: ContractId,
+    /// The bytecode of the contract. It is empty if the contract refers to the shared
+    /// bytecode by the `code_hash`.
     #[serde_as(as = "HexType")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub code: Vec<u8>,
+    /// The hash of the bytecode in the `codes` of the state config, shared by
+    /// the contracts deployed from the same bytecode.
+    #[serde_as(as = "Option<HexType>")]
+    #[serde(default)]
+    pub code_hash: Option<Bytes32>,
     #[serde_as(as = "HexType")]
     pub salt: Salt,
     #[serde_as(as = "Option<Vec<(HexType, HexType)>>")]
@@ -63,7 +73,21 @@ pub struct ContractConfig {
 }
 
 impl ContractConfig {
-    pub fn calculate_contract_id(&mut self) {
+    /// Returns the hash used to refer to the shared bytecode.
+    pub fn hash_code(code: &[u8]) -> Bytes32 {
+        Hasher::hash(code)
+    }
+
+    /// Calculates the `contract_id` from the `code` of the contract. Fails if the
+    /// contract refers to the shared bytecode by the `code_hash`, because the `code`
+    /// is empty in that case.
+    pub fn calculate_contract_id(&mut self) -> anyhow::Result<()> {
+        if let Some(hash) = self.code_hash {
+            return Err(anyhow!(
+                "The contract refers to the shared code {hash:#x} by the `code_hash`, \
+                so its id can't be calculated from the `code`"
+            ))
+        }
         let bytes = &self.code;
         let salt = self.salt;
         let slots = self.state.clone().map(|slots| {
@@ -80,5 +104,6 @@ impl ContractConfig {
         let root = contract.root();
         let contract_id = contract.id(&salt, &root, &state_root);
         self.contract_id = contract_id;
+        Ok(())
     }
 }
@@ -0,0 +1,237 @@
+//! The encryption of the snapshots at rest. The encrypted snapshot can be distributed
+//! over the shared storage, while only the holders of the key can load it.
+//!
+//! The snapshot is encrypted by AES-256-GCM with a random nonce. The encrypted file
+//! starts with the [`MAGIC`] header followed by the nonce and the ciphertext, so the
+//! readers detect the encrypted snapshots and decrypt them transparently. The header
+//! is authenticated as the associated data, so it can't be changed unnoticed.
+
+use aes_gcm::{
+    aead::{
+        Aead,
+        AeadCore,
+        KeyInit,
+        OsRng,
+        Payload,
+    },
+    Aes256Gcm,
+    Nonce,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use core::{
+    fmt,
+    str::FromStr,
+};
+use std::path::Path;
+
+/// The header of the encrypted snapshot.
+pub const MAGIC: &[u8] = b"fuel-snapshot-aes-256-gcm-v1\n";
+/// The environment variable with the hex key of the encrypted snapshots.
+pub const SNAPSHOT_KEY_ENV: &str = "FUEL_SNAPSHOT_KEY";
+/// The environment variable with the path to the file with the hex key of the
+/// encrypted snapshots. Used if the [`SNAPSHOT_KEY_ENV`] is not set.
+pub const SNAPSHOT_KEY_FILE_ENV: &str = "FUEL_SNAPSHOT_KEY_FILE";
+
+const NONCE_SIZE: usize = 12;
+
+/// The 256-bit key of the encrypted snapshots.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SnapshotKey([u8; 32]);
+
+impl SnapshotKey {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        Self(Aes256Gcm::generate_key(OsRng).into())
+    }
+
+    /// Reads the hex key from the file.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let key = std::fs::read_to_string(path).with_context(|| {
+            format!("Failed to read the snapshot key from {}", path.display())
+        })?;
+        key.parse()
+    }
+
+    /// Returns the key from the [`SNAPSHOT_KEY_ENV`] or from the file at the
+    /// [`SNAPSHOT_KEY_FILE_ENV`]. Returns `None` if neither is set.
+    pub fn from_env() -> anyhow::Result<Option<Self>> {
+        if let Ok(key) = std::env::var(SNAPSHOT_KEY_ENV) {
+            return key
+                .parse()
+                .map(Some)
+                .with_context(|| format!("Invalid `{SNAPSHOT_KEY_ENV}`"))
+        }
+        if let Ok(path) = std::env::var(SNAPSHOT_KEY_FILE_ENV) {
+            return Self::from_file(Path::new(&path)).map(Some)
+        }
+        Ok(None)
+    }
+
+    /// Encrypts the `snapshot` with a random nonce.
+    pub fn encrypt(&self, snapshot: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: snapshot,
+                    aad: MAGIC,
+                },
+            )
+            .map_err(|_| anyhow!("Failed to encrypt the snapshot"))?;
+
+        let mut encrypted = MAGIC.to_vec();
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend(ciphertext);
+        Ok(encrypted)
+    }
+
+    /// Decrypts the snapshot encrypted by [`SnapshotKey::encrypt`]. Fails if the key
+    /// is wrong or the snapshot is corrupted.
+    pub fn decrypt(&self, encrypted: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let body = encrypted
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| anyhow!("The snapshot is not encrypted"))?;
+        if body.len() < NONCE_SIZE {
+            return Err(anyhow!("The encrypted snapshot is truncated"))
+        }
+        let (nonce, ciphertext) = body.split_at(NONCE_SIZE);
+        self.cipher()
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: MAGIC,
+                },
+            )
+            .map_err(|_| {
+                anyhow!("Failed to decrypt the snapshot: the key is wrong or the file is corrupted")
+            })
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(&self.0.into())
+    }
+}
+
+impl FromStr for SnapshotKey {
+    type Err = anyhow::Error;
+
+    /// Parses the hex key with an optional `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(s, &mut bytes)
+            .map_err(|e| anyhow!("Expected 32 hex bytes of the snapshot key: {e}"))?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::LowerHex for SnapshotKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for SnapshotKey {
+    /// Doesn't reveal the key in the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SnapshotKey(..)")
+    }
+}
+
+/// Returns `true` if the `snapshot` is encrypted.
+pub fn is_encrypted(snapshot: &[u8]) -> bool {
+    snapshot.starts_with(MAGIC)
+}
+
+/// Decrypts the `snapshot` with the key from the environment if it is encrypted,
+/// see [`SnapshotKey::from_env`]. The plain snapshot is returned as is.
+pub fn decrypt_snapshot(snapshot: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    if !is_encrypted(&snapshot) {
+        return Ok(snapshot)
+    }
+    let key = SnapshotKey::from_env()?.ok_or_else(|| {
+        anyhow!(
+            "The snapshot is encrypted, set `{SNAPSHOT_KEY_ENV}` or \
+            `{SNAPSHOT_KEY_FILE_ENV}` to decrypt it"
+        )
+    })?;
+    key.decrypt(&snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &[u8] = br#"{"chain_name":"local"}"#;
+
+    #[test]
+    fn encrypted_snapshot_is_decrypted_by_same_key() {
+        let key = SnapshotKey::generate();
+
+        let encrypted = key.encrypt(SNAPSHOT).unwrap();
+
+        assert!(is_encrypted(&encrypted));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), SNAPSHOT);
+    }
+
+    #[test]
+    fn decryption_fails_with_wrong_key() {
+        let encrypted = SnapshotKey::new([1; 32]).encrypt(SNAPSHOT).unwrap();
+
+        let result = SnapshotKey::new([2; 32]).decrypt(&encrypted);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decryption_fails_if_snapshot_is_modified() {
+        let key = SnapshotKey::generate();
+        let mut encrypted = key.encrypt(SNAPSHOT).unwrap();
+        *encrypted.last_mut().unwrap() ^= 1;
+
+        assert!(key.decrypt(&encrypted).is_err());
+        assert!(key.decrypt(&encrypted[..MAGIC.len()]).is_err());
+    }
+
+    #[test]
+    fn snapshot_is_encrypted_with_header_as_associated_data() {
+        let key = SnapshotKey::generate();
+        let encrypted = key.encrypt(SNAPSHOT).unwrap();
+        let (nonce, ciphertext) = encrypted[MAGIC.len()..].split_at(NONCE_SIZE);
+
+        let without_header = key.cipher().decrypt(Nonce::from_slice(nonce), ciphertext);
+
+        assert!(without_header.is_err());
+    }
+
+    #[test]
+    fn plain_snapshot_is_not_decrypted() {
+        assert!(!is_encrypted(SNAPSHOT));
+        assert_eq!(decrypt_snapshot(SNAPSHOT.to_vec()).unwrap(), SNAPSHOT);
+    }
+
+    #[test]
+    fn key_is_parsed_from_hex() {
+        let key = SnapshotKey::generate();
+
+        let parsed: SnapshotKey = format!("{key:#x}\n").parse().unwrap();
+
+        assert_eq!(parsed, key);
+        assert_eq!(format!("{key:x}").parse::<SnapshotKey>().unwrap(), key);
+        assert!("0x1234".parse::<SnapshotKey>().is_err());
+    }
+}
@@ -0,0 +1,420 @@
+//! The JSON Schema of the chain configuration files, used by the tooling to validate
+//! the configs and by the editors to provide the autocomplete.
+//!
+//! The schema is inferred from the serialized samples of the configs, so it always
+//! follows the serde representation, including the hex encoding of the numbers and
+//! the bytes. The samples set all optional fields and leave them unset, so the fields
+//! present in every sample are required.
+
+use crate::{
+    BlockTimeConfig,
+    ChainConfig,
+    CoinConfig,
+    ConsensusConfig,
+    ContractConfig,
+    MessageConfig,
+    StateConfig,
+    SupplyLimitConfig,
+    VestingConfig,
+};
+use fuel_core_types::fuel_tx::DependentCost;
+use serde::Serialize;
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+/// The version of the JSON Schema specification.
+const SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+/// The hex bytes and numbers, with an optional `0x` prefix.
+const HEX_PATTERN: &str = "^(0x)?[0-9a-fA-F]*$";
+/// The name of the definition of `DependentCost`, the only enum of the configs.
+const DEPENDENT_COST: &str = "DependentCost";
+/// The fields of all nested objects are optional, because the types use `serde(default)`.
+const DEFAULTED_FIELDS: [&str; 1] = ["consensus_parameters"];
+
+impl ChainConfig {
+    /// Returns the JSON Schema of the chain config file.
+    pub fn json_schema() -> Value {
+        let samples = StateConfig::schema_samples()
+            .into_iter()
+            .map(Some)
+            .chain([None])
+            .map(|initial_state| ChainConfig {
+                initial_state,
+                ..Default::default()
+            })
+            .chain([ChainConfig {
+                max_supply: Some(vec![SupplyLimitConfig {
+                    asset_id: Default::default(),
+                    max_supply: 0,
+                }]),
+                consensus: ConsensusConfig::PoA {
+                    signing_key: Default::default(),
+                    block_time: Some(BlockTimeConfig {
+                        max_future_drift: Some(0),
+                        min_block_interval: 0,
+                    }),
+                },
+                genesis_time: Some(0),
+                genesis_extra_data: Some(vec![0]),
+                ..Default::default()
+            }]);
+        document("ChainConfig", samples)
+    }
+}
+
+impl StateConfig {
+    /// Returns the JSON Schema of the state config file.
+    pub fn json_schema() -> Value {
+        document("StateConfig", Self::schema_samples())
+    }
+
+    fn schema_samples() -> Vec<StateConfig> {
+        let full = StateConfig {
+            coins: Some(vec![CoinConfig {
+                tx_id: Some(Default::default()),
+                output_index: Some(0),
+                tx_pointer_block_height: Some(Default::default()),
+                tx_pointer_tx_idx: Some(0),
+                maturity: Some(Default::default()),
+                owner: Default::default(),
+                amount: 0,
+                asset_id: Default::default(),
+            }]),
+            contracts: Some(vec![ContractConfig {
+                contract_id: Default::default(),
+                code: vec![0],
+                code_hash: Some(Default::default()),
+                salt: Default::default(),
+                state: Some(vec![Default::default()]),
+                balances: Some(vec![Default::default()]),
+                tx_id: Some(Default::default()),
+                output_index: Some(0),
+                tx_pointer_block_height: Some(Default::default()),
+                tx_pointer_tx_idx: Some(0),
+            }]),
+            messages: Some(vec![MessageConfig {
+                sender: Default::default(),
+                recipient: Default::default(),
+                nonce: Default::default(),
+                amount: 0,
+                data: vec![],
+                da_height: Default::default(),
+            }]),
+            vesting: Some(vec![VestingConfig {
+                owner: Default::default(),
+                asset_id: Default::default(),
+                amount: 0,
+                start: Default::default(),
+                interval: 0,
+                tranches: 0,
+            }]),
+            codes: Some(vec![vec![0]]),
+            height: Some(Default::default()),
+        };
+        let sparse = StateConfig {
+            coins: Some(vec![CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner: Default::default(),
+                amount: 0,
+                asset_id: Default::default(),
+            }]),
+            contracts: Some(vec![ContractConfig {
+                contract_id: Default::default(),
+                code: vec![],
+                code_hash: None,
+                salt: Default::default(),
+                state: None,
+                balances: None,
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            }]),
+            ..full.clone()
+        };
+        vec![full, sparse, StateConfig::default()]
+    }
+}
+
+/// The schema inferred from the JSON values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Schema {
+    /// Any value, inferred from `null` or from the conflicting values.
+    Any,
+    Boolean,
+    Integer,
+    String {
+        hex: bool,
+    },
+    Array(Box<Schema>),
+    Object {
+        properties: BTreeMap<String, Schema>,
+        required: BTreeSet<String>,
+    },
+    /// The reference to the definition.
+    Ref(&'static str),
+}
+
+impl Schema {
+    fn infer(value: &Value) -> Self {
+        match value {
+            Value::Null => Schema::Any,
+            Value::Bool(_) => Schema::Boolean,
+            // The configs don't use floats.
+            Value::Number(_) => Schema::Integer,
+            Value::String(string) => Schema::String {
+                hex: is_hex(string),
+            },
+            Value::Array(items) => Schema::Array(Box::new(Self::infer_all(items))),
+            Value::Object(object) if is_dependent_cost(object) => {
+                Schema::Ref(DEPENDENT_COST)
+            }
+            Value::Object(object) => Schema::Object {
+                properties: object
+                    .iter()
+                    .map(|(key, value)| {
+                        let mut schema = Self::infer(value);
+                        if DEFAULTED_FIELDS.contains(&key.as_str()) {
+                            schema.make_optional();
+                        }
+                        (key.clone(), schema)
+                    })
+                    .collect(),
+                required: object.keys().cloned().collect(),
+            },
+        }
+    }
+
+    fn infer_all<'a>(values: impl IntoIterator<Item = &'a Value>) -> Self {
+        values
+            .into_iter()
+            .map(Self::infer)
+            .reduce(Self::merge)
+            .unwrap_or(Schema::Any)
+    }
+
+    /// Merges the schemas of the values of the same field. Only the fields present in
+    /// both schemas remain required.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Schema::Any, schema) | (schema, Schema::Any) => schema,
+            (Schema::String { hex: a }, Schema::String { hex: b }) => {
+                Schema::String { hex: a && b }
+            }
+            (Schema::Array(a), Schema::Array(b)) => Schema::Array(Box::new(a.merge(*b))),
+            (
+                Schema::Object {
+                    properties: mut a,
+                    required: required_a,
+                },
+                Schema::Object {
+                    properties: b,
+                    required: required_b,
+                },
+            ) => {
+                for (key, schema) in b {
+                    let merged = match a.remove(&key) {
+                        Some(existing) => existing.merge(schema),
+                        None => schema,
+                    };
+                    a.insert(key, merged);
+                }
+                Schema::Object {
+                    properties: a,
+                    required: required_a.intersection(&required_b).cloned().collect(),
+                }
+            }
+            (a, b) if a == b => a,
+            _ => Schema::Any,
+        }
+    }
+
+    /// Makes the fields of the object and of all nested objects optional.
+    fn make_optional(&mut self) {
+        match self {
+            Schema::Array(items) => items.make_optional(),
+            Schema::Object {
+                properties,
+                required,
+            } => {
+                required.clear();
+                properties.values_mut().for_each(Self::make_optional);
+            }
+            _ => {}
+        }
+    }
+
+    fn refers_to(&self, name: &str) -> bool {
+        match self {
+            Schema::Array(items) => items.refers_to(name),
+            Schema::Object { properties, .. } => {
+                properties.values().any(|schema| schema.refers_to(name))
+            }
+            Schema::Ref(reference) => *reference == name,
+            _ => false,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Schema::Any => json!({}),
+            Schema::Boolean => json!({ "type": "boolean" }),
+            Schema::Integer => json!({ "type": "integer", "minimum": 0 }),
+            Schema::String { hex: true } => {
+                json!({ "type": "string", "pattern": HEX_PATTERN })
+            }
+            Schema::String { hex: false } => json!({ "type": "string" }),
+            Schema::Array(items) => json!({ "type": "array", "items": items.to_json() }),
+            Schema::Object {
+                properties,
+                required,
+            } => {
+                let properties: Map<_, _> = properties
+                    .iter()
+                    .map(|(key, schema)| (key.clone(), schema.to_json()))
+                    .collect();
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                })
+            }
+            Schema::Ref(name) => json!({ "$ref": format!("#/definitions/{name}") }),
+        }
+    }
+}
+
+fn is_hex(string: &str) -> bool {
+    string
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+fn is_dependent_cost(object: &Map<String, Value>) -> bool {
+    object.len() == 1
+        && object
+            .keys()
+            .all(|key| key == "LightOperation" || key == "HeavyOperation")
+}
+
+/// The externally tagged variants of `DependentCost`.
+fn dependent_cost_definition() -> Value {
+    let variants: Vec<_> = [
+        DependentCost::LightOperation {
+            base: 0,
+            units_per_gas: 0,
+        },
+        DependentCost::HeavyOperation {
+            base: 0,
+            gas_per_unit: 0,
+        },
+    ]
+    .iter()
+    .map(|variant| {
+        let value = serde_json::to_value(variant).expect("Serialization of the sample");
+        let Value::Object(object) = value else {
+            unreachable!("`DependentCost` is serialized as an object")
+        };
+        // Infers the variant as the plain object to avoid the self-reference.
+        Schema::Object {
+            properties: object
+                .iter()
+                .map(|(key, value)| (key.clone(), Schema::infer(value)))
+                .collect(),
+            required: object.keys().cloned().collect(),
+        }
+        .to_json()
+    })
+    .collect();
+    json!({ "oneOf": variants })
+}
+
+fn document<T: Serialize>(title: &str, samples: impl IntoIterator<Item = T>) -> Value {
+    let samples: Vec<_> = samples
+        .into_iter()
+        .map(|sample| serde_json::to_value(sample).expect("Serialization of the sample"))
+        .collect();
+    let inferred = Schema::infer_all(&samples);
+    let mut schema = inferred.to_json();
+    let root = schema.as_object_mut().expect("The config is an object");
+    root.insert("$schema".to_string(), SCHEMA_DRAFT.into());
+    root.insert("title".to_string(), title.into());
+    if inferred.refers_to(DEPENDENT_COST) {
+        root.insert(
+            "definitions".to_string(),
+            json!({ DEPENDENT_COST: dependent_cost_definition() }),
+        );
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(schema: &Value) -> Vec<&str> {
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|key| key.as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn chain_config_schema_requires_only_mandatory_fields() {
+        let schema = ChainConfig::json_schema();
+
+        assert_eq!(
+            required(&schema),
+            [
+                "block_gas_limit",
+                "chain_name",
+                "consensus",
+                "consensus_parameters"
+            ]
+        );
+        assert!(required(&schema["properties"]["consensus_parameters"]).is_empty());
+        let coin = &schema["properties"]["initial_state"]["properties"]["coins"]["items"];
+        assert_eq!(required(coin), ["amount", "asset_id", "owner"]);
+        assert_eq!(coin["properties"]["amount"]["pattern"], HEX_PATTERN);
+    }
+
+    #[test]
+    fn state_config_schema_matches_snapshot() {
+        let schema = StateConfig::json_schema();
+
+        let json = serde_json::to_string_pretty(&schema).unwrap();
+        insta::assert_snapshot!(json);
+    }
+
+    #[test]
+    fn dependent_costs_refer_to_definition() {
+        let schema = ChainConfig::json_schema();
+
+        let gas_costs =
+            &schema["properties"]["consensus_parameters"]["properties"]["gas_costs"];
+        assert_eq!(
+            gas_costs["properties"]["call"]["$ref"],
+            "#/definitions/DependentCost"
+        );
+        assert_eq!(
+            schema["definitions"]["DependentCost"]["oneOf"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
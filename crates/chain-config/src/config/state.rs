@@ -1,7 +1,18 @@
-use crate::serialization::HexNumber;
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
 
+use anyhow::anyhow;
 use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    Bytes32,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 
 use serde::{
     Deserialize,
@@ -16,6 +27,7 @@ use super::{
     coin::CoinConfig,
     contract::ContractConfig,
     message::MessageConfig,
+    vesting::VestingConfig,
 };
 
 // TODO: do streaming deserialization to handle large state configs
@@ -29,6 +41,12 @@ pub struct StateConfig {
     pub contracts: Option<Vec<ContractConfig>>,
     /// Messages from Layer 1
     pub messages: Option<Vec<MessageConfig>>,
+    /// Coins unlocked by tranches, created in addition to the `coins`
+    pub vesting: Option<Vec<VestingConfig>>,
+    /// The bytecode shared by several `contracts`, referred by the `code_hash`
+    #[serde_as(as = "Option<Vec<HexType>>")]
+    #[serde(default)]
+    pub codes: Option<Vec<Vec<u8>>>,
     /// Starting block height (useful for flattened fork networks)
     #[serde_as(as = "Option<HexNumber>")]
     #[serde(default)]
@@ -40,12 +58,84 @@ impl StateConfig {
     where
         T: ChainConfigDb,
     {
-        Ok(StateConfig {
+        let mut state = StateConfig {
             coins: db.get_coin_config()?,
             contracts: db.get_contract_config()?,
             messages: db.get_message_config()?,
+            vesting: None,
+            codes: None,
             height: Some(db.get_block_height()?),
-        })
+        };
+        state.deduplicate_codes();
+        Ok(state)
+    }
+
+    /// Moves the bytecode used by several contracts into the `codes`, so each
+    /// bytecode is stored once and the contracts refer to it by the `code_hash`.
+    pub fn deduplicate_codes(&mut self) {
+        let Some(contracts) = &mut self.contracts else {
+            return
+        };
+        let mut usages = HashMap::<Bytes32, usize>::new();
+        for contract in contracts.iter().filter(|c| c.code_hash.is_none()) {
+            let usage = usages
+                .entry(ContractConfig::hash_code(&contract.code))
+                .or_default();
+            *usage = usage.saturating_add(1);
+        }
+
+        let codes = self.codes.get_or_insert_with(Vec::new);
+        let mut known = codes
+            .iter()
+            .map(|code| ContractConfig::hash_code(code))
+            .collect::<HashSet<_>>();
+        for contract in contracts.iter_mut().filter(|c| c.code_hash.is_none()) {
+            let hash = ContractConfig::hash_code(&contract.code);
+            if usages.get(&hash).copied().unwrap_or_default() < 2 {
+                continue
+            }
+            let code = core::mem::take(&mut contract.code);
+            if known.insert(hash) {
+                codes.push(code);
+            }
+            contract.code_hash = Some(hash);
+        }
+        if codes.is_empty() {
+            self.codes = None;
+        }
+    }
+
+    /// Returns the bytecode of each of the `contracts`, taken from the `codes`
+    /// if the contract refers to the shared bytecode.
+    pub fn contract_codes(&self) -> anyhow::Result<Vec<&[u8]>> {
+        let codes = self
+            .codes
+            .iter()
+            .flatten()
+            .map(|code| (ContractConfig::hash_code(code), code.as_slice()))
+            .collect::<HashMap<_, _>>();
+        self.contracts
+            .iter()
+            .flatten()
+            .map(|contract| match contract.code_hash {
+                Some(hash) => codes.get(&hash).copied().ok_or_else(|| {
+                    anyhow!(
+                        "The code {hash:#x} of the contract {:#x} is not in the `codes`",
+                        contract.contract_id
+                    )
+                }),
+                None => Ok(contract.code.as_slice()),
+            })
+            .collect()
+    }
+
+    /// Returns the `coins` followed by the coins of the `vesting` schedules.
+    pub fn all_coins(&self) -> anyhow::Result<Vec<CoinConfig>> {
+        let mut coins = self.coins.clone().unwrap_or_default();
+        for vesting in self.vesting.iter().flatten() {
+            coins.extend(vesting.coins()?);
+        }
+        Ok(coins)
     }
 }
 
@@ -0,0 +1,131 @@
+use super::state::{
+    ChainConfigDb,
+    StateConfig,
+};
+use core::fmt::Debug;
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The source of the initial state imported by the genesis. The embedders implement it
+/// to feed the genesis from the custom sources, like a generator or a network stream,
+/// without writing the state into the files.
+pub trait StateReader: Debug + Send + Sync {
+    /// Reads the initial state. It is called once per import or verification of
+    /// the genesis.
+    fn read(&self) -> anyhow::Result<StateConfig>;
+
+    /// Returns the height of the initial state. The default implementation reads
+    /// the whole state, so the sources with the known height should override it.
+    fn height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        Ok(self.read()?.height)
+    }
+}
+
+impl StateReader for StateConfig {
+    fn read(&self) -> anyhow::Result<StateConfig> {
+        Ok(self.clone())
+    }
+
+    fn height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        Ok(self.height)
+    }
+}
+
+/// Reads the latest state of another chain from its database.
+#[derive(Clone, Debug)]
+pub struct DatabaseStateReader<T>(pub T);
+
+impl<T> StateReader for DatabaseStateReader<T>
+where
+    T: ChainConfigDb + Clone + Debug + Send + Sync,
+{
+    fn read(&self) -> anyhow::Result<StateConfig> {
+        Ok(StateConfig::generate_state_config(self.0.clone())?)
+    }
+
+    fn height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        Ok(Some(self.0.get_block_height()?))
+    }
+}
+
+/// Reads the state config from the JSON file. With the `encryption` feature, the
+/// encrypted files are decrypted by the key from the environment, see
+/// `SnapshotKey::from_env`.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct JsonStateReader {
+    path: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl JsonStateReader {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StateReader for JsonStateReader {
+    fn read(&self) -> anyhow::Result<StateConfig> {
+        use anyhow::Context;
+
+        let contents = std::fs::read(&self.path).with_context(|| {
+            format!("Failed to read the state config {}", self.path.display())
+        })?;
+        #[cfg(feature = "encryption")]
+        let contents = crate::encryption::decrypt_snapshot(contents)?;
+        serde_json::from_slice(&contents).with_context(|| {
+            format!("Failed to parse the state config {}", self.path.display())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CoinConfig;
+
+    fn state() -> StateConfig {
+        StateConfig {
+            coins: Some(vec![CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner: Default::default(),
+                amount: 100,
+                asset_id: Default::default(),
+            }]),
+            height: Some(10u32.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn state_config_reads_itself() {
+        let state = state();
+
+        assert_eq!(state.read().unwrap(), state);
+        assert_eq!(state.height().unwrap(), Some(10u32.into()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn json_reader_reads_state_from_file() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), serde_json::to_vec(&state()).unwrap()).unwrap();
+
+        let reader = JsonStateReader::new(file.path());
+
+        assert_eq!(reader.read().unwrap(), state());
+        assert_eq!(reader.height().unwrap(), Some(10u32.into()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn json_reader_fails_if_file_is_missing() {
+        let reader = JsonStateReader::new("missing-state-config.json");
+
+        assert!(reader.read().is_err());
+    }
+}
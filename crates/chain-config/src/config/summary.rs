@@ -0,0 +1,199 @@
+use super::state::StateConfig;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+    BlockHeight,
+    ContractId,
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
+
+/// The size of the storage slot: the 32 bytes key and the 32 bytes value.
+const SLOT_SIZE: u64 = 64;
+
+/// The summary of the state config: the number of entries per table,
+/// the total supply per asset and the largest owners.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateSummary {
+    /// Starting block height of the state.
+    pub height: Option<BlockHeight>,
+    /// The number of coins.
+    pub coins: usize,
+    /// The number of messages.
+    pub messages: usize,
+    /// The number of contracts.
+    pub contracts: usize,
+    /// The number of storage slots of all contracts.
+    pub contract_state_slots: usize,
+    /// The total size of the keys and the values of the storage slots of all contracts.
+    pub contract_state_bytes: u64,
+    /// The number of balances of all contracts.
+    pub contract_balances: usize,
+    /// The sum of coins, contract balances and messages per asset.
+    pub total_supply: BTreeMap<AssetId, u128>,
+    /// The owners with the largest amount of coins of an asset,
+    /// sorted by the amount in descending order.
+    pub top_owners: Vec<(Address, AssetId, u128)>,
+    /// The contracts with the largest state as the number of the storage slots
+    /// and their size in bytes, sorted by the size in descending order.
+    pub top_contracts: Vec<(ContractId, usize, u64)>,
+}
+
+impl StateConfig {
+    /// Summarizes the state. The messages are counted in the supply of the
+    /// `base_asset_id`. Returns up to `top` largest owners and contracts.
+    pub fn summary(&self, base_asset_id: &AssetId, top: usize) -> StateSummary {
+        let mut summary = StateSummary {
+            height: self.height,
+            ..Default::default()
+        };
+        let mut owners = HashMap::<(Address, AssetId), u128>::new();
+        let mut contracts = vec![];
+
+        for coin in self.coins.iter().flatten() {
+            summary.coins = summary.coins.saturating_add(1);
+            add(&mut summary.total_supply, coin.asset_id, coin.amount);
+            let owned = owners.entry((coin.owner, coin.asset_id)).or_default();
+            *owned = owned.saturating_add(coin.amount as u128);
+        }
+
+        for vesting in self.vesting.iter().flatten() {
+            summary.coins = summary.coins.saturating_add(vesting.tranches as usize);
+            add(&mut summary.total_supply, vesting.asset_id, vesting.amount);
+            let owned = owners.entry((vesting.owner, vesting.asset_id)).or_default();
+            *owned = owned.saturating_add(vesting.amount as u128);
+        }
+
+        for message in self.messages.iter().flatten() {
+            summary.messages = summary.messages.saturating_add(1);
+            add(&mut summary.total_supply, *base_asset_id, message.amount);
+        }
+
+        for contract in self.contracts.iter().flatten() {
+            summary.contracts = summary.contracts.saturating_add(1);
+            let slots = contract.state.as_ref().map_or(0, Vec::len);
+            let bytes = (slots as u64).saturating_mul(SLOT_SIZE);
+            summary.contract_state_slots =
+                summary.contract_state_slots.saturating_add(slots);
+            summary.contract_state_bytes =
+                summary.contract_state_bytes.saturating_add(bytes);
+            if slots > 0 {
+                contracts.push((contract.contract_id, slots, bytes));
+            }
+            for (asset_id, amount) in contract.balances.iter().flatten() {
+                summary.contract_balances = summary.contract_balances.saturating_add(1);
+                add(&mut summary.total_supply, *asset_id, *amount);
+            }
+        }
+
+        let mut owners = owners
+            .into_iter()
+            .map(|((owner, asset_id), amount)| (owner, asset_id, amount))
+            .collect::<Vec<_>>();
+        owners.sort_unstable_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        owners.truncate(top);
+        summary.top_owners = owners;
+
+        contracts.sort_unstable_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+        contracts.truncate(top);
+        summary.top_contracts = contracts;
+
+        summary
+    }
+}
+
+fn add(total_supply: &mut BTreeMap<AssetId, u128>, asset_id: AssetId, amount: u64) {
+    let supply = total_supply.entry(asset_id).or_default();
+    *supply = supply.saturating_add(amount as u128);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        coin::CoinConfig,
+        contract::ContractConfig,
+        message::MessageConfig,
+        vesting::VestingConfig,
+    };
+
+    fn coin(owner: u8, asset_id: u8, amount: u64) -> CoinConfig {
+        CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Address::new([owner; 32]),
+            amount,
+            asset_id: AssetId::new([asset_id; 32]),
+        }
+    }
+
+    #[test]
+    fn summary_counts_entries_supply_and_top_owners() {
+        let base_asset_id = AssetId::new([0; 32]);
+        let state = StateConfig {
+            coins: Some(vec![
+                coin(1, 0, 10),
+                coin(1, 0, 15),
+                coin(2, 0, 20),
+                coin(3, 1, 5),
+            ]),
+            messages: Some(vec![MessageConfig {
+                sender: Default::default(),
+                recipient: Default::default(),
+                nonce: Default::default(),
+                amount: 100,
+                data: vec![],
+                da_height: Default::default(),
+            }]),
+            contracts: Some(vec![ContractConfig {
+                contract_id: Default::default(),
+                code: vec![],
+                code_hash: None,
+                salt: Default::default(),
+                state: Some(vec![Default::default(); 3]),
+                balances: Some(vec![(AssetId::new([1; 32]), 7)]),
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            }]),
+            vesting: Some(vec![VestingConfig {
+                owner: Address::new([4; 32]),
+                asset_id: base_asset_id,
+                amount: 30,
+                start: 10u32.into(),
+                interval: 10,
+                tranches: 3,
+            }]),
+            codes: None,
+            height: Some(5u32.into()),
+        };
+
+        let summary = state.summary(&base_asset_id, 2);
+
+        assert_eq!(summary.height, Some(5u32.into()));
+        assert_eq!(summary.coins, 7);
+        assert_eq!(summary.messages, 1);
+        assert_eq!(summary.contracts, 1);
+        assert_eq!(summary.contract_state_slots, 3);
+        assert_eq!(summary.contract_state_bytes, 192);
+        assert_eq!(summary.top_contracts, vec![(Default::default(), 3, 192)]);
+        assert_eq!(summary.contract_balances, 1);
+        assert_eq!(
+            summary.total_supply,
+            BTreeMap::from([(base_asset_id, 175), (AssetId::new([1; 32]), 12)])
+        );
+        assert_eq!(
+            summary.top_owners,
+            vec![
+                (Address::new([4; 32]), base_asset_id, 30),
+                (Address::new([1; 32]), base_asset_id, 25),
+            ]
+        );
+    }
+}
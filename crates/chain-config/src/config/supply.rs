@@ -0,0 +1,240 @@
+use crate::{
+    serialization::{
+        HexNumber,
+        HexType,
+    },
+    ChainConfig,
+};
+use anyhow::anyhow;
+use fuel_core_types::fuel_types::AssetId;
+use itertools::Itertools;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+use std::collections::BTreeMap;
+
+/// The maximum number of the entries listed in the error for each asset.
+const MAX_REPORTED_ENTRIES: usize = 10;
+
+/// The maximum total amount of the asset in the genesis state.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SupplyLimitConfig {
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    #[serde_as(as = "HexNumber")]
+    pub max_supply: u64,
+}
+
+/// The entry of the genesis state that holds the asset.
+struct SupplyEntry {
+    description: String,
+    amount: u64,
+}
+
+impl ChainConfig {
+    /// Checks that the total amount of each asset limited by the `max_supply` doesn't
+    /// exceed the limit. The total includes the coins, the vesting coins, the contract
+    /// balances, and the messages for the base asset.
+    ///
+    /// The error lists every exceeded asset with its largest entries.
+    pub fn check_supply(&self) -> anyhow::Result<()> {
+        let Some(limits) = &self.max_supply else {
+            return Ok(())
+        };
+        let mut max_supply = BTreeMap::new();
+        for limit in limits {
+            if max_supply
+                .insert(limit.asset_id, limit.max_supply)
+                .is_some()
+            {
+                return Err(anyhow!(
+                    "The max supply of {} is defined more than once",
+                    limit.asset_id
+                ))
+            }
+        }
+
+        let mut entries = BTreeMap::<AssetId, Vec<SupplyEntry>>::new();
+        let mut add = |asset_id: &AssetId, description: String, amount: u64| {
+            if max_supply.contains_key(asset_id) {
+                entries.entry(*asset_id).or_default().push(SupplyEntry {
+                    description,
+                    amount,
+                });
+            }
+        };
+
+        if let Some(state) = &self.initial_state {
+            for coin in state.all_coins()? {
+                add(
+                    &coin.asset_id,
+                    format!("coin of {}", coin.owner),
+                    coin.amount,
+                );
+            }
+            for contract in state.contracts.iter().flatten() {
+                for (asset_id, amount) in contract.balances.iter().flatten() {
+                    add(
+                        asset_id,
+                        format!("balance of contract {}", contract.contract_id),
+                        *amount,
+                    );
+                }
+            }
+            let base_asset_id = self.consensus_parameters.base_asset_id;
+            for message in state.messages.iter().flatten() {
+                add(
+                    &base_asset_id,
+                    format!("message {}", message.nonce),
+                    message.amount,
+                );
+            }
+        }
+
+        let errors = entries
+            .into_iter()
+            .filter_map(|(asset_id, mut entries)| {
+                let max_supply = max_supply[&asset_id];
+                let total = entries
+                    .iter()
+                    .map(|entry| u128::from(entry.amount))
+                    .fold(0u128, u128::saturating_add);
+                if total <= u128::from(max_supply) {
+                    return None
+                }
+                entries.sort_by_key(|entry| core::cmp::Reverse(entry.amount));
+                let largest = entries
+                    .iter()
+                    .take(MAX_REPORTED_ENTRIES)
+                    .map(|entry| format!("{}: {}", entry.description, entry.amount))
+                    .join(", ");
+                Some(format!(
+                    "The supply {total} of {asset_id} exceeds the max supply {max_supply} \
+                     in {} entries, the largest are [{largest}]",
+                    entries.len()
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(errors.join("\n")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CoinConfig,
+        ContractConfig,
+        MessageConfig,
+        StateConfig,
+    };
+    use fuel_core_types::fuel_types::{
+        Address,
+        ContractId,
+    };
+
+    const ASSET: AssetId = AssetId::new([1; 32]);
+
+    fn coin(asset_id: AssetId, amount: u64) -> CoinConfig {
+        CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Address::from([2; 32]),
+            amount,
+            asset_id,
+        }
+    }
+
+    fn contract(balance: u64) -> ContractConfig {
+        ContractConfig {
+            contract_id: ContractId::from([3; 32]),
+            code: vec![],
+            code_hash: None,
+            salt: Default::default(),
+            state: None,
+            balances: Some(vec![(ASSET, balance)]),
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        }
+    }
+
+    fn config(max_supply: u64, state: StateConfig) -> ChainConfig {
+        ChainConfig {
+            initial_state: Some(state),
+            max_supply: Some(vec![SupplyLimitConfig {
+                asset_id: ASSET,
+                max_supply,
+            }]),
+            ..ChainConfig::default()
+        }
+    }
+
+    #[test]
+    fn supply_within_limit_is_accepted() {
+        let state = StateConfig {
+            coins: Some(vec![coin(ASSET, 60), coin(AssetId::BASE, 1_000)]),
+            contracts: Some(vec![contract(40)]),
+            ..Default::default()
+        };
+
+        config(100, state).check_supply().unwrap();
+    }
+
+    #[test]
+    fn supply_above_limit_is_rejected_with_entries() {
+        let state = StateConfig {
+            coins: Some(vec![coin(ASSET, 60)]),
+            contracts: Some(vec![contract(41)]),
+            ..Default::default()
+        };
+
+        let err = config(100, state).check_supply().unwrap_err().to_string();
+
+        assert!(err.contains("The supply 101"), "{err}");
+        assert!(err.contains(&format!("coin of {}: 60", Address::from([2; 32]))));
+        assert!(err.contains(&format!(
+            "balance of contract {}: 41",
+            ContractId::from([3; 32])
+        )));
+    }
+
+    #[test]
+    fn messages_count_towards_base_asset() {
+        let mut config = config(
+            10,
+            StateConfig {
+                messages: Some(vec![MessageConfig {
+                    amount: 11,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+        );
+        config.max_supply.as_mut().unwrap()[0].asset_id =
+            config.consensus_parameters.base_asset_id;
+
+        assert!(config.check_supply().is_err());
+    }
+
+    #[test]
+    fn duplicated_limit_is_rejected() {
+        let mut config = config(10, StateConfig::default());
+        let limit = config.max_supply.as_ref().unwrap()[0].clone();
+        config.max_supply.as_mut().unwrap().push(limit);
+
+        assert!(config.check_supply().is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    serialization::{
+        HexNumber,
+        HexType,
+    },
+    CoinConfig,
+};
+use anyhow::anyhow;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+    BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+
+/// The allocation of the `amount` to the `owner` unlocked by equal tranches.
+/// Each tranche is a separate genesis coin with its own maturity: the first tranche
+/// matures at the `start` height and every next one `interval` blocks later.
+/// The remainder of the division is added to the last tranche.
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct VestingConfig {
+    #[serde_as(as = "HexType")]
+    pub owner: Address,
+    #[serde_as(as = "HexType")]
+    pub asset_id: AssetId,
+    /// The total amount of all tranches.
+    #[serde_as(as = "HexNumber")]
+    pub amount: u64,
+    /// The maturity of the first tranche.
+    #[serde_as(as = "HexNumber")]
+    pub start: BlockHeight,
+    /// The number of blocks between the maturities of the tranches.
+    #[serde_as(as = "HexNumber")]
+    pub interval: u32,
+    /// The number of tranches.
+    #[serde_as(as = "HexNumber")]
+    pub tranches: u32,
+}
+
+impl VestingConfig {
+    /// Creates the schedule with the tranches unlocked every `blocks_per_month`
+    /// blocks during `months` months, starting one month after the `start`.
+    pub fn monthly(
+        owner: Address,
+        asset_id: AssetId,
+        amount: u64,
+        start: BlockHeight,
+        months: u32,
+        blocks_per_month: u32,
+    ) -> Self {
+        Self {
+            owner,
+            asset_id,
+            amount,
+            start: u32::from(start).saturating_add(blocks_per_month).into(),
+            interval: blocks_per_month,
+            tranches: months,
+        }
+    }
+
+    /// Returns the coins of the tranches ordered by maturity.
+    pub fn coins(&self) -> anyhow::Result<Vec<CoinConfig>> {
+        if self.tranches == 0 {
+            return Err(anyhow!(
+                "The vesting schedule of {} should have at least one tranche",
+                self.owner
+            ))
+        }
+        let tranches = u64::from(self.tranches);
+        if self.amount < tranches {
+            return Err(anyhow!(
+                "The vesting amount {} of {} is less than the number of tranches {}",
+                self.amount,
+                self.owner,
+                tranches
+            ))
+        }
+        let tranche_amount = self.amount.checked_div(tranches).expect("Checked above");
+        let remainder = self.amount.checked_rem(tranches).expect("Checked above");
+
+        (0..self.tranches)
+            .map(|tranche| {
+                let maturity = tranche
+                    .checked_mul(self.interval)
+                    .and_then(|offset| u32::from(self.start).checked_add(offset))
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "The maturity of the tranche {} of {} overflows the block height",
+                            tranche,
+                            self.owner
+                        )
+                    })?;
+                let is_last = tranche.saturating_add(1) == self.tranches;
+                let amount = if is_last {
+                    tranche_amount.saturating_add(remainder)
+                } else {
+                    tranche_amount
+                };
+                Ok(CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: Some(maturity.into()),
+                    owner: self.owner,
+                    amount,
+                    asset_id: self.asset_id,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(amount: u64, tranches: u32) -> VestingConfig {
+        VestingConfig {
+            owner: Address::from([1; 32]),
+            asset_id: AssetId::BASE,
+            amount,
+            start: 100u32.into(),
+            interval: 10,
+            tranches,
+        }
+    }
+
+    #[test]
+    fn coins_split_amount_into_tranches() {
+        let coins = schedule(1_000, 3).coins().unwrap();
+
+        let maturities = coins
+            .iter()
+            .map(|coin| coin.maturity.unwrap())
+            .collect::<Vec<_>>();
+        let amounts = coins.iter().map(|coin| coin.amount).collect::<Vec<_>>();
+        assert_eq!(
+            maturities,
+            vec![100u32.into(), 110u32.into(), 120u32.into()]
+        );
+        assert_eq!(amounts, vec![333, 333, 334]);
+    }
+
+    #[test]
+    fn monthly_schedule_starts_after_first_month() {
+        let vesting = VestingConfig::monthly(
+            [1; 32].into(),
+            AssetId::BASE,
+            12,
+            0u32.into(),
+            12,
+            30,
+        );
+        let coins = vesting.coins().unwrap();
+
+        assert_eq!(coins.len(), 12);
+        assert_eq!(coins[0].maturity, Some(30u32.into()));
+        assert_eq!(coins[11].maturity, Some(360u32.into()));
+    }
+
+    #[test]
+    fn coins_fail_without_tranches() {
+        assert!(schedule(1_000, 0).coins().is_err());
+    }
+
+    #[test]
+    fn coins_fail_if_amount_is_less_than_tranches() {
+        assert!(schedule(2, 3).coins().is_err());
+    }
+
+    #[test]
+    fn coins_fail_if_maturity_overflows() {
+        let mut vesting = schedule(1_000, 3);
+        vesting.interval = u32::MAX;
+        assert!(vesting.coins().is_err());
+    }
+}
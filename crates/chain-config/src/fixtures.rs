@@ -0,0 +1,213 @@
+//! Deterministic fixtures of the state snapshots for tests and benchmarks.
+//! The same [`StateFixture`] always generates the same [`StateConfig`], so
+//! the tests of the genesis and the synchronization can rely on the resulting
+//! state without storing large snapshots in the repository.
+
+use crate::{
+    ChainConfig,
+    CoinConfig,
+    ContractConfig,
+    MessageConfig,
+    StateConfig,
+};
+use fuel_core_types::fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// The size of the instruction in bytes. The generated contract code is aligned to it.
+const INSTRUCTION_SIZE: usize = 4;
+
+/// The description of the generated state snapshot. The default value describes
+/// a mid-sized snapshot that takes a few seconds to import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateFixture {
+    /// The seed of the generator.
+    pub seed: u64,
+    /// The number of the coins.
+    pub coins: usize,
+    /// The number of the different assets used by the coins and the contract balances.
+    /// The base asset is always one of them.
+    pub assets: usize,
+    /// The number of the contracts.
+    pub contracts: usize,
+    /// The size of the code of each contract in bytes.
+    pub contract_code_size: usize,
+    /// The number of the storage slots of each contract.
+    pub contract_storage_slots: usize,
+    /// The number of the assets owned by each contract.
+    pub contract_balances: usize,
+    /// The number of the messages from the DA layer.
+    pub messages: usize,
+    /// The height of the genesis block.
+    pub height: BlockHeight,
+}
+
+impl Default for StateFixture {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            coins: 10_000,
+            assets: 10,
+            contracts: 100,
+            contract_code_size: 16 * 1024,
+            contract_storage_slots: 1_000,
+            contract_balances: 10,
+            messages: 1_000,
+            height: 0u32.into(),
+        }
+    }
+}
+
+impl StateFixture {
+    /// Returns the default fixture with the `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Default::default()
+        }
+    }
+
+    /// Generates the state snapshot.
+    pub fn state_config(&self) -> StateConfig {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let assets = self.assets(&mut rng);
+
+        let coins = (0..self.coins)
+            .map(|_| CoinConfig {
+                tx_id: Some(rng.gen()),
+                output_index: Some(rng.gen()),
+                tx_pointer_block_height: Some(self.height),
+                tx_pointer_tx_idx: Some(rng.gen()),
+                maturity: None,
+                owner: rng.gen(),
+                amount: rng.gen_range(1..=1_000_000_000),
+                asset_id: assets[rng.gen_range(0..assets.len())],
+            })
+            .collect();
+
+        let contracts = (0..self.contracts)
+            .map(|_| {
+                let instructions = self.contract_code_size / INSTRUCTION_SIZE;
+                let code = (0..instructions)
+                    .flat_map(|_| rng.gen::<[u8; INSTRUCTION_SIZE]>())
+                    .collect();
+                let state = (0..self.contract_storage_slots)
+                    .map(|_| (rng.gen::<Bytes32>(), rng.gen::<Bytes32>()))
+                    .collect();
+                let balances = assets
+                    .iter()
+                    .take(self.contract_balances)
+                    .map(|asset_id| (*asset_id, rng.gen_range(1..=1_000_000_000)))
+                    .collect();
+                let mut contract = ContractConfig {
+                    contract_id: Default::default(),
+                    code,
+                    code_hash: None,
+                    salt: rng.gen(),
+                    state: Some(state),
+                    balances: Some(balances),
+                    tx_id: Some(rng.gen()),
+                    output_index: Some(rng.gen()),
+                    tx_pointer_block_height: Some(self.height),
+                    tx_pointer_tx_idx: Some(rng.gen()),
+                };
+                contract
+                    .calculate_contract_id()
+                    .expect("The contract doesn't refer to the shared code");
+                contract
+            })
+            .collect();
+
+        let messages = (0..self.messages)
+            .map(|_| MessageConfig {
+                sender: rng.gen(),
+                recipient: rng.gen(),
+                nonce: rng.gen(),
+                amount: rng.gen_range(1..=1_000_000_000),
+                data: vec![],
+                da_height: rng.gen_range(0..=1_000u64).into(),
+            })
+            .collect();
+
+        StateConfig {
+            coins: Some(coins),
+            contracts: Some(contracts),
+            messages: Some(messages),
+            vesting: None,
+            codes: None,
+            height: Some(self.height),
+        }
+    }
+
+    /// Returns the `local_testnet` chain config with the generated state snapshot
+    /// instead of the initial state.
+    pub fn chain_config(&self) -> ChainConfig {
+        ChainConfig {
+            initial_state: Some(self.state_config()),
+            ..ChainConfig::local_testnet()
+        }
+    }
+
+    fn assets(&self, rng: &mut StdRng) -> Vec<AssetId> {
+        core::iter::once(AssetId::BASE)
+            .chain((1..self.assets).map(|_| rng.gen()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_fixture(seed: u64) -> StateFixture {
+        StateFixture {
+            seed,
+            coins: 100,
+            contracts: 3,
+            contract_code_size: 1023,
+            contract_storage_slots: 20,
+            messages: 10,
+            height: 10u32.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn state_config_is_deterministic() {
+        assert_eq!(
+            small_fixture(1).state_config(),
+            small_fixture(1).state_config()
+        );
+        assert_ne!(
+            small_fixture(1).state_config(),
+            small_fixture(2).state_config()
+        );
+    }
+
+    #[test]
+    fn state_config_has_requested_size() {
+        let fixture = small_fixture(1);
+        let state = fixture.state_config();
+
+        assert_eq!(state.coins.unwrap().len(), fixture.coins);
+        assert_eq!(state.messages.unwrap().len(), fixture.messages);
+        assert_eq!(state.height, Some(fixture.height));
+        let contracts = state.contracts.unwrap();
+        assert_eq!(contracts.len(), fixture.contracts);
+        for contract in contracts {
+            assert_eq!(contract.code.len(), 1020);
+            assert_eq!(
+                contract.state.unwrap().len(),
+                fixture.contract_storage_slots
+            );
+            assert_eq!(contract.balances.unwrap().len(), fixture.contract_balances);
+        }
+    }
+}
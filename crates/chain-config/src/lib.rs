@@ -3,8 +3,11 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
 
+pub mod address;
 pub mod config;
 pub mod fee_collection_contract;
+#[cfg(feature = "test-fixtures")]
+pub mod fixtures;
 mod genesis;
 mod serialization;
 
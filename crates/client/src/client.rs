@@ -47,7 +47,10 @@ use fuel_core_types::{
     },
     fuel_types,
     fuel_types::{
-        canonical::Serialize,
+        canonical::{
+            Deserialize,
+            Serialize,
+        },
         BlockHeight,
         Nonce,
     },
@@ -370,6 +373,17 @@ impl FuelClient {
             .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
     }
 
+    /// Returns the progress of the synchronization with the network, or `None`
+    /// if the node doesn't sync the blocks from the peers.
+    pub async fn sync_status(
+        &self,
+    ) -> io::Result<Option<fuel_core_types::services::p2p::SyncStatus>> {
+        let query = schema::node_info::QuerySyncStatus::build(());
+        self.query(query)
+            .await
+            .map(|r| r.sync_status.map(Into::into))
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain.into())
@@ -431,6 +445,25 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the transaction that spends the outputs of the `after` transaction.
+    /// The node waits for the `after` transaction if it is not yet in the `TxPool`,
+    /// so the chain of the dependent transactions can be submitted without waiting
+    /// for the result of each submission.
+    pub async fn submit_after(
+        &self,
+        tx: &Transaction,
+        after: &TxId,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitAfter::build(schema::tx::SubmitAfterArg {
+            tx: HexString(Bytes(tx)),
+            after: (*after).into(),
+        });
+
+        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        Ok(id)
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -498,6 +531,14 @@ impl FuelClient {
         Ok(self.query(query).await?.register.0 as Word)
     }
 
+    pub async fn registers(&self, id: &str) -> io::Result<Vec<Word>> {
+        let query = schema::Registers::build(IdArg { id: id.into() });
+
+        let registers = self.query(query).await?.registers;
+
+        Ok(registers.into_iter().map(|r| r.0 as Word).collect())
+    }
+
     pub async fn memory(&self, id: &str, start: u32, size: u32) -> io::Result<Vec<u8>> {
         let query = schema::Memory::build(MemoryArgs {
             id: id.into(),
@@ -532,6 +573,31 @@ impl FuelClient {
         Ok(())
     }
 
+    pub async fn remove_breakpoint(
+        &self,
+        session_id: &str,
+        contract: fuel_types::ContractId,
+        pc: u64,
+    ) -> io::Result<()> {
+        let operation = schema::RemoveBreakpoint::build(SetBreakpointArgs {
+            id: Id::new(session_id),
+            bp: schema::Breakpoint {
+                contract: contract.into(),
+                pc: U64(pc),
+            },
+        });
+        self.query(operation).await?;
+        Ok(())
+    }
+
+    pub async fn clear_breakpoints(&self, session_id: &str) -> io::Result<()> {
+        let operation = schema::ClearBreakpoints::build(IdArg {
+            id: Id::new(session_id),
+        });
+        self.query(operation).await?;
+        Ok(())
+    }
+
     pub async fn set_single_stepping(
         &self,
         session_id: &str,
@@ -579,17 +645,13 @@ impl FuelClient {
 
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
-        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
-
-        let tx = self.query(query).await?.transaction.ok_or_else(|| {
-            io::Error::new(
-                ErrorKind::NotFound,
-                format!("status not found for transaction {id} "),
-            )
-        })?;
+        let query =
+            schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
 
-        let status = tx
-            .status
+        let status = self
+            .query(query)
+            .await?
+            .transaction_status
             .ok_or_else(|| {
                 io::Error::new(
                     ErrorKind::NotFound,
@@ -662,6 +724,16 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions pending in the `TxPool`.
+    pub async fn pool_transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PoolTransaction, String>> {
+        let query = schema::tx::PoolTransactionsQuery::build(request.into());
+        let transactions = self.query(query).await?.pool_transactions.try_into()?;
+        Ok(transactions)
+    }
+
     /// Returns a paginated set of transactions associated with a txo owner address.
     pub async fn transactions_by_owner(
         &self,
@@ -755,6 +827,41 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Returns the DA-compressed representation of the block at the `height`,
+    /// the bytes posted to the data availability layer.
+    pub async fn da_compressed_block(&self, height: u32) -> io::Result<Option<Vec<u8>>> {
+        let query = schema::block::DaCompressedBlockQuery::build(
+            schema::block::DaCompressedBlockArgs {
+                height: U32(height),
+            },
+        );
+
+        let block = self
+            .query(query)
+            .await?
+            .da_compressed_block
+            .map(|block| block.bytes.0 .0);
+
+        Ok(block)
+    }
+
+    /// Verifies the block `header` serialized with `postcard` and its PoA `signature`
+    /// against the consensus of the chain known to the node.
+    pub async fn verify_block_header(
+        &self,
+        header: &[u8],
+        signature: &fuel_core_types::fuel_crypto::Signature,
+    ) -> io::Result<schema::block::HeaderVerification> {
+        let query = schema::block::VerifyBlockHeaderQuery::build(
+            schema::block::VerifyBlockHeaderArgs {
+                header: HexString(Bytes(header.to_vec())),
+                signature: fuel_core_types::fuel_types::Bytes64::from(**signature).into(),
+            },
+        );
+
+        self.query(query).await.map(|r| r.verify_block_header)
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -836,6 +943,28 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Returns the unsigned transaction that consolidates up to `max_inputs` of the
+    /// smallest coins of the `owner` into one change output.
+    pub async fn consolidation_transaction(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        max_inputs: Option<u32>,
+    ) -> io::Result<Transaction> {
+        let query = schema::coins::ConsolidationTransactionQuery::build(
+            schema::coins::ConsolidationTransactionArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                max_inputs: max_inputs.map(Into::into),
+            },
+        );
+
+        let bytes = self.query(query).await?.consolidation_transaction;
+        let tx = Transaction::from_bytes(bytes.0 .0.as_slice())
+            .map_err(ConversionError::TransactionFromBytesError)?;
+        Ok(tx)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -844,6 +973,23 @@ impl FuelClient {
         Ok(contract)
     }
 
+    /// Returns the number and the size of the storage slots of the contract.
+    pub async fn contract_storage_stats(
+        &self,
+        id: &ContractId,
+    ) -> io::Result<Option<types::ContractStorageStats>> {
+        let query =
+            schema::contract::ContractStorageStatsQuery::build(ContractByIdArgs {
+                id: (*id).into(),
+            });
+        let stats = self
+            .query(query)
+            .await?
+            .contract
+            .map(|contract| contract.storage_stats.into());
+        Ok(stats)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -111,6 +111,17 @@ pub struct Register {
     pub register: U64,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "IdArg"
+)]
+pub struct Registers {
+    #[arguments(id: $id)]
+    pub registers: Vec<U64>,
+}
+
 #[derive(cynic::QueryVariables)]
 pub struct MemoryArgs {
     pub id: cynic::Id,
@@ -146,6 +157,28 @@ pub struct SetBreakpoint {
     pub set_breakpoint: bool,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SetBreakpointArgs"
+)]
+pub struct RemoveBreakpoint {
+    #[arguments(id: $id, breakpoint: $bp)]
+    pub remove_breakpoint: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "IdArg"
+)]
+pub struct ClearBreakpoints {
+    #[arguments(id: $id)]
+    pub clear_breakpoints: bool,
+}
+
 #[derive(cynic::InputObject, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Breakpoint {
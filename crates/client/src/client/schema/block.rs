@@ -2,6 +2,8 @@ use crate::client::schema::{
     schema,
     BlockId,
     ConnectionArgs,
+    ContractId,
+    HexString,
     PageInfo,
     Signature,
     Tai64Timestamp,
@@ -47,6 +49,52 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressedBlockArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockArgs"
+)]
+pub struct DaCompressedBlockQuery {
+    #[arguments(height: $height)]
+    pub da_compressed_block: Option<DaCompressedBlock>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressedBlock {
+    pub bytes: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct VerifyBlockHeaderArgs {
+    pub header: HexString,
+    pub signature: Signature,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "VerifyBlockHeaderArgs"
+)]
+pub struct VerifyBlockHeaderQuery {
+    #[arguments(header: $header, signature: $signature)]
+    pub verify_block_header: HeaderVerification,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HeaderVerification {
+    pub valid: bool,
+    pub id: BlockId,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -79,6 +127,7 @@ pub struct Block {
     pub header: Header,
     pub consensus: Consensus,
     pub transactions: Vec<TransactionIdFragment>,
+    pub coinbase_recipient: Option<ContractId>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -141,6 +190,7 @@ pub struct Genesis {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct PoAConsensus {
     pub signature: Signature,
+    pub beacon: Option<Bytes32>,
 }
 
 impl Block {
@@ -181,6 +231,25 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn da_compressed_block_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            DaCompressedBlockQuery::build(DaCompressedBlockArgs { height: U32(0) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn verify_block_header_query_gql_output() {
+        use crate::client::schema::Bytes;
+        use cynic::QueryBuilder;
+        let operation = VerifyBlockHeaderQuery::build(VerifyBlockHeaderArgs {
+            header: HexString(Bytes(vec![])),
+            signature: Signature::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
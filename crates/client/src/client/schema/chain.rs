@@ -321,6 +321,7 @@ pub struct ChainQuery {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ChainInfo {
     pub da_height: U64,
+    pub finalized_height: Option<U32>,
     pub name: String,
     pub latest_block: Block,
     pub consensus_parameters: ConsensusParameters,
@@ -3,6 +3,7 @@ use crate::client::{
         schema,
         Address,
         AssetId,
+        HexString,
         Nonce,
         PageInfo,
         UtxoId,
@@ -212,6 +213,27 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ConsolidationTransactionArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset of the consolidated coins.
+    pub asset_id: AssetId,
+    /// The maximal number of the consolidated coins.
+    pub max_inputs: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConsolidationTransactionArgs"
+)]
+pub struct ConsolidationTransactionQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, maxInputs: $max_inputs)]
+    pub consolidation_transaction: HexString,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,6 +61,30 @@ pub struct Contract {
     pub salt: Salt,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageStats {
+    pub slots: U64,
+    pub bytes: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
+pub struct ContractStorageStatsFragment {
+    pub storage_stats: ContractStorageStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdArgs"
+)]
+pub struct ContractStorageStatsQuery {
+    #[arguments(id: $id)]
+    pub contract: Option<ContractStorageStatsFragment>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
 pub struct ContractIdFragment {
@@ -4,6 +4,7 @@ use crate::client::schema::{
     U64,
 };
 use fuel_core_types::services::p2p::{
+    self,
     HeartbeatData,
     PeerId,
 };
@@ -24,6 +25,9 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub tx_pool_size: U64,
+    pub is_synced: Option<bool>,
+    pub uptime: U64,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -58,7 +62,7 @@ pub struct PeerInfo {
     pub app_score: f64,
 }
 
-impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
+impl From<PeerInfo> for p2p::PeerInfo {
     fn from(info: PeerInfo) -> Self {
         Self {
             id: PeerId::from_str(info.id.as_str()).unwrap_or_default(),
@@ -75,6 +79,50 @@ impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
     }
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SyncStage {
+    Connecting,
+    HeaderSync,
+    BlockDownload,
+    Execution,
+    Synced,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SyncStatus {
+    pub stage: SyncStage,
+    pub local_height: Option<U32>,
+    pub best_known_height: Option<U32>,
+    pub blocks_per_second: f64,
+    pub eta_seconds: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QuerySyncStatus {
+    pub sync_status: Option<SyncStatus>,
+}
+
+impl From<SyncStatus> for p2p::SyncStatus {
+    fn from(status: SyncStatus) -> Self {
+        Self {
+            stage: match status.stage {
+                SyncStage::Connecting => p2p::SyncStage::Connecting,
+                SyncStage::HeaderSync => p2p::SyncStage::HeaderSync,
+                SyncStage::BlockDownload => p2p::SyncStage::BlockDownload,
+                SyncStage::Execution => p2p::SyncStage::Execution,
+                SyncStage::Synced => p2p::SyncStage::Synced,
+            },
+            local_height: status.local_height.map(|h| h.0.into()),
+            best_known_height: status.best_known_height.map(|h| h.0.into()),
+            blocks_per_second: status.blocks_per_second,
+            eta: status.eta_seconds.map(|eta| Duration::from_secs(eta.0)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +140,11 @@ mod tests {
         let operation = QueryPeersInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn sync_status_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QuerySyncStatus::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
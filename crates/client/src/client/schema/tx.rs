@@ -5,11 +5,13 @@ use crate::client::{
         tx::transparent_receipt::Receipt,
         Address,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -24,6 +26,7 @@ use fuel_core_types::{
     },
     fuel_vm,
     services::executor::{
+        self,
         TransactionExecutionResult,
         TransactionExecutionStatus,
     },
@@ -53,6 +56,18 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+/// Retrieves the status of the transaction, including the archived ones
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionStatusQuery {
+    #[arguments(id: $id)]
+    pub transaction_status: Option<TransactionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -94,6 +109,59 @@ pub struct TransactionEdge {
     pub node: OpaqueTransaction,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct PoolTransactionsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub pool_transactions: PoolTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PoolTransactionConnection {
+    pub edges: Vec<PoolTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+impl TryFrom<PoolTransactionConnection>
+    for PaginatedResult<crate::client::types::PoolTransaction, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: PoolTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<_>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PoolTransactionEdge {
+    pub cursor: String,
+    pub node: PoolTransaction,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PoolTransaction {
+    pub id: TransactionId,
+    pub transaction: OpaqueTransaction,
+    pub submitted_at: Tai64Timestamp,
+    pub gas_price: U64,
+    pub dependencies: Vec<TransactionId>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(graphql_type = "Transaction", schema_path = "./assets/schema.sdl")]
 pub struct OpaqueTransaction {
@@ -182,6 +250,7 @@ pub struct SuccessStatus {
     pub time: Tai64Timestamp,
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeReport,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -193,6 +262,32 @@ pub struct FailureStatus {
     pub reason: String,
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeReport,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FeeReport {
+    pub script_gas_used: U64,
+    pub predicate_gas_used: U64,
+    pub gas_price: U64,
+    pub max_fee: U64,
+    pub fee: U64,
+    pub refunded: U64,
+    pub recipient: Option<ContractId>,
+}
+
+impl From<FeeReport> for executor::FeeReport {
+    fn from(report: FeeReport) -> Self {
+        Self {
+            script_gas_used: report.script_gas_used.into(),
+            predicate_gas_used: report.predicate_gas_used.into(),
+            gas_price: report.gas_price.into(),
+            max_fee: report.max_fee.into(),
+            fee: report.fee.into(),
+            refunded: report.refunded.into(),
+        }
+    }
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -225,6 +320,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
                 TransactionExecutionResult::Success {
                     result: s.program_state.map(TryInto::try_into).transpose()?,
                     receipts,
+                    fee: s.fee.into(),
                 }
             }
             DryRunTransactionStatus::FailureStatus(s) => {
@@ -236,6 +332,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
                 TransactionExecutionResult::Failed {
                     result: s.program_state.map(TryInto::try_into).transpose()?,
                     receipts,
+                    fee: s.fee.into(),
                 }
             }
             DryRunTransactionStatus::Unknown => {
@@ -250,6 +347,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
 pub struct DryRunSuccessStatus {
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeReport,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -257,6 +355,7 @@ pub struct DryRunSuccessStatus {
 pub struct DryRunFailureStatus {
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeReport,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -381,6 +480,23 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct SubmitAfterArg {
+    pub tx: HexString,
+    pub after: TransactionId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "SubmitAfterArg"
+)]
+pub struct SubmitAfter {
+    #[arguments(tx: $tx, after: $after)]
+    pub submit: TransactionIdFragment,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -422,6 +538,15 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn transaction_status_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionStatusQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn transactions_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -434,6 +559,18 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn pool_transactions_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PoolTransactionsQuery::build(ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn transactions_by_owner_gql_output() {
         use cynic::QueryBuilder;
@@ -24,6 +24,7 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractStorageStats,
 };
 pub use gas_costs::{
     DependentCost,
@@ -39,6 +40,7 @@ pub use node_info::NodeInfo;
 use crate::client::schema::{
     tx::{
         OpaqueTransaction,
+        PoolTransaction as SchemaPoolTransaction,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
@@ -47,9 +49,14 @@ use fuel_core_types::{
     fuel_tx::{
         Receipt,
         Transaction,
+        TxId,
+    },
+    fuel_types::{
+        canonical::Deserialize,
+        ContractId,
     },
-    fuel_types::canonical::Deserialize,
     fuel_vm::ProgramState,
+    services::executor::FeeReport,
 };
 use tai64::Tai64;
 
@@ -96,6 +103,9 @@ pub enum TransactionStatus {
         time: Tai64,
         program_state: Option<ProgramState>,
         receipts: Vec<Receipt>,
+        fee: FeeReport,
+        /// The contract that received the fee, if the fee is collected.
+        fee_recipient: Option<ContractId>,
     },
     SqueezedOut {
         reason: String,
@@ -106,6 +116,9 @@ pub enum TransactionStatus {
         reason: String,
         program_state: Option<ProgramState>,
         receipts: Vec<Receipt>,
+        fee: FeeReport,
+        /// The contract that received the fee, if the fee is collected.
+        fee_recipient: Option<ContractId>,
     },
 }
 
@@ -126,6 +139,8 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<Vec<_>, _>>()?,
+                fee_recipient: s.fee.recipient.clone().map(Into::into),
+                fee: s.fee.into(),
             },
             SchemaTxStatus::FailureStatus(s) => TransactionStatus::Failure {
                 block_id: s.block.id.0.to_string(),
@@ -137,6 +152,8 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<Vec<_>, _>>()?,
+                fee_recipient: s.fee.recipient.clone().map(Into::into),
+                fee: s.fee.into(),
             },
             SchemaTxStatus::SqueezedOutStatus(s) => {
                 TransactionStatus::SqueezedOut { reason: s.reason }
@@ -148,6 +165,31 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
     }
 }
 
+/// The transaction pending in the `TxPool`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PoolTransaction {
+    pub id: TxId,
+    pub transaction: Transaction,
+    pub submitted_at: Tai64,
+    pub gas_price: u64,
+    /// The ids of the pending transactions whose outputs are spent by the transaction.
+    pub dependencies: Vec<TxId>,
+}
+
+impl TryFrom<SchemaPoolTransaction> for PoolTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaPoolTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            transaction: value.transaction.try_into()?,
+            submitted_at: value.submitted_at.0,
+            gas_price: value.gas_price.into(),
+            dependencies: value.dependencies.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
 impl TryFrom<OpaqueTransaction> for TransactionResponse {
     type Error = ConversionError;
 
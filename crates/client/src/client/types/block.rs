@@ -2,6 +2,7 @@ use crate::client::{
     schema,
     types::primitives::{
         BlockId,
+        ContractId,
         Hash,
         MerkleRoot,
         PublicKey,
@@ -19,6 +20,8 @@ pub struct Block {
     pub consensus: Consensus,
     pub transactions: Vec<TransactionId>,
     pub block_producer: Option<PublicKey>,
+    /// The recipient of the fees of the block, if the fees are collected.
+    pub coinbase_recipient: Option<ContractId>,
 }
 
 impl Block {
@@ -59,6 +62,8 @@ pub struct Genesis {
 #[derive(Debug)]
 pub struct PoAConsensus {
     pub signature: Signature,
+    /// The random value of the randomness beacon of the block, if any.
+    pub beacon: Option<Hash>,
 }
 
 // GraphQL Translation
@@ -110,6 +115,7 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
         let bytes: [u8; 64] = value.signature.0 .0.into();
         Self {
             signature: Signature::from_bytes(bytes),
+            beacon: value.beacon.map(Into::into),
         }
     }
 }
@@ -129,6 +135,7 @@ impl From<schema::block::Block> for Block {
             consensus: value.consensus.into(),
             transactions,
             block_producer,
+            coinbase_recipient: value.coinbase_recipient.map(Into::into),
         }
     }
 }
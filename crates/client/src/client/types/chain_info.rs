@@ -9,6 +9,7 @@ use fuel_core_types::{
 
 pub struct ChainInfo {
     pub da_height: u64,
+    pub finalized_height: Option<u32>,
     pub name: String,
     pub latest_block: Block,
     pub consensus_parameters: ConsensusParameters,
@@ -20,6 +21,7 @@ impl From<schema::chain::ChainInfo> for ChainInfo {
     fn from(value: schema::chain::ChainInfo) -> Self {
         Self {
             da_height: value.da_height.into(),
+            finalized_height: value.finalized_height.map(Into::into),
             name: value.name,
             latest_block: value.latest_block.into(),
             consensus_parameters: value.consensus_parameters.into(),
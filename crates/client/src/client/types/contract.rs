@@ -15,6 +15,12 @@ pub struct Contract {
     pub salt: Salt,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractStorageStats {
+    pub slots: u64,
+    pub bytes: u64,
+}
+
 #[derive(Debug)]
 pub struct ContractBalance {
     pub contract: ContractId,
@@ -34,6 +40,15 @@ impl From<schema::contract::Contract> for Contract {
     }
 }
 
+impl From<schema::contract::ContractStorageStats> for ContractStorageStats {
+    fn from(value: schema::contract::ContractStorageStats) -> Self {
+        Self {
+            slots: value.slots.into(),
+            bytes: value.bytes.into(),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalance> for ContractBalance {
     fn from(value: schema::contract::ContractBalance) -> Self {
         Self {
@@ -7,6 +7,11 @@ pub struct NodeInfo {
     pub max_tx: u64,
     pub max_depth: u64,
     pub node_version: String,
+    pub tx_pool_size: u64,
+    /// `None` if the block production is disabled on the node.
+    pub is_synced: Option<bool>,
+    /// The number of seconds since the start of the node.
+    pub uptime: u64,
 }
 
 // GraphQL Translation
@@ -20,6 +25,9 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
             max_tx: value.max_tx.into(),
             max_depth: value.max_depth.into(),
             node_version: value.node_version,
+            tx_pool_size: value.tx_pool_size.into(),
+            is_synced: value.is_synced,
+            uptime: value.uptime.into(),
         }
     }
 }
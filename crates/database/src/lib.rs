@@ -12,7 +12,10 @@
 #![deny(unused_variables)]
 
 use fuel_core_storage::Error as StorageError;
-use fuel_core_types::services::executor::Error as ExecutorError;
+use fuel_core_types::{
+    fuel_types::ChainId,
+    services::executor::Error as ExecutorError,
+};
 
 /// The error occurred during work with any of databases.
 #[derive(Debug, derive_more::Display, derive_more::From)]
@@ -37,6 +40,26 @@ pub enum Error {
         /// the database version expected by this build of fuel-core
         expected: u32,
     },
+    /// There is no migration to upgrade the database from the version.
+    #[display(
+        fmt = "There is no migration of the {name} database from the version {version}"
+    )]
+    MissingMigration {
+        /// the name of the database
+        name: &'static str,
+        /// the version of the database without the migration
+        version: u32,
+    },
+    /// The database was created for another chain.
+    #[display(
+        fmt = "The database was created for the chain id {found}, but the node runs the chain id {expected}"
+    )]
+    ChainIdMismatch {
+        /// the chain id of the database
+        found: ChainId,
+        /// the chain id of the node
+        expected: ChainId,
+    },
 
     /// Not related to database error.
     #[from]
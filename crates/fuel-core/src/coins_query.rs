@@ -13,6 +13,13 @@ use fuel_core_types::{
         CoinId,
         CoinType,
     },
+    fuel_tx::{
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        Witness,
+    },
     fuel_types::{
         Address,
         AssetId,
@@ -40,6 +47,8 @@ pub enum CoinsQueryError {
     MaxCoinsReached,
     #[error("the query contains duplicate assets")]
     DuplicateAssets(AssetId),
+    #[error("at least two coins are required for the consolidation")]
+    NothingToConsolidate,
 }
 
 #[cfg(test)]
@@ -49,6 +58,21 @@ impl PartialEq for CoinsQueryError {
     }
 }
 
+/// The algorithm used to select the coins to spend.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Selects the biggest coins first. Minimizes the number of inputs.
+    LargestFirst,
+    /// Searches for the set of coins that matches the target exactly, so the
+    /// transaction doesn't create the change output. Falls back to the
+    /// [`SelectionStrategy::LargestFirst`] if there is no exact match.
+    BranchAndBound,
+    /// Selects random coins and improves the result with the dust.
+    /// Doesn't reveal the structure of the owner's coins.
+    #[default]
+    RandomImprove,
+}
+
 /// The prepared spend queries.
 pub struct SpendQuery {
     owner: Address,
@@ -157,6 +181,91 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
     Ok(coins)
 }
 
+/// The maximal number of the steps of the search for the exact match before giving up.
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
+/// Returns the inputs of the `owner` that sum up to the `target` of the asset exactly.
+/// If there is no such set of inputs, or the search takes too long, the result
+/// of the [`largest_first`] is returned.
+pub fn branch_and_bound(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let amounts = inputs.iter().map(CoinType::amount).collect_vec();
+    match exact_match(&amounts, query.asset.target, query.asset.max) {
+        Some(selected) => {
+            let mut inputs = inputs.into_iter().map(Some).collect_vec();
+            Ok(selected
+                .into_iter()
+                .filter_map(|index| inputs.get_mut(index).and_then(Option::take))
+                .collect())
+        }
+        None => largest_first(query),
+    }
+}
+
+/// Searches for the indexes of the `amounts`, sorted in descending order, that
+/// sum up to the `target` and whose number doesn't exceed `max`.
+fn exact_match(amounts: &[Word], target: Word, max: usize) -> Option<Vec<usize>> {
+    let target = u128::from(target);
+    // The sum of the amounts starting from the index, used to prune the branches
+    // that can't reach the target.
+    let mut remaining = vec![0u128; amounts.len().saturating_add(1)];
+    for (index, amount) in amounts.iter().enumerate().rev() {
+        remaining[index] =
+            remaining[index.saturating_add(1)].saturating_add(u128::from(*amount));
+    }
+
+    let mut selected: Vec<usize> = vec![];
+    let mut sum = 0u128;
+    let mut next = 0usize;
+    for _ in 0..BRANCH_AND_BOUND_MAX_TRIES {
+        if sum == target {
+            return Some(selected)
+        }
+
+        let exceeded = sum > target;
+        let unreachable = sum.saturating_add(remaining[next]) < target;
+        if exceeded || unreachable || selected.len() >= max {
+            // Excludes the last selected coin and tries the next one.
+            let last = selected.pop()?;
+            sum = sum.saturating_sub(u128::from(amounts[last]));
+            next = last.saturating_add(1);
+            // The coins with the same amount lead to the same sums.
+            while next < amounts.len() && amounts[next] == amounts[last] {
+                next = next.saturating_add(1);
+            }
+        } else {
+            selected.push(next);
+            sum = sum.saturating_add(u128::from(amounts[next]));
+            next = next.saturating_add(1);
+        }
+    }
+
+    None
+}
+
+/// Selects the coins for each asset of the `spend_query` with the `strategy`.
+pub fn select_coins(
+    db: &ReadView,
+    spend_query: &SpendQuery,
+    strategy: SelectionStrategy,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    match strategy {
+        SelectionStrategy::LargestFirst => spend_query
+            .asset_queries(db)
+            .iter()
+            .map(largest_first)
+            .collect(),
+        SelectionStrategy::BranchAndBound => spend_query
+            .asset_queries(db)
+            .iter()
+            .map(branch_and_bound)
+            .collect(),
+        SelectionStrategy::RandomImprove => random_improve(db, spend_query),
+    }
+}
+
 // An implementation of the method described on: https://iohk.io/en/blog/posts/2018/07/03/self-organisation-in-coin-selection/
 pub fn random_improve(
     db: &ReadView,
@@ -212,6 +321,49 @@ pub fn random_improve(
     Ok(coins_per_asset)
 }
 
+/// Returns the unsigned transaction that consolidates the smallest inputs of the
+/// `owner` into one change output. The number of inputs doesn't exceed the `max`
+/// of the asset query. All inputs are signed by the first witness.
+pub fn consolidation_transaction(
+    query: &AssetQuery,
+) -> Result<Transaction, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.sort_by_key(CoinType::amount);
+    inputs.truncate(query.asset.max);
+
+    if inputs.len() < 2 {
+        return Err(CoinsQueryError::NothingToConsolidate)
+    }
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for input in inputs {
+        let input = match input {
+            CoinType::Coin(coin) => Input::coin_signed(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                coin.tx_pointer,
+                0,
+                coin.maturity,
+            ),
+            CoinType::MessageCoin(message) => Input::message_coin_signed(
+                message.sender,
+                message.recipient,
+                message.amount,
+                message.nonce,
+                0,
+            ),
+        };
+        builder.add_input(input);
+    }
+    builder
+        .add_output(Output::change(*query.owner, 0, query.asset.id))
+        .add_witness(Witness::default());
+
+    Ok(builder.finalize_without_signature_as_transaction())
+}
+
 impl From<StorageError> for CoinsQueryError {
     fn from(e: StorageError) -> Self {
         CoinsQueryError::StorageError(e)
@@ -480,6 +632,146 @@ mod tests {
         }
     }
 
+    mod branch_and_bound {
+        use super::*;
+        use crate::coins_query::{
+            branch_and_bound,
+            exact_match,
+        };
+
+        fn query(
+            asset: AssetSpendTarget,
+            owner: &Address,
+            base_asset_id: &AssetId,
+            db: &ServiceDatabase,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let coins = branch_and_bound(&AssetQuery::new(
+                owner,
+                &asset,
+                base_asset_id,
+                None,
+                &db.view(),
+            ))?;
+            Ok(coins.iter().map(|coin| coin.amount()).collect())
+        }
+
+        #[test]
+        fn selects_exact_match() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins_and_messages();
+
+            for asset_id in asset_ids {
+                for target in 1..=15 {
+                    let amounts = query(
+                        AssetSpendTarget::new(asset_id, target, usize::MAX),
+                        &owner,
+                        &base_asset_id,
+                        &db.service_database(),
+                    )
+                    .unwrap();
+
+                    assert_eq!(amounts.iter().sum::<Word>(), target);
+                }
+            }
+        }
+
+        #[test]
+        fn falls_back_to_largest_first_without_exact_match() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+
+            let amounts = query(
+                AssetSpendTarget::new(asset_ids[0], 7, 1),
+                &owner,
+                &base_asset_id,
+                &db.service_database(),
+            );
+            assert_matches!(amounts, Err(CoinsQueryError::MaxCoinsReached));
+
+            let amounts = query(
+                AssetSpendTarget::new(asset_ids[0], 16, usize::MAX),
+                &owner,
+                &base_asset_id,
+                &db.service_database(),
+            );
+            assert_matches!(
+                amounts,
+                Err(CoinsQueryError::InsufficientCoins {
+                    collected_amount: 15,
+                    ..
+                })
+            );
+        }
+
+        #[test]
+        fn exact_match_respects_max_inputs() {
+            let amounts = [10, 7, 5, 3, 1];
+
+            assert_eq!(exact_match(&amounts, 8, usize::MAX), Some(vec![1, 4]));
+            assert_eq!(exact_match(&amounts, 9, 2), None);
+            assert_eq!(exact_match(&amounts, 9, 3), Some(vec![2, 3, 4]));
+            assert_eq!(exact_match(&amounts, 27, usize::MAX), None);
+        }
+    }
+
+    mod consolidation {
+        use super::*;
+        use crate::coins_query::consolidation_transaction;
+        use fuel_core_types::fuel_tx::field::{
+            Inputs,
+            Outputs,
+            Witnesses,
+        };
+
+        #[test]
+        fn consolidates_smallest_coins_into_change() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins_and_messages();
+            let db = db.service_database();
+            let target = AssetSpendTarget::new(asset_ids[0], u64::MAX, 3);
+
+            let tx = consolidation_transaction(&AssetQuery::new(
+                &owner,
+                &target,
+                &base_asset_id,
+                None,
+                &db.view(),
+            ))
+            .unwrap();
+
+            let Transaction::Script(script) = tx else {
+                panic!("Expected script transaction");
+            };
+            let amounts = script
+                .inputs()
+                .iter()
+                .map(|input| input.amount().unwrap())
+                .collect_vec();
+            assert_eq!(amounts, vec![1, 2, 3]);
+            assert_eq!(
+                script.outputs(),
+                &vec![Output::change(owner, 0, asset_ids[0])]
+            );
+            assert_eq!(script.witnesses().len(), 1);
+        }
+
+        #[test]
+        fn single_coin_is_not_consolidated() {
+            let (_, asset_ids, base_asset_id, mut db) = setup_coins();
+            let other_owner = Address::from([1; 32]);
+            db.make_coin(other_owner, 10, asset_ids[0]);
+            let db = db.service_database();
+            let target = AssetSpendTarget::new(asset_ids[0], u64::MAX, usize::MAX);
+
+            let result = consolidation_transaction(&AssetQuery::new(
+                &other_owner,
+                &target,
+                &base_asset_id,
+                None,
+                &db.view(),
+            ));
+
+            assert_matches!(result, Err(CoinsQueryError::NothingToConsolidate));
+        }
+    }
+
     mod random_improve {
         use super::*;
 
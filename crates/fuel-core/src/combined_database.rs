@@ -3,15 +3,25 @@ use crate::database::{
         off_chain::OffChain,
         on_chain::OnChain,
         relayer::Relayer,
+        DatabaseDescription,
     },
+    migration::MigrationConfig,
     Database,
+    Error as DatabaseError,
     Result as DatabaseResult,
 };
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
 };
+use std::path::Path;
+
+/// The file in the directory of the database with the chain id of the database.
+const CHAIN_ID_FILE: &str = "CHAIN_ID";
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
 #[derive(Default, Clone)]
@@ -47,6 +57,15 @@ impl CombinedDatabase {
         })
     }
 
+    /// Adds the cache of the `capacity` entries in front of the contract state
+    /// and balances. See [`Database::with_state_cache`].
+    pub fn with_state_cache(self, capacity: usize) -> Self {
+        Self {
+            on_chain: self.on_chain.with_state_cache(capacity),
+            ..self
+        }
+    }
+
     pub fn in_memory() -> Self {
         Self::new(
             Database::in_memory(),
@@ -66,6 +85,15 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Migrates the databases to the versions expected by this build of the node.
+    /// See [`Database::migrate`] for details.
+    pub fn migrate(&self, config: &MigrationConfig) -> StorageResult<Vec<&'static str>> {
+        let mut applied = self.on_chain.migrate(&OnChain::migrations(), config)?;
+        applied.extend(self.off_chain.migrate(&OffChain::migrations(), config)?);
+        applied.extend(self.relayer.migrate(&Relayer::migrations(), config)?);
+        Ok(applied)
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -83,10 +111,97 @@ impl CombinedDatabase {
         &self.relayer
     }
 
+    /// Returns the approximate size in bytes of each column of each database,
+    /// as the `(database, column, size)` triples.
+    pub fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, &'static str, u64)>> {
+        fn sizes<Description: DatabaseDescription>(
+            database: &Database<Description>,
+        ) -> DatabaseResult<Vec<(&'static str, &'static str, u64)>> {
+            Ok(database
+                .column_sizes()?
+                .into_iter()
+                .map(|(column, size)| (Description::name(), column, size))
+                .collect())
+        }
+        let mut result = sizes(&self.on_chain)?;
+        result.extend(sizes(&self.off_chain)?);
+        result.extend(sizes(&self.relayer)?);
+        Ok(result)
+    }
+
+    /// Returns the directory with the data of the on-chain database,
+    /// if it is stored on the disk. All databases share the same volume.
+    pub fn path(&self) -> Option<std::path::PathBuf> {
+        self.on_chain.path()
+    }
+
+    /// Enables or disables the write-ahead log of all databases.
+    /// See [`Database::set_durable`].
+    pub fn set_durable(&self, durable: bool) -> DatabaseResult<()> {
+        self.on_chain.set_durable(durable)?;
+        self.off_chain.set_durable(durable)?;
+        self.relayer.set_durable(durable)?;
+        Ok(())
+    }
+
     pub fn flush(self) -> DatabaseResult<()> {
         self.on_chain.flush()?;
         self.off_chain.flush()?;
         self.relayer.flush()?;
         Ok(())
     }
+
+    /// Compacts the storages of all databases.
+    pub fn compact(&self) -> DatabaseResult<()> {
+        self.on_chain.compact()?;
+        self.off_chain.compact()?;
+        self.relayer.compact()?;
+        Ok(())
+    }
+
+    /// Checks that the database at the `path` was created for the `chain_id`.
+    /// The database without the chain id is bound to the `chain_id`, so
+    /// the databases of different chains can't be mixed up by the path.
+    pub fn check_chain_id(path: &Path, chain_id: &ChainId) -> DatabaseResult<()> {
+        let file = path.join(CHAIN_ID_FILE);
+        if file.exists() {
+            let content = std::fs::read_to_string(&file).map_err(anyhow::Error::from)?;
+            let found = content.trim().parse::<u64>().map_err(|e| {
+                anyhow::anyhow!("Invalid chain id in {}: {e}", file.display())
+            })?;
+            let found = ChainId::new(found);
+            if &found != chain_id {
+                return Err(DatabaseError::ChainIdMismatch {
+                    found,
+                    expected: *chain_id,
+                })
+            }
+        } else {
+            std::fs::create_dir_all(path).map_err(anyhow::Error::from)?;
+            std::fs::write(&file, u64::from(*chain_id).to_string())
+                .map_err(anyhow::Error::from)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn database_is_bound_to_first_chain_id() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("db");
+
+        CombinedDatabase::check_chain_id(&path, &ChainId::new(1)).unwrap();
+        CombinedDatabase::check_chain_id(&path, &ChainId::new(1)).unwrap();
+        let result = CombinedDatabase::check_chain_id(&path, &ChainId::new(2));
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::ChainIdMismatch { found, expected })
+                if found == ChainId::new(1) && expected == ChainId::new(2)
+        ));
+    }
 }
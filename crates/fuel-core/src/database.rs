@@ -9,6 +9,7 @@ use crate::{
         transaction::DatabaseTransaction,
     },
     state::{
+        cached::CachedStore,
         in_memory::memory_store::MemoryStore,
         DataSource,
     },
@@ -26,6 +27,7 @@ use fuel_core_storage::{
         Encode,
         Encoder,
     },
+    column::Column,
     iter::IterDirection,
     kv_store::{
         BatchOperations,
@@ -57,6 +59,8 @@ use std::{
         Formatter,
     },
     marker::Send,
+    num::NonZeroUsize,
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -77,10 +81,13 @@ use tempfile::TempDir;
 pub mod balances;
 pub mod block;
 pub mod coin;
+pub mod contract_storage_stats;
 pub mod contracts;
 pub mod database_description;
 pub mod message;
 pub mod metadata;
+pub mod migration;
+pub mod owned_balances;
 pub mod sealed_block;
 pub mod state;
 pub mod statistic;
@@ -189,6 +196,47 @@ where
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    /// Compacts the underlying storage.
+    pub fn compact(&self) -> DatabaseResult<()> {
+        self.data.as_ref().compact()
+    }
+
+    /// Returns the approximate size in bytes of the data of each column.
+    pub fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, u64)>> {
+        self.data.as_ref().column_sizes()
+    }
+
+    /// Returns the directory with the data of the database, if it is stored on the disk.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.data.as_ref().path()
+    }
+
+    /// Enables or disables the write-ahead log. See [`crate::state::TransactableStorage::set_durable`].
+    pub fn set_durable(&self, durable: bool) -> DatabaseResult<()> {
+        self.data.as_ref().set_durable(durable)
+    }
+}
+
+impl Database<OnChain> {
+    /// Adds the LRU cache of the `capacity` entries in front of the contract state
+    /// and balances, because popular contracts read the same slots in every block.
+    /// The zero `capacity` disables the cache.
+    pub fn with_state_cache(self, capacity: usize) -> Self {
+        let Some(capacity) = NonZeroUsize::new(capacity) else {
+            return self
+        };
+        let data: &DataSource = self.data.as_ref();
+        let cached = CachedStore::new(
+            Arc::clone(data),
+            vec![Column::ContractsState, Column::ContractsAssets],
+            capacity,
+        );
+        Self {
+            data: StructuredStorage::new(Arc::new(cached).into()),
+            _drop: self._drop,
+        }
+    }
 }
 
 impl<Description> KeyValueStore for DataSource<Description>
@@ -332,17 +380,18 @@ where
             )
             .map(|val| {
                 val.and_then(|(key, value)| {
-                    let key =
+                    let corruption = || StorageError::corruption(M::column(), &key);
+                    let decoded_key =
                         <M::Blueprint as Blueprint<M, DataSource>>::KeyCodec::decode(
                             key.as_slice(),
                         )
-                        .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))?;
+                        .map_err(|_| corruption())?;
                     let value =
                         <M::Blueprint as Blueprint<M, DataSource>>::ValueCodec::decode(
                             value.as_slice(),
                         )
-                        .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))?;
-                    Ok((key, value))
+                        .map_err(|_| corruption())?;
+                    Ok((decoded_key, value))
                 })
             })
     }
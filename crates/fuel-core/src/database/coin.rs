@@ -1,5 +1,6 @@
 use crate::database::{
     database_description::on_chain::OnChain,
+    owned_balances::BalanceChange,
     Database,
 };
 use fuel_core_chain_config::CoinConfig;
@@ -85,6 +86,15 @@ impl StorageMutate<Coins> for Database {
         // insert secondary index by owner
         self.storage_as_mut::<OwnedCoins>()
             .insert(&coin_by_owner, &())?;
+        // update the balances of the owners
+        if let Some(replaced) = &insert {
+            let key = owner_coin_id_key(replaced.owner(), key);
+            if key != coin_by_owner {
+                self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            }
+            self.update_coin_balance(replaced, BalanceChange::Decrease)?;
+        }
+        self.update_coin_balance(value, BalanceChange::Increase)?;
         Ok(insert)
     }
 
@@ -95,6 +105,7 @@ impl StorageMutate<Coins> for Database {
         if let Some(coin) = &coin {
             let key = owner_coin_id_key(coin.owner(), key);
             self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            self.update_coin_balance(coin, BalanceChange::Decrease)?;
         }
 
         Ok(coin)
@@ -0,0 +1,340 @@
+//! The number and the size of the storage slots of each contract maintained
+//! incrementally on each change of the contract state, so the contributors to the
+//! growth of the state are known without iterating it.
+
+use crate::database::{
+    database_description::on_chain::OnChain,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::ContractsState,
+    ContractsStateKey,
+    Error as StorageError,
+    Mappable,
+    MerkleRoot,
+    MerkleRootStorage,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+};
+use fuel_core_types::{
+    fuel_types::{
+        Bytes32,
+        ContractId,
+    },
+    services::graphql_api::ContractStorageStats,
+};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
+
+/// The table of the number and the size of the storage slots per contract.
+pub struct ContractsStorageStats;
+
+impl Mappable for ContractsStorageStats {
+    type Key = ContractId;
+    type OwnedKey = Self::Key;
+    type Value = ContractStorageStats;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractsStorageStats {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractsStorageStats
+    }
+}
+
+/// The size of the storage slot: the key of the slot and the value.
+fn slot_size(value: &Bytes32) -> u64 {
+    (Bytes32::LEN as u64).saturating_add(value.len() as u64)
+}
+
+/// Whether the storage slot is added to the contract or removed from it.
+#[derive(Clone, Copy)]
+pub(crate) enum SlotChange {
+    Added,
+    Removed,
+}
+
+impl SlotChange {
+    fn apply(self, stats: &mut ContractStorageStats, slots: u64, bytes: u64) {
+        match self {
+            Self::Added => {
+                stats.slots = stats.slots.saturating_add(slots);
+                stats.bytes = stats.bytes.saturating_add(bytes);
+            }
+            Self::Removed => {
+                stats.slots = stats.slots.saturating_sub(slots);
+                stats.bytes = stats.bytes.saturating_sub(bytes);
+            }
+        }
+    }
+}
+
+impl StorageInspect<ContractsState> for Database {
+    type Error = StorageError;
+
+    fn get(&self, key: &ContractsStateKey) -> StorageResult<Option<Cow<'_, Bytes32>>> {
+        self.data.storage::<ContractsState>().get(key)
+    }
+
+    fn contains_key(&self, key: &ContractsStateKey) -> StorageResult<bool> {
+        self.data.storage::<ContractsState>().contains_key(key)
+    }
+}
+
+impl StorageMutate<ContractsState> for Database {
+    fn insert(
+        &mut self,
+        key: &ContractsStateKey,
+        value: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        let result = self
+            .data
+            .storage_as_mut::<ContractsState>()
+            .insert(key, value)?;
+
+        let contract_id = key.contract_id();
+        if let Some(replaced) = &result {
+            self.update_contract_storage_stats(
+                contract_id,
+                SlotChange::Removed,
+                1,
+                slot_size(replaced),
+            )?;
+        }
+        self.update_contract_storage_stats(
+            contract_id,
+            SlotChange::Added,
+            1,
+            slot_size(value),
+        )?;
+
+        Ok(result)
+    }
+
+    fn remove(&mut self, key: &ContractsStateKey) -> StorageResult<Option<Bytes32>> {
+        let result = self.data.storage_as_mut::<ContractsState>().remove(key)?;
+
+        if let Some(removed) = &result {
+            self.update_contract_storage_stats(
+                key.contract_id(),
+                SlotChange::Removed,
+                1,
+                slot_size(removed),
+            )?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl StorageBatchMutate<ContractsState> for Database {
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a ContractsStateKey, &'a Bytes32)>,
+    {
+        let set = set.collect::<Vec<_>>();
+        StorageBatchMutate::<ContractsState>::init_storage(
+            &mut self.data,
+            set.iter().copied(),
+        )?;
+
+        let mut contracts = HashMap::<ContractId, ContractStorageStats>::new();
+        for (key, value) in set {
+            let stats = contracts.entry(*key.contract_id()).or_default();
+            SlotChange::Added.apply(stats, 1, slot_size(value));
+        }
+        for (contract_id, stats) in contracts {
+            self.update_contract_storage_stats(
+                &contract_id,
+                SlotChange::Added,
+                stats.slots,
+                stats.bytes,
+            )?;
+        }
+        Ok(())
+    }
+
+    // The stats depend on the previous values of the slots,
+    // so the slots are updated one by one.
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a ContractsStateKey, &'a Bytes32)>,
+    {
+        for (key, value) in set {
+            StorageMutate::<ContractsState>::insert(self, key, value)?;
+        }
+        Ok(())
+    }
+
+    fn remove_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = &'a ContractsStateKey>,
+    {
+        for key in set {
+            StorageMutate::<ContractsState>::remove(self, key)?;
+        }
+        Ok(())
+    }
+}
+
+impl MerkleRootStorage<ContractId, ContractsState> for Database {
+    fn root(&self, key: &ContractId) -> StorageResult<MerkleRoot> {
+        self.data.storage::<ContractsState>().root(key)
+    }
+}
+
+impl Database<OnChain> {
+    /// Returns the number and the size of the storage slots of the contract.
+    pub fn contract_storage_stats(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<ContractStorageStats> {
+        Ok(self
+            .storage::<ContractsStorageStats>()
+            .get(contract_id)?
+            .map(Cow::into_owned)
+            .unwrap_or_default())
+    }
+
+    /// Updates the storage stats of the contract by the `slots` with the total size of `bytes`.
+    pub(crate) fn update_contract_storage_stats(
+        &mut self,
+        contract_id: &ContractId,
+        change: SlotChange,
+        slots: u64,
+        bytes: u64,
+    ) -> StorageResult<()> {
+        let mut stats = self.contract_storage_stats(contract_id)?;
+        change.apply(&mut stats, slots, bytes);
+        if stats.slots == 0 {
+            self.storage_as_mut::<ContractsStorageStats>()
+                .remove(contract_id)?;
+        } else {
+            self.storage_as_mut::<ContractsStorageStats>()
+                .insert(contract_id, &stats)?;
+        }
+        Ok(())
+    }
+
+    /// Builds the storage stats from the state of all contracts. Used by the migration
+    /// of the databases created before the stats were maintained.
+    pub(crate) fn rebuild_contract_storage_stats(&mut self) -> StorageResult<()> {
+        let mut contracts = HashMap::<ContractId, ContractStorageStats>::new();
+        for result in self.iter_all::<ContractsState>(None) {
+            let (key, value) = result?;
+            let stats = contracts.entry(*key.contract_id()).or_default();
+            SlotChange::Added.apply(stats, 1, slot_size(&value));
+        }
+
+        for (contract_id, stats) in contracts {
+            self.storage_as_mut::<ContractsStorageStats>()
+                .insert(&contract_id, &stats)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_id() -> ContractId {
+        ContractId::from([1; 32])
+    }
+
+    fn key(slot: u8) -> ContractsStateKey {
+        ContractsStateKey::new(&contract_id(), &Bytes32::from([slot; 32]))
+    }
+
+    #[test]
+    fn stats_follow_contract_state() {
+        let mut database = Database::<OnChain>::default();
+
+        StorageMutate::<ContractsState>::insert(&mut database, &key(1), &[1; 32].into())
+            .unwrap();
+        StorageMutate::<ContractsState>::insert(&mut database, &key(2), &[2; 32].into())
+            .unwrap();
+        // Overwriting the slot doesn't add a new one.
+        StorageMutate::<ContractsState>::insert(&mut database, &key(2), &[3; 32].into())
+            .unwrap();
+        assert_eq!(
+            database.contract_storage_stats(&contract_id()).unwrap(),
+            ContractStorageStats {
+                slots: 2,
+                bytes: 128
+            }
+        );
+
+        StorageMutate::<ContractsState>::remove(&mut database, &key(1)).unwrap();
+        StorageMutate::<ContractsState>::remove(&mut database, &key(1)).unwrap();
+        assert_eq!(
+            database.contract_storage_stats(&contract_id()).unwrap(),
+            ContractStorageStats {
+                slots: 1,
+                bytes: 64
+            }
+        );
+
+        StorageMutate::<ContractsState>::remove(&mut database, &key(2)).unwrap();
+        assert_eq!(
+            database.contract_storage_stats(&contract_id()).unwrap(),
+            ContractStorageStats::default()
+        );
+    }
+
+    #[test]
+    fn initialized_state_is_counted() {
+        let mut database = Database::<OnChain>::default();
+        let slots = (0..10u8).map(|i| (Bytes32::from([i; 32]), Bytes32::from([i; 32])));
+
+        database.init_contract_state(&contract_id(), slots).unwrap();
+
+        assert_eq!(
+            database.contract_storage_stats(&contract_id()).unwrap(),
+            ContractStorageStats {
+                slots: 10,
+                bytes: 640
+            }
+        );
+    }
+
+    #[test]
+    fn rebuilt_stats_match_incremental_ones() {
+        let mut database = Database::<OnChain>::default();
+        for slot in 0..5 {
+            StorageMutate::<ContractsState>::insert(
+                &mut database,
+                &key(slot),
+                &[slot; 32].into(),
+            )
+            .unwrap();
+        }
+        let expected = database.contract_storage_stats(&contract_id()).unwrap();
+
+        database
+            .storage_as_mut::<ContractsStorageStats>()
+            .remove(&contract_id())
+            .unwrap();
+        database.rebuild_contract_storage_stats().unwrap();
+
+        assert_eq!(
+            database.contract_storage_stats(&contract_id()).unwrap(),
+            expected
+        );
+    }
+}
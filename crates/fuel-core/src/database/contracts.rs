@@ -1,7 +1,10 @@
 use crate::database::Database;
 use fuel_core_chain_config::ContractConfig;
 use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::raw::Raw,
     iter::IterDirection,
+    structured_storage::TableWithBlueprint,
     tables::{
         ContractsAssets,
         ContractsInfo,
@@ -10,11 +13,14 @@ use fuel_core_storage::{
         ContractsState,
     },
     ContractsAssetKey,
+    Mappable,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
 };
 use fuel_core_types::{
     entities::contract::ContractUtxoInfo,
+    fuel_tx::Contract,
     fuel_types::{
         AssetId,
         Bytes32,
@@ -23,6 +29,27 @@ use fuel_core_types::{
     },
 };
 
+/// The [`ContractsRawCode`] table of the version 2 of the on-chain database, which
+/// stored the bytecode of each contract instead of the hash of the shared bytecode.
+/// Used only to migrate the database.
+pub struct ContractsRawCodeV2;
+
+impl Mappable for ContractsRawCodeV2 {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ContractId;
+    type Value = [u8];
+    type OwnedValue = Contract;
+}
+
+impl TableWithBlueprint for ContractsRawCodeV2 {
+    type Blueprint = Plain<Raw, Raw>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractsRawCode
+    }
+}
+
 impl Database {
     pub fn get_contract_config_by_id(
         &self,
@@ -77,6 +104,7 @@ impl Database {
         Ok(ContractConfig {
             contract_id,
             code,
+            code_hash: None,
             salt,
             state,
             balances,
@@ -114,14 +142,35 @@ impl Database {
 
         Ok(Some(configs))
     }
+
+    /// Moves the bytecode of each contract into the `ContractsCode` table and
+    /// replaces it in the [`ContractsRawCode`] with the hash of the bytecode.
+    pub(crate) fn deduplicate_contract_codes(&mut self) -> StorageResult<()> {
+        // Only the ids are collected, so the bytecode is loaded one contract at a time.
+        let contract_ids = self
+            .iter_all::<ContractsRawCodeV2>(None)
+            .map(|result| result.map(|(contract_id, _)| contract_id))
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        for contract_id in contract_ids {
+            // The previous value is decoded on insert, so the bytecode is removed first.
+            let code = self
+                .storage_as_mut::<ContractsRawCodeV2>()
+                .remove(&contract_id)?;
+            if let Some(code) = code {
+                self.storage_as_mut::<ContractsRawCode>()
+                    .insert(&contract_id, code.as_ref())?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::database::database_description::on_chain::OnChain;
-    use fuel_core_storage::StorageAsMut;
-    use fuel_core_types::fuel_tx::Contract;
+    use fuel_core_storage::tables::ContractsCode;
     use rand::{
         RngCore,
         SeedableRng,
@@ -149,4 +198,35 @@ mod tests {
             .into_owned();
         assert_eq!(returned, contract);
     }
+
+    #[test]
+    fn codes_of_version_2_are_deduplicated() {
+        let shared = Contract::from(vec![1u8; 64]);
+        let unique = Contract::from(vec![2u8; 64]);
+        let contracts = [
+            (ContractId::from([1u8; 32]), &shared),
+            (ContractId::from([2u8; 32]), &unique),
+            (ContractId::from([3u8; 32]), &shared),
+        ];
+        let mut database = Database::<OnChain>::default();
+        for (contract_id, code) in contracts {
+            database
+                .storage_as_mut::<ContractsRawCodeV2>()
+                .insert(&contract_id, code.as_ref())
+                .unwrap();
+        }
+
+        database.deduplicate_contract_codes().unwrap();
+
+        for (contract_id, code) in contracts {
+            let returned: Contract = database
+                .storage::<ContractsRawCode>()
+                .get(&contract_id)
+                .unwrap()
+                .unwrap()
+                .into_owned();
+            assert_eq!(&returned, code);
+        }
+        assert_eq!(database.iter_all::<ContractsCode>(None).count(), 2);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::database::migration::Migration;
 use core::fmt::Debug;
 use fuel_core_storage::kv_store::StorageColumn;
 
@@ -23,6 +24,13 @@ pub trait DatabaseDescription: 'static + Clone + Debug + Send + Sync {
 
     /// Returns the prefix for the column.
     fn prefix(column: &Self::Column) -> Option<usize>;
+
+    /// Returns the migrations from the previous versions of the database
+    /// to the [`Self::version`]. Changing the layout of the columns requires
+    /// increasing the version and adding the migration from the previous one.
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![]
+    }
 }
 
 /// The metadata of the database contains information about the version and its height.
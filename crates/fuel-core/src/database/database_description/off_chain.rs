@@ -1,6 +1,11 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::{
+        database_description::DatabaseDescription,
+        migration::Migration,
+        Database,
+    },
     fuel_core_graphql_api,
+    fuel_core_graphql_api::storage::receipts::CONTRACT_RECEIPTS_PREFIX_SIZE,
 };
 use fuel_core_types::fuel_types::BlockHeight;
 
@@ -12,13 +17,21 @@ impl DatabaseDescription for OffChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        1
     }
 
     fn name() -> &'static str {
         "off_chain"
     }
 
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![Migration {
+            version: 0,
+            name: "add the fee to the statuses of the transactions",
+            migrate: Database::add_fee_to_tx_statuses,
+        }]
+    }
+
     fn metadata_column() -> Self::Column {
         Self::Column::Metadata
     }
@@ -31,6 +44,7 @@ impl DatabaseDescription for OffChain {
                 // prefix is address length
                 Some(32)
             }
+            Self::Column::ContractReceipts => Some(CONTRACT_RECEIPTS_PREFIX_SIZE),
             _ => None,
         }
     }
@@ -1,4 +1,8 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::database::{
+    database_description::DatabaseDescription,
+    migration::Migration,
+    Database,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 
 #[derive(Clone, Debug)]
@@ -9,13 +13,33 @@ impl DatabaseDescription for OnChain {
     type Height = BlockHeight;
 
     fn version() -> u32 {
-        0
+        3
     }
 
     fn name() -> &'static str {
         "on_chain"
     }
 
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![
+            Migration {
+                version: 0,
+                name: "build the balances of the owners",
+                migrate: Database::rebuild_owned_balances,
+            },
+            Migration {
+                version: 1,
+                name: "build the storage stats of the contracts",
+                migrate: Database::rebuild_contract_storage_stats,
+            },
+            Migration {
+                version: 2,
+                name: "move the bytecode of the contracts into the deduplicated table",
+                migrate: Database::deduplicate_contract_codes,
+            },
+        ]
+    }
+
     fn metadata_column() -> Self::Column {
         Self::Column::Metadata
     }
@@ -24,6 +48,7 @@ impl DatabaseDescription for OnChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::OwnedMessageIds
+            | Self::Column::OwnedBalances
             | Self::Column::ContractsAssets
             | Self::Column::ContractsState => {
                 // prefix is address length
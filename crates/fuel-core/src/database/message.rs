@@ -1,5 +1,6 @@
 use crate::database::{
     database_description::on_chain::OnChain,
+    owned_balances::BalanceChange,
     Database,
 };
 use fuel_core_chain_config::MessageConfig;
@@ -95,6 +96,16 @@ impl StorageMutate<Messages> for Database {
         self.storage_as_mut::<OwnedMessageIds>()
             .insert(&OwnedMessageKey::new(value.recipient(), key), &())?;
 
+        // update the balances of the recipients
+        if let Some(replaced) = &result {
+            if replaced.recipient() != value.recipient() {
+                self.storage_as_mut::<OwnedMessageIds>()
+                    .remove(&OwnedMessageKey::new(replaced.recipient(), key))?;
+            }
+            self.update_message_balance(replaced, BalanceChange::Decrease)?;
+        }
+        self.update_message_balance(value, BalanceChange::Increase)?;
+
         Ok(result)
     }
 
@@ -105,6 +116,7 @@ impl StorageMutate<Messages> for Database {
         if let Some(message) = &result {
             self.storage_as_mut::<OwnedMessageIds>()
                 .remove(&OwnedMessageKey::new(message.recipient(), key))?;
+            self.update_message_balance(message, BalanceChange::Decrease)?;
         }
 
         Ok(result)
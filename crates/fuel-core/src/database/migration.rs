@@ -0,0 +1,320 @@
+//! The migrations of the database between the versions of the schema.
+//!
+//! The version of the schema is stored in the [`MetadataTable`] of each database, and
+//! [`DatabaseDescription::version`] is the version expected by this build. When the node
+//! is upgraded, the migrations registered in [`DatabaseDescription::migrations`] move the
+//! data from the stored version to the expected one, instead of requiring a resync.
+
+use crate::database::{
+    database_description::{
+        DatabaseDescription,
+        DatabaseMetadata,
+    },
+    metadata::MetadataTable,
+    Database,
+    Error as DatabaseError,
+};
+use fuel_core_storage::{
+    transactional::Transaction,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use std::path::PathBuf;
+
+/// The migration of the database from the `version` to the `version + 1`.
+pub struct Migration<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// The version of the database before the migration.
+    pub version: u32,
+    /// The short description of the migration for the logs.
+    pub name: &'static str,
+    /// Migrates the data. It runs inside of the transaction, so the failed
+    /// migration doesn't leave the database half-migrated.
+    pub migrate: fn(&mut Database<Description>) -> StorageResult<()>,
+}
+
+/// The configuration of the migrations performed at the start of the node.
+#[derive(Clone, Debug, Default)]
+pub struct MigrationConfig {
+    /// Runs the migrations without committing the changes, to check that
+    /// they succeed on the existing data.
+    pub dry_run: bool,
+    /// The directory for the backup of the database made before the migrations.
+    /// The backup isn't created if there is nothing to migrate.
+    pub backup_path: Option<PathBuf>,
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Self: StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
+    /// Migrates the database to the [`DatabaseDescription::version`] with the
+    /// `migrations` and returns the names of the applied ones.
+    ///
+    /// All migrations and the update of the version are committed atomically.
+    /// The new database doesn't have the metadata yet, so there is nothing to migrate.
+    pub fn migrate(
+        &self,
+        migrations: &[Migration<Description>],
+        config: &MigrationConfig,
+    ) -> StorageResult<Vec<&'static str>> {
+        let Some(metadata) = self.storage::<MetadataTable<Description>>().get(&())?
+        else {
+            return Ok(vec![])
+        };
+        let found = metadata.version();
+        let expected = Description::version();
+        if found > expected {
+            return Err(DatabaseError::InvalidDatabaseVersion { found, expected }.into())
+        }
+
+        // Checks the whole path before touching the data.
+        let plan = (found..expected)
+            .map(|version| {
+                migrations
+                    .iter()
+                    .find(|migration| migration.version == version)
+                    .ok_or(DatabaseError::MissingMigration {
+                        name: Description::name(),
+                        version,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if plan.is_empty() {
+            return Ok(vec![])
+        }
+
+        if let Some(backup_path) = config.backup_path.as_ref().filter(|_| !config.dry_run)
+        {
+            tracing::info!(
+                "Creating the backup of the {} database at {}",
+                Description::name(),
+                backup_path.display()
+            );
+            self.data.as_ref().backup(backup_path)?;
+        }
+
+        let mut transaction = self.transaction();
+        for migration in &plan {
+            tracing::info!(
+                "Migrating the {} database from the version {}: {}",
+                Description::name(),
+                migration.version,
+                migration.name
+            );
+            (migration.migrate)(transaction.as_mut())?;
+        }
+        transaction
+            .as_mut()
+            .storage_as_mut::<MetadataTable<Description>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: expected,
+                    height: *metadata.height(),
+                },
+            )?;
+
+        if config.dry_run {
+            tracing::info!(
+                "The dry run of the migrations of the {} database succeeded, the changes are discarded",
+                Description::name()
+            );
+        } else {
+            transaction.commit()?;
+        }
+
+        Ok(plan.into_iter().map(|migration| migration.name).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        column::Column,
+        not_found,
+        tables::SpentMessages,
+        StorageInspect,
+    };
+    use fuel_core_types::fuel_types::{
+        BlockHeight,
+        Nonce,
+    };
+
+    /// The database of the version `2` that is migrated from the version `0`.
+    #[derive(Clone, Debug)]
+    struct Versioned;
+
+    impl DatabaseDescription for Versioned {
+        type Column = Column;
+        type Height = BlockHeight;
+
+        fn version() -> u32 {
+            2
+        }
+
+        fn name() -> &'static str {
+            "versioned"
+        }
+
+        fn metadata_column() -> Self::Column {
+            Column::Metadata
+        }
+
+        fn prefix(_: &Self::Column) -> Option<usize> {
+            None
+        }
+    }
+
+    fn nonce(byte: u8) -> Nonce {
+        Nonce::from([byte; 32])
+    }
+
+    fn spend_first(database: &mut Database<Versioned>) -> StorageResult<()> {
+        database
+            .storage_as_mut::<SpentMessages>()
+            .insert(&nonce(1), &())?;
+        Ok(())
+    }
+
+    fn spend_second(database: &mut Database<Versioned>) -> StorageResult<()> {
+        // Depends on the previous migration.
+        if !is_spent(database, 1) {
+            return Err(not_found!(SpentMessages))
+        }
+        database
+            .storage_as_mut::<SpentMessages>()
+            .insert(&nonce(2), &())?;
+        Ok(())
+    }
+
+    fn migrations() -> Vec<Migration<Versioned>> {
+        vec![
+            Migration {
+                version: 1,
+                name: "spend the second message",
+                migrate: spend_second,
+            },
+            Migration {
+                version: 0,
+                name: "spend the first message",
+                migrate: spend_first,
+            },
+        ]
+    }
+
+    fn database_with_version(version: u32) -> Database<Versioned> {
+        let mut database = Database::<Versioned>::in_memory();
+        database
+            .storage_as_mut::<MetadataTable<Versioned>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version,
+                    height: 10u32.into(),
+                },
+            )
+            .unwrap();
+        database
+    }
+
+    fn version(database: &Database<Versioned>) -> u32 {
+        database
+            .storage::<MetadataTable<Versioned>>()
+            .get(&())
+            .unwrap()
+            .unwrap()
+            .version()
+    }
+
+    fn is_spent(database: &Database<Versioned>, byte: u8) -> bool {
+        StorageInspect::<SpentMessages>::contains_key(database, &nonce(byte)).unwrap()
+    }
+
+    #[test]
+    fn migrations_are_applied_in_order_of_versions() {
+        let database = database_with_version(0);
+
+        let applied = database
+            .migrate(&migrations(), &Default::default())
+            .unwrap();
+
+        assert_eq!(
+            applied,
+            vec!["spend the first message", "spend the second message"]
+        );
+        assert_eq!(version(&database), 2);
+        assert!(is_spent(&database, 1));
+        assert!(is_spent(&database, 2));
+    }
+
+    #[test]
+    fn only_missing_migrations_are_applied() {
+        let database = database_with_version(1);
+
+        let result = database.migrate(&migrations(), &Default::default());
+
+        // The first migration is skipped, so the second one fails.
+        assert!(result.is_err());
+        assert_eq!(version(&database), 1);
+        assert!(!is_spent(&database, 2));
+    }
+
+    #[test]
+    fn dry_run_discards_the_changes() {
+        let database = database_with_version(0);
+        let config = MigrationConfig {
+            dry_run: true,
+            backup_path: None,
+        };
+
+        let applied = database.migrate(&migrations(), &config).unwrap();
+
+        assert_eq!(applied.len(), 2);
+        assert_eq!(version(&database), 0);
+        assert!(!is_spent(&database, 1));
+    }
+
+    #[test]
+    fn migration_gap_is_rejected_before_changing_data() {
+        let database = database_with_version(0);
+        let mut migrations = migrations();
+        migrations.retain(|migration| migration.version == 0);
+
+        let result = database.migrate(&migrations, &Default::default());
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("MissingMigration"), "{err}");
+        assert!(err.contains("version: 1"), "{err}");
+        assert_eq!(version(&database), 0);
+        assert!(!is_spent(&database, 1));
+    }
+
+    #[test]
+    fn new_database_has_nothing_to_migrate() {
+        let database = Database::<Versioned>::in_memory();
+
+        let applied = database
+            .migrate(&migrations(), &Default::default())
+            .unwrap();
+
+        assert!(applied.is_empty());
+    }
+
+    #[test]
+    fn database_of_newer_version_is_rejected() {
+        let database = database_with_version(3);
+
+        let result = database.migrate(&migrations(), &Default::default());
+
+        assert!(result.is_err());
+        assert_eq!(version(&database), 3);
+    }
+}
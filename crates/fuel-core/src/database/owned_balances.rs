@@ -0,0 +1,380 @@
+//! The balances of the owners maintained incrementally on each change of the coins
+//! and the messages, so the balance query doesn't iterate all owned UTXOs.
+
+use crate::database::{
+    database_description::on_chain::OnChain,
+    Database,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        raw::Raw,
+        Decode,
+        Encode,
+    },
+    iter::IterDirection,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        Messages,
+    },
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    entities::{
+        coins::coin::CompressedCoin,
+        message::Message,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+    },
+};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
+
+fuel_core_types::fuel_vm::double_key!(
+    OwnedBalanceKey,
+    Address,
+    address,
+    AssetId,
+    asset_id
+);
+
+/// The table of the total amount of the coins per owner and asset.
+pub struct OwnedBalances;
+
+impl Mappable for OwnedBalances {
+    type Key = OwnedBalanceKey;
+    type OwnedKey = Self::Key;
+    type Value = u128;
+    type OwnedValue = Self::Value;
+}
+
+impl Encode<OwnedBalanceKey> for Manual<OwnedBalanceKey> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &OwnedBalanceKey) -> Self::Encoder<'_> {
+        Cow::Borrowed(t.as_ref())
+    }
+}
+
+impl Decode<OwnedBalanceKey> for Manual<OwnedBalanceKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedBalanceKey> {
+        OwnedBalanceKey::from_slice(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+impl TableWithBlueprint for OwnedBalances {
+    type Blueprint = Plain<Manual<OwnedBalanceKey>, Postcard>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedBalances
+    }
+}
+
+/// The table of the total amount of the spendable messages per recipient.
+/// The messages are the base asset, which is defined by the chain config.
+pub struct OwnedMessageBalances;
+
+impl Mappable for OwnedMessageBalances {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = u128;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for OwnedMessageBalances {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = fuel_core_storage::column::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedMessageBalances
+    }
+}
+
+/// Whether the amount is added to the balance or subtracted from it.
+#[derive(Clone, Copy)]
+pub(crate) enum BalanceChange {
+    Increase,
+    Decrease,
+}
+
+impl BalanceChange {
+    fn apply(self, balance: u128, amount: u64) -> u128 {
+        match self {
+            Self::Increase => balance.saturating_add(amount.into()),
+            Self::Decrease => balance.saturating_sub(amount.into()),
+        }
+    }
+}
+
+impl Database<OnChain> {
+    /// Returns the amount of the `asset_id` owned by the `owner`: the coins and,
+    /// for the base asset, the spendable messages.
+    pub fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<u128> {
+        let coins = self
+            .storage::<OwnedBalances>()
+            .get(&OwnedBalanceKey::new(owner, asset_id))?
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+        let messages = if asset_id == base_asset_id {
+            self.owned_message_balance(owner)?
+        } else {
+            0
+        };
+        Ok(coins.saturating_add(messages))
+    }
+
+    /// Returns the non-zero balances of all assets owned by the `owner`,
+    /// sorted by the asset id.
+    pub fn owned_balances(
+        &self,
+        owner: &Address,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<Vec<(AssetId, u128)>> {
+        let mut balances = self
+            .iter_all_filtered::<OwnedBalances, _>(
+                Some(owner),
+                None,
+                Some(IterDirection::Forward),
+            )
+            .map(|result| result.map(|(key, amount)| (*key.asset_id(), amount)))
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let messages = self.owned_message_balance(owner)?;
+        if messages > 0 {
+            match balances.binary_search_by_key(base_asset_id, |(asset_id, _)| *asset_id)
+            {
+                Ok(index) => {
+                    let (_, amount) = &mut balances[index];
+                    *amount = amount.saturating_add(messages);
+                }
+                Err(index) => balances.insert(index, (*base_asset_id, messages)),
+            }
+        }
+        Ok(balances)
+    }
+
+    fn owned_message_balance(&self, owner: &Address) -> StorageResult<u128> {
+        Ok(self
+            .storage::<OwnedMessageBalances>()
+            .get(owner)?
+            .map(Cow::into_owned)
+            .unwrap_or_default())
+    }
+
+    /// Updates the balance of the owner of the `coin`.
+    pub(crate) fn update_coin_balance(
+        &mut self,
+        coin: &CompressedCoin,
+        change: BalanceChange,
+    ) -> StorageResult<()> {
+        let key = OwnedBalanceKey::new(coin.owner(), coin.asset_id());
+        let balance = self
+            .storage::<OwnedBalances>()
+            .get(&key)?
+            .map(Cow::into_owned)
+            .unwrap_or_default();
+        let balance = change.apply(balance, *coin.amount());
+        if balance == 0 {
+            self.storage_as_mut::<OwnedBalances>().remove(&key)?;
+        } else {
+            self.storage_as_mut::<OwnedBalances>()
+                .insert(&key, &balance)?;
+        }
+        Ok(())
+    }
+
+    /// Updates the balance of the recipient of the spendable `message`.
+    /// The messages with the data are not spendable as the coins.
+    pub(crate) fn update_message_balance(
+        &mut self,
+        message: &Message,
+        change: BalanceChange,
+    ) -> StorageResult<()> {
+        if !message.data().is_empty() {
+            return Ok(())
+        }
+        let owner = message.recipient();
+        let balance = change.apply(self.owned_message_balance(owner)?, message.amount());
+        if balance == 0 {
+            self.storage_as_mut::<OwnedMessageBalances>()
+                .remove(owner)?;
+        } else {
+            self.storage_as_mut::<OwnedMessageBalances>()
+                .insert(owner, &balance)?;
+        }
+        Ok(())
+    }
+
+    /// Builds the balances from all coins and messages. Used by the migration of
+    /// the databases created before the balances were maintained.
+    pub(crate) fn rebuild_owned_balances(&mut self) -> StorageResult<()> {
+        let mut coins = HashMap::<OwnedBalanceKey, u128>::new();
+        for result in self.iter_all::<Coins>(None) {
+            let (_, coin) = result?;
+            let balance = coins
+                .entry(OwnedBalanceKey::new(coin.owner(), coin.asset_id()))
+                .or_default();
+            *balance = balance.saturating_add((*coin.amount()).into());
+        }
+
+        let mut messages = HashMap::<Address, u128>::new();
+        for result in self.iter_all::<Messages>(None) {
+            let (_, message) = result?;
+            if message.data().is_empty() {
+                let balance = messages.entry(*message.recipient()).or_default();
+                *balance = balance.saturating_add(message.amount().into());
+            }
+        }
+
+        for (key, balance) in coins {
+            self.storage_as_mut::<OwnedBalances>()
+                .insert(&key, &balance)?;
+        }
+        for (owner, balance) in messages {
+            self.storage_as_mut::<OwnedMessageBalances>()
+                .insert(&owner, &balance)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageMutate;
+    use fuel_core_types::{
+        entities::message::MessageV1,
+        fuel_tx::UtxoId,
+        fuel_types::Nonce,
+    };
+
+    const BASE: AssetId = AssetId::BASE;
+
+    fn owner() -> Address {
+        Address::from([1; 32])
+    }
+
+    fn asset() -> AssetId {
+        AssetId::from([2; 32])
+    }
+
+    fn coin(amount: u64, asset_id: AssetId) -> CompressedCoin {
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner());
+        coin.set_amount(amount);
+        coin.set_asset_id(asset_id);
+        coin
+    }
+
+    fn message(amount: u64, data: Vec<u8>) -> Message {
+        Message::V1(MessageV1 {
+            recipient: owner(),
+            amount,
+            data,
+            ..Default::default()
+        })
+    }
+
+    fn utxo_id(index: u8) -> UtxoId {
+        UtxoId::new([index; 32].into(), index)
+    }
+
+    #[test]
+    fn balance_follows_coins() {
+        let mut database = Database::<OnChain>::default();
+
+        StorageMutate::<Coins>::insert(&mut database, &utxo_id(1), &coin(10, asset()))
+            .unwrap();
+        StorageMutate::<Coins>::insert(&mut database, &utxo_id(2), &coin(20, asset()))
+            .unwrap();
+        assert_eq!(
+            database.owned_balance(&owner(), &asset(), &BASE).unwrap(),
+            30
+        );
+
+        StorageMutate::<Coins>::remove(&mut database, &utxo_id(1)).unwrap();
+        assert_eq!(
+            database.owned_balance(&owner(), &asset(), &BASE).unwrap(),
+            20
+        );
+
+        StorageMutate::<Coins>::remove(&mut database, &utxo_id(2)).unwrap();
+        assert_eq!(
+            database.owned_balance(&owner(), &asset(), &BASE).unwrap(),
+            0
+        );
+        assert!(database.owned_balances(&owner(), &BASE).unwrap().is_empty());
+    }
+
+    #[test]
+    fn spendable_messages_are_added_to_base_asset() {
+        let mut database = Database::<OnChain>::default();
+        StorageMutate::<Coins>::insert(&mut database, &utxo_id(1), &coin(10, asset()))
+            .unwrap();
+        StorageMutate::<Messages>::insert(
+            &mut database,
+            &Nonce::from([1; 32]),
+            &message(5, vec![]),
+        )
+        .unwrap();
+        StorageMutate::<Messages>::insert(
+            &mut database,
+            &Nonce::from([2; 32]),
+            &message(100, vec![1]),
+        )
+        .unwrap();
+
+        assert_eq!(database.owned_balance(&owner(), &BASE, &BASE).unwrap(), 5);
+        let mut expected = vec![(BASE, 5), (asset(), 10)];
+        expected.sort();
+        assert_eq!(database.owned_balances(&owner(), &BASE).unwrap(), expected);
+
+        StorageMutate::<Messages>::remove(&mut database, &Nonce::from([1; 32])).unwrap();
+        assert_eq!(database.owned_balance(&owner(), &BASE, &BASE).unwrap(), 0);
+    }
+
+    #[test]
+    fn rebuilt_balances_match_incremental_ones() {
+        let mut database = Database::<OnChain>::default();
+        StorageMutate::<Coins>::insert(&mut database, &utxo_id(1), &coin(10, asset()))
+            .unwrap();
+        StorageMutate::<Coins>::insert(&mut database, &utxo_id(2), &coin(7, BASE))
+            .unwrap();
+        StorageMutate::<Messages>::insert(
+            &mut database,
+            &Nonce::from([1; 32]),
+            &message(5, vec![]),
+        )
+        .unwrap();
+        let expected = database.owned_balances(&owner(), &BASE).unwrap();
+
+        database
+            .storage_as_mut::<OwnedBalances>()
+            .remove(&OwnedBalanceKey::new(&owner(), &asset()))
+            .unwrap();
+        database
+            .storage_as_mut::<OwnedMessageBalances>()
+            .remove(&owner())
+            .unwrap();
+        database.rebuild_owned_balances().unwrap();
+
+        assert_eq!(database.owned_balances(&owner(), &BASE).unwrap(), expected);
+    }
+}
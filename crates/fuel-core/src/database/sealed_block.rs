@@ -93,6 +93,8 @@ impl Database {
         }
     }
 
+    /// Returns the transactions of the blocks in the range. Returns `None` if any of
+    /// the blocks is unknown or its body is pruned, so the peer asks another node.
     pub fn get_transactions_on_blocks(
         &self,
         block_height_range: Range<u32>,
@@ -101,6 +103,9 @@ impl Database {
             .into_iter()
             .map(BlockHeight::from)
             .map(|block_height| {
+                if self.is_block_body_pruned(&block_height)? {
+                    return Ok(None)
+                }
                 let transactions = self
                     .get_sealed_block_by_height(&block_height)?
                     .map(|Sealed { entity: block, .. }| block.into_inner().1)
@@ -27,7 +27,7 @@ impl Database {
             .collect_vec();
         #[allow(clippy::map_identity)]
         <_ as StorageBatchMutate<ContractsState>>::init_storage(
-            &mut self.data,
+            self,
             &mut slots.iter().map(|(key, value)| (key, value)),
         )
     }
@@ -103,4 +103,33 @@ mod tests {
             assert_eq!(seq_value, value);
         }
     }
+
+    #[test]
+    fn state_cache_sees_committed_changes() {
+        use fuel_core_storage::{
+            transactional::Transaction,
+            StorageInspect,
+        };
+
+        let database = Database::<OnChain>::in_memory().with_state_cache(10);
+        let key = ContractsStateKey::new(&ContractId::from([1u8; 32]), &[2u8; 32].into());
+        let state = |database: &Database| {
+            StorageInspect::<ContractsState>::get(database, &key)
+                .unwrap()
+                .map(|value| value.into_owned())
+        };
+        assert_eq!(state(&database), None);
+
+        let mut transaction = database.transaction();
+        transaction
+            .as_mut()
+            .storage::<ContractsState>()
+            .insert(&key, &[3u8; 32].into())
+            .unwrap();
+        // The uncommitted change is invisible, and the cached absence stays.
+        assert_eq!(state(&database), None);
+        transaction.commit().unwrap();
+
+        assert_eq!(state(&database), Some([3u8; 32].into()));
+    }
 }
@@ -18,6 +18,7 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageMutate,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 /// The table that stores all statistic about blockchain. Each key is a string, while the value
 /// depends on the context.
@@ -75,3 +76,29 @@ impl Database<OffChain> {
         Ok(new_tx_count)
     }
 }
+
+/// The height of the latest block with the pruned transactions and receipts.
+pub(crate) const PRUNED_BLOCK_BODIES_HEIGHT: &str = "pruned_block_bodies_height";
+
+impl Database<OffChain> {
+    /// Returns the height of the latest block with the pruned body, if any.
+    pub fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>> {
+        use fuel_core_storage::StorageAsRef;
+        Ok(self
+            .storage::<StatisticTable<BlockHeight>>()
+            .get(PRUNED_BLOCK_BODIES_HEIGHT)?
+            .map(|height| height.into_owned()))
+    }
+
+    pub fn set_pruned_block_bodies_height(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        <_ as StorageMutate<StatisticTable<BlockHeight>>>::insert(
+            &mut self.data,
+            PRUNED_BLOCK_BODIES_HEIGHT,
+            height,
+        )?;
+        Ok(())
+    }
+}
@@ -2,13 +2,26 @@ use crate::{
     database::{
         block::FuelBlockSecondaryKeyBlockHeights,
         coin::OwnedCoins,
+        contract_storage_stats::ContractsStorageStats,
+        contracts::ContractsRawCodeV2,
         database_description::DatabaseDescription,
         message::OwnedMessageIds,
+        owned_balances::{
+            OwnedBalances,
+            OwnedMessageBalances,
+        },
         Database,
     },
-    fuel_core_graphql_api::storage::transactions::{
-        OwnedTransactions,
-        TransactionStatuses,
+    fuel_core_graphql_api::storage::{
+        da_compression::DaCompressedBlocks,
+        receipts::ContractReceipts,
+        transactions::{
+            ArchivedTransactionStatuses,
+            ArchivedTransactionStatusesByTime,
+            OwnedTransactions,
+            TransactionStatuses,
+            TransactionStatusesV0,
+        },
     },
     state::DataSource,
 };
@@ -24,13 +37,14 @@ use fuel_core_storage::{
             FuelBlockMerkleMetadata,
         },
         ContractsAssets,
+        ContractsCode,
         ContractsInfo,
         ContractsLatestUtxo,
         ContractsRawCode,
-        ContractsState,
         ProcessedTransactions,
         SealedBlockConsensus,
         SpentMessages,
+        StateDiffs,
         Transactions,
     },
     Error as StorageError,
@@ -73,22 +87,32 @@ macro_rules! use_structured_implementation {
 
 use_structured_implementation!(
     ContractsRawCode,
+    ContractsRawCodeV2,
+    ContractsCode,
     ContractsAssets,
-    ContractsState,
     ContractsLatestUtxo,
     ContractsInfo,
     SpentMessages,
     SealedBlockConsensus,
     Transactions,
     ProcessedTransactions,
+    StateDiffs,
     ContractsStateMerkleMetadata,
     ContractsStateMerkleData,
     ContractsAssetsMerkleMetadata,
     ContractsAssetsMerkleData,
     OwnedCoins,
     OwnedMessageIds,
+    OwnedBalances,
+    OwnedMessageBalances,
+    ContractsStorageStats,
     OwnedTransactions,
     TransactionStatuses,
+    TransactionStatusesV0,
+    ArchivedTransactionStatuses,
+    ArchivedTransactionStatusesByTime,
+    ContractReceipts,
+    DaCompressedBlocks,
     FuelBlockSecondaryKeyBlockHeights,
     FuelBlockMerkleData,
     FuelBlockMerkleMetadata
@@ -3,17 +3,33 @@ use crate::{
         database_description::off_chain::OffChain,
         Database,
     },
-    fuel_core_graphql_api::storage::transactions::{
-        OwnedTransactionIndexCursor,
-        OwnedTransactionIndexKey,
-        OwnedTransactions,
-        TransactionIndex,
-        TransactionStatuses,
+    fuel_core_graphql_api::storage::{
+        receipts::{
+            ContractReceiptKey,
+            ContractReceipts,
+            ReceiptPointer,
+            ReceiptSelector,
+        },
+        transactions::{
+            ArchivedTransactionStatus,
+            ArchivedTransactionStatusKey,
+            ArchivedTransactionStatuses,
+            ArchivedTransactionStatusesByTime,
+            OwnedTransactionIndexCursor,
+            OwnedTransactionIndexKey,
+            OwnedTransactions,
+            TransactionIndex,
+            TransactionStatuses,
+            TransactionStatusesV0,
+        },
     },
 };
 use fuel_core_storage::{
     iter::IterDirection,
-    tables::Transactions,
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -26,8 +42,10 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         BlockHeight,
+        ContractId,
     },
     services::txpool::TransactionStatus,
+    tai64::Tai64,
 };
 
 impl Database {
@@ -39,6 +57,37 @@ impl Database {
         self.iter_all_by_start::<Transactions>(start, direction)
             .map(|res| res.map(|(_, tx)| tx))
     }
+
+    /// Removes the transactions of the block at the `height`, but keeps the block itself.
+    /// Returns the ids of the transactions of the block.
+    pub fn remove_block_body(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<Vec<Bytes32>> {
+        use fuel_core_storage::StorageAsMut;
+        let Some(block) = self.storage::<FuelBlocks>().get(height)? else {
+            return Ok(vec![])
+        };
+        let tx_ids = block.transactions().to_vec();
+        for tx_id in tx_ids.iter() {
+            self.storage::<Transactions>().remove(tx_id)?;
+        }
+        Ok(tx_ids)
+    }
+
+    /// Returns `true` if the transactions of the block at the `height` were removed
+    /// by [`Self::remove_block_body`]. Each block has at least the `Mint` transaction,
+    /// so the pruned block is the one without its first transaction.
+    pub fn is_block_body_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        use fuel_core_storage::StorageAsRef;
+        let Some(block) = self.storage::<FuelBlocks>().get(height)? else {
+            return Ok(false)
+        };
+        match block.transactions().first() {
+            Some(tx_id) => Ok(!self.storage::<Transactions>().contains_key(tx_id)?),
+            None => Ok(false),
+        }
+    }
 }
 
 impl Database<OffChain> {
@@ -79,6 +128,40 @@ impl Database<OffChain> {
         )
     }
 
+    /// Iterates over a KV mapping of `[contract id + selector + receipt pointer] => transaction id`.
+    /// It allows to find receipts of the contract with the same selector sorted by their
+    /// position in the blockchain. The cursor is the pointer of the receipt.
+    pub fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(ReceiptPointer, Bytes32)>> + '_ {
+        let start =
+            start.map(|pointer| ContractReceiptKey::new(&contract_id, selector, pointer));
+        self.iter_all_filtered::<ContractReceipts, _>(
+            Some(ContractReceiptKey::prefix(&contract_id, selector)),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|(key, tx_id)| (key.pointer, tx_id)))
+    }
+
+    pub fn record_contract_receipt(
+        &mut self,
+        contract_id: &ContractId,
+        selector: ReceiptSelector,
+        pointer: ReceiptPointer,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        use fuel_core_storage::StorageAsMut;
+        self.storage::<ContractReceipts>().insert(
+            &ContractReceiptKey::new(contract_id, selector, pointer),
+            tx_id,
+        )
+    }
+
     pub fn update_tx_status(
         &mut self,
         id: &Bytes32,
@@ -97,4 +180,279 @@ impl Database<OffChain> {
             .get(id)
             .map(|v| v.map(|v| v.into_owned()))
     }
+
+    /// Rewrites the statuses stored by the version `0` of the database into
+    /// the [`TransactionStatus`] with the fee. Used by the migration of the database.
+    pub(crate) fn add_fee_to_tx_statuses(&mut self) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        let statuses = self
+            .iter_all::<TransactionStatusesV0>(None)
+            .collect::<StorageResult<Vec<_>>>()?;
+        for (id, status) in statuses {
+            // The insertion decodes the previous value, so the status
+            // of the old format is removed first.
+            self.storage::<TransactionStatusesV0>().remove(&id)?;
+            self.storage::<TransactionStatuses>()
+                .insert(&id, &status.into())?;
+        }
+        Ok(())
+    }
+
+    /// Removes the receipts of the included transaction, except the `MessageOut`
+    /// receipts required to build the message proofs.
+    pub fn prune_tx_receipts(&mut self, id: &Bytes32) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        let Some(mut status) = self.get_tx_status(id)? else {
+            return Ok(())
+        };
+        if let TransactionStatus::Success { receipts, .. }
+        | TransactionStatus::Failed { receipts, .. } = &mut status
+        {
+            receipts.retain(|receipt| receipt.message_id().is_some());
+            self.storage::<TransactionStatuses>().insert(id, &status)?;
+        }
+        Ok(())
+    }
+
+    /// Archives the final `status` of the transaction that is not included in the block.
+    /// The previous archived status of the transaction is replaced.
+    pub fn archive_tx_status(
+        &mut self,
+        id: &Bytes32,
+        status: TransactionStatus,
+        archived_at: Tai64,
+    ) -> StorageResult<()> {
+        use fuel_core_storage::StorageAsMut;
+        let previous = self.storage::<ArchivedTransactionStatuses>().insert(
+            id,
+            &ArchivedTransactionStatus {
+                status,
+                archived_at,
+            },
+        )?;
+        if let Some(previous) = previous {
+            self.storage::<ArchivedTransactionStatusesByTime>().remove(
+                &ArchivedTransactionStatusKey::new(previous.archived_at, *id),
+            )?;
+        }
+        self.storage::<ArchivedTransactionStatusesByTime>()
+            .insert(&ArchivedTransactionStatusKey::new(archived_at, *id), &())?;
+        Ok(())
+    }
+
+    pub fn get_archived_tx_status(
+        &self,
+        id: &Bytes32,
+    ) -> StorageResult<Option<TransactionStatus>> {
+        use fuel_core_storage::StorageAsRef;
+        self.storage::<ArchivedTransactionStatuses>()
+            .get(id)
+            .map(|v| v.map(|v| v.into_owned().status))
+    }
+
+    /// Removes the statuses archived before `archived_before`.
+    /// Returns the number of removed statuses.
+    pub fn prune_archived_tx_statuses(
+        &mut self,
+        archived_before: Tai64,
+    ) -> StorageResult<usize> {
+        use fuel_core_storage::StorageAsMut;
+        let expired = self
+            .iter_all::<ArchivedTransactionStatusesByTime>(None)
+            .map(|res| res.map(|(key, _)| key))
+            .take_while(|res| {
+                res.as_ref()
+                    .map(|key| key.archived_at < archived_before)
+                    .unwrap_or(true)
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        for key in expired.iter() {
+            self.storage::<ArchivedTransactionStatuses>()
+                .remove(&key.tx_id)?;
+            self.storage::<ArchivedTransactionStatusesByTime>()
+                .remove(key)?;
+        }
+        Ok(expired.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuel_core_graphql_api::storage::transactions::TransactionStatusV0;
+    use fuel_core_types::fuel_tx::Receipt;
+
+    fn squeezed_out(reason: &str) -> TransactionStatus {
+        TransactionStatus::SqueezedOut {
+            reason: reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn prune_tx_receipts_keeps_only_message_out_receipts() {
+        let mut database = Database::<OffChain>::default();
+        let id = Bytes32::from([1; 32]);
+        let message_out = Receipt::message_out(
+            &Default::default(),
+            0,
+            Default::default(),
+            Default::default(),
+            10,
+            vec![],
+        );
+        let status = TransactionStatus::Success {
+            block_id: Default::default(),
+            time: Tai64(10),
+            result: None,
+            receipts: vec![
+                Receipt::ret(Default::default(), 1, 2, 3),
+                message_out.clone(),
+            ],
+            fee: Default::default(),
+        };
+        database.update_tx_status(&id, status).unwrap();
+
+        database.prune_tx_receipts(&id).unwrap();
+
+        let Some(TransactionStatus::Success { receipts, .. }) =
+            database.get_tx_status(&id).unwrap()
+        else {
+            panic!("The status should be kept");
+        };
+        assert_eq!(receipts, vec![message_out]);
+    }
+
+    #[test]
+    fn pruned_block_bodies_are_not_served_to_peers() {
+        use fuel_core_storage::{
+            tables::SealedBlockConsensus,
+            StorageAsMut,
+        };
+        use fuel_core_types::{
+            blockchain::{
+                block::PartialFuelBlock,
+                consensus::Consensus,
+                header::PartialBlockHeader,
+            },
+            fuel_tx::UniqueIdentifier,
+            fuel_types::ChainId,
+        };
+        let mut database = Database::default();
+        let tx = Transaction::default_test_tx();
+        let tx_id = tx.id(&ChainId::default());
+        let height = BlockHeight::from(1);
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height;
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&height, &block.compress(&ChainId::default()))
+            .unwrap();
+        database
+            .storage::<SealedBlockConsensus>()
+            .insert(&height, &Consensus::PoA(Default::default()))
+            .unwrap();
+        database
+            .storage::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        assert!(database.get_transactions_on_blocks(1..2).unwrap().is_some());
+
+        assert_eq!(database.remove_block_body(&height).unwrap(), vec![tx_id]);
+
+        assert!(database.is_block_body_pruned(&height).unwrap());
+        assert!(database.get_transactions_on_blocks(1..2).unwrap().is_none());
+    }
+
+    #[test]
+    fn statuses_of_version_0_are_migrated_with_zero_fee() {
+        use fuel_core_storage::StorageAsMut;
+        let mut database = Database::<OffChain>::default();
+        let success = Bytes32::from([1; 32]);
+        let squeezed = Bytes32::from([2; 32]);
+        let receipts = vec![Receipt::ret(Default::default(), 1, 2, 3)];
+        database
+            .storage::<TransactionStatusesV0>()
+            .insert(
+                &success,
+                &TransactionStatusV0::Success {
+                    block_id: Default::default(),
+                    time: Tai64(10),
+                    result: None,
+                    receipts: receipts.clone(),
+                },
+            )
+            .unwrap();
+        database
+            .storage::<TransactionStatusesV0>()
+            .insert(
+                &squeezed,
+                &TransactionStatusV0::SqueezedOut {
+                    reason: "reason".to_string(),
+                },
+            )
+            .unwrap();
+        assert!(database.get_tx_status(&success).is_err());
+
+        database.add_fee_to_tx_statuses().unwrap();
+
+        assert_eq!(
+            database.get_tx_status(&success).unwrap(),
+            Some(TransactionStatus::Success {
+                block_id: Default::default(),
+                time: Tai64(10),
+                result: None,
+                receipts,
+                fee: Default::default(),
+            })
+        );
+        assert_eq!(
+            database.get_tx_status(&squeezed).unwrap(),
+            Some(squeezed_out("reason"))
+        );
+    }
+
+    #[test]
+    fn prune_removes_only_expired_statuses() {
+        let mut database = Database::<OffChain>::default();
+        let expired = Bytes32::from([1; 32]);
+        let actual = Bytes32::from([2; 32]);
+        database
+            .archive_tx_status(&expired, squeezed_out("expired"), Tai64(10))
+            .unwrap();
+        database
+            .archive_tx_status(&actual, squeezed_out("actual"), Tai64(20))
+            .unwrap();
+
+        let removed = database.prune_archived_tx_statuses(Tai64(15)).unwrap();
+
+        assert_eq!(removed, 1);
+        assert_eq!(database.get_archived_tx_status(&expired).unwrap(), None);
+        assert_eq!(
+            database.get_archived_tx_status(&actual).unwrap(),
+            Some(squeezed_out("actual"))
+        );
+    }
+
+    #[test]
+    fn archiving_again_replaces_the_previous_status() {
+        let mut database = Database::<OffChain>::default();
+        let id = Bytes32::from([1; 32]);
+        database
+            .archive_tx_status(&id, squeezed_out("first"), Tai64(10))
+            .unwrap();
+        database
+            .archive_tx_status(&id, squeezed_out("second"), Tai64(20))
+            .unwrap();
+
+        // The index entry of the first status is removed, so the status archived
+        // later is not pruned by the time of the first one.
+        let removed = database.prune_archived_tx_statuses(Tai64(15)).unwrap();
+
+        assert_eq!(removed, 0);
+        assert_eq!(
+            database.get_archived_tx_status(&id).unwrap(),
+            Some(squeezed_out("second"))
+        );
+    }
 }
@@ -12,6 +12,7 @@ mod tests {
             OnceTransactionsSource,
         },
         ports::RelayerPort,
+        profiler::ExecutionProfiler,
         refs::ContractRef,
         Config,
     };
@@ -19,7 +20,9 @@ mod tests {
         tables::{
             Coins,
             ContractsRawCode,
+            FuelBlocks,
             Messages,
+            StateDiffs,
         },
         transactional::AtomicView,
         Result as StorageResult,
@@ -29,13 +32,17 @@ mod tests {
         blockchain::{
             block::{
                 Block,
+                CompressedBlock,
                 PartialFuelBlock,
             },
             header::{
                 ConsensusHeader,
                 PartialBlockHeader,
             },
-            primitives::DaBlockHeight,
+            primitives::{
+                BlockId,
+                DaBlockHeight,
+            },
         },
         entities::{
             coins::coin::CompressedCoin,
@@ -61,6 +68,7 @@ mod tests {
                 Outputs,
                 Script as ScriptField,
                 TxPointer as TxPointerTraitTrait,
+                Witnesses,
             },
             input::{
                 coin::CoinSigned,
@@ -113,9 +121,13 @@ mod tests {
                 ExecutionResult,
                 ExecutionType,
                 ExecutionTypes,
+                FeeReport,
+                ReceiptsLimitExceeded,
+                ReceiptsLimits,
                 TransactionExecutionResult,
                 TransactionValidityError,
             },
+            predicate_cache::PredicateCache,
             relayer::Event,
         },
         tai64::Tai64,
@@ -128,7 +140,10 @@ mod tests {
     };
     use std::{
         ops::DerefMut,
-        sync::Arc,
+        sync::{
+            atomic::AtomicBool,
+            Arc,
+        },
     };
 
     #[derive(Clone, Debug)]
@@ -315,6 +330,476 @@ mod tests {
         assert!(skipped_transactions.is_empty());
     }
 
+    #[test]
+    fn executor_invalidates_block_above_hard_gas_limit() {
+        let producer = create_executor(Default::default(), Default::default());
+        let verifier = create_executor(
+            Default::default(),
+            Config {
+                block_gas_limit: Some(0),
+                ..Default::default()
+            },
+        );
+        let block = test_block(1u32.into(), 0u64.into(), 10);
+
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let validation_result = verifier
+            .execute_and_commit(ExecutionTypes::Validation(block), Default::default());
+        assert!(matches!(
+            validation_result,
+            Err(ExecutorError::BlockGasLimitExceeded { limit: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn executor_validates_correctly_produced_block_in_parallel() {
+        let producer = create_executor(Default::default(), Default::default());
+        let verifier = create_executor(
+            Default::default(),
+            Config {
+                execution_threads: 4,
+                ..Default::default()
+            },
+        );
+        let mut block = test_block(1u32.into(), 0u64.into(), 10);
+        // The script depends on the contract created by the previous transaction.
+        let (create, script) = setup_executable_script();
+        block.transactions_mut().push(create.into());
+        block.transactions_mut().push(script.into());
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            tx_status: produced_status,
+        } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        assert!(skipped_transactions.is_empty());
+
+        let ExecutionResult {
+            tx_status: validated_status,
+            ..
+        } = verifier
+            .execute_and_commit(ExecutionTypes::Validation(block), Default::default())
+            .expect("Parallel validation should accept the produced block");
+        assert_eq!(
+            produced_status.iter().map(|status| status.id).collect_vec(),
+            validated_status
+                .iter()
+                .map(|status| status.id)
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn parallel_validation_of_block_matches_sequential_validation() {
+        let producer = create_executor(Default::default(), Default::default());
+        let mut block = test_block(1u32.into(), 0u64.into(), 10);
+        let (create, script) = setup_executable_script();
+        block.transactions_mut().push(create.into());
+        block.transactions_mut().push(script.into());
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let validate = |execution_threads| {
+            let mut database = Database::default();
+            let verifier = create_executor(
+                database.clone(),
+                Config {
+                    execution_threads,
+                    record_state_diff: true,
+                    ..Default::default()
+                },
+            );
+            let result = verifier
+                .execute_and_commit(
+                    ExecutionTypes::Validation(block.clone()),
+                    Default::default(),
+                )
+                .expect("Validation should accept the produced block");
+            let state_diff = database
+                .storage::<StateDiffs>()
+                .get(block.header().height())
+                .unwrap()
+                .expect("The state diff should be recorded")
+                .into_owned();
+            (result, state_diff)
+        };
+        let (sequential, sequential_diff) = validate(1);
+        let (parallel, parallel_diff) = validate(4);
+
+        assert_eq!(sequential.block.id(), parallel.block.id());
+        assert_eq!(
+            format!("{:?}", sequential.tx_status),
+            format!("{:?}", parallel.tx_status)
+        );
+        assert_eq!(sequential_diff, parallel_diff);
+    }
+
+    #[test]
+    fn executor_records_state_diff_of_the_block() {
+        let mut database = Database::default();
+        let producer = create_executor(
+            database.clone(),
+            Config {
+                record_state_diff: true,
+                ..Default::default()
+            },
+        );
+        let mut block = test_block(1u32.into(), 0u64.into(), 3);
+        let (create, script) = setup_executable_script();
+        let contract_id = create
+            .outputs()
+            .iter()
+            .find_map(|output| match output {
+                Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+                _ => None,
+            })
+            .expect("The transaction creates the contract");
+        block.transactions_mut().push(create.into());
+        block.transactions_mut().push(script.into());
+        let spent_coins = block
+            .transactions()
+            .iter()
+            .flat_map(|tx| match tx {
+                Transaction::Script(script) => script.inputs().clone(),
+                Transaction::Create(create) => create.inputs().clone(),
+                Transaction::Mint(_) => vec![],
+            })
+            .filter(Input::is_coin)
+            .filter_map(|input| input.utxo_id().copied())
+            .collect_vec();
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        assert!(skipped_transactions.is_empty());
+
+        let state_diff = database
+            .storage::<StateDiffs>()
+            .get(block.header().height())
+            .unwrap()
+            .expect("The state diff should be recorded")
+            .into_owned();
+        assert_eq!(state_diff.spent_coins, spent_coins);
+        assert!(!state_diff.created_coins.is_empty());
+        for coin in &state_diff.created_coins {
+            let stored_coin = database
+                .storage::<Coins>()
+                .get(&coin.utxo_id)
+                .unwrap()
+                .expect("Created coin should be stored")
+                .into_owned();
+            assert_eq!(stored_coin.uncompress(coin.utxo_id), *coin);
+        }
+        assert!(state_diff
+            .contract_balances
+            .iter()
+            .any(|change| change.contract_id == contract_id));
+    }
+
+    #[test]
+    fn executor_does_not_record_state_diff_by_default() {
+        let mut database = Database::default();
+        let producer = create_executor(database.clone(), Default::default());
+        let block = test_block(1u32.into(), 0u64.into(), 3);
+
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        assert!(!database
+            .storage::<StateDiffs>()
+            .contains_key(block.header().height())
+            .unwrap());
+    }
+
+    #[test]
+    fn abandoned_dry_run_is_stopped_before_the_next_transaction() {
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .script_gas_limit(1000)
+            .start_script(vec![op::ret(RegId::ONE)], vec![])
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+            .into();
+        let executor = create_executor(Default::default(), Default::default());
+        let dry_run = |abandoned: bool| {
+            executor.dry_run(
+                Components {
+                    header_to_produce: Default::default(),
+                    transactions_source: vec![tx.clone()],
+                    gas_limit: u64::MAX,
+                    coinbase_recipient: None,
+                },
+                None,
+                Arc::new(AtomicBool::new(abandoned)),
+            )
+        };
+
+        assert_eq!(dry_run(false).unwrap().len(), 1);
+        assert!(matches!(dry_run(true), Err(ExecutorError::DryRunAbandoned)));
+    }
+
+    #[test]
+    fn dry_run_and_production_apply_same_receipts_limits() {
+        // Produces `LogData`, `Return` and `ScriptResult` receipts.
+        let script = vec![
+            op::movi(0x10, 32),
+            op::logd(RegId::ZERO, RegId::ZERO, RegId::ZERO, 0x10),
+            op::ret(RegId::ONE),
+        ];
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .script_gas_limit(1000)
+            .start_script(script, vec![])
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+            .into();
+        let execute = |receipts_limits: ReceiptsLimits| {
+            let executor = create_executor(
+                Default::default(),
+                Config {
+                    receipts_limits,
+                    ..Default::default()
+                },
+            );
+            let dry_run = executor
+                .dry_run(
+                    Components {
+                        header_to_produce: Default::default(),
+                        transactions_source: vec![tx.clone()],
+                        gas_limit: u64::MAX,
+                        coinbase_recipient: None,
+                    },
+                    None,
+                    Default::default(),
+                )
+                .err();
+            let mut block = Block::default();
+            *block.transactions_mut() = vec![tx.clone()];
+            let ExecutionResult {
+                skipped_transactions,
+                ..
+            } = executor
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+            let production = skipped_transactions.into_iter().next().map(|(_, e)| e);
+            (dry_run, production)
+        };
+        let reason = |error: Option<ExecutorError>| match error {
+            Some(ExecutorError::ReceiptsLimitExceeded { reason, .. }) => Some(reason),
+            None => None,
+            Some(error) => panic!("Unexpected error {error:?}"),
+        };
+
+        let (dry_run, production) = execute(ReceiptsLimits {
+            max_receipts: Some(2),
+            max_log_data_bytes: None,
+        });
+        let expected = ReceiptsLimitExceeded::TooManyReceipts { count: 3, limit: 2 };
+        assert_eq!(reason(dry_run), Some(expected));
+        assert_eq!(reason(production), Some(expected));
+
+        let (dry_run, production) = execute(ReceiptsLimits {
+            max_receipts: None,
+            max_log_data_bytes: Some(31),
+        });
+        let expected = ReceiptsLimitExceeded::TooMuchLogData {
+            bytes: 32,
+            limit: 31,
+        };
+        assert_eq!(reason(dry_run), Some(expected));
+        assert_eq!(reason(production), Some(expected));
+
+        let (dry_run, production) = execute(ReceiptsLimits {
+            max_receipts: Some(3),
+            max_log_data_bytes: Some(32),
+        });
+        assert_eq!(reason(dry_run), None);
+        assert_eq!(reason(production), None);
+    }
+
+    #[cfg(feature = "fault-injection")]
+    #[test]
+    fn executor_skips_transaction_with_injected_vm_panic() {
+        use fuel_core_executor::fault_injection::{
+            Fault,
+            FaultInjector,
+        };
+        use fuel_core_types::{
+            fuel_asm::PanicReason,
+            fuel_vm::InterpreterError,
+        };
+
+        let fault_injector = FaultInjector::new();
+        let producer = create_executor(
+            Default::default(),
+            Config {
+                fault_injector: Some(fault_injector.clone()),
+                ..Default::default()
+            },
+        );
+        let block = test_block(1u32.into(), 0u64.into(), 3);
+        let tx_id = block.transactions()[1].id(&ChainId::default());
+        fault_injector.inject(Fault::VmPanic {
+            tx_id,
+            reason: PanicReason::MemoryOverflow,
+        });
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        // Two transactions and `Mint` are included.
+        assert_eq!(block.transactions().len(), 3);
+        assert_eq!(skipped_transactions.len(), 1);
+        assert_eq!(skipped_transactions[0].0, tx_id);
+        assert!(matches!(
+            skipped_transactions[0].1,
+            ExecutorError::VmExecution {
+                error: InterpreterError::Panic(PanicReason::MemoryOverflow),
+                ..
+            }
+        ));
+        assert!(fault_injector.pending().is_empty());
+    }
+
+    #[cfg(feature = "fault-injection")]
+    #[test]
+    fn executor_rolls_back_block_on_injected_storage_failure() {
+        use fuel_core_executor::fault_injection::{
+            Fault,
+            FaultInjector,
+        };
+        use fuel_core_storage::tables::FuelBlocks;
+
+        let producer = create_executor(Default::default(), Default::default());
+        let fault_injector = FaultInjector::new();
+        let mut verifier = create_executor(
+            Default::default(),
+            Config {
+                fault_injector: Some(fault_injector.clone()),
+                ..Default::default()
+            },
+        );
+        let block = test_block(1u32.into(), 0u64.into(), 3);
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        fault_injector.inject(Fault::StorageWrite { nth: 2 });
+
+        let result = verifier.execute_and_commit(
+            ExecutionTypes::Validation(block.clone()),
+            Default::default(),
+        );
+
+        assert!(matches!(result, Err(ExecutorError::StorageError(_))));
+        assert!(!verifier
+            .database_view_provider
+            .storage::<FuelBlocks>()
+            .contains_key(block.header().height())
+            .unwrap());
+        // The fault is triggered only once, so the next attempt succeeds.
+        verifier
+            .execute_and_commit(ExecutionTypes::Validation(block), Default::default())
+            .expect("The block should be valid without the fault");
+    }
+
+    #[test]
+    fn executor_collects_execution_profile_of_the_block() {
+        let profiler = ExecutionProfiler::new(false);
+        let producer = create_executor(
+            Default::default(),
+            Config {
+                profiler: Some(profiler.clone()),
+                ..Default::default()
+            },
+        );
+        let mut block = test_block(1u32.into(), 0u64.into(), 3);
+        let (create, script) = setup_executable_script();
+        block.transactions_mut().push(create.into());
+        block.transactions_mut().push(script.clone().into());
+        let contract_id = script
+            .inputs()
+            .iter()
+            .find_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .expect("The script calls the contract");
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_and_commit(
+                ExecutionTypes::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        assert!(skipped_transactions.is_empty());
+
+        let (height, profile) = profiler
+            .last_block()
+            .expect("The profile of the block should be collected");
+        assert_eq!(&height, block.header().height());
+        assert_eq!(profile.blocks, 1);
+        // All transactions except `Mint` are executed by the VM.
+        assert_eq!(profile.total.transactions, 5);
+        assert_eq!(profile.contracts[&contract_id].transactions, 1);
+        #[cfg(feature = "profile-gas")]
+        {
+            assert!(profile.contracts[&contract_id].gas > 0);
+            assert!(profile
+                .opcodes
+                .contains_key(&fuel_core_types::fuel_asm::Opcode::CALL));
+        }
+        assert_eq!(profiler.take_report(), profile);
+        assert_eq!(profiler.take_report(), Default::default());
+    }
+
     // Ensure transaction commitment != default after execution
     #[test]
     fn executor_commits_transactions_to_block() {
@@ -553,6 +1038,70 @@ mod tests {
             assert_eq!(amount, expected_fee_amount_1 + expected_fee_amount_2);
         }
 
+        #[test]
+        fn executor_reports_fee_breakdown_of_transaction() {
+            let price = 2;
+            let script = TxBuilder::new(2322u64)
+                .script_gas_limit(1000)
+                .gas_price(price)
+                .coin_input(AssetId::BASE, 100000)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone();
+            let recipient = Contract::EMPTY_CONTRACT_ID;
+            let config = Config {
+                coinbase_recipient: recipient,
+                consensus_parameters: ConsensusParameters {
+                    fee_params: FeeParameters {
+                        gas_price_factor: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let database = &mut Database::default();
+            database
+                .storage::<ContractsRawCode>()
+                .insert(&recipient, &[])
+                .expect("Should insert coinbase contract");
+            let producer = create_executor(database.clone(), config);
+
+            let mut block = Block::default();
+            block.header_mut().set_block_height(1.into());
+            *block.transactions_mut() = vec![script.into()];
+
+            let ExecutionResult {
+                block, tx_status, ..
+            } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let fee = *tx_status[0].result.fee();
+            let script_gas_used = tx_status[0]
+                .result
+                .receipts()
+                .iter()
+                .find_map(|receipt| match receipt {
+                    Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                    _ => None,
+                })
+                .expect("The script should produce the result");
+            assert_eq!(fee.gas_price, price);
+            assert_eq!(fee.script_gas_used, script_gas_used);
+            assert_eq!(fee.predicate_gas_used, 0);
+            assert!(fee.refunded > 0);
+            assert_eq!(fee.fee.checked_add(fee.refunded), Some(fee.max_fee));
+            // The `Mint` transaction collects the fee and doesn't pay it.
+            assert_eq!(tx_status[1].result.fee(), &FeeReport::default());
+            let mint = block.transactions()[1].as_mint().unwrap();
+            assert_eq!(mint.mint_amount(), &fee.fee);
+        }
+
         #[test]
         fn skip_coinbase_during_dry_run() {
             let price = 1;
@@ -581,6 +1130,7 @@ mod tests {
                     header_to_produce: Default::default(),
                     transactions_source: OnceTransactionsSource::new(vec![script.into()]),
                     gas_limit: u64::MAX,
+                    coinbase_recipient: None,
                 }))
                 .unwrap();
             let ExecutionResult { block, .. } = result.into_result();
@@ -588,6 +1138,37 @@ mod tests {
             assert_eq!(block.transactions().len(), 1);
         }
 
+        #[test]
+        fn coinbase_recipient_of_block_overrides_config() {
+            let config = Config {
+                coinbase_recipient: [1u8; 32].into(),
+                ..Default::default()
+            };
+            let block_recipient = [2u8; 32].into();
+            let producer = create_executor(Default::default(), config.clone());
+            let verifier = create_executor(Default::default(), config);
+
+            let mut header = PartialBlockHeader::default();
+            header.consensus.height = 1u32.into();
+            let result = producer
+                .execute_without_commit(ExecutionTypes::Production(Components {
+                    header_to_produce: header,
+                    transactions_source: OnceTransactionsSource::new(vec![]),
+                    gas_limit: u64::MAX,
+                    coinbase_recipient: Some(block_recipient),
+                }))
+                .unwrap();
+            let ExecutionResult { block, .. } = result.into_result();
+
+            let mint = block.transactions()[0].as_mint().unwrap();
+            assert_eq!(mint.input_contract().contract_id, block_recipient);
+            let validation_result = verifier.execute_and_commit(
+                ExecutionTypes::Validation(block),
+                Default::default(),
+            );
+            assert!(validation_result.is_ok());
+        }
+
         #[test]
         fn executor_commits_transactions_with_non_zero_coinbase_validation() {
             let price = 1;
@@ -1253,6 +1834,205 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn validation_skips_signatures_up_to_trusted_checkpoint() {
+        let mut tx = TxBuilder::new(2322u64)
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        tx.witnesses_mut()[0] = vec![0; 64].into();
+        let input = tx.inputs()[0].clone();
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(*input.input_owner().unwrap());
+        coin.set_amount(100);
+
+        // The producer doesn't verify the signatures.
+        let producer = create_executor(Default::default(), Default::default());
+        let mut block = Block::default();
+        block.header_mut().set_block_height(2.into());
+        *block.transactions_mut() = vec![tx.into()];
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let mut checkpoint = CompressedBlock::default();
+        checkpoint.header_mut().set_block_height(3.into());
+        checkpoint.header_mut().recalculate_metadata();
+        let checkpoint_id = checkpoint.header().id();
+
+        let validate = |skip_signatures_until: Option<(BlockHeight, BlockId)>,
+                        store_checkpoint: bool| {
+            let mut db = Database::default();
+            db.storage::<Coins>()
+                .insert(input.utxo_id().unwrap(), &coin)
+                .unwrap();
+            if store_checkpoint {
+                db.storage::<FuelBlocks>()
+                    .insert(&3.into(), &checkpoint)
+                    .unwrap();
+            }
+            let validator = create_executor(
+                db,
+                Config {
+                    utxo_validation_default: true,
+                    skip_signatures_until,
+                    ..Default::default()
+                },
+            );
+            validator.execute_and_commit(
+                ExecutionBlock::Validation(block.clone()),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+        };
+
+        assert!(validate(None, true).is_err());
+        assert!(validate(Some((3.into(), checkpoint_id)), true).is_ok());
+        assert!(validate(Some((2.into(), checkpoint_id)), true).is_err());
+        // The blocks above the checkpoint are verified.
+        assert!(validate(Some((1.into(), checkpoint_id)), true).is_err());
+        // The checkpoint is not trusted until its block is known.
+        assert!(validate(Some((3.into(), checkpoint_id)), false).is_err());
+        // The block at the checkpoint height doesn't match the checkpoint.
+        assert!(matches!(
+            validate(Some((3.into(), BlockId::default())), true),
+            Err(ExecutorError::TrustedCheckpointMismatch { found, .. })
+                if found == checkpoint_id
+        ));
+    }
+
+    #[test]
+    fn parallel_validation_verifies_signatures() {
+        let signed_tx = |seed: u64| {
+            TxBuilder::new(seed)
+                .coin_input(AssetId::default(), 100)
+                .change_output(AssetId::default())
+                .build()
+                .transaction()
+                .clone()
+        };
+        let validate = |transactions: Vec<Script>| {
+            let mut db = Database::default();
+            for tx in &transactions {
+                let input = &tx.inputs()[0];
+                let mut coin = CompressedCoin::default();
+                coin.set_owner(*input.input_owner().unwrap());
+                coin.set_amount(100);
+                db.storage::<Coins>()
+                    .insert(input.utxo_id().unwrap(), &coin)
+                    .unwrap();
+            }
+            // The producer doesn't verify the signatures.
+            let producer = create_executor(Default::default(), Default::default());
+            let mut block = Block::default();
+            *block.transactions_mut() =
+                transactions.into_iter().map(Into::into).collect();
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let validator = create_executor(
+                db,
+                Config {
+                    utxo_validation_default: true,
+                    execution_threads: 4,
+                    ..Default::default()
+                },
+            );
+            validator.execute_and_commit(
+                ExecutionBlock::Validation(block),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+        };
+
+        let mut transactions = (0..8).map(signed_tx).collect::<Vec<_>>();
+        assert!(validate(transactions.clone()).is_ok());
+
+        transactions[5].witnesses_mut()[0] = vec![0; 64].into();
+        assert!(matches!(
+            validate(transactions),
+            Err(ExecutorError::TransactionValidity(_))
+        ));
+    }
+
+    #[test]
+    fn validation_skips_cached_predicates() {
+        // The predicate always fails, so only the cached result lets it pass.
+        let predicate: Vec<u8> = vec![op::ret(RegId::ZERO)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let input = Input::coin_predicate(
+            Default::default(),
+            owner,
+            100,
+            AssetId::default(),
+            Default::default(),
+            Default::default(),
+            100,
+            predicate,
+            vec![],
+        );
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(100)
+            .add_input(input.clone())
+            .finalize_as_transaction();
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+        coin.set_amount(100);
+
+        // The producer doesn't verify the predicates.
+        let producer = create_executor(Default::default(), Default::default());
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![tx];
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let validate = |predicate_cache: Option<PredicateCache>| {
+            let mut db = Database::default();
+            db.storage::<Coins>()
+                .insert(input.utxo_id().unwrap(), &coin)
+                .unwrap();
+            let validator = create_executor(
+                db,
+                Config {
+                    utxo_validation_default: true,
+                    predicate_cache,
+                    ..Default::default()
+                },
+            );
+            validator.execute_and_commit(
+                ExecutionBlock::Validation(block.clone()),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+        };
+
+        let cache = PredicateCache::new(10);
+        assert!(matches!(
+            validate(Some(cache.clone())),
+            Err(ExecutorError::TransactionValidity(
+                TransactionValidityError::InvalidPredicate(_)
+            ))
+        ));
+        cache.insert(block.transactions()[0].as_script().unwrap());
+        assert!(validate(Some(cache)).is_ok());
+    }
+
     #[test]
     fn skipped_tx_not_changed_spent_status() {
         // `tx2` has two inputs: one used by `tx1` and on random. So after the execution of `tx1`,
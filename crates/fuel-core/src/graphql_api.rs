@@ -1,3 +1,5 @@
+use dry_run_limiter::DryRunLimits;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -5,12 +7,15 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
     secrecy::Secret,
+    services::block_importer::Finality,
 };
 use std::net::SocketAddr;
 
 pub mod api_service;
 pub mod database;
+pub mod dry_run_limiter;
 pub(crate) mod metrics_extension;
 pub mod ports;
 pub mod storage;
@@ -23,12 +28,48 @@ pub struct Config {
     pub utxo_validation: bool,
     pub debug: bool,
     pub vm_backtrace: bool,
-    pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
+    pub block_gas_limit: u64,
+    /// The consensus of the chain used to verify the headers of the blocks.
+    pub consensus: ConsensusConfig,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Rejects the mutations that change the state of the node, like the submission
+    /// of transactions and the block production.
+    pub read_only: bool,
+    /// The number of blocks after which the pagination cursors expire.
+    pub cursor_expiry: u32,
+    pub dry_run_limits: DryRunLimits,
+    /// The rule used to decide which blocks are final.
+    pub finality: Finality,
+}
+
+impl Config {
+    /// Returns the error if the node is read-only and doesn't accept the `action`.
+    pub fn ensure_writable(&self, action: &str) -> anyhow::Result<()> {
+        if self.read_only {
+            Err(anyhow::anyhow!(
+                "The node is in the read-only mode and doesn't accept {action}"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The value of the `code` extension of the error returned for the pruned data.
+pub const PRUNED_ERROR_CODE: &str = "PRUNED";
+
+/// Returns the error for the transactions or receipts of the block pruned by the node.
+/// Unlike the missing data, the error has the `code` extension set to [`PRUNED_ERROR_CODE`].
+pub fn pruned_error(height: &BlockHeight) -> async_graphql::Error {
+    use async_graphql::ErrorExtensions;
+    async_graphql::Error::new(format!(
+        "The transactions and receipts of the block {height} are pruned"
+    ))
+    .extend_with(|_, extensions| extensions.set("code", PRUNED_ERROR_CODE))
 }
 
 pub trait IntoApiResult<T> {
@@ -1,7 +1,12 @@
 use crate::{
     fuel_core_graphql_api::{
+        dry_run_limiter::{
+            ClientIp,
+            DryRunLimiter,
+        },
         metrics_extension::MetricsExtension,
         ports::{
+            BlockImporterPort,
             BlockProducerPort,
             ConsensusModulePort,
             OffChainDatabase,
@@ -28,6 +33,7 @@ use async_graphql::{
 };
 use axum::{
     extract::{
+        ConnectInfo,
         DefaultBodyLimit,
         Extension,
     },
@@ -68,7 +74,10 @@ use std::{
         TcpListener,
     },
     pin::Pin,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tokio_stream::StreamExt;
 use tower_http::{
@@ -87,6 +96,11 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
+
+/// The time when the GraphQL service was created, used to report the uptime of the node.
+#[derive(Clone, Copy)]
+pub struct StartTime(pub Instant);
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -131,7 +145,7 @@ impl RunnableService for GraphqlService {
 
         let server = axum::Server::from_tcp(listener)
             .unwrap()
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(async move {
                 state
                     .while_started()
@@ -173,6 +187,7 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    block_importer: BlockImporter,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
@@ -185,16 +200,20 @@ where
     let network_addr = config.addr;
     let combined_read_database = ReadDatabase::new(on_database, off_database);
 
+    let dry_run_limiter = DryRunLimiter::new(config.dry_run_limits.clone());
     let schema = schema
         .data(config)
+        .data(dry_run_limiter)
         .data(combined_read_database)
         .data(txpool)
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(block_importer)
+        .data(StartTime(Instant::now()))
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
-        .extension(ViewExtension::new())
+        .extension(ViewExtension::new(request_timeout))
         .finish();
 
     let router = Router::new()
@@ -244,9 +263,11 @@ async fn health() -> Json<serde_json::Value> {
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
     req: Json<Request>,
 ) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    let req = req.0.data(ClientIp(client.ip()));
+    schema.execute(req).await.into()
 }
 
 async fn graphql_subscription_handler(
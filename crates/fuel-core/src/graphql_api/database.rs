@@ -9,10 +9,15 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::receipts::{
+        ReceiptPointer,
+        ReceiptSelector,
+    },
 };
 use fuel_core_storage::{
     iter::{
         BoxedIter,
+        IntoBoxedIter,
         IterDirection,
     },
     transactional::AtomicView,
@@ -48,13 +53,17 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        graphql_api::{
+            ContractBalance,
+            ContractStorageStats,
+        },
         txpool::TransactionStatus,
     },
 };
 use std::{
     borrow::Cow,
     sync::Arc,
+    time::Instant,
 };
 
 mod arc_wrapper;
@@ -96,6 +105,7 @@ impl ReadDatabase {
         ReadView {
             on_chain: self.on_chain.latest_view(),
             off_chain: self.off_chain.latest_view(),
+            deadline: None,
         }
     }
 }
@@ -103,6 +113,53 @@ impl ReadDatabase {
 pub struct ReadView {
     on_chain: OnChainView,
     off_chain: OffChainView,
+    /// The iterations over the storage stop with an error after the deadline.
+    deadline: Option<Instant>,
+}
+
+impl ReadView {
+    /// Returns `true` if the transactions and receipts of the block at the `height` are pruned.
+    pub fn is_body_pruned(&self, height: &BlockHeight) -> StorageResult<bool> {
+        Ok(matches!(
+            self.pruned_block_bodies_height()?,
+            Some(pruned) if *height <= pruned
+        ))
+    }
+
+    /// Sets the `deadline` of the request that uses the view.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Checks the deadline before each item of the `iter`. The blocking iteration
+    /// can't be interrupted by the timeout of the request, so it stops by itself
+    /// instead of running to completion after the request is abandoned.
+    fn until_deadline<'a, T>(
+        &self,
+        mut iter: BoxedIter<'a, StorageResult<T>>,
+    ) -> BoxedIter<'a, StorageResult<T>>
+    where
+        T: 'a,
+    {
+        let Some(deadline) = self.deadline else {
+            return iter
+        };
+        let mut expired = false;
+        core::iter::from_fn(move || {
+            if expired {
+                return None
+            }
+            if Instant::now() >= deadline {
+                expired = true;
+                return Some(Err(StorageError::Other(anyhow::anyhow!(
+                    "The request exceeded its execution timeout"
+                ))))
+            }
+            iter.next()
+        })
+        .into_boxed()
+    }
 }
 
 impl DatabaseBlocks for ReadView {
@@ -115,7 +172,7 @@ impl DatabaseBlocks for ReadView {
         height: Option<BlockHeight>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>> {
-        self.on_chain.blocks(height, direction)
+        self.until_deadline(self.on_chain.blocks(height, direction))
     }
 
     fn latest_height(&self) -> StorageResult<BlockHeight> {
@@ -145,7 +202,7 @@ impl DatabaseMessages for ReadView {
         start_message_id: Option<Nonce>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Message>> {
-        self.on_chain.all_messages(start_message_id, direction)
+        self.until_deadline(self.on_chain.all_messages(start_message_id, direction))
     }
 
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
@@ -164,8 +221,18 @@ impl DatabaseContracts for ReadView {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>> {
-        self.on_chain
-            .contract_balances(contract, start_asset, direction)
+        self.until_deadline(self.on_chain.contract_balances(
+            contract,
+            start_asset,
+            direction,
+        ))
+    }
+
+    fn contract_storage_stats(
+        &self,
+        contract: &ContractId,
+    ) -> StorageResult<ContractStorageStats> {
+        self.on_chain.contract_storage_stats(contract)
     }
 }
 
@@ -193,8 +260,11 @@ impl OnChainDatabase for ReadView {
         start_message_id: Option<Nonce>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Nonce>> {
-        self.on_chain
-            .owned_message_ids(owner, start_message_id, direction)
+        self.until_deadline(self.on_chain.owned_message_ids(
+            owner,
+            start_message_id,
+            direction,
+        ))
     }
 
     fn owned_coins_ids(
@@ -203,7 +273,24 @@ impl OnChainDatabase for ReadView {
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>> {
-        self.on_chain.owned_coins_ids(owner, start_coin, direction)
+        self.until_deadline(self.on_chain.owned_coins_ids(owner, start_coin, direction))
+    }
+
+    fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        self.on_chain.owned_balance(owner, asset_id, base_asset_id)
+    }
+
+    fn owned_balances(
+        &self,
+        owner: &Address,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<Vec<(AssetId, u64)>> {
+        self.on_chain.owned_balances(owner, base_asset_id)
     }
 }
 
@@ -212,13 +299,77 @@ impl OffChainDatabase for ReadView {
         self.off_chain.tx_status(tx_id)
     }
 
+    fn archived_tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
+        self.off_chain.archived_tx_status(tx_id)
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
-        self.off_chain
-            .owned_transactions_ids(owner, start, direction)
+        self.until_deadline(
+            self.off_chain
+                .owned_transactions_ids(owner, start, direction),
+        )
+    }
+
+    fn contract_receipts_ids(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, TxId)>> {
+        self.until_deadline(self.off_chain.contract_receipts_ids(
+            contract_id,
+            selector,
+            start,
+            direction,
+        ))
+    }
+
+    fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>> {
+        self.off_chain.da_compressed_block(height)
+    }
+
+    fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.off_chain.pruned_block_bodies_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combined_database::CombinedDatabase;
+
+    fn view() -> ReadView {
+        let database = CombinedDatabase::default();
+        ReadDatabase::new(database.on_chain().clone(), database.off_chain().clone())
+            .view()
+    }
+
+    #[test]
+    fn iteration_stops_after_deadline() {
+        let view = view().with_deadline(Instant::now());
+
+        let result: Vec<_> = view
+            .owned_coins_ids(&Address::default(), None, IterDirection::Forward)
+            .collect();
+
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_err());
+    }
+
+    #[test]
+    fn iteration_without_deadline_is_not_interrupted() {
+        let view = view();
+
+        let result: Vec<_> = view
+            .owned_coins_ids(&Address::default(), None, IterDirection::Forward)
+            .collect();
+
+        assert!(result.is_empty());
     }
 }
@@ -0,0 +1,204 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    net::IpAddr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::Semaphore;
+
+/// The limits of the resources available to the dry runs of the API.
+#[derive(Clone, Debug)]
+pub struct DryRunLimits {
+    /// The maximum number of the dry runs executed at the same time.
+    /// Other dry runs wait for their turn.
+    pub max_concurrent: usize,
+    /// The maximum gas of all dry runs in progress.
+    pub max_gas: u64,
+    /// The maximum gas of the dry runs in progress requested from one IP address.
+    pub max_gas_per_ip: u64,
+    /// The maximum time of the dry run, including the waiting for the turn.
+    pub timeout: Duration,
+}
+
+impl Default for DryRunLimits {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 8,
+            max_gas: 1_000_000_000,
+            max_gas_per_ip: 300_000_000,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The IP address of the client of the request.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientIp(pub IpAddr);
+
+/// Limits the resources used by the dry runs, so the simulations can't starve
+/// the block production on the nodes that also serve the API.
+pub struct DryRunLimiter {
+    limits: DryRunLimits,
+    concurrency: Semaphore,
+    gas: Arc<Mutex<GasInProgress>>,
+}
+
+#[derive(Default)]
+struct GasInProgress {
+    total: u64,
+    per_ip: HashMap<IpAddr, u64>,
+}
+
+impl DryRunLimiter {
+    pub fn new(limits: DryRunLimits) -> Self {
+        Self {
+            concurrency: Semaphore::new(limits.max_concurrent),
+            limits,
+            gas: Default::default(),
+        }
+    }
+
+    /// Runs the `dry_run` with the `gas` if it fits into the limits. The requests above
+    /// the limits of the gas are rejected instead of waiting, because the gas in progress
+    /// is the gas of the dry runs of the same or other clients.
+    pub async fn run<F, T>(
+        &self,
+        ip: Option<IpAddr>,
+        gas: u64,
+        dry_run: F,
+    ) -> anyhow::Result<T>
+    where
+        F: Future<Output = anyhow::Result<T>>,
+    {
+        let _reservation = self.reserve(ip, gas)?;
+
+        tokio::time::timeout(self.limits.timeout, async {
+            let _permit = self.concurrency.acquire().await?;
+            dry_run.await
+        })
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "The dry run exceeded the timeout of {:?}",
+                self.limits.timeout
+            )
+        })?
+    }
+
+    fn reserve(&self, ip: Option<IpAddr>, gas: u64) -> anyhow::Result<GasReservation> {
+        let mut in_progress = self.gas.lock().expect("poisoned");
+
+        let total = in_progress.total.saturating_add(gas);
+        if total > self.limits.max_gas {
+            return Err(anyhow::anyhow!(
+                "The node is busy with other dry runs, try again later"
+            ))
+        }
+        if let Some(ip) = ip {
+            let per_ip = in_progress
+                .per_ip
+                .get(&ip)
+                .copied()
+                .unwrap_or_default()
+                .saturating_add(gas);
+            if per_ip > self.limits.max_gas_per_ip {
+                return Err(anyhow::anyhow!(
+                    "Too much gas of the dry runs in progress from `{ip}`"
+                ))
+            }
+            in_progress.per_ip.insert(ip, per_ip);
+        }
+        in_progress.total = total;
+
+        Ok(GasReservation {
+            gas_in_progress: self.gas.clone(),
+            ip,
+            gas,
+        })
+    }
+}
+
+/// Returns the reserved gas to the limiter when the dry run is finished or abandoned.
+struct GasReservation {
+    gas_in_progress: Arc<Mutex<GasInProgress>>,
+    ip: Option<IpAddr>,
+    gas: u64,
+}
+
+impl Drop for GasReservation {
+    fn drop(&mut self) {
+        let mut in_progress = self.gas_in_progress.lock().expect("poisoned");
+        in_progress.total = in_progress.total.saturating_sub(self.gas);
+        if let Some(ip) = self.ip {
+            if let Some(per_ip) = in_progress.per_ip.get_mut(&ip) {
+                *per_ip = per_ip.saturating_sub(self.gas);
+                if *per_ip == 0 {
+                    in_progress.per_ip.remove(&ip);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter() -> DryRunLimiter {
+        DryRunLimiter::new(DryRunLimits {
+            max_concurrent: 1,
+            max_gas: 100,
+            max_gas_per_ip: 60,
+            timeout: Duration::from_millis(100),
+        })
+    }
+
+    fn ip(last: u8) -> Option<IpAddr> {
+        Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, last)))
+    }
+
+    #[test]
+    fn gas_per_ip_is_limited() {
+        let limiter = limiter();
+
+        let _first = limiter.reserve(ip(1), 50).unwrap();
+
+        assert!(limiter.reserve(ip(1), 20).is_err());
+        assert!(limiter.reserve(ip(2), 20).is_ok());
+    }
+
+    #[test]
+    fn total_gas_is_limited() {
+        let limiter = limiter();
+
+        let _first = limiter.reserve(ip(1), 60).unwrap();
+
+        assert!(limiter.reserve(ip(2), 50).is_err());
+        assert!(limiter.reserve(None, 40).is_ok());
+    }
+
+    #[test]
+    fn gas_is_returned_after_dry_run() {
+        let limiter = limiter();
+
+        drop(limiter.reserve(ip(1), 60).unwrap());
+
+        assert!(limiter.reserve(ip(1), 60).is_ok());
+    }
+
+    #[tokio::test]
+    async fn dry_run_waiting_for_turn_times_out() {
+        let limiter = limiter();
+        let _permit = limiter.concurrency.acquire().await.unwrap();
+
+        let result = limiter.run(ip(1), 10, async { Ok(()) }).await;
+
+        assert!(result.is_err());
+        assert!(limiter.reserve(ip(1), 60).is_ok());
+    }
+}
@@ -1,3 +1,7 @@
+use crate::fuel_core_graphql_api::storage::receipts::{
+    ReceiptPointer,
+    ReceiptSelector,
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -13,13 +17,17 @@ use fuel_core_storage::{
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
+        StateDiffs,
         Transactions,
     },
     Error as StorageError,
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::{
+    service::TxStatusMessage,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -37,6 +45,7 @@ use fuel_core_types::{
         TxId,
         TxPointer,
         UtxoId,
+        Word,
     },
     fuel_types::{
         Address,
@@ -46,9 +55,16 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
+        block_importer::SharedImportResult,
         executor::TransactionExecutionStatus,
-        graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        graphql_api::{
+            ContractBalance,
+            ContractStorageStats,
+        },
+        p2p::{
+            PeerInfo,
+            SyncStatus,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -61,12 +77,29 @@ use std::sync::Arc;
 pub trait OffChainDatabase: Send + Sync {
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the archived final status of the transaction not included in the block.
+    fn archived_tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    fn contract_receipts_ids(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, TxId)>>;
+
+    /// Returns the DA-compressed representation of the block at the `height`.
+    fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>>;
+
+    /// Returns the height of the latest block with the pruned transactions and receipts.
+    fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -79,6 +112,7 @@ pub trait OnChainDatabase:
     + StorageInspect<Transactions, Error = StorageError>
     + DatabaseMessages
     + StorageInspect<Coins, Error = StorageError>
+    + StorageInspect<StateDiffs, Error = StorageError>
     + DatabaseContracts
     + DatabaseChain
     + DatabaseMessageProof
@@ -96,6 +130,21 @@ pub trait OnChainDatabase:
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns the amount of the `asset_id` owned by the `owner`.
+    fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<u64>;
+
+    /// Returns the amounts of all assets owned by the `owner`, sorted by the asset id.
+    fn owned_balances(
+        &self,
+        owner: &Address,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<Vec<(AssetId, u64)>>;
 }
 
 /// Trait that specifies all the getters required for blocks.
@@ -139,6 +188,12 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the number and the size of the storage slots of the contract.
+    fn contract_storage_stats(
+        &self,
+        contract: &ContractId,
+    ) -> StorageResult<ContractStorageStats>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -152,11 +207,30 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    fn min_gas_price(&self) -> u64;
+
+    fn pending_number(&self) -> usize;
+
+    /// Returns the gas price and the max gas of the pending transactions.
+    fn pending_gas_prices(&self) -> Vec<(Word, Word)>;
+
+    /// Returns the pending transactions in the order of their submission together with
+    /// the ids of the pending transactions they depend on.
+    fn pending_transactions(&self) -> Vec<(TxInfo, Vec<TxId>)>;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Inserts the `tx` that may depend on the outputs of the `after` transaction.
+    /// The `tx` waits for the `after` if its inputs are not found.
+    async fn insert_after(
+        &self,
+        tx: Arc<Transaction>,
+        after: TxId,
+    ) -> anyhow::Result<InsertionResult>;
+
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
@@ -180,6 +254,9 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Returns `None` if the block production is disabled.
+    fn is_synced(&self) -> Option<bool>;
 }
 
 /// Trait that specifies queries supported by the database.
@@ -196,12 +273,27 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Returns the progress of the synchronization with the network,
+    /// or `None` if the node doesn't sync the blocks.
+    fn sync_status(&self) -> Option<SyncStatus>;
+}
+
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns a stream of the imported blocks with the results of their execution.
+    fn block_events(&self) -> BoxStream<SharedImportResult>;
 }
 
 pub mod worker {
-    use crate::database::{
-        database_description::off_chain::OffChain,
-        metadata::MetadataTable,
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            metadata::MetadataTable,
+        },
+        fuel_core_graphql_api::storage::receipts::{
+            ReceiptPointer,
+            ReceiptSelector,
+        },
     };
     use fuel_core_services::stream::BoxStream;
     use fuel_core_storage::{
@@ -210,16 +302,19 @@ pub mod worker {
         Result as StorageResult,
         StorageMutate,
     };
+    use fuel_core_txpool::service::SqueezedOutTransaction;
     use fuel_core_types::{
         fuel_tx::{
             Address,
             Bytes32,
+            ContractId,
         },
         fuel_types::BlockHeight,
         services::{
             block_importer::SharedImportResult,
             txpool::TransactionStatus,
         },
+        tai64::Tai64,
     };
 
     pub trait OffChainDatabase:
@@ -236,19 +331,74 @@ pub mod worker {
             tx_id: &Bytes32,
         ) -> StorageResult<Option<Bytes32>>;
 
+        fn record_contract_receipt(
+            &mut self,
+            contract_id: &ContractId,
+            selector: ReceiptSelector,
+            pointer: ReceiptPointer,
+            tx_id: &Bytes32,
+        ) -> StorageResult<Option<Bytes32>>;
+
         fn update_tx_status(
             &mut self,
             id: &Bytes32,
             status: TransactionStatus,
         ) -> StorageResult<Option<TransactionStatus>>;
 
+        /// Archives the final status of the transaction not included in the block.
+        fn archive_tx_status(
+            &mut self,
+            id: &Bytes32,
+            status: TransactionStatus,
+            archived_at: Tai64,
+        ) -> StorageResult<()>;
+
+        /// Removes the statuses archived before `archived_before`.
+        /// Returns the number of removed statuses.
+        fn prune_archived_tx_statuses(
+            &mut self,
+            archived_before: Tai64,
+        ) -> StorageResult<usize>;
+
+        /// Stores the DA-compressed representation of the block at the `height`.
+        fn store_da_compressed_block(
+            &mut self,
+            height: &BlockHeight,
+            block: &[u8],
+        ) -> StorageResult<()>;
+
         /// Update metadata about the total number of transactions on the chain.
         /// Returns the total count after the update.
         fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64>;
+
+        /// Removes the receipts of the transaction, except the `MessageOut` ones.
+        fn prune_tx_receipts(&mut self, id: &Bytes32) -> StorageResult<()>;
+
+        /// Returns the height of the latest block with the pruned body.
+        fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+        fn set_pruned_block_bodies_height(
+            &mut self,
+            height: &BlockHeight,
+        ) -> StorageResult<()>;
+    }
+
+    pub trait BlockBodies: Send + Sync {
+        /// Removes the transactions of the block at the `height`, keeping its header.
+        /// Returns the ids of the removed transactions.
+        fn remove_block_body(
+            &mut self,
+            height: &BlockHeight,
+        ) -> StorageResult<Vec<Bytes32>>;
     }
 
     pub trait BlockImporter {
         /// Returns a stream of imported block.
         fn block_events(&self) -> BoxStream<SharedImportResult>;
     }
+
+    pub trait TxPool {
+        /// Returns a stream of the transactions squeezed out of the tx pool.
+        fn squeezed_out_events(&self) -> BoxStream<SqueezedOutTransaction>;
+    }
 }
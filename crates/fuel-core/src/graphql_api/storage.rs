@@ -1,5 +1,7 @@
 use fuel_core_storage::kv_store::StorageColumn;
 
+pub mod da_compression;
+pub mod receipts;
 pub mod transactions;
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
@@ -28,6 +30,14 @@ pub enum Column {
     OwnedMessageIds = 4,
     /// The column of the table that stores statistic about the blockchain.
     Statistic = 5,
+    /// The column of the table of receipts by the contract and the receipt selector
+    ContractReceipts = 6,
+    /// Transaction id to the archived final status
+    ArchivedTransactionStatus = 7,
+    /// The column of the table of archived statuses sorted by the time of archiving
+    ArchivedTransactionStatusByTime = 8,
+    /// The column of the table of DA-compressed blocks by the block height
+    DaCompressedBlocks = 9,
 }
 
 impl Column {
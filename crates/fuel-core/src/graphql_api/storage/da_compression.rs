@@ -0,0 +1,108 @@
+use flate2::{
+    read::DeflateDecoder,
+    write::DeflateEncoder,
+    Compression,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        primitive::Primitive,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::BlockHeader,
+    },
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+};
+use std::io::{
+    Read,
+    Write,
+};
+
+/// The table stores the DA-compressed representation of each block. These are
+/// exactly the bytes posted to the data availability layer.
+pub struct DaCompressedBlocks;
+
+impl Mappable for DaCompressedBlocks {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = [u8];
+    type OwnedValue = Vec<u8>;
+}
+
+impl TableWithBlueprint for DaCompressedBlocks {
+    type Blueprint = Plain<Primitive<4>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressedBlocks
+    }
+}
+
+////////////////////////////////////// Not storage part //////////////////////////////////////
+
+/// The versioned block in the format posted to the data availability layer.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum VersionedCompressedBlock {
+    V0(CompressedBlockV0),
+}
+
+/// The block header with all transactions of the block. The block id and
+/// the transaction ids are not included, because they can be recalculated.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedBlockV0 {
+    pub header: BlockHeader,
+    pub transactions: Vec<Transaction>,
+}
+
+impl VersionedCompressedBlock {
+    /// Encodes the `block` with `postcard` and compresses it with the DEFLATE algorithm.
+    pub fn compress(block: &Block) -> anyhow::Result<Vec<u8>> {
+        let block = VersionedCompressedBlock::V0(CompressedBlockV0 {
+            header: block.header().clone(),
+            transactions: block.transactions().to_vec(),
+        });
+        let encoded = postcard::to_allocvec(&block)?;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&encoded)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Decompresses the block produced by [`VersionedCompressedBlock::compress`].
+    pub fn decompress(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut encoded = Vec::new();
+        DeflateDecoder::new(bytes).read_to_end(&mut encoded)?;
+        Ok(postcard::from_bytes(&encoded)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_block_can_be_decompressed() {
+        let mut block = Block::default();
+        let tx = Transaction::default_test_tx();
+        block.transactions_mut().push(tx.clone());
+        block.transactions_mut().push(tx);
+
+        let bytes = VersionedCompressedBlock::compress(&block).unwrap();
+        let VersionedCompressedBlock::V0(decompressed) =
+            VersionedCompressedBlock::decompress(&bytes).unwrap();
+
+        assert_eq!(&decompressed.header, block.header());
+        assert_eq!(decompressed.transactions, block.transactions());
+    }
+
+    #[test]
+    fn decompression_of_garbage_fails() {
+        assert!(VersionedCompressedBlock::decompress(&[1, 2, 3]).is_err());
+    }
+}
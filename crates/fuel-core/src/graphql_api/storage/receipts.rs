@@ -0,0 +1,331 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        raw::Raw,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        Bytes32,
+        ContractId,
+        Receipt,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    array::TryFromSliceError,
+    mem::size_of,
+};
+
+/// The table allows iteration over receipts of the contract with the same selector,
+/// sorted by the position of the receipt in the blockchain.
+pub struct ContractReceipts;
+
+impl Mappable for ContractReceipts {
+    type Key = ContractReceiptKey;
+    type OwnedKey = Self::Key;
+    /// The id of the transaction that produced the receipt.
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractReceipts {
+    type Blueprint = Plain<Manual<ContractReceiptKey>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractReceipts
+    }
+}
+
+const SELECTOR_SIZE: usize = size_of::<u8>() + size_of::<Word>();
+/// The size of the `contract id + selector` prefix of the key.
+pub const CONTRACT_RECEIPTS_PREFIX_SIZE: usize = ContractId::LEN + SELECTOR_SIZE;
+const POINTER_SIZE: usize =
+    size_of::<BlockHeight>() + size_of::<TransactionIndex>() + size_of::<ReceiptIndex>();
+const INDEX_SIZE: usize = CONTRACT_RECEIPTS_PREFIX_SIZE + POINTER_SIZE;
+
+////////////////////////////////////// Not storage part //////////////////////////////////////
+
+pub type TransactionIndex = u16;
+pub type ReceiptIndex = u16;
+
+/// The kind of the receipt and the id of the log. The log id is the value of the
+/// `rB` register for `Log` and `LogData` receipts and zero for others.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReceiptSelector {
+    /// The kind of the receipt in the order of variants of the [`Receipt`].
+    pub kind: u8,
+    pub log_id: Word,
+}
+
+impl ReceiptSelector {
+    pub fn new(kind: u8, log_id: Word) -> Self {
+        Self { kind, log_id }
+    }
+
+    /// Returns the selector of the `receipt`.
+    pub fn of(receipt: &Receipt) -> Self {
+        let kind = match receipt {
+            Receipt::Call { .. } => 0,
+            Receipt::Return { .. } => 1,
+            Receipt::ReturnData { .. } => 2,
+            Receipt::Panic { .. } => 3,
+            Receipt::Revert { .. } => 4,
+            Receipt::Log { .. } => 5,
+            Receipt::LogData { .. } => 6,
+            Receipt::Transfer { .. } => 7,
+            Receipt::TransferOut { .. } => 8,
+            Receipt::ScriptResult { .. } => 9,
+            Receipt::MessageOut { .. } => 10,
+            Receipt::Mint { .. } => 11,
+            Receipt::Burn { .. } => 12,
+        };
+        Self::new(kind, receipt.rb().unwrap_or_default())
+    }
+
+    /// Returns contracts under which the `receipt` is indexed: the contract that
+    /// produced the receipt and, for `Call`, the called contract.
+    pub fn contracts(receipt: &Receipt) -> Vec<ContractId> {
+        let mut contracts = vec![];
+        contracts.extend(receipt.id().copied());
+        if let Receipt::Call { to, .. } = receipt {
+            if !contracts.contains(to) {
+                contracts.push(*to);
+            }
+        }
+        contracts
+    }
+}
+
+/// The position of the receipt in the blockchain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReceiptPointer {
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+    pub receipt_idx: ReceiptIndex,
+}
+
+impl ReceiptPointer {
+    pub fn new(
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        receipt_idx: ReceiptIndex,
+    ) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; POINTER_SIZE] {
+        let mut bytes = [0u8; POINTER_SIZE];
+        bytes[..4].copy_from_slice(self.block_height.to_bytes().as_ref());
+        bytes[4..6].copy_from_slice(self.tx_idx.to_be_bytes().as_ref());
+        bytes[6..].copy_from_slice(self.receipt_idx.to_be_bytes().as_ref());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: [u8; POINTER_SIZE]) -> Self {
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes.copy_from_slice(&bytes[..4]);
+        let mut tx_idx_bytes: [u8; 2] = Default::default();
+        tx_idx_bytes.copy_from_slice(&bytes[4..6]);
+        let mut receipt_idx_bytes: [u8; 2] = Default::default();
+        receipt_idx_bytes.copy_from_slice(&bytes[6..]);
+
+        Self {
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            tx_idx: u16::from_be_bytes(tx_idx_bytes),
+            receipt_idx: u16::from_be_bytes(receipt_idx_bytes),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractReceiptKey {
+    pub contract_id: ContractId,
+    pub selector: ReceiptSelector,
+    pub pointer: ReceiptPointer,
+}
+
+impl ContractReceiptKey {
+    pub fn new(
+        contract_id: &ContractId,
+        selector: ReceiptSelector,
+        pointer: ReceiptPointer,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            selector,
+            pointer,
+        }
+    }
+
+    /// Returns the prefix of keys of all receipts of the contract with the selector.
+    pub fn prefix(
+        contract_id: &ContractId,
+        selector: ReceiptSelector,
+    ) -> [u8; CONTRACT_RECEIPTS_PREFIX_SIZE] {
+        let mut prefix = [0u8; CONTRACT_RECEIPTS_PREFIX_SIZE];
+        prefix[..ContractId::LEN].copy_from_slice(contract_id.as_ref());
+        prefix[ContractId::LEN] = selector.kind;
+        prefix[ContractId::LEN + 1..].copy_from_slice(&selector.log_id.to_be_bytes());
+        prefix
+    }
+
+    fn to_bytes(&self) -> [u8; INDEX_SIZE] {
+        // generate prefix to enable sorted indexing of receipts by contract and selector
+        // contract_id + kind + log_id + block_height + tx_idx + receipt_idx
+        let mut bytes = [0u8; INDEX_SIZE];
+        bytes[..CONTRACT_RECEIPTS_PREFIX_SIZE]
+            .copy_from_slice(&Self::prefix(&self.contract_id, self.selector));
+        bytes[CONTRACT_RECEIPTS_PREFIX_SIZE..].copy_from_slice(&self.pointer.to_bytes());
+        bytes
+    }
+}
+
+impl From<[u8; INDEX_SIZE]> for ContractReceiptKey {
+    fn from(bytes: [u8; INDEX_SIZE]) -> Self {
+        let contract_id: [u8; 32] = bytes[..ContractId::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let mut log_id_bytes: [u8; 8] = Default::default();
+        log_id_bytes
+            .copy_from_slice(&bytes[ContractId::LEN + 1..CONTRACT_RECEIPTS_PREFIX_SIZE]);
+        let pointer: [u8; POINTER_SIZE] = bytes[CONTRACT_RECEIPTS_PREFIX_SIZE..]
+            .try_into()
+            .expect("It's an array of 8 bytes");
+
+        Self {
+            contract_id: ContractId::from(contract_id),
+            selector: ReceiptSelector::new(
+                bytes[ContractId::LEN],
+                Word::from_be_bytes(log_id_bytes),
+            ),
+            pointer: ReceiptPointer::from_bytes(pointer),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ContractReceiptKey {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; INDEX_SIZE] = bytes.try_into()?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl Encode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    type Encoder<'a> = [u8; INDEX_SIZE];
+
+    fn encode(t: &ContractReceiptKey) -> Self::Encoder<'_> {
+        t.to_bytes()
+    }
+}
+
+impl Decode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractReceiptKey> {
+        ContractReceiptKey::try_from(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractReceipts as Mappable>::Key {
+        let mut bytes = [0u8; INDEX_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractReceipts,
+        [1u8; INDEX_SIZE].into(),
+        <ContractReceipts as Mappable>::Value::default(),
+        <ContractReceipts as Mappable>::Value::default(),
+        generate_key
+    );
+
+    #[test]
+    fn contract_receipts_are_filtered_by_contract_and_selector() {
+        use crate::database::{
+            database_description::off_chain::OffChain,
+            Database,
+        };
+        use fuel_core_storage::iter::IterDirection;
+
+        let mut db = Database::<OffChain>::in_memory();
+        let contract = ContractId::from([1u8; 32]);
+        let log = ReceiptSelector::new(5, 1);
+        let entries = [
+            (contract, log, ReceiptPointer::new(1u32.into(), 0, 0)),
+            (
+                contract,
+                ReceiptSelector::new(5, 2),
+                ReceiptPointer::new(1u32.into(), 0, 1),
+            ),
+            (
+                contract,
+                ReceiptSelector::new(6, 1),
+                ReceiptPointer::new(1u32.into(), 0, 2),
+            ),
+            (
+                ContractId::from([2u8; 32]),
+                log,
+                ReceiptPointer::new(2u32.into(), 0, 0),
+            ),
+            (contract, log, ReceiptPointer::new(3u32.into(), 1, 0)),
+        ];
+        for (contract_id, selector, pointer) in entries {
+            db.record_contract_receipt(
+                &contract_id,
+                selector,
+                pointer,
+                &Bytes32::zeroed(),
+            )
+            .unwrap();
+        }
+
+        let forward = db
+            .contract_receipts(contract, log, None, None)
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+        let backward = db
+            .contract_receipts(contract, log, None, Some(IterDirection::Reverse))
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            forward,
+            vec![
+                ReceiptPointer::new(1u32.into(), 0, 0),
+                ReceiptPointer::new(3u32.into(), 1, 0)
+            ]
+        );
+        assert_eq!(backward, forward.iter().rev().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn key_encoding_roundtrip() {
+        let key = ContractReceiptKey::new(
+            &ContractId::from([3u8; 32]),
+            ReceiptSelector::new(6, 0x0102030405060708),
+            ReceiptPointer::new(42u32.into(), 7, 1000),
+        );
+
+        let decoded = ContractReceiptKey::from(key.to_bytes());
+
+        assert_eq!(decoded, key);
+    }
+}
@@ -11,12 +11,16 @@ use fuel_core_storage::{
     Mappable,
 };
 use fuel_core_types::{
+    blockchain::primitives::BlockId,
     fuel_tx::{
         Address,
         Bytes32,
+        Receipt,
     },
     fuel_types::BlockHeight,
+    fuel_vm::ProgramState,
     services::txpool::TransactionStatus,
+    tai64::Tai64,
 };
 use std::{
     array::TryFromSliceError,
@@ -61,6 +65,67 @@ impl TableWithBlueprint for TransactionStatuses {
     }
 }
 
+/// The [`TransactionStatuses`] of the version `0` of the off-chain database, before
+/// the fee was added to the statuses of the included transactions. It shares the
+/// column with the [`TransactionStatuses`] and is only read by the migration.
+pub struct TransactionStatusesV0;
+
+impl Mappable for TransactionStatusesV0 {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = TransactionStatusV0;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionStatusesV0 {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionStatus
+    }
+}
+
+/// The table stores the final statuses of the transactions that are not included in
+/// the blocks, like squeezed out ones. The statuses are removed after the retention period.
+pub struct ArchivedTransactionStatuses;
+
+impl Mappable for ArchivedTransactionStatuses {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = ArchivedTransactionStatus;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ArchivedTransactionStatuses {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ArchivedTransactionStatus
+    }
+}
+
+/// The table allows iteration over the archived statuses sorted by the time of
+/// archiving to remove the expired ones.
+pub struct ArchivedTransactionStatusesByTime;
+
+impl Mappable for ArchivedTransactionStatusesByTime {
+    type Key = ArchivedTransactionStatusKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ArchivedTransactionStatusesByTime {
+    type Blueprint = Plain<Manual<ArchivedTransactionStatusKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ArchivedTransactionStatusByTime
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -80,8 +145,113 @@ fn owned_tx_index_key(
     default
 }
 
+const ARCHIVED_KEY_SIZE: usize = Tai64::BYTE_SIZE + Bytes32::LEN;
+
 ////////////////////////////////////// Not storage part //////////////////////////////////////
 
+/// The final status of the transaction with the time when it was archived.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ArchivedTransactionStatus {
+    pub status: TransactionStatus,
+    pub archived_at: Tai64,
+}
+
+/// The [`TransactionStatus`] stored by the version `0` of the off-chain database.
+/// The order of the variants matches the [`TransactionStatus`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TransactionStatusV0 {
+    Submitted {
+        time: Tai64,
+    },
+    Success {
+        block_id: BlockId,
+        time: Tai64,
+        result: Option<ProgramState>,
+        receipts: Vec<Receipt>,
+    },
+    SqueezedOut {
+        reason: String,
+    },
+    Failed {
+        block_id: BlockId,
+        time: Tai64,
+        result: Option<ProgramState>,
+        receipts: Vec<Receipt>,
+    },
+}
+
+impl From<TransactionStatusV0> for TransactionStatus {
+    /// The fee of the transactions included before the migration is unknown,
+    /// so it is reported as zero.
+    fn from(status: TransactionStatusV0) -> Self {
+        match status {
+            TransactionStatusV0::Submitted { time } => Self::Submitted { time },
+            TransactionStatusV0::Success {
+                block_id,
+                time,
+                result,
+                receipts,
+            } => Self::Success {
+                block_id,
+                time,
+                result,
+                receipts,
+                fee: Default::default(),
+            },
+            TransactionStatusV0::SqueezedOut { reason } => Self::SqueezedOut { reason },
+            TransactionStatusV0::Failed {
+                block_id,
+                time,
+                result,
+                receipts,
+            } => Self::Failed {
+                block_id,
+                time,
+                result,
+                receipts,
+                fee: Default::default(),
+            },
+        }
+    }
+}
+
+/// The key of the archived status sorted by the time of archiving.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArchivedTransactionStatusKey {
+    pub archived_at: Tai64,
+    pub tx_id: Bytes32,
+}
+
+impl ArchivedTransactionStatusKey {
+    pub fn new(archived_at: Tai64, tx_id: Bytes32) -> Self {
+        Self { archived_at, tx_id }
+    }
+}
+
+impl Encode<ArchivedTransactionStatusKey> for Manual<ArchivedTransactionStatusKey> {
+    type Encoder<'a> = [u8; ARCHIVED_KEY_SIZE];
+
+    fn encode(t: &ArchivedTransactionStatusKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; ARCHIVED_KEY_SIZE];
+        bytes[..Tai64::BYTE_SIZE].copy_from_slice(t.archived_at.to_bytes().as_ref());
+        bytes[Tai64::BYTE_SIZE..].copy_from_slice(t.tx_id.as_ref());
+        bytes
+    }
+}
+
+impl Decode<ArchivedTransactionStatusKey> for Manual<ArchivedTransactionStatusKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ArchivedTransactionStatusKey> {
+        if bytes.len() != ARCHIVED_KEY_SIZE {
+            return Err(anyhow::anyhow!("Unable to decode bytes"))
+        }
+        let (archived_at, tx_id) = bytes.split_at(Tai64::BYTE_SIZE);
+        let archived_at = Tai64::try_from(archived_at)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let tx_id = Bytes32::try_from(tx_id)?;
+        Ok(ArchivedTransactionStatusKey::new(archived_at, tx_id))
+    }
+}
+
 pub type TransactionIndex = u16;
 
 #[derive(Clone)]
@@ -194,6 +364,10 @@ mod test {
         bytes.into()
     }
 
+    fn generate_archived_key(rng: &mut impl rand::Rng) -> ArchivedTransactionStatusKey {
+        ArchivedTransactionStatusKey::new(Tai64(rng.gen()), rng.gen())
+    }
+
     fuel_core_storage::basic_storage_tests!(
         OwnedTransactions,
         [1u8; INDEX_SIZE].into(),
@@ -209,4 +383,23 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        ArchivedTransactionStatuses,
+        <ArchivedTransactionStatuses as Mappable>::Key::default(),
+        ArchivedTransactionStatus {
+            status: TransactionStatus::SqueezedOut {
+                reason: "reason".to_string(),
+            },
+            archived_at: Tai64::UNIX_EPOCH,
+        }
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        ArchivedTransactionStatusesByTime,
+        ArchivedTransactionStatusKey::new(Tai64::UNIX_EPOCH, Bytes32::default()),
+        (),
+        (),
+        generate_archived_key
+    );
 }
@@ -9,22 +9,31 @@ use async_graphql::{
     Request,
     ServerResult,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 /// The extension that adds the `ReadView` to the request context.
 /// It guarantees that the request works with the one view of the database,
 /// and external database modification cannot affect the result.
-pub(crate) struct ViewExtension;
+/// The view stops the iterations over the database after the `request_timeout`.
+pub(crate) struct ViewExtension {
+    request_timeout: Duration,
+}
 
 impl ViewExtension {
-    pub fn new() -> Self {
-        Self
+    pub fn new(request_timeout: Duration) -> Self {
+        Self { request_timeout }
     }
 }
 
 impl ExtensionFactory for ViewExtension {
     fn create(&self) -> Arc<dyn Extension> {
-        Arc::new(ViewExtension::new())
+        Arc::new(ViewExtension::new(self.request_timeout))
     }
 }
 
@@ -38,6 +47,10 @@ impl Extension for ViewExtension {
     ) -> ServerResult<Request> {
         let database: &ReadDatabase = ctx.data_unchecked();
         let view = database.view();
+        let view = match Instant::now().checked_add(self.request_timeout) {
+            Some(deadline) => view.with_deadline(deadline),
+            None => view,
+        };
         let request = request.data(view);
         next.run(ctx, request).await
     }
@@ -7,7 +7,17 @@ use crate::{
         },
         metadata::MetadataTable,
     },
-    fuel_core_graphql_api::ports,
+    fuel_core_graphql_api::{
+        ports,
+        storage::{
+            da_compression::VersionedCompressedBlock,
+            receipts::{
+                ReceiptPointer,
+                ReceiptSelector,
+            },
+        },
+    },
+    service::disk_usage::WritesPause,
 };
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
@@ -22,6 +32,7 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageAsMut,
 };
+use fuel_core_txpool::service::SqueezedOutTransaction;
 use fuel_core_types::{
     blockchain::block::Block,
     fuel_tx::{
@@ -44,28 +55,51 @@ use fuel_core_types::{
     },
     services::{
         block_importer::{
+            Finality,
             ImportResult,
             SharedImportResult,
         },
         executor::TransactionExecutionStatus,
-        txpool::from_executor_to_status,
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus,
+        },
     },
+    tai64::Tai64,
 };
 use futures::{
     FutureExt,
     StreamExt,
 };
+use std::time::Duration;
+
+/// The maximum number of blocks pruned while processing one imported block.
+/// It prevents the stall of the worker when the pruning is enabled on the long chain.
+const MAX_PRUNED_BLOCKS_PER_BLOCK: u32 = 10;
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
-pub struct Task<D> {
+pub struct Task<D, B> {
     block_importer: BoxStream<SharedImportResult>,
+    squeezed_out_txs: BoxStream<SqueezedOutTransaction>,
     database: D,
+    block_bodies: B,
+    /// How long the archived transaction statuses are kept.
+    archive_retention: Duration,
+    /// The number of the latest blocks with the transactions and receipts kept.
+    /// The bodies of the older blocks are pruned. Nothing is pruned if not set.
+    block_body_retention: Option<u32>,
+    /// The rule of the finality. The bodies of the blocks that are not final
+    /// are never pruned.
+    finality: Finality,
+    /// The non-critical writes are skipped while the disk space is low.
+    writes_pause: WritesPause,
 }
 
-impl<D> Task<D>
+impl<D, B> Task<D, B>
 where
     D: ports::worker::OffChainDatabase,
+    B: ports::worker::BlockBodies,
 {
     fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
         // TODO: Implement the creation of indexes for the messages and coins.
@@ -78,6 +112,31 @@ where
 
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(block, transaction.as_mut())?;
+
+        // save the position of each receipt for the contracts that produced it
+        self.index_receipts_for_block(&result, transaction.as_mut())?;
+
+        // save the representation of the block posted to the data availability layer
+        if !self.writes_pause.is_paused() {
+            let compressed_block = VersionedCompressedBlock::compress(block)?;
+            transaction
+                .as_mut()
+                .store_da_compressed_block(block.header().height(), &compressed_block)?;
+        }
+
+        // remove the archived statuses older than the retention period
+        let archived_before = Tai64(
+            Tai64::now()
+                .0
+                .saturating_sub(self.archive_retention.as_secs()),
+        );
+        transaction
+            .as_mut()
+            .prune_archived_tx_statuses(archived_before)?;
+
+        // remove the transactions and receipts of the blocks older than the retention
+        self.prune_block_bodies(*block.header().height(), transaction.as_mut())?;
+
         let total_tx_count = transaction
             .as_mut()
             .increase_tx_count(block.transactions().len() as u64)
@@ -105,6 +164,64 @@ where
         Ok(())
     }
 
+    /// Archive the status of the transaction squeezed out of the tx pool, so it is
+    /// available after the transaction is removed from the tx pool.
+    fn archive_squeezed_out_tx(
+        &mut self,
+        squeezed_out: SqueezedOutTransaction,
+    ) -> anyhow::Result<()> {
+        if self.writes_pause.is_paused() {
+            return Ok(())
+        }
+        let SqueezedOutTransaction { tx_id, reason } = squeezed_out;
+        let mut transaction = self.database.transaction();
+        transaction.as_mut().archive_tx_status(
+            &tx_id,
+            TransactionStatus::SqueezedOut { reason },
+            Tai64::now(),
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Prunes the transactions and receipts of the blocks out of the retention.
+    /// The headers of the blocks and the `MessageOut` receipts are kept,
+    /// so the node still serves the message proofs for the pruned blocks.
+    fn prune_block_bodies(
+        &mut self,
+        current_height: BlockHeight,
+        db: &mut D,
+    ) -> anyhow::Result<()> {
+        let Some(retention) = self.block_body_retention else {
+            return Ok(())
+        };
+        let Some(prune_until) = u32::from(current_height).checked_sub(retention) else {
+            return Ok(())
+        };
+        let Some(finalized) = self.finality.finalized_height(current_height) else {
+            return Ok(())
+        };
+        let prune_until = prune_until.min(u32::from(finalized));
+        let first = match db.pruned_block_bodies_height()? {
+            Some(pruned) => u32::from(pruned).saturating_add(1),
+            None => 0,
+        };
+        let end = prune_until
+            .saturating_add(1)
+            .min(first.saturating_add(MAX_PRUNED_BLOCKS_PER_BLOCK));
+
+        for height in first..end {
+            let height = BlockHeight::from(height);
+            // The removal of the body is idempotent, so it is safe to repeat it
+            // if the off-chain database is not committed.
+            for tx_id in self.block_bodies.remove_block_body(&height)? {
+                db.prune_tx_receipts(&tx_id)?;
+            }
+            db.set_pruned_block_bodies_height(&height)?;
+        }
+        Ok(())
+    }
+
     /// Associate all transactions within a block to their respective UTXO owners
     fn index_tx_owners_for_block(
         &self,
@@ -185,6 +302,36 @@ where
         Ok(())
     }
 
+    /// Index the position of receipts by the contract and the receipt selector
+    fn index_receipts_for_block(
+        &self,
+        import_result: &ImportResult,
+        db: &mut D,
+    ) -> anyhow::Result<()> {
+        let block_height = *import_result.sealed_block.entity.header().height();
+        for (tx_idx, TransactionExecutionStatus { id, result }) in
+            import_result.tx_status.iter().enumerate()
+        {
+            let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+            })?;
+            for (receipt_idx, receipt) in result.receipts().iter().enumerate() {
+                let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The transaction has more than `u16::MAX` receipts, {}",
+                        e
+                    )
+                })?;
+                let pointer = ReceiptPointer::new(block_height, tx_idx, receipt_idx);
+                let selector = ReceiptSelector::of(receipt);
+                for contract_id in ReceiptSelector::contracts(receipt) {
+                    db.record_contract_receipt(&contract_id, selector, pointer, id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn persist_transaction_status(
         &self,
         import_result: &ImportResult,
@@ -209,9 +356,10 @@ where
 }
 
 #[async_trait::async_trait]
-impl<D> RunnableService for Task<D>
+impl<D, B> RunnableService for Task<D, B>
 where
     D: ports::worker::OffChainDatabase,
+    B: ports::worker::BlockBodies,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
     type SharedData = EmptyShared;
@@ -242,9 +390,10 @@ where
 }
 
 #[async_trait::async_trait]
-impl<D> RunnableTask for Task<D>
+impl<D, B> RunnableTask for Task<D, B>
 where
     D: ports::worker::OffChainDatabase,
+    B: ports::worker::BlockBodies,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
@@ -264,6 +413,11 @@ where
                     should_continue = false
                 }
             }
+
+            Some(squeezed_out) = self.squeezed_out_txs.next() => {
+                self.archive_squeezed_out_tx(squeezed_out)?;
+                should_continue = true
+            }
         }
         Ok(should_continue)
     }
@@ -283,14 +437,33 @@ where
     }
 }
 
-pub fn new_service<I, D>(block_importer: I, database: D) -> ServiceRunner<Task<D>>
+#[allow(clippy::too_many_arguments)]
+pub fn new_service<I, TxPool, D, B>(
+    block_importer: I,
+    txpool: TxPool,
+    database: D,
+    block_bodies: B,
+    archive_retention: Duration,
+    block_body_retention: Option<u32>,
+    finality: Finality,
+    writes_pause: WritesPause,
+) -> ServiceRunner<Task<D, B>>
 where
     I: ports::worker::BlockImporter,
+    TxPool: ports::worker::TxPool,
     D: ports::worker::OffChainDatabase,
+    B: ports::worker::BlockBodies,
 {
     let block_importer = block_importer.block_events();
+    let squeezed_out_txs = txpool.squeezed_out_events();
     ServiceRunner::new(Task {
         block_importer,
+        squeezed_out_txs,
         database,
+        block_bodies,
+        archive_retention,
+        block_body_retention,
+        finality,
+        writes_pause,
     })
 }
@@ -3,7 +3,10 @@
 use crate::{
     chain_config::ChainConfig,
     database::Database,
-    p2p::Multiaddr,
+    p2p::{
+        Multiaddr,
+        PeerId,
+    },
     service::{
         genesis::execute_and_commit_genesis_block,
         Config,
@@ -13,6 +16,7 @@ use crate::{
 };
 use fuel_core_p2p::{
     codecs::postcard::PostcardCodec,
+    network_conditions::NetworkConditions,
     network_service::FuelP2PService,
     p2p_service::FuelP2PEvent,
     service::to_message_acceptance,
@@ -133,7 +137,9 @@ impl Bootstrap {
         let codec = PostcardCodec::new(bootstrap_config.max_block_size);
         let (sender, _) =
             broadcast::channel(bootstrap_config.reserved_nodes.len().saturating_add(1));
-        let mut bootstrap = FuelP2PService::new(sender, bootstrap_config, codec);
+        let chain_id = node_config.chain_conf.consensus_parameters.chain_id;
+        let mut bootstrap =
+            FuelP2PService::new(chain_id, sender, bootstrap_config, codec);
         bootstrap.start().await.unwrap();
 
         let listeners = bootstrap.multiaddrs();
@@ -262,7 +268,10 @@ pub async fn make_nodes(
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
                         match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
+                            crate::chain_config::ConsensusConfig::PoA {
+                                signing_key,
+                                ..
+                            } => {
                                 *signing_key = pub_key;
                             }
                         }
@@ -311,7 +320,7 @@ pub async fn make_nodes(
             node_config.utxo_validation = utxo_validation;
             let pub_key = secret.public_key();
             match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
+                crate::chain_config::ConsensusConfig::PoA { signing_key, .. } => {
                     *signing_key = Input::owner(&pub_key);
                 }
             }
@@ -355,7 +364,7 @@ pub async fn make_nodes(
                 }
             }
             match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
+                crate::chain_config::ConsensusConfig::PoA { signing_key, .. } => {
                     *signing_key = pub_key;
                 }
             }
@@ -502,6 +511,46 @@ impl Node {
     pub async fn shutdown(&mut self) {
         self.node.stop_and_await().await.unwrap();
     }
+
+    /// The id of the node in the p2p network.
+    pub fn peer_id(&self) -> PeerId {
+        self.config
+            .p2p
+            .as_ref()
+            .expect("The node should have the p2p config")
+            .keypair
+            .public()
+            .to_peer_id()
+    }
+
+    /// The simulated conditions of the network seen by the node.
+    /// They are reset when the node is restarted.
+    pub fn network_conditions(&self) -> &NetworkConditions {
+        self.node
+            .shared
+            .network
+            .as_ref()
+            .expect("The node should run the p2p service")
+            .network_conditions()
+    }
+}
+
+/// Splits the network between the nodes of the `left` and the `right` groups,
+/// so they stop hearing from each other until they are healed with [`heal`].
+pub fn partition(left: &[&Node], right: &[&Node]) {
+    for (from, to) in [(left, right), (right, left)] {
+        let peers: Vec<_> = to.iter().map(|node| node.peer_id()).collect();
+        for node in from {
+            node.network_conditions().partition(peers.iter().copied());
+        }
+    }
+}
+
+/// Removes the partitions of the network between the `nodes`.
+pub fn heal(nodes: &[&Node]) {
+    for node in nodes {
+        node.network_conditions().heal();
+    }
 }
 
 fn not_found_txs<'iter>(
@@ -3,7 +3,9 @@ mod block;
 mod chain;
 mod coin;
 mod contract;
+mod gas_price;
 mod message;
+mod state_diff;
 mod subscriptions;
 mod tx;
 
@@ -13,6 +15,8 @@ pub use block::*;
 pub use chain::*;
 pub use coin::*;
 pub use contract::*;
+pub use gas_price::*;
 pub use message::*;
+pub use state_diff::*;
 pub(crate) use subscriptions::*;
 pub use tx::*;
@@ -1,8 +1,6 @@
-use crate::fuel_core_graphql_api::database::ReadView;
-use asset_query::{
-    AssetQuery,
-    AssetSpendTarget,
-    AssetsQuery,
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    ports::OnChainDatabase,
 };
 use fuel_core_storage::{
     iter::{
@@ -19,11 +17,6 @@ use fuel_core_types::{
     },
     services::graphql_api::AddressBalance,
 };
-use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-};
 
 pub mod asset_query;
 
@@ -50,23 +43,7 @@ impl BalanceQueryData for ReadView {
         asset_id: AssetId,
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, usize::MAX),
-            &base_asset_id,
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
-
-            // Increase the balance
-            balance = balance.saturating_add(amount);
-
-            Ok(balance)
-        })?;
+        let amount = self.owned_balance(&owner, &asset_id, &base_asset_id)?;
 
         Ok(AddressBalance {
             owner,
@@ -81,39 +58,10 @@ impl BalanceQueryData for ReadView {
         direction: IterDirection,
         base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
-        let mut amounts_per_asset = HashMap::new();
-        let mut errors = vec![];
-
-        for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
-            match coin {
-                Ok(coin) => {
-                    let amount: &mut u64 = amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default();
-                    *amount = amount.saturating_add(coin.amount());
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
-            }
-        }
-
-        let mut balances = amounts_per_asset
-            .into_iter()
-            .map(|(asset_id, amount)| AddressBalance {
-                owner,
-                amount,
-                asset_id,
-            })
-            .collect_vec();
-
-        balances.sort_by(|l, r| {
-            if l.asset_id < r.asset_id {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
+        let mut balances = match self.owned_balances(&owner, &base_asset_id) {
+            Ok(balances) => balances,
+            Err(err) => return core::iter::once(Err(err)).into_boxed(),
+        };
 
         if direction == IterDirection::Reverse {
             balances.reverse();
@@ -121,8 +69,13 @@ impl BalanceQueryData for ReadView {
 
         balances
             .into_iter()
-            .map(Ok)
-            .chain(errors.into_iter().map(Err))
+            .map(move |(asset_id, amount)| {
+                Ok(AddressBalance {
+                    owner,
+                    amount,
+                    asset_id,
+                })
+            })
             .into_boxed()
     }
 }
@@ -19,7 +19,10 @@ use fuel_core_types::{
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageStats,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -41,6 +44,11 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_stats(
+        &self,
+        contract_id: ContractId,
+    ) -> StorageResult<ContractStorageStats>;
 }
 
 impl<D: OnChainDatabase + ?Sized> ContractQueryData for D {
@@ -99,4 +107,11 @@ impl<D: OnChainDatabase + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_storage_stats(
+        &self,
+        contract_id: ContractId,
+    ) -> StorageResult<ContractStorageStats> {
+        self.contract_storage_stats(&contract_id)
+    }
 }
@@ -0,0 +1,208 @@
+use crate::fuel_core_graphql_api::ports::OnChainDatabase;
+use fuel_core_storage::{
+    iter::IterDirection,
+    not_found,
+    tables::Transactions,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_tx::{
+    field::ScriptGasLimit,
+    Chargeable,
+    Transaction,
+    Word,
+};
+use itertools::Itertools;
+
+/// The number of the latest blocks analyzed by the gas price estimation.
+pub const ANALYZED_BLOCKS: u32 = 100;
+
+/// The block is considered full if its transactions use this percent of the block gas limit.
+const FULL_BLOCK_PERCENT: Word = 90;
+
+/// The percent of the past inclusion windows in which the estimated gas price
+/// would have been enough for the inclusion.
+const INCLUSION_PERCENTILE: usize = 90;
+
+/// The inclusion of the transactions into one block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInclusion {
+    /// The lowest gas price of the included transactions.
+    /// `None` if the block doesn't have transactions.
+    pub min_gas_price: Option<Word>,
+    /// The gas limit of the included scripts.
+    pub gas: Word,
+}
+
+pub trait GasPriceQueryData: Send + Sync {
+    /// Returns the inclusion of the transactions into the `blocks` latest blocks,
+    /// starting from the oldest one.
+    fn recent_inclusions(&self, blocks: u32) -> StorageResult<Vec<BlockInclusion>>;
+}
+
+impl<D: OnChainDatabase + ?Sized> GasPriceQueryData for D {
+    fn recent_inclusions(&self, blocks: u32) -> StorageResult<Vec<BlockInclusion>> {
+        let mut inclusions = self
+            .blocks(None, IterDirection::Reverse)
+            .take(blocks as usize)
+            .map(|block| {
+                let block = block?;
+                let mut inclusion = BlockInclusion {
+                    min_gas_price: None,
+                    gas: 0,
+                };
+                for tx_id in block.transactions() {
+                    let tx = self
+                        .storage::<Transactions>()
+                        .get(tx_id)?
+                        .ok_or(not_found!(Transactions))?;
+                    let (price, gas) = match tx.as_ref() {
+                        Transaction::Script(script) => {
+                            (script.price(), *script.script_gas_limit())
+                        }
+                        Transaction::Create(create) => (create.price(), 0),
+                        Transaction::Mint(_) => continue,
+                    };
+                    inclusion.min_gas_price = Some(
+                        inclusion
+                            .min_gas_price
+                            .map_or(price, |min_price| min_price.min(price)),
+                    );
+                    inclusion.gas = inclusion.gas.saturating_add(gas);
+                }
+                Ok(inclusion)
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        inclusions.reverse();
+        Ok(inclusions)
+    }
+}
+
+/// Estimates the gas price that is enough for the inclusion within `horizon` blocks.
+///
+/// The history part is the price that was enough for the inclusion within any `horizon`
+/// consecutive blocks of the `history` in most cases. The blocks that are not full
+/// accept the `min_gas_price`. The pool part is the price that outbids the `pending`
+/// transactions squeezed out of the next `horizon` blocks by the more priced ones.
+/// The `pending` contains the gas price and the max gas of the pending transactions.
+pub fn estimate_gas_price(
+    history: &[BlockInclusion],
+    pending: &[(Word, Word)],
+    horizon: u32,
+    min_gas_price: Word,
+    block_gas_limit: Word,
+) -> Word {
+    let horizon = (horizon as usize).max(1);
+
+    let full_block_gas = block_gas_limit.saturating_mul(FULL_BLOCK_PERCENT) / 100;
+    let clearing_prices = history
+        .iter()
+        .map(|block| match block.min_gas_price {
+            Some(price) if block.gas >= full_block_gas => price,
+            _ => min_gas_price,
+        })
+        .collect_vec();
+
+    let mut window_prices = clearing_prices
+        .windows(horizon.min(clearing_prices.len()).max(1))
+        .filter_map(|window| window.iter().min().copied())
+        .collect_vec();
+    window_prices.sort_unstable();
+    let history_price = window_prices
+        .get(window_prices.len().saturating_mul(INCLUSION_PERCENTILE) / 100)
+        .or(window_prices.last())
+        .copied()
+        .unwrap_or(min_gas_price);
+
+    let capacity = block_gas_limit.saturating_mul(horizon as Word);
+    let mut gas = 0u64;
+    let pool_price = pending
+        .iter()
+        .sorted_by(|a, b| b.0.cmp(&a.0))
+        .find(|(_, max_gas)| {
+            gas = gas.saturating_add(*max_gas);
+            gas > capacity
+        })
+        .map(|(price, _)| price.saturating_add(1))
+        .unwrap_or(min_gas_price);
+
+    min_gas_price.max(history_price).max(pool_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCK_GAS_LIMIT: Word = 1000;
+
+    fn full(price: Word) -> BlockInclusion {
+        BlockInclusion {
+            min_gas_price: Some(price),
+            gas: BLOCK_GAS_LIMIT,
+        }
+    }
+
+    fn empty() -> BlockInclusion {
+        BlockInclusion {
+            min_gas_price: None,
+            gas: 0,
+        }
+    }
+
+    #[test]
+    fn quiet_chain_recommends_min_gas_price() {
+        let history = vec![empty(); 10];
+
+        let price = estimate_gas_price(&history, &[], 1, 5, BLOCK_GAS_LIMIT);
+
+        assert_eq!(price, 5);
+    }
+
+    #[test]
+    fn blocks_that_are_not_full_accept_min_gas_price() {
+        let history = vec![
+            BlockInclusion {
+                min_gas_price: Some(100),
+                gas: 10,
+            };
+            10
+        ];
+
+        let price = estimate_gas_price(&history, &[], 1, 5, BLOCK_GAS_LIMIT);
+
+        assert_eq!(price, 5);
+    }
+
+    #[test]
+    fn full_blocks_require_their_clearing_price() {
+        let history = vec![full(50); 10];
+
+        let price = estimate_gas_price(&history, &[], 1, 5, BLOCK_GAS_LIMIT);
+
+        assert_eq!(price, 50);
+    }
+
+    #[test]
+    fn longer_horizon_recommends_lower_price() {
+        let history = (0..20)
+            .map(|i| if i % 2 == 0 { full(100) } else { full(10) })
+            .collect_vec();
+
+        let next_block = estimate_gas_price(&history, &[], 1, 5, BLOCK_GAS_LIMIT);
+        let two_blocks = estimate_gas_price(&history, &[], 2, 5, BLOCK_GAS_LIMIT);
+
+        assert_eq!(next_block, 100);
+        assert_eq!(two_blocks, 10);
+    }
+
+    #[test]
+    fn congested_pool_requires_outbidding_squeezed_out_transactions() {
+        let pending = vec![(30, 600), (20, 600), (10, 600)];
+
+        let next_block = estimate_gas_price(&[], &pending, 1, 5, BLOCK_GAS_LIMIT);
+        let two_blocks = estimate_gas_price(&[], &pending, 2, 5, BLOCK_GAS_LIMIT);
+
+        assert_eq!(next_block, 21);
+        assert_eq!(two_blocks, 5);
+    }
+}
@@ -184,6 +184,7 @@ async fn can_build_message_proof() {
                 time: Tai64::UNIX_EPOCH,
                 result: None,
                 receipts: vec![],
+                fee: Default::default(),
             })
         });
 
@@ -0,0 +1,27 @@
+use crate::fuel_core_graphql_api::ports::OnChainDatabase;
+use fuel_core_storage::{
+    not_found,
+    tables::StateDiffs,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::executor::StateDiff,
+};
+
+pub trait StateDiffQueryData: Send + Sync {
+    fn state_diff(&self, height: &BlockHeight) -> StorageResult<StateDiff>;
+}
+
+impl<D: OnChainDatabase + ?Sized> StateDiffQueryData for D {
+    fn state_diff(&self, height: &BlockHeight) -> StorageResult<StateDiff> {
+        let state_diff = self
+            .storage::<StateDiffs>()
+            .get(height)?
+            .ok_or(not_found!(StateDiffs))?
+            .into_owned();
+
+        Ok(state_diff)
+    }
+}
@@ -56,6 +56,7 @@ fn success() -> TransactionStatus {
         time: Tai64(0),
         result: None,
         receipts: vec![],
+        fee: Default::default(),
     }
 }
 
@@ -66,6 +67,7 @@ fn failed() -> TransactionStatus {
         time: Tai64(0),
         result: None,
         receipts: vec![],
+        fee: Default::default(),
     }
 }
 
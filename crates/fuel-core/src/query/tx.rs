@@ -1,6 +1,12 @@
-use crate::fuel_core_graphql_api::ports::{
-    OffChainDatabase,
-    OnChainDatabase,
+use crate::fuel_core_graphql_api::{
+    ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    storage::receipts::{
+        ReceiptPointer,
+        ReceiptSelector,
+    },
 };
 use fuel_core_storage::{
     iter::{
@@ -20,7 +26,11 @@ use fuel_core_types::{
         Transaction,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ContractId,
+    },
     services::txpool::TransactionStatus,
 };
 
@@ -63,6 +73,14 @@ pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, Transaction)>>;
+
+    fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, TxId, Receipt)>>;
 }
 
 impl<D> TransactionQueryData for D
@@ -82,6 +100,7 @@ where
         self.owned_transactions_ids(owner, start, direction)
             .map(|result| {
                 result.and_then(|(tx_pointer, tx_id)| {
+                    ensure_body_not_pruned(self, tx_pointer.block_height())?;
                     let tx = self.transaction(&tx_id)?;
 
                     Ok((tx_pointer, tx))
@@ -89,4 +108,43 @@ where
             })
             .into_boxed()
     }
+
+    fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, TxId, Receipt)>> {
+        self.contract_receipts_ids(contract_id, selector, start, direction)
+            .map(|result| {
+                result.and_then(|(pointer, tx_id)| {
+                    // Only `MessageOut` receipts of the pruned block are kept,
+                    // so the `receipt_idx` doesn't point to the receipt anymore.
+                    ensure_body_not_pruned(self, pointer.block_height)?;
+                    let receipt = self
+                        .receipts(&tx_id)?
+                        .into_iter()
+                        .nth(pointer.receipt_idx as usize)
+                        .ok_or(not_found!("Receipt"))?;
+
+                    Ok((pointer, tx_id, receipt))
+                })
+            })
+            .into_boxed()
+    }
+}
+
+/// Returns the error if the transactions and receipts of the block at the `height` are pruned.
+fn ensure_body_not_pruned<D>(database: &D, height: BlockHeight) -> StorageResult<()>
+where
+    D: OffChainDatabase + ?Sized,
+{
+    match database.pruned_block_bodies_height()? {
+        Some(pruned) if height <= pruned => Err(anyhow::anyhow!(
+            "The transactions and receipts of the block {height} are pruned"
+        )
+        .into()),
+        _ => Ok(()),
+    }
 }
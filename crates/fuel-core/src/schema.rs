@@ -1,3 +1,11 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::DatabaseBlocks,
+        Config,
+    },
+    schema::scalars::SnapshotCursor,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +15,7 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -17,6 +26,7 @@ use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use itertools::Itertools;
 
 pub mod balance;
@@ -24,10 +34,12 @@ pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod da_compressed;
 pub mod dap;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod state_diff;
 
 pub mod gas_price;
 pub mod scalars;
@@ -38,6 +50,7 @@ pub struct Query(
     dap::DapQuery,
     balance::BalanceQuery,
     block::BlockQuery,
+    da_compressed::DaCompressedBlockQuery,
     chain::ChainQuery,
     tx::TxQuery,
     health::HealthQuery,
@@ -48,13 +61,14 @@ pub struct Query(
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
+    state_diff::StateDiffQuery,
 );
 
 #[derive(MergedObject, Default)]
 pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, tx::ContractReceiptsSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -193,3 +207,151 @@ where
     )
     .await
 }
+
+/// Paginates the `entries` like [`query_pagination`], but the cursors also contain the
+/// height of the chain at the moment of the first page. The following pages use the
+/// same snapshot height, and `entries` must skip the entities created above it, so the
+/// new blocks don't shift the pages. The cursors of the snapshots older than the
+/// `cursor_expiry` of the [`Config`], or above the latest block, are rejected.
+async fn query_snapshot_pagination<F, Entries, SchemaKey, SchemaValue>(
+    ctx: &Context<'_>,
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    entries: F,
+) -> async_graphql::Result<
+    Connection<SnapshotCursor<SchemaKey>, SchemaValue, EmptyFields, EmptyFields>,
+>
+where
+    SchemaKey: CursorType + Clone + Eq + Send + Sync,
+    <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
+    SchemaValue: OutputType,
+    F: FnOnce(&Option<SchemaKey>, IterDirection, BlockHeight) -> StorageResult<Entries>,
+    Entries: Iterator<Item = StorageResult<(SchemaKey, SchemaValue)>>,
+{
+    let query: &ReadView = ctx.data_unchecked();
+    let config = ctx.data_unchecked::<Config>();
+    let latest = query.latest_height()?;
+    let snapshot = snapshot_height::<SchemaKey>(
+        after.as_deref(),
+        before.as_deref(),
+        latest,
+        config.cursor_expiry,
+    )?;
+
+    query_pagination(
+        after,
+        before,
+        first,
+        last,
+        |start: &Option<SnapshotCursor<SchemaKey>>, direction| {
+            let start = start.as_ref().map(|cursor| cursor.key.clone());
+            let entries = entries(&start, direction, snapshot)?.map(move |result| {
+                result.map(|(key, value)| (SnapshotCursor { snapshot, key }, value))
+            });
+            Ok(entries)
+        },
+    )
+    .await
+}
+
+/// Returns the snapshot height of the cursors, or the `latest` height for the first page.
+fn snapshot_height<SchemaKey>(
+    after: Option<&str>,
+    before: Option<&str>,
+    latest: BlockHeight,
+    cursor_expiry: u32,
+) -> anyhow::Result<BlockHeight>
+where
+    SchemaKey: CursorType,
+    <SchemaKey as CursorType>::Error: core::fmt::Display,
+{
+    let mut snapshots = after
+        .into_iter()
+        .chain(before)
+        .map(|cursor| {
+            SnapshotCursor::<SchemaKey>::decode_cursor(cursor)
+                .map(|cursor| cursor.snapshot)
+                .map_err(|e| anyhow!("Invalid cursor `{cursor}`: {e}"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    snapshots.dedup();
+
+    let snapshot = match snapshots.as_slice() {
+        [] => return Ok(latest),
+        [snapshot] => *snapshot,
+        _ => return Err(anyhow!("The cursors belong to different snapshots")),
+    };
+
+    if snapshot > latest {
+        return Err(anyhow!(
+            "The snapshot `{snapshot}` of the cursor is above the latest block `{latest}`"
+        ))
+    }
+    if (*latest).saturating_sub(*snapshot) > cursor_expiry {
+        return Err(anyhow!(
+            "The cursor of the snapshot `{snapshot}` has expired, \
+            restart the pagination from the first page"
+        ))
+    }
+
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::scalars::U32;
+
+    fn cursor(snapshot: u32, key: u32) -> String {
+        SnapshotCursor {
+            snapshot: snapshot.into(),
+            key: U32(key),
+        }
+        .encode_cursor()
+    }
+
+    #[test]
+    fn first_page_uses_latest_height() {
+        let snapshot = snapshot_height::<U32>(None, None, 10u32.into(), 5).unwrap();
+
+        assert_eq!(snapshot, 10u32.into());
+    }
+
+    #[test]
+    fn following_pages_use_snapshot_of_cursor() {
+        let after = cursor(7, 3);
+
+        let snapshot =
+            snapshot_height::<U32>(Some(&after), None, 10u32.into(), 5).unwrap();
+
+        assert_eq!(snapshot, 7u32.into());
+    }
+
+    #[test]
+    fn expired_cursor_is_rejected() {
+        let after = cursor(4, 3);
+
+        let result = snapshot_height::<U32>(Some(&after), None, 10u32.into(), 5);
+
+        assert!(result.unwrap_err().to_string().contains("expired"));
+    }
+
+    #[test]
+    fn cursors_of_different_snapshots_are_rejected() {
+        let after = cursor(7, 3);
+        let before = cursor(8, 5);
+
+        let result = snapshot_height::<U32>(Some(&after), Some(&before), 10u32.into(), 5);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn cursor_without_snapshot_is_rejected() {
+        let result = snapshot_height::<U32>(Some("3"), None, 10u32.into(), 5);
+
+        assert!(result.is_err());
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
         api_service::ConsensusModule,
         database::ReadView,
         ports::DatabaseBlocks,
+        pruned_error,
         Config as GraphQLConfig,
         IntoApiResult,
     },
@@ -19,7 +20,10 @@ use crate::{
     schema::{
         scalars::{
             BlockId,
+            ContractId,
+            HexString,
             Signature,
+            SnapshotCursor,
             U32,
             U64,
         },
@@ -48,8 +52,16 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        consensus::poa::{
+            PoABeacon as CorePoABeacon,
+            PoAConsensus as CorePoAConsensus,
+        },
         header::BlockHeader,
     },
+    fuel_tx::{
+        self,
+        field::InputContract,
+    },
     fuel_types,
     fuel_types::BlockHeight,
 };
@@ -83,6 +95,7 @@ pub struct Genesis {
 
 pub struct PoAConsensus {
     signature: Signature,
+    beacon: Option<CorePoABeacon>,
 }
 
 #[Object]
@@ -110,6 +123,10 @@ impl Block {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &ReadView = ctx.data_unchecked();
+        let height = self.0.header().height();
+        if query.is_body_pruned(height)? {
+            return Err(pruned_error(height))
+        }
         self.0
             .transactions()
             .iter()
@@ -119,6 +136,27 @@ impl Block {
             })
             .collect()
     }
+
+    /// The recipient of the fees of the block, recorded in its `Mint` transaction.
+    /// `null` if the fees of the block are not collected.
+    async fn coinbase_recipient(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ContractId>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = self.0.header().height();
+        if query.is_body_pruned(height)? {
+            return Err(pruned_error(height))
+        }
+        let Some(tx_id) = self.0.transactions().last() else {
+            return Ok(None)
+        };
+        let recipient = match query.transaction(tx_id)? {
+            fuel_tx::Transaction::Mint(mint) => mint.input_contract().contract_id,
+            _ => return Ok(None),
+        };
+        Ok((recipient != fuel_types::ContractId::zeroed()).then(|| recipient.into()))
+    }
 }
 
 #[Object]
@@ -181,6 +219,16 @@ impl PoAConsensus {
     async fn signature(&self) -> Signature {
         self.signature
     }
+
+    /// The random value of the randomness beacon of the block, if the block has it.
+    async fn beacon(&self) -> Option<Bytes32> {
+        self.beacon.as_ref().map(|beacon| beacon.value().into())
+    }
+
+    /// The signature of the block height that proves the beacon value.
+    async fn beacon_proof(&self) -> Option<Signature> {
+        self.beacon.as_ref().map(|beacon| beacon.proof.into())
+    }
 }
 
 #[derive(Default)]
@@ -223,13 +271,80 @@ impl BlockQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
+    ) -> async_graphql::Result<
+        Connection<SnapshotCursor<U32>, Block, EmptyFields, EmptyFields>,
+    > {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        crate::schema::query_snapshot_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction, snapshot| {
+                Ok(blocks_query(
+                    query,
+                    start.map(Into::into),
+                    direction,
+                    snapshot,
+                ))
+            },
+        )
         .await
     }
+
+    /// Verifies the block header received from another source against the consensus
+    /// of the chain known to the node.
+    async fn verify_block_header(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The block header serialized with `postcard`")]
+        header: HexString,
+        #[graphql(desc = "The PoA signature of the block header")] signature: Signature,
+    ) -> async_graphql::Result<HeaderVerification> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let header: BlockHeader = postcard::from_bytes(&header.0)
+            .map_err(|e| anyhow!("Invalid block header: {e}"))?;
+        let signature = fuel_core_types::fuel_vm::Signature::from_bytes(*signature.0);
+
+        Ok(HeaderVerification::verify(
+            &config.consensus,
+            &header,
+            signature,
+        ))
+    }
+}
+
+/// The result of the verification of the block header.
+#[derive(SimpleObject)]
+pub struct HeaderVerification {
+    /// The header is consistent and signed by the block producer of the chain.
+    pub valid: bool,
+    /// The canonical hash of the header. The block producer signs this hash.
+    pub id: BlockId,
+}
+
+impl HeaderVerification {
+    /// Verifies the `header` against the `consensus` of the chain. The header is valid
+    /// if its application hash is correct and the `signature` is made by the block producer.
+    pub(crate) fn verify(
+        consensus: &fuel_core_chain_config::ConsensusConfig,
+        header: &BlockHeader,
+        signature: fuel_core_types::fuel_vm::Signature,
+    ) -> Self {
+        let id = header.consensus().hash();
+        let valid = header.application_hash() == &header.application().hash()
+            && fuel_core_poa::verifier::verify_consensus(
+                consensus,
+                header,
+                &CorePoAConsensus::new(signature),
+            );
+        let id: fuel_types::Bytes32 = id.into();
+        HeaderVerification {
+            valid,
+            id: id.into(),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -256,11 +371,25 @@ impl HeaderQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<U32, Header, EmptyFields, EmptyFields>> {
+    ) -> async_graphql::Result<
+        Connection<SnapshotCursor<U32>, Header, EmptyFields, EmptyFields>,
+    > {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(blocks_query(query, start.map(Into::into), direction))
-        })
+        crate::schema::query_snapshot_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction, snapshot| {
+                Ok(blocks_query(
+                    query,
+                    start.map(Into::into),
+                    direction,
+                    snapshot,
+                ))
+            },
+        )
         .await
     }
 }
@@ -269,14 +398,27 @@ fn blocks_query<T>(
     query: &ReadView,
     height: Option<BlockHeight>,
     direction: IterDirection,
+    snapshot: BlockHeight,
 ) -> BoxedIter<StorageResult<(U32, T)>>
 where
     T: async_graphql::OutputType,
     T: From<CompressedBlock>,
 {
-    let blocks = query.compressed_blocks(height, direction).map(|result| {
-        result.map(|block| ((*block.header().height()).into(), block.into()))
-    });
+    // The blocks above the `snapshot` are not visible to the pagination.
+    let height = match direction {
+        IterDirection::Forward => height,
+        IterDirection::Reverse => height.or(Some(snapshot)),
+    };
+    let blocks = query
+        .compressed_blocks(height, direction)
+        .take_while(move |result| {
+            result
+                .as_ref()
+                .map_or(true, |block| *block.header().height() <= snapshot)
+        })
+        .map(|result| {
+            result.map(|block| ((*block.header().height()).into(), block.into()))
+        });
 
     blocks.into_boxed()
 }
@@ -303,6 +445,7 @@ impl BlockMutation {
         if !config.debug {
             return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
         }
+        config.ensure_writable("the block production")?;
 
         let start_time = start_timestamp.map(|timestamp| timestamp.0);
         let blocks_to_produce: u32 = blocks_to_produce.into();
@@ -354,7 +497,14 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::Genesis(genesis) => Ok(Consensus::Genesis(genesis.into())),
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
+                beacon: None,
             })),
+            CoreConsensus::PoAWithBeacon(poa, beacon) => {
+                Ok(Consensus::PoA(PoAConsensus {
+                    signature: poa.signature.into(),
+                    beacon: Some(beacon),
+                }))
+            }
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
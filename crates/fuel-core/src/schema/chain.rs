@@ -705,6 +705,21 @@ impl ChainInfo {
         height.0.into()
     }
 
+    /// The height of the latest final block, if any block is final.
+    async fn finalized_height(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<U32>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        let latest_height = query.latest_block_height()?;
+        Ok(config
+            .finality
+            .finalized_height(latest_height)
+            .map(|height| height.into()))
+    }
+
     async fn consensus_parameters(
         &self,
         ctx: &Context<'_>,
@@ -1,6 +1,8 @@
 use crate::{
     coins_query::{
-        random_improve,
+        consolidation_transaction,
+        select_coins,
+        SelectionStrategy,
         SpendQuery,
     },
     fuel_core_graphql_api::{
@@ -9,13 +11,18 @@ use crate::{
         IntoApiResult,
     },
     query::{
-        asset_query::AssetSpendTarget,
+        asset_query::{
+            AssetQuery,
+            AssetSpendTarget,
+        },
         CoinQueryData,
     },
     schema::scalars::{
         Address,
         AssetId,
+        HexString,
         Nonce,
+        SnapshotCursor,
         UtxoId,
         U32,
         U64,
@@ -37,6 +44,7 @@ use fuel_core_types::{
         },
     },
     fuel_tx,
+    fuel_types::canonical::Serialize,
 };
 use itertools::Itertools;
 
@@ -115,6 +123,28 @@ pub enum CoinType {
     MessageCoin(MessageCoin),
 }
 
+/// The algorithm used to select the coins to spend.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CoinSelectionStrategy {
+    /// Selects the biggest coins first. Minimizes the number of inputs.
+    LargestFirst,
+    /// Selects the coins that match the target exactly, to avoid the change output.
+    /// Falls back to the largest first if there is no exact match.
+    BranchAndBound,
+    /// Selects random coins and improves the result with the dust.
+    RandomImprove,
+}
+
+impl From<CoinSelectionStrategy> for SelectionStrategy {
+    fn from(strategy: CoinSelectionStrategy) -> Self {
+        match strategy {
+            CoinSelectionStrategy::LargestFirst => SelectionStrategy::LargestFirst,
+            CoinSelectionStrategy::BranchAndBound => SelectionStrategy::BranchAndBound,
+            CoinSelectionStrategy::RandomImprove => SelectionStrategy::RandomImprove,
+        }
+    }
+}
+
 #[derive(async_graphql::InputObject)]
 struct CoinFilterInput {
     /// Returns coins owned by the `owner`.
@@ -165,26 +195,41 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
+    ) -> async_graphql::Result<
+        Connection<SnapshotCursor<UtxoId>, Coin, EmptyFields, EmptyFields>,
+    > {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let owner: fuel_tx::Address = filter.owner.into();
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
+        crate::schema::query_snapshot_pagination(
+            ctx,
+            after,
+            before,
+            first,
+            last,
+            |start, direction, snapshot| {
+                let owner: fuel_tx::Address = filter.owner.into();
+                let coins = query
+                    .owned_coins(&owner, (*start).map(Into::into), direction)
+                    .filter_map(move |result| {
+                        if let Ok(coin) = &result {
+                            // The coins created above the `snapshot` are not visible
+                            // to the pagination.
+                            if coin.tx_pointer.block_height() > snapshot {
+                                return None
+                            }
+                            if let Some(filter_asset_id) = &filter.asset_id {
+                                if coin.asset_id != filter_asset_id.0 {
+                                    return None
+                                }
+                            }
                         }
-                    }
 
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+                        Some(result)
+                    })
+                    .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
+                Ok(coins)
+            },
+        )
         .await
     }
 
@@ -210,6 +255,9 @@ impl CoinQuery {
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "\
+            The algorithm used to select the coins. The random improve is used by default.")]
+        strategy: Option<CoinSelectionStrategy>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
@@ -242,7 +290,8 @@ impl CoinQuery {
 
         let query: &ReadView = ctx.data_unchecked();
 
-        let coins = random_improve(query, &spend_query)?
+        let strategy = strategy.map(Into::into).unwrap_or_default();
+        let coins = select_coins(query, &spend_query, strategy)?
             .into_iter()
             .map(|coins| {
                 coins
@@ -259,6 +308,36 @@ impl CoinQuery {
 
         Ok(coins)
     }
+
+    /// Returns the unsigned transaction that consolidates the smallest coins of the
+    /// `owner` with `asset_id` into one change output. The caller signs the transaction
+    /// and adds the payment of the fee if the asset is not the base asset.
+    async fn consolidation_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset of the consolidated coins.")] asset_id: AssetId,
+        #[graphql(desc = "\
+            The maximal number of the consolidated coins. It can't exceed the \
+            `max_inputs` of the transaction parameters.")]
+        max_inputs: Option<U32>,
+    ) -> async_graphql::Result<HexString> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let params = &config.consensus_parameters;
+
+        let max_inputs_allowed = params.tx_params().max_inputs as usize;
+        let max_inputs = max_inputs
+            .map(|max| (max.0 as usize).min(max_inputs_allowed))
+            .unwrap_or(max_inputs_allowed);
+
+        let target = AssetSpendTarget::new(asset_id.0, u64::MAX, max_inputs);
+        let query: &ReadView = ctx.data_unchecked();
+        let asset_query =
+            AssetQuery::new(&owner.0, &target, params.base_asset_id(), None, query);
+        let tx = consolidation_transaction(&asset_query)?;
+
+        Ok(HexString(tx.to_bytes()))
+    }
 }
 
 impl From<CoinModel> for Coin {
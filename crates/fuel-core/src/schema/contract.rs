@@ -55,6 +55,33 @@ impl Contract {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// The number and the size of the storage slots of the contract.
+    async fn storage_stats(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ContractStorageStats> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_storage_stats(self.0)
+            .map(ContractStorageStats)
+            .map_err(Into::into)
+    }
+}
+
+pub struct ContractStorageStats(graphql_api::ContractStorageStats);
+
+#[Object]
+impl ContractStorageStats {
+    /// The number of the storage slots.
+    async fn slots(&self) -> U64 {
+        self.0.slots.into()
+    }
+
+    /// The total size of the keys and the values of the storage slots in bytes.
+    async fn bytes(&self) -> U64 {
+        self.0.bytes.into()
+    }
 }
 
 #[derive(Default)]
@@ -0,0 +1,49 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        IntoApiResult,
+    },
+    schema::scalars::{
+        HexString,
+        U32,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+    SimpleObject,
+};
+
+#[derive(SimpleObject)]
+pub struct DaCompressedBlock {
+    /// The block header and transactions encoded with `postcard` and compressed
+    /// with the DEFLATE algorithm.
+    bytes: HexString,
+}
+
+impl From<Vec<u8>> for DaCompressedBlock {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes: HexString(bytes),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DaCompressedBlockQuery;
+
+#[Object]
+impl DaCompressedBlockQuery {
+    /// Returns the DA-compressed representation of the block at the `height`.
+    /// These are exactly the bytes posted to the data availability layer.
+    async fn da_compressed_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<DaCompressedBlock>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height: u32 = height.into();
+        query.da_compressed_block(&height.into()).into_api_result()
+    }
+}
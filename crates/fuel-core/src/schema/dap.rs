@@ -83,6 +83,10 @@ impl ConcreteStorage {
             .and_then(|vm| vm.registers().get(register).copied())
     }
 
+    pub fn registers(&self, id: &ID) -> Option<&[Word]> {
+        self.vm.get(id).map(|vm| vm.registers())
+    }
+
     pub fn memory(&self, id: &ID, start: usize, size: usize) -> Option<&[u8]> {
         let (end, overflow) = start.overflowing_add(size);
         if overflow || end as u64 > consts::VM_MAX_RAM {
@@ -241,6 +245,21 @@ impl DapQuery {
             .map(|val| val.into())
     }
 
+    /// Read the values of all registers.
+    async fn registers(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<Vec<U64>> {
+        require_debug(ctx)?;
+        ctx.data_unchecked::<GraphStorage>()
+            .lock()
+            .await
+            .registers(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))
+            .map(|registers| registers.iter().map(|val| (*val).into()).collect())
+    }
+
     /// Read read a range of memory bytes.
     async fn memory(
         &self,
@@ -373,6 +392,45 @@ impl DapMutation {
         Ok(true)
     }
 
+    /// Remove a breakpoint from a VM instance.
+    async fn remove_breakpoint(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+        breakpoint: gql_types::Breakpoint,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        trace!("Remove breakpoint for VM {:?}", id);
+
+        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
+        let vm = locked
+            .vm
+            .get_mut(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+
+        vm.remove_breakpoint(&breakpoint.into());
+        Ok(true)
+    }
+
+    /// Remove all breakpoints from a VM instance.
+    async fn clear_breakpoints(
+        &self,
+        ctx: &Context<'_>,
+        id: ID,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        trace!("Clear breakpoints for VM {:?}", id);
+
+        let mut locked = ctx.data_unchecked::<GraphStorage>().lock().await;
+        let vm = locked
+            .vm
+            .get_mut(&id)
+            .ok_or_else(|| async_graphql::Error::new("VM not found"))?;
+
+        vm.clear_breakpoints();
+        Ok(true)
+    }
+
     /// Run a single transaction in given session until it
     /// hits a breakpoint or completes.
     async fn start_tx(
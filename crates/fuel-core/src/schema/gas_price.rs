@@ -4,10 +4,16 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        api_service::TxPool,
         database::ReadView,
         Config as GraphQLConfig,
     },
-    query::BlockQueryData,
+    query::{
+        estimate_gas_price,
+        BlockQueryData,
+        GasPriceQueryData,
+        ANALYZED_BLOCKS,
+    },
 };
 use async_graphql::{
     Context,
@@ -40,14 +46,14 @@ impl LatestGasPriceQuery {
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<LatestGasPrice> {
-        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let txpool = ctx.data_unchecked::<TxPool>();
 
         let query: &ReadView = ctx.data_unchecked();
         let latest_block: Block<_> = query.latest_block()?;
         let block_height = u32::from(*latest_block.header().height());
 
         Ok(LatestGasPrice {
-            gas_price: config.min_gas_price.into(),
+            gas_price: txpool.min_gas_price().into(),
             block_height: block_height.into(),
         })
     }
@@ -69,6 +75,9 @@ pub struct EstimateGasPriceQuery {}
 
 #[Object]
 impl EstimateGasPriceQuery {
+    /// Estimates the gas price for the inclusion of the transaction within
+    /// the `block_horizon` blocks. The estimation is based on the gas prices
+    /// included into the recent blocks and on the competition in the `TxPool`.
     async fn estimate_gas_price(
         &self,
         ctx: &Context<'_>,
@@ -77,13 +86,25 @@ impl EstimateGasPriceQuery {
         )]
         block_horizon: Option<U32>,
     ) -> async_graphql::Result<EstimateGasPrice> {
-        // TODO: implement dynamic calculation based on block horizon
-        //   https://github.com/FuelLabs/fuel-core/issues/1653
-        let _ = block_horizon;
-
+        let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<GraphQLConfig>();
-        let gas_price = config.min_gas_price.into();
+        let query: &ReadView = ctx.data_unchecked();
+
+        let horizon = block_horizon
+            .map(|horizon| horizon.0)
+            .unwrap_or(1)
+            .clamp(1, ANALYZED_BLOCKS);
+        let history = query.recent_inclusions(ANALYZED_BLOCKS)?;
+        let gas_price = estimate_gas_price(
+            &history,
+            &txpool.pending_gas_prices(),
+            horizon,
+            txpool.min_gas_price(),
+            config.block_gas_limit,
+        );
 
-        Ok(EstimateGasPrice { gas_price })
+        Ok(EstimateGasPrice {
+            gas_price: gas_price.into(),
+        })
     }
 }
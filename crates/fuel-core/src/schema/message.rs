@@ -13,6 +13,7 @@ use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
         ports::DatabaseBlocks,
+        Config as GraphQLConfig,
     },
     graphql_api::IntoApiResult,
     query::MessageQueryData,
@@ -138,6 +139,15 @@ impl MessageQuery {
                 "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
             ))?,
         };
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let latest_height = query.latest_height()?;
+        if !config.finality.is_final(height, latest_height) {
+            return Err(anyhow!(
+                "The commit block {height} is not final yet, the latest final block is {:?}",
+                config.finality.finalized_height(latest_height)
+            )
+            .into())
+        }
 
         Ok(crate::query::message_proof(
             query,
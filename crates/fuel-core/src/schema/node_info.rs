@@ -2,11 +2,21 @@ use super::scalars::{
     U32,
     U64,
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::fuel_core_graphql_api::{
+    api_service::{
+        ConsensusModule,
+        P2pService,
+        StartTime,
+        TxPool,
+    },
+    Config as GraphQLConfig,
+};
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
+use fuel_core_types::services::p2p;
 use std::time::UNIX_EPOCH;
 
 pub struct NodeInfo {
@@ -16,6 +26,9 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    tx_pool_size: U64,
+    is_synced: Option<bool>,
+    uptime: U64,
 }
 
 #[Object]
@@ -44,11 +57,26 @@ impl NodeInfo {
         self.node_version.to_owned()
     }
 
+    /// The number of transactions in the `TxPool`.
+    async fn tx_pool_size(&self) -> U64 {
+        self.tx_pool_size
+    }
+
+    /// Whether the node is synced with the network. It is `null` if the block
+    /// production is disabled.
+    async fn is_synced(&self) -> Option<bool> {
+        self.is_synced
+    }
+
+    /// The number of seconds since the start of the node.
+    async fn uptime(&self) -> U64 {
+        self.uptime
+    }
+
     async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
-            let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
-                _ctx.data_unchecked();
+            let p2p: &P2pService = _ctx.data_unchecked();
             let peer_info = p2p.all_peer_info().await?;
             let peers = peer_info.into_iter().map(PeerInfo).collect();
             Ok(peers)
@@ -69,21 +97,84 @@ pub struct NodeQuery {}
 impl NodeQuery {
     async fn node_info(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeInfo> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let start_time = ctx.data_unchecked::<StartTime>();
 
         const VERSION: &str = env!("CARGO_PKG_VERSION");
 
         Ok(NodeInfo {
             utxo_validation: config.utxo_validation,
             vm_backtrace: config.vm_backtrace,
-            min_gas_price: config.min_gas_price.into(),
+            min_gas_price: txpool.min_gas_price().into(),
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            tx_pool_size: (txpool.pending_number() as u64).into(),
+            is_synced: consensus_module.is_synced(),
+            uptime: start_time.0.elapsed().as_secs().into(),
         })
     }
+
+    /// The progress of the synchronization with the network. It is `null` if
+    /// the node doesn't sync the blocks from the peers.
+    async fn sync_status(&self, ctx: &Context<'_>) -> Option<SyncStatus> {
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.sync_status().map(SyncStatus)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum SyncStage {
+    /// The node waits for the peers to report their heights.
+    Connecting,
+    /// The node checks the consensus of the headers of the next blocks.
+    HeaderSync,
+    /// The node downloads the transactions of the next blocks.
+    BlockDownload,
+    /// The node executes and commits the downloaded blocks.
+    Execution,
+    /// The node reached the best known height.
+    Synced,
+}
+
+struct SyncStatus(p2p::SyncStatus);
+
+#[Object]
+impl SyncStatus {
+    /// The current stage of the synchronization.
+    async fn stage(&self) -> SyncStage {
+        match self.0.stage {
+            p2p::SyncStage::Connecting => SyncStage::Connecting,
+            p2p::SyncStage::HeaderSync => SyncStage::HeaderSync,
+            p2p::SyncStage::BlockDownload => SyncStage::BlockDownload,
+            p2p::SyncStage::Execution => SyncStage::Execution,
+            p2p::SyncStage::Synced => SyncStage::Synced,
+        }
+    }
+
+    /// The height of the latest block committed by the node.
+    async fn local_height(&self) -> Option<U32> {
+        self.0.local_height.map(|height| (*height).into())
+    }
+
+    /// The highest height reported by the peers.
+    async fn best_known_height(&self) -> Option<U32> {
+        self.0.best_known_height.map(|height| (*height).into())
+    }
+
+    /// The number of blocks committed per second since the node fell behind.
+    async fn blocks_per_second(&self) -> f64 {
+        self.0.blocks_per_second
+    }
+
+    /// The estimated number of seconds until the node reaches the best known height.
+    async fn eta_seconds(&self) -> Option<U64> {
+        self.0.eta.map(|eta| eta.as_secs().into())
+    }
 }
 
-struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
+struct PeerInfo(p2p::PeerInfo);
 
 #[Object]
 impl PeerInfo {
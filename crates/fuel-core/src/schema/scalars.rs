@@ -11,6 +11,7 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     tai64::Tai64,
 };
+pub use receipt_pointer::ReceiptPointer;
 use std::{
     array::TryFromSliceError,
     convert::TryInto,
@@ -24,6 +25,7 @@ pub use tx_pointer::TxPointer;
 pub use utxo_id::UtxoId;
 
 pub mod message_id;
+pub mod receipt_pointer;
 pub mod tx_pointer;
 pub mod utxo_id;
 
@@ -156,6 +158,40 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The cursor that contains the height of the chain at the moment of the first page
+/// of the pagination. The following pages ignore the entities created above the
+/// `snapshot`, so the new blocks don't cause duplicates or gaps between pages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotCursor<Key> {
+    pub snapshot: BlockHeight,
+    pub key: Key,
+}
+
+impl<Key> CursorType for SnapshotCursor<Key>
+where
+    Key: CursorType,
+    Key::Error: Display,
+{
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (snapshot, key) = s
+            .split_once('@')
+            .ok_or("The cursor doesn't contain the snapshot height")?;
+
+        Ok(Self {
+            snapshot: u32::from_str(snapshot)
+                .map_err(|_| "Failed to decode the snapshot height")?
+                .into(),
+            key: Key::decode_cursor(key).map_err(|e| e.to_string())?,
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}@{}", self.snapshot, self.key.encode_cursor())
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -353,4 +389,14 @@ mod tests {
         let res = HexString::from_str(hex_data);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn receipt_pointer_roundtrip() {
+        let pointer = "0000002a00070010";
+        let parsed = ReceiptPointer::from_str(pointer).expect("parseable");
+        assert_eq!(*parsed.0.block_height, 42);
+        assert_eq!(parsed.0.tx_idx, 7);
+        assert_eq!(parsed.0.receipt_idx, 16);
+        assert_eq!(parsed.to_string(), pointer);
+    }
 }
@@ -0,0 +1,92 @@
+use crate::fuel_core_graphql_api::storage::receipts;
+use async_graphql::{
+    connection::CursorType,
+    InputValueError,
+    InputValueResult,
+    Scalar,
+    ScalarType,
+    Value,
+};
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+    },
+    str::FromStr,
+};
+
+/// The position of the receipt in the blockchain: the block height, the index of
+/// the transaction in the block and the index of the receipt in the transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReceiptPointer(pub(crate) receipts::ReceiptPointer);
+
+#[Scalar(name = "ReceiptPointer")]
+impl ScalarType for ReceiptPointer {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        if let Value::String(value) = &value {
+            ReceiptPointer::from_str(value.as_str()).map_err(Into::into)
+        } else {
+            Err(InputValueError::expected_type(value))
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl FromStr for ReceiptPointer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const ERR: &str = "Invalid receipt pointer";
+
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        if s.len() != 16 || !s.is_ascii() {
+            return Err(ERR.to_owned())
+        }
+        let block_height = u32::from_str_radix(&s[..8], 16).map_err(|_| ERR)?;
+        let tx_idx = u16::from_str_radix(&s[8..12], 16).map_err(|_| ERR)?;
+        let receipt_idx = u16::from_str_radix(&s[12..], 16).map_err(|_| ERR)?;
+
+        Ok(Self(receipts::ReceiptPointer::new(
+            block_height.into(),
+            tx_idx,
+            receipt_idx,
+        )))
+    }
+}
+
+impl Display for ReceiptPointer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "{:08x}{:04x}{:04x}",
+            *self.0.block_height, self.0.tx_idx, self.0.receipt_idx
+        );
+        s.fmt(f)
+    }
+}
+
+impl From<ReceiptPointer> for receipts::ReceiptPointer {
+    fn from(s: ReceiptPointer) -> Self {
+        s.0
+    }
+}
+
+impl From<receipts::ReceiptPointer> for ReceiptPointer {
+    fn from(pointer: receipts::ReceiptPointer) -> Self {
+        Self(pointer)
+    }
+}
+
+impl CursorType for ReceiptPointer {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+
+    fn encode_cursor(&self) -> String {
+        self.to_string()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        IntoApiResult,
+    },
+    query::StateDiffQueryData,
+    schema::{
+        coins::Coin,
+        scalars::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            UtxoId,
+            U32,
+            U64,
+        },
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::services::executor::{
+    ContractBalanceChange as ContractBalanceChangeModel,
+    ContractSlotChange as ContractSlotChangeModel,
+    StateDiff as StateDiffModel,
+};
+
+/// The changes made to the state by the block.
+pub struct StateDiff(pub(crate) StateDiffModel);
+
+#[Object]
+impl StateDiff {
+    /// Coins created by the block.
+    async fn created_coins(&self) -> Vec<Coin> {
+        self.0
+            .created_coins
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Coins spent by the block.
+    async fn spent_coins(&self) -> Vec<UtxoId> {
+        self.0.spent_coins.iter().copied().map(Into::into).collect()
+    }
+
+    /// The final values of the contracts' storage slots modified by the block.
+    async fn contract_slots(&self) -> Vec<ContractSlotChange> {
+        self.0
+            .contract_slots
+            .iter()
+            .cloned()
+            .map(ContractSlotChange)
+            .collect()
+    }
+
+    /// The final balances of the contracts' assets modified by the block.
+    async fn contract_balances(&self) -> Vec<ContractBalanceChange> {
+        self.0
+            .contract_balances
+            .iter()
+            .cloned()
+            .map(ContractBalanceChange)
+            .collect()
+    }
+}
+
+pub struct ContractSlotChange(ContractSlotChangeModel);
+
+#[Object]
+impl ContractSlotChange {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The new value of the slot. `null` if the slot was removed.
+    async fn value(&self) -> Option<Bytes32> {
+        self.0.value.map(Into::into)
+    }
+}
+
+pub struct ContractBalanceChange(ContractBalanceChangeModel);
+
+#[Object]
+impl ContractBalanceChange {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+}
+
+#[derive(Default)]
+pub struct StateDiffQuery;
+
+#[Object]
+impl StateDiffQuery {
+    /// Gets the changes made to the state by the block at `height`.
+    /// Returns `null` if the node doesn't record state diffs or the block is unknown.
+    async fn state_diff(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<StateDiff>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height: u32 = height.into();
+        query.state_diff(&height.into()).into_api_result()
+    }
+}
+
+impl From<StateDiffModel> for StateDiff {
+    fn from(state_diff: StateDiffModel) -> Self {
+        StateDiff(state_diff)
+    }
+}
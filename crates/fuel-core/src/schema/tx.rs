@@ -1,11 +1,20 @@
 use crate::{
     fuel_core_graphql_api::{
         api_service::{
+            BlockImporter,
             BlockProducer,
             TxPool,
         },
         database::ReadView,
-        ports::OffChainDatabase,
+        dry_run_limiter::{
+            ClientIp,
+            DryRunLimiter,
+        },
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
+        pruned_error,
         Config,
         IntoApiResult,
     },
@@ -18,12 +27,25 @@ use crate::{
     schema::{
         scalars::{
             Address,
+            ContractId,
             HexString,
+            ReceiptPointer,
+            SnapshotCursor,
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            U64,
+        },
+        tx::{
+            receipt::{
+                ContractReceipt,
+                ReceiptType,
+            },
+            types::{
+                PoolTransaction,
+                TransactionStatus,
+            },
         },
-        tx::types::TransactionStatus,
     },
 };
 use async_graphql::{
@@ -38,6 +60,7 @@ use async_graphql::{
 use fuel_core_storage::{
     iter::IterDirection,
     Error as StorageError,
+    IsNotFound,
     Result as StorageResult,
 };
 use fuel_core_txpool::{
@@ -46,6 +69,7 @@ use fuel_core_txpool::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        field::ScriptGasLimit,
         Cacheable,
         Transaction as FuelTx,
         UniqueIdentifier,
@@ -57,6 +81,7 @@ use fuel_core_types::{
         EstimatePredicates,
     },
     services::txpool,
+    tai64::Tai64,
 };
 use futures::{
     Stream,
@@ -65,6 +90,7 @@ use futures::{
 use itertools::Itertools;
 use std::{
     iter,
+    ops::Deref,
     sync::Arc,
 };
 use tokio_stream::StreamExt;
@@ -95,13 +121,90 @@ impl TxQuery {
         if let Some(transaction) = txpool.transaction(id) {
             Ok(Some(Transaction(transaction, id)))
         } else {
-            query
-                .transaction(&id)
+            let result = query.transaction(&id);
+            if result.is_not_found() {
+                // The body of the included transaction is missing only if it is pruned.
+                if let Ok(
+                    txpool::TransactionStatus::Success { block_id, .. }
+                    | txpool::TransactionStatus::Failed { block_id, .. },
+                ) = query.status(&id)
+                {
+                    let height = query.block_height(&block_id)?;
+                    if query.is_body_pruned(&height)? {
+                        return Err(pruned_error(&height))
+                    }
+                }
+            }
+            result
                 .map(|tx| Transaction::from_tx(id, tx))
                 .into_api_result()
         }
     }
 
+    /// Returns the status of the transaction. The statuses of the transactions removed
+    /// from the tx pool without the inclusion into the block are available during
+    /// the retention period of the node.
+    async fn transaction_status(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<TransactionStatus>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        types::get_tx_status(id.0, query, txpool).map_err(Into::into)
+    }
+
+    /// Returns the transactions pending in the `TxPool` in the order of their submission.
+    /// The reason of the removal of the transaction from the pool is available via
+    /// the `transactionStatus` query.
+    async fn pool_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, PoolTransaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransactionId>, direction| {
+                let mut txs = txpool.pending_transactions();
+                if direction == IterDirection::Reverse {
+                    txs.reverse();
+                }
+                let start = start.map(|id| id.0);
+                let txs = txs
+                    .into_iter()
+                    .skip_while(move |(info, _)| {
+                        start.is_some() && start != Some(info.tx().id())
+                    })
+                    .map(|(info, dependencies)| {
+                        let tx = info.tx();
+                        let id = tx.id();
+                        let submitted_at =
+                            Tai64::from_unix(info.submitted_time().as_secs() as i64);
+                        let tx = PoolTransaction {
+                            id,
+                            tx: tx.deref().into(),
+                            submitted_at,
+                            gas_price: tx.price(),
+                            dependencies,
+                        };
+                        Ok((id.into(), tx))
+                    });
+
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -110,18 +213,30 @@ impl TxQuery {
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<
-        Connection<SortedTxCursor, Transaction, EmptyFields, EmptyFields>,
+        Connection<SnapshotCursor<SortedTxCursor>, Transaction, EmptyFields, EmptyFields>,
     > {
         let query: &ReadView = ctx.data_unchecked();
-        crate::schema::query_pagination(
+        crate::schema::query_snapshot_pagination(
+            ctx,
             after,
             before,
             first,
             last,
-            |start: &Option<SortedTxCursor>, direction| {
+            |start: &Option<SortedTxCursor>, direction, snapshot| {
                 let start = *start;
                 let block_id = start.map(|sorted| sorted.block_height);
-                let all_block_ids = query.compressed_blocks(block_id, direction);
+                // The blocks above the `snapshot` are not visible to the pagination.
+                let block_id = match direction {
+                    IterDirection::Forward => block_id,
+                    IterDirection::Reverse => block_id.or(Some(snapshot)),
+                };
+                let all_block_ids = query
+                    .compressed_blocks(block_id, direction)
+                    .take_while(move |result| {
+                        result
+                            .as_ref()
+                            .map_or(true, |block| *block.header().height() <= snapshot)
+                    });
 
                 let all_txs = all_block_ids
                     .map(move |block| {
@@ -171,34 +286,89 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
-    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
-    {
+    ) -> async_graphql::Result<
+        Connection<SnapshotCursor<TxPointer>, Transaction, EmptyFields, EmptyFields>,
+    > {
         let query: &ReadView = ctx.data_unchecked();
         let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
 
-        crate::schema::query_pagination(
+        crate::schema::query_snapshot_pagination(
+            ctx,
             after,
             before,
             first,
             last,
-            |start: &Option<TxPointer>, direction| {
+            |start: &Option<TxPointer>, direction, snapshot| {
                 let start = (*start).map(Into::into);
-                let txs =
-                    query
-                        .owned_transactions(owner, start, direction)
-                        .map(|result| {
-                            result.map(|(cursor, tx)| {
-                                let tx_id = tx.id(&config.consensus_parameters.chain_id);
-                                (cursor.into(), Transaction::from_tx(tx_id, tx))
-                            })
-                        });
+                let txs = query
+                    .owned_transactions(owner, start, direction)
+                    .filter(move |result| {
+                        result
+                            .as_ref()
+                            .map_or(true, |(cursor, _)| cursor.block_height() <= snapshot)
+                    })
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
                 Ok(txs)
             },
         )
         .await
     }
 
+    /// Returns receipts of the `contract` with the `receipt_type` sorted by their
+    /// position in the blockchain. The receipt is produced by the contract, or, in the
+    /// case of `Call`, is a call of the contract. The `log_id` is the value of the `rB`
+    /// register of `Log` and `LogData` receipts. It is zero by default and is ignored
+    /// for other types.
+    #[allow(clippy::too_many_arguments)]
+    async fn receipts_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        receipt_type: ReceiptType,
+        log_id: Option<U64>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReceiptPointer, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        let query: &ReadView = ctx.data_unchecked();
+        let selector = receipt_type.selector(log_id.map(|id| id.0).unwrap_or_default());
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ReceiptPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let receipts = query
+                    .contract_receipts(contract.0, selector, start, direction)
+                    .map(|result| {
+                        result.map(|(pointer, tx_id, receipt)| {
+                            (
+                                pointer.into(),
+                                ContractReceipt {
+                                    pointer,
+                                    tx_id,
+                                    receipt,
+                                },
+                            )
+                        })
+                    });
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -257,8 +427,23 @@ impl TxMutation {
             transaction.precompute(&config.consensus_parameters.chain_id)?;
         }
 
-        let tx_statuses = block_producer
-            .dry_run_txs(transactions, None, utxo_validation)
+        // The gas used by the scripts is limited per client and in total.
+        let gas = transactions
+            .iter()
+            .map(|tx| match tx {
+                FuelTx::Script(script) => *script.script_gas_limit(),
+                _ => 0,
+            })
+            .fold(0u64, u64::saturating_add);
+        let client_ip = ctx.data_opt::<ClientIp>().map(|ip| ip.0);
+        let limiter = ctx.data_unchecked::<DryRunLimiter>();
+
+        let tx_statuses = limiter
+            .run(
+                client_ip,
+                gas,
+                block_producer.dry_run_txs(transactions, None, utxo_validation),
+            )
             .await?;
         let tx_statuses = tx_statuses
             .into_iter()
@@ -275,16 +460,18 @@ impl TxMutation {
         &self,
         ctx: &Context<'_>,
         tx: HexString,
+        #[graphql(desc = "\
+            The transaction submitted before this one. If the inputs of the transaction \
+            are not found, it waits for the `after` transaction to be included \
+            in the `TxPool` instead of the rejection.")]
+        after: Option<TransactionId>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
+        config.ensure_writable("transactions")?;
         let tx = FuelTx::from_bytes(&tx.0)?;
 
-        let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
-            .await
-            .into_iter()
-            .try_collect()?;
+        insert(txpool, Arc::new(tx.clone()), after).await?;
         let id = tx.id(&config.consensus_parameters.chain_id);
 
         let tx = Transaction(tx, id);
@@ -292,6 +479,46 @@ impl TxMutation {
     }
 }
 
+#[derive(Default)]
+pub struct ContractReceiptsSubscription;
+
+#[Subscription]
+impl ContractReceiptsSubscription {
+    /// Returns a stream of receipts of the `contract` from the newly imported blocks.
+    /// The receipt is produced by the contract, or, in the case of `Call`, is a call
+    /// of the contract. The stream returns `Log`, `LogData` and `Transfer` receipts
+    /// if `receipt_types` are not set. The `log_id` is the value of the `rB` register
+    /// of `Log` and `LogData` receipts; logs with any id are returned if it is not set.
+    ///
+    /// The receipts of the blocks imported before the subscription are not returned.
+    async fn contract_receipts<'a>(
+        &self,
+        ctx: &Context<'a>,
+        contract: ContractId,
+        receipt_types: Option<Vec<ReceiptType>>,
+        log_id: Option<U64>,
+    ) -> impl Stream<Item = ContractReceipt> + 'a {
+        let importer = ctx.data_unchecked::<BlockImporter>();
+        let receipt_types = receipt_types.unwrap_or_else(|| {
+            vec![
+                ReceiptType::Log,
+                ReceiptType::LogData,
+                ReceiptType::Transfer,
+            ]
+        });
+        let log_id = log_id.map(|id| id.0);
+
+        futures::StreamExt::flat_map(importer.block_events(), move |result| {
+            futures::stream::iter(ContractReceipt::of_block(
+                &result,
+                &contract.0,
+                &receipt_types,
+                log_id,
+            ))
+        })
+    }
+}
+
 #[derive(Default)]
 pub struct TxStatusSubscription;
 
@@ -338,20 +565,22 @@ impl TxStatusSubscription {
         &self,
         ctx: &Context<'a>,
         tx: HexString,
+        #[graphql(desc = "\
+            The transaction submitted before this one. If the inputs of the transaction \
+            are not found, it waits for the `after` transaction to be included \
+            in the `TxPool` instead of the rejection.")]
+        after: Option<TransactionId>,
     ) -> async_graphql::Result<
         impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
     > {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
+        config.ensure_writable("transactions")?;
         let tx = FuelTx::from_bytes(&tx.0)?;
         let tx_id = tx.id(&config.consensus_parameters.chain_id);
         let subscription = txpool.tx_update_subscribe(tx_id)?;
 
-        let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx)])
-            .await
-            .into_iter()
-            .try_collect()?;
+        insert(txpool, Arc::new(tx), after).await?;
 
         Ok(subscription
             .skip_while(|event| {
@@ -372,3 +601,20 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+/// Inserts the `tx` into the `TxPool`, after the `after` transaction if it is set.
+async fn insert(
+    txpool: &TxPool,
+    tx: Arc<FuelTx>,
+    after: Option<TransactionId>,
+) -> anyhow::Result<()> {
+    match after {
+        Some(after) => {
+            txpool.insert_after(tx, after.0).await?;
+        }
+        None => {
+            let _: Vec<_> = txpool.insert(vec![tx]).await.into_iter().try_collect()?;
+        }
+    }
+    Ok(())
+}
@@ -1,13 +1,18 @@
-use crate::schema::{
-    contract::Contract,
-    scalars::{
-        Address,
-        AssetId,
-        Bytes32,
-        ContractId,
-        HexString,
-        Nonce,
-        U64,
+use crate::{
+    fuel_core_graphql_api::storage::receipts,
+    schema::{
+        contract::Contract,
+        scalars::{
+            Address,
+            AssetId,
+            Bytes32,
+            ContractId,
+            HexString,
+            Nonce,
+            TransactionId,
+            U32,
+            U64,
+        },
     },
 };
 use async_graphql::{
@@ -17,6 +22,10 @@ use async_graphql::{
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
+    services::{
+        block_importer::ImportResult,
+        executor::TransactionExecutionStatus,
+    },
 };
 
 #[derive(
@@ -160,6 +169,88 @@ impl From<fuel_tx::Receipt> for Receipt {
     }
 }
 
+impl ReceiptType {
+    /// Returns the selector of receipts of this type. The `log_id` is used only
+    /// by `Log` and `LogData` receipts.
+    pub fn selector(self, log_id: Word) -> receipts::ReceiptSelector {
+        let log_id = match self {
+            ReceiptType::Log | ReceiptType::LogData => log_id,
+            _ => 0,
+        };
+        receipts::ReceiptSelector::new(self as u8, log_id)
+    }
+}
+
+/// The receipt of the contract found in the receipts index.
+pub struct ContractReceipt {
+    pub(crate) pointer: receipts::ReceiptPointer,
+    pub(crate) tx_id: fuel_tx::TxId,
+    pub(crate) receipt: fuel_tx::Receipt,
+}
+
+impl ContractReceipt {
+    /// Returns the receipts of the `contract` with one of the `receipt_types` from
+    /// the imported block. The `log_id` filters only `Log` and `LogData` receipts.
+    pub(crate) fn of_block(
+        import_result: &ImportResult,
+        contract: &fuel_tx::ContractId,
+        receipt_types: &[ReceiptType],
+        log_id: Option<Word>,
+    ) -> Vec<ContractReceipt> {
+        let block_height = *import_result.sealed_block.entity.header().height();
+        let mut contract_receipts = vec![];
+        for (tx_idx, TransactionExecutionStatus { id, result }) in
+            import_result.tx_status.iter().enumerate()
+        {
+            let Ok(tx_idx) = u16::try_from(tx_idx) else {
+                break
+            };
+            for (receipt_idx, receipt) in result.receipts().iter().enumerate() {
+                let Ok(receipt_idx) = u16::try_from(receipt_idx) else {
+                    break
+                };
+                let receipt_type = ReceiptType::from(receipt);
+                let log_matches = match receipt_type {
+                    ReceiptType::Log | ReceiptType::LogData => {
+                        log_id.is_none() || receipt.rb() == log_id
+                    }
+                    _ => true,
+                };
+                if receipt_types.contains(&receipt_type)
+                    && log_matches
+                    && receipts::ReceiptSelector::contracts(receipt).contains(contract)
+                {
+                    contract_receipts.push(ContractReceipt {
+                        pointer: receipts::ReceiptPointer::new(
+                            block_height,
+                            tx_idx,
+                            receipt_idx,
+                        ),
+                        tx_id: *id,
+                        receipt: receipt.clone(),
+                    });
+                }
+            }
+        }
+        contract_receipts
+    }
+}
+
+#[Object]
+impl ContractReceipt {
+    async fn block_height(&self) -> U32 {
+        (*self.pointer.block_height).into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.tx_id.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        self.receipt.clone().into()
+    }
+}
+
 #[cfg(feature = "test-helpers")]
 pub fn all_receipts() -> Vec<fuel_tx::Receipt> {
     use strum::IntoEnumIterator;
@@ -265,3 +356,20 @@ pub fn all_receipts() -> Vec<fuel_tx::Receipt> {
     }
     receipts
 }
+
+#[cfg(all(test, feature = "test-helpers"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receipt_type_selector_matches_index() {
+        for receipt in all_receipts() {
+            let log_id = receipt.rb().unwrap_or_default();
+
+            assert_eq!(
+                ReceiptType::from(&receipt).selector(log_id),
+                receipts::ReceiptSelector::of(&receipt)
+            );
+        }
+    }
+}
@@ -7,12 +7,17 @@ use crate::{
     fuel_core_graphql_api::{
         api_service::TxPool,
         database::ReadView,
-        ports::DatabaseBlocks,
+        ports::{
+            DatabaseBlocks,
+            OffChainDatabase,
+        },
+        pruned_error,
         Config,
         IntoApiResult,
     },
     query::{
         SimpleBlockData,
+        SimpleTransactionData,
         TransactionQueryData,
     },
     schema::{
@@ -21,6 +26,7 @@ use crate::{
         scalars::{
             AssetId,
             Bytes32,
+            ContractId,
             HexString,
             Salt,
             Tai64Timestamp,
@@ -75,6 +81,7 @@ use fuel_core_types::{
     fuel_vm::ProgramState as VmProgramState,
     services::{
         executor::{
+            self,
             TransactionExecutionResult,
             TransactionExecutionStatus,
         },
@@ -154,6 +161,7 @@ pub struct SuccessStatus {
     time: Tai64,
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeReport,
 }
 
 #[Object]
@@ -177,9 +185,18 @@ impl SuccessStatus {
         self.result.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = query.block_height(&self.block_id)?;
+        if query.is_body_pruned(&height)? {
+            return Err(pruned_error(&height))
+        }
         Ok(self.receipts.iter().map(Into::into).collect())
     }
+
+    async fn fee(&self) -> FeeReport {
+        FeeReport::new(self.fee, Some(self.block_id))
+    }
 }
 
 #[derive(Debug)]
@@ -189,6 +206,7 @@ pub struct FailureStatus {
     time: Tai64,
     state: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeReport,
 }
 
 #[Object]
@@ -216,9 +234,91 @@ impl FailureStatus {
         self.state.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height = query.block_height(&self.block_id)?;
+        if query.is_body_pruned(&height)? {
+            return Err(pruned_error(&height))
+        }
         Ok(self.receipts.iter().map(Into::into).collect())
     }
+
+    async fn fee(&self) -> FeeReport {
+        FeeReport::new(self.fee, Some(self.block_id))
+    }
+}
+
+/// The breakdown of the fee charged for the transaction. The `max_fee` is charged
+/// before the execution, and the unused part of it is refunded after.
+#[derive(Debug)]
+pub struct FeeReport {
+    report: executor::FeeReport,
+    /// The block that includes the transaction. `None` for the dry run.
+    block_id: Option<primitives::BlockId>,
+}
+
+impl FeeReport {
+    pub fn new(
+        report: executor::FeeReport,
+        block_id: Option<primitives::BlockId>,
+    ) -> Self {
+        Self { report, block_id }
+    }
+}
+
+#[Object]
+impl FeeReport {
+    /// The gas used by the script of the transaction.
+    async fn script_gas_used(&self) -> U64 {
+        self.report.script_gas_used.into()
+    }
+
+    /// The gas used by the predicates of the transaction.
+    async fn predicate_gas_used(&self) -> U64 {
+        self.report.predicate_gas_used.into()
+    }
+
+    async fn gas_price(&self) -> U64 {
+        self.report.gas_price.into()
+    }
+
+    /// The max fee charged from the inputs before the execution.
+    async fn max_fee(&self) -> U64 {
+        self.report.max_fee.into()
+    }
+
+    /// The fee paid for the transaction.
+    async fn fee(&self) -> U64 {
+        self.report.fee.into()
+    }
+
+    /// The part of the `max_fee` refunded after the execution.
+    async fn refunded(&self) -> U64 {
+        self.report.refunded.into()
+    }
+
+    /// The contract that received the fee. It is `null` for the dry run and when
+    /// the block producer doesn't collect fees.
+    async fn recipient(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ContractId>> {
+        let Some(block_id) = &self.block_id else {
+            return Ok(None)
+        };
+        let query: &ReadView = ctx.data_unchecked();
+        let height = query.block_height(block_id)?;
+        let block = query.block(&height)?;
+        // The `Mint` transaction is the last transaction of the block.
+        let Some(mint_id) = block.transactions().last() else {
+            return Ok(None)
+        };
+        let recipient = match query.transaction(mint_id)? {
+            fuel_tx::Transaction::Mint(mint) => mint.input_contract().contract_id,
+            _ => return Ok(None),
+        };
+        Ok((recipient != fuel_tx::ContractId::zeroed()).then(|| recipient.into()))
+    }
 }
 
 #[derive(Debug)]
@@ -244,12 +344,14 @@ impl TransactionStatus {
                 result,
                 time,
                 receipts,
+                fee,
             } => TransactionStatus::Success(SuccessStatus {
                 tx_id,
                 block_id,
                 result,
                 time,
                 receipts,
+                fee,
             }),
             TxStatus::SqueezedOut { reason } => {
                 TransactionStatus::SqueezedOut(SqueezedOutStatus { reason })
@@ -259,12 +361,14 @@ impl TransactionStatus {
                 time,
                 result,
                 receipts,
+                fee,
             } => TransactionStatus::Failed(FailureStatus {
                 tx_id,
                 block_id,
                 time,
                 state: result,
                 receipts,
+                fee,
             }),
         }
     }
@@ -281,12 +385,14 @@ impl From<TransactionStatus> for TxStatus {
                 result,
                 time,
                 receipts,
+                fee,
                 ..
             }) => TxStatus::Success {
                 block_id,
                 result,
                 time,
                 receipts,
+                fee,
             },
             TransactionStatus::SqueezedOut(SqueezedOutStatus { reason }) => {
                 TxStatus::SqueezedOut { reason }
@@ -296,12 +402,14 @@ impl From<TransactionStatus> for TxStatus {
                 time,
                 state: result,
                 receipts,
+                fee,
                 ..
             }) => TxStatus::Failed {
                 block_id,
                 time,
                 result,
                 receipts,
+                fee,
             },
         }
     }
@@ -331,6 +439,42 @@ impl Policies {
     }
 }
 
+/// The transaction pending in the `TxPool`.
+pub struct PoolTransaction {
+    pub(crate) id: TxId,
+    pub(crate) tx: fuel_tx::Transaction,
+    pub(crate) submitted_at: Tai64,
+    pub(crate) gas_price: u64,
+    pub(crate) dependencies: Vec<TxId>,
+}
+
+#[Object]
+impl PoolTransaction {
+    async fn id(&self) -> TransactionId {
+        self.id.into()
+    }
+
+    async fn transaction(&self) -> Transaction {
+        Transaction::from_tx(self.id, self.tx.clone())
+    }
+
+    /// The time when the transaction was inserted into the `TxPool`.
+    async fn submitted_at(&self) -> Tai64Timestamp {
+        self.submitted_at.into()
+    }
+
+    /// The gas price used by the `TxPool` to prioritize the transaction.
+    async fn gas_price(&self) -> U64 {
+        self.gas_price.into()
+    }
+
+    /// The ids of the pending transactions whose outputs are spent by the transaction.
+    /// The transaction can be included into the block only after them.
+    async fn dependencies(&self) -> Vec<TransactionId> {
+        self.dependencies.iter().copied().map(Into::into).collect()
+    }
+}
+
 pub struct Transaction(pub(crate) fuel_tx::Transaction, pub(crate) fuel_tx::TxId);
 
 impl Transaction {
@@ -612,12 +756,24 @@ pub enum DryRunTransactionStatus {
 impl DryRunTransactionStatus {
     pub fn new(tx_status: TransactionExecutionResult) -> Self {
         match tx_status {
-            TransactionExecutionResult::Success { result, receipts } => {
-                DryRunTransactionStatus::Success(DryRunSuccessStatus { result, receipts })
-            }
-            TransactionExecutionResult::Failed { result, receipts } => {
-                DryRunTransactionStatus::Failed(DryRunFailureStatus { result, receipts })
-            }
+            TransactionExecutionResult::Success {
+                result,
+                receipts,
+                fee,
+            } => DryRunTransactionStatus::Success(DryRunSuccessStatus {
+                result,
+                receipts,
+                fee,
+            }),
+            TransactionExecutionResult::Failed {
+                result,
+                receipts,
+                fee,
+            } => DryRunTransactionStatus::Failed(DryRunFailureStatus {
+                result,
+                receipts,
+                fee,
+            }),
         }
     }
 }
@@ -626,6 +782,7 @@ impl DryRunTransactionStatus {
 pub struct DryRunSuccessStatus {
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeReport,
 }
 
 #[Object]
@@ -637,12 +794,17 @@ impl DryRunSuccessStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.receipts.iter().map(Into::into).collect()
     }
+
+    async fn fee(&self) -> FeeReport {
+        FeeReport::new(self.fee, None)
+    }
 }
 
 #[derive(Debug)]
 pub struct DryRunFailureStatus {
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeReport,
 }
 
 #[Object]
@@ -658,6 +820,10 @@ impl DryRunFailureStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.receipts.iter().map(Into::into).collect()
     }
+
+    async fn fee(&self) -> FeeReport {
+        FeeReport::new(self.fee, None)
+    }
 }
 
 pub struct DryRunTransactionExecutionStatus(pub TransactionExecutionStatus);
@@ -695,7 +861,12 @@ pub(crate) fn get_tx_status(
             Some(submitted_time) => Ok(Some(TransactionStatus::Submitted(
                 SubmittedStatus(submitted_time),
             ))),
-            _ => Ok(None),
+            // The transaction could be removed from the tx pool without the inclusion
+            // into the block, in this case its final status is archived.
+            _ => Ok(query
+                .archived_tx_status(&id)
+                .into_api_result::<txpool::TransactionStatus, StorageError>()?
+                .map(|status| TransactionStatus::new(id, status))),
         },
     }
 }
@@ -7,7 +7,10 @@ use crate::{
             P2PAdapter,
             PoAAdapter,
         },
-        genesis::execute_genesis_block,
+        genesis::{
+            execute_genesis_block,
+            verify_existing_genesis,
+        },
     },
 };
 use fuel_core_poa::ports::BlockImporter;
@@ -19,25 +22,53 @@ use fuel_core_services::{
     StateWatcher,
 };
 use fuel_core_storage::{
+    tables::ContractsLatestUtxo,
     transactional::AtomicView,
     IsNotFound,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Input,
+        Transaction,
+    },
+    secrecy::ExposeSecret,
+};
+use std::{
+    net::SocketAddr,
+    ops::Deref,
+    sync::Arc,
 };
-use std::net::SocketAddr;
 use tracing::warn;
 
 pub use config::{
     Config,
     DbType,
+    ExecutionProfiler,
+    NodeMode,
     RelayerConsensusConfig,
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
+pub use node::{
+    FuelNode,
+    FuelNodeBuilder,
+};
 
 pub mod adapters;
+pub mod admin;
 pub mod config;
+pub mod disk_usage;
+pub mod fast_sync;
 pub mod genesis;
 pub mod metrics;
+pub mod node;
+#[cfg(feature = "p2p")]
+pub mod peers_persistence;
 mod query;
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod simulation;
+pub mod snapshot_export;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -108,15 +139,21 @@ impl FuelService {
                     );
                     CombinedDatabase::default()
                 } else {
+                    CombinedDatabase::check_chain_id(
+                        &config.database_path,
+                        &config.chain_conf.consensus_parameters.chain_id,
+                    )?;
                     tracing::info!(
                         "Opening database {:?} with cache size \"{}\"",
                         config.database_path,
                         config.max_database_cache_size
                     );
-                    CombinedDatabase::open(
+                    let database = CombinedDatabase::open(
                         &config.database_path,
                         config.max_database_cache_size,
-                    )?
+                    )?;
+                    database.migrate(&config.database_migration)?;
+                    database.with_state_cache(config.state_cache_capacity)
                 }
             }
             DbType::InMemory => CombinedDatabase::in_memory(),
@@ -235,6 +272,75 @@ impl Task {
     pub fn sub_services(&mut self) -> &mut SubServices {
         &mut self.services
     }
+
+    /// The read-only node doesn't have the transactions, so it keeps the transactions
+    /// saved by the previous full node untouched.
+    fn txpool_persistence_path(&self) -> Option<&std::path::PathBuf> {
+        let config = &self.shared.config;
+        if config.mode.is_read_only() {
+            None
+        } else {
+            config.txpool_persistence_path.as_ref()
+        }
+    }
+
+    /// Inserts the transactions saved during the previous shutdown into the `TxPool`.
+    async fn restore_txpool(&self) -> anyhow::Result<()> {
+        let Some(path) = self.txpool_persistence_path() else {
+            return Ok(())
+        };
+        if !path.exists() {
+            return Ok(())
+        }
+
+        let txs: Vec<Transaction> = serde_json::from_slice(&std::fs::read(path)?)?;
+        let total = txs.len();
+        let results = self
+            .shared
+            .txpool
+            .insert(txs.into_iter().map(Arc::new).collect())
+            .await;
+        let restored = results.iter().filter(|result| result.is_ok()).count();
+        tracing::info!(
+            "Restored {restored} of {total} transactions of the TxPool from {path:?}"
+        );
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    /// Saves the addresses of the connected peers to reconnect to them at the next start.
+    #[cfg(feature = "p2p")]
+    async fn persist_peers(&self) -> anyhow::Result<()> {
+        let (Some(path), Some(network)) = (
+            self.shared.config.peers_persistence_path.as_ref(),
+            self.shared.network.as_ref(),
+        ) else {
+            return Ok(())
+        };
+
+        let peers = network.get_all_peers().await?;
+        let saved = peers_persistence::save(path, peers)?;
+        tracing::info!("Saved {saved} addresses of the peers to {path:?}");
+        Ok(())
+    }
+
+    /// Saves the transactions of the `TxPool` to restore them at the next start.
+    fn persist_txpool(&self) -> anyhow::Result<()> {
+        let Some(path) = self.txpool_persistence_path() else {
+            return Ok(())
+        };
+
+        let txs: Vec<Transaction> = self
+            .shared
+            .txpool
+            .all_transactions()
+            .iter()
+            .map(|tx| tx.deref().into())
+            .collect();
+        std::fs::write(path, serde_json::to_vec(&txs)?)?;
+        tracing::info!("Saved {} transactions of the TxPool to {path:?}", txs.len());
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -255,21 +361,59 @@ impl RunnableService for Task {
     ) -> anyhow::Result<Self::Task> {
         let view = self.shared.database.on_chain().latest_view();
         // check if chain is initialized
-        if let Err(err) = view.get_genesis() {
-            if err.is_not_found() {
+        match view.get_genesis() {
+            Ok(genesis) => {
+                verify_existing_genesis(&self.shared.config, &genesis)?;
+                tracing::info!("The genesis is already imported, skipping it");
+            }
+            Err(err) if err.is_not_found() => {
                 let result = execute_genesis_block(&self.shared.config, &view)?;
 
                 self.shared.block_importer.commit_result(result).await?;
             }
+            Err(err) => return Err(err.into()),
         }
+        verify_coinbase_recipient(&self.shared.config, self.shared.database.on_chain())?;
 
         for service in &self.services {
             service.start_and_await().await?;
         }
+
+        if let Err(err) = self.restore_txpool().await {
+            tracing::error!("Failed to restore the transactions of the TxPool: {err}");
+        }
         Ok(self)
     }
 }
 
+/// Verifies that the contracts receiving the fees of the produced blocks are deployed.
+/// With the UTXO validation, the mint transaction spends the UTXO of the contract,
+/// so the node can't produce blocks otherwise.
+fn verify_coinbase_recipient(config: &Config, database: &Database) -> anyhow::Result<()> {
+    if !config.block_producer.utxo_validation {
+        return Ok(())
+    }
+    let signer = config
+        .consensus_key
+        .as_ref()
+        .map(|key| Input::owner(&key.expose_secret().public_key()));
+    let contracts = config
+        .block_producer
+        .coinbase_recipient
+        .contracts(signer.as_ref());
+    for contract_id in contracts {
+        if !database
+            .storage::<ContractsLatestUtxo>()
+            .contains_key(&contract_id)?
+        {
+            return Err(anyhow::anyhow!(
+                "The coinbase recipient contract {contract_id:#x} is not deployed"
+            ))
+        }
+    }
+    Ok(())
+}
+
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     #[tracing::instrument(skip_all)]
@@ -293,7 +437,13 @@ impl RunnableTask for Task {
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        for service in self.services {
+        // The peers are only known while the P2P service is running.
+        #[cfg(feature = "p2p")]
+        if let Err(err) = self.persist_peers().await {
+            tracing::error!("Failed to save the addresses of the peers: {err}");
+        }
+
+        for service in &self.services {
             let result = service.stop_and_await().await;
 
             if let Err(err) = result {
@@ -303,22 +453,55 @@ impl RunnableTask for Task {
                 );
             }
         }
+
+        // All services that produce and import blocks are stopped, so the state of
+        // the database and the `TxPool` is consistent.
+        if let Err(err) = self.persist_txpool() {
+            tracing::error!("Failed to save the transactions of the TxPool: {err}");
+        }
+        let height = self.shared.database.on_chain().latest_height();
         self.shared.database.flush()?;
+        match height {
+            Ok(height) => {
+                tracing::info!("The node is stopped at the block height {height}")
+            }
+            Err(err) => tracing::error!("Failed to get the latest block height: {err}"),
+        }
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::service::{
-        Config,
-        Task,
+    use crate::{
+        database::Database,
+        service::{
+            Config,
+            FuelService,
+            NodeMode,
+            ServiceTrait,
+            Task,
+        },
+    };
+    use fuel_core_chain_config::{
+        ContractConfig,
+        StateConfig,
     };
+    use fuel_core_poa::{
+        service::Mode,
+        Trigger,
+    };
+    use fuel_core_producer::CoinbaseRecipient;
     use fuel_core_services::{
         RunnableService,
         RunnableTask,
         State,
     };
+    use fuel_core_types::fuel_tx::{
+        ContractId,
+        TransactionBuilder,
+        UniqueIdentifier,
+    };
     use std::{
         thread::sleep,
         time::Duration,
@@ -347,9 +530,9 @@ mod tests {
             i += 1;
         }
 
-        // current services: graphql, graphql worker, txpool, PoA
+        // current services: graphql, graphql worker, txpool, PoA, disk usage
         #[allow(unused_mut)]
-        let mut expected_services = 4;
+        let mut expected_services = 5;
 
         // Relayer service is disabled with `Config::local_node`.
         // #[cfg(feature = "relayer")]
@@ -366,6 +549,73 @@ mod tests {
         assert_eq!(i, expected_services);
     }
 
+    #[tokio::test]
+    async fn read_only_node_does_not_produce_blocks() {
+        let full_node = Task::new(Default::default(), Config::local_node()).unwrap();
+        let read_only_node = Task::new(
+            Default::default(),
+            Config {
+                mode: NodeMode::ReadOnly,
+                ..Config::local_node()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(read_only_node.services.len(), full_node.services.len() - 1);
+        assert!(read_only_node
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks: 1
+                }
+            )
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn node_does_not_start_without_coinbase_recipient_contract() {
+        let contract_id = ContractId::new([1; 32]);
+        let mut config = Config::local_node();
+        config.utxo_validation = true;
+        config.block_producer.utxo_validation = true;
+        config.block_producer.coinbase_recipient = CoinbaseRecipient::Static(contract_id);
+        let task = Task::new(Default::default(), config.clone()).unwrap();
+
+        // When
+        let result = task.into_task(&Default::default(), ()).await;
+
+        // Then
+        assert!(result.is_err());
+
+        // Given
+        let contract = ContractConfig {
+            contract_id,
+            code: vec![],
+            code_hash: None,
+            salt: Default::default(),
+            state: None,
+            balances: None,
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        };
+        config.chain_conf.initial_state = Some(StateConfig {
+            contracts: Some(vec![contract]),
+            ..Default::default()
+        });
+        let task = Task::new(Default::default(), config).unwrap();
+
+        // When
+        let result = task.into_task(&Default::default(), ()).await;
+
+        // Then
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn shutdown_stops_all_services() {
         let task = Task::new(Default::default(), Config::local_node()).unwrap();
@@ -387,4 +637,32 @@ mod tests {
             assert_eq!(service.borrow_and_update().clone(), State::Stopped);
         }
     }
+
+    #[tokio::test]
+    async fn txpool_is_restored_after_restart() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("txpool.json");
+        let mut config = Config::local_node();
+        config.block_production = Trigger::Never;
+        config.txpool_persistence_path = Some(path.clone());
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        let tx_id = tx.id(&config.chain_conf.consensus_parameters.chain_id);
+
+        let database = Database::default();
+        let service = FuelService::from_database(database.clone(), config.clone())
+            .await
+            .unwrap();
+        service.submit(tx).await.unwrap();
+        service.stop_and_await().await.unwrap();
+        assert!(path.exists());
+
+        let service = FuelService::from_database(database, config).await.unwrap();
+        let restored = service.shared.txpool.find_one(tx_id);
+        service.stop_and_await().await.unwrap();
+
+        assert!(restored.is_some());
+    }
 }
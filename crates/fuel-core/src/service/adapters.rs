@@ -9,14 +9,21 @@ use fuel_core_consensus_module::{
     block_verifier::Verifier,
     RelayerConsensusConfig,
 };
-use fuel_core_executor::executor::Executor;
+use fuel_core_executor::{
+    executor::Executor,
+    upgradable::UpgradableExecutor,
+};
+use fuel_core_producer::TxSelector;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::block_importer::SharedImportResult,
+    services::block_importer::{
+        ImporterEventKind,
+        SharedImportResult,
+    },
 };
 use std::sync::Arc;
 
@@ -39,27 +46,43 @@ pub struct PoAAdapter {
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState<P2PAdapter, Database>,
+    tx_selector: Arc<dyn TxSelector>,
+    metrics: bool,
 }
 
 impl TxPoolAdapter {
-    pub fn new(service: TxPoolSharedState<P2PAdapter, Database>) -> Self {
-        Self { service }
+    pub fn new(
+        service: TxPoolSharedState<P2PAdapter, Database>,
+        tx_selector: Arc<dyn TxSelector>,
+        metrics: bool,
+    ) -> Self {
+        Self {
+            service,
+            tx_selector,
+            metrics,
+        }
     }
 }
 
 #[derive(Clone)]
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database>,
+    tx_selector: Arc<dyn TxSelector>,
+    metrics: bool,
     _block_height: BlockHeight,
 }
 
 impl TransactionsSource {
     pub fn new(
         txpool: TxPoolSharedState<P2PAdapter, Database>,
+        tx_selector: Arc<dyn TxSelector>,
+        metrics: bool,
         block_height: BlockHeight,
     ) -> Self {
         Self {
             txpool,
+            tx_selector,
+            metrics,
             _block_height: block_height,
         }
     }
@@ -67,7 +90,7 @@ impl TransactionsSource {
 
 #[derive(Clone)]
 pub struct ExecutorAdapter {
-    pub executor: Arc<Executor<Database, Database<Relayer>>>,
+    pub executor: Arc<UpgradableExecutor<Database>>,
 }
 
 impl ExecutorAdapter {
@@ -82,7 +105,7 @@ impl ExecutorAdapter {
             config: Arc::new(config),
         };
         Self {
-            executor: Arc::new(executor),
+            executor: Arc::new(UpgradableExecutor::new(Arc::new(executor))),
         }
     }
 }
@@ -133,11 +156,23 @@ pub struct BlockImporterAdapter {
 }
 
 impl BlockImporterAdapter {
+    /// Returns the stream of the imported blocks.
     pub fn events(&self) -> BoxStream<SharedImportResult> {
+        self.subscribe()
+    }
+
+    /// Returns the stream of the block importer events of the kind `T`, for example,
+    /// [`SharedImportResult`] for the imported blocks or
+    /// [`BlockFinalized`](fuel_core_types::services::block_importer::BlockFinalized)
+    /// for the final heights.
+    pub fn subscribe<T>(&self) -> BoxStream<T>
+    where
+        T: ImporterEventKind + Send + Sync + 'static,
+    {
         use futures::StreamExt;
         fuel_core_services::stream::IntoBoxStream::into_boxed(
             tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|r| futures::future::ready(r.ok())),
+                .filter_map(|r| futures::future::ready(r.ok().and_then(T::from_event))),
         )
     }
 }
@@ -146,6 +181,7 @@ impl BlockImporterAdapter {
 #[derive(Clone)]
 pub struct P2PAdapter {
     service: Option<fuel_core_p2p::service::SharedState>,
+    sync: Option<fuel_core_sync::service::SharedState>,
     peer_report_config: PeerReportConfig,
 }
 
@@ -171,9 +207,27 @@ impl P2PAdapter {
     ) -> Self {
         Self {
             service,
+            sync: None,
             peer_report_config,
         }
     }
+
+    /// Returns the adapter that neither sends nor receives anything over the network.
+    pub fn disconnected(&self) -> Self {
+        Self {
+            service: None,
+            sync: None,
+            peer_report_config: self.peer_report_config.clone(),
+        }
+    }
+
+    /// Returns the adapter that reports the progress of the `sync` service.
+    pub fn with_sync(&self, sync: Option<fuel_core_sync::service::SharedState>) -> Self {
+        Self {
+            sync,
+            ..self.clone()
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -181,4 +235,9 @@ impl P2PAdapter {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Returns the adapter that neither sends nor receives anything over the network.
+    pub fn disconnected(&self) -> Self {
+        Self
+    }
 }
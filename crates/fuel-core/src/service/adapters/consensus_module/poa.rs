@@ -71,6 +71,10 @@ impl ConsensusModulePort for PoAAdapter {
         self.manually_produce_blocks(start_time, Mode::Blocks { number_of_blocks })
             .await
     }
+
+    fn is_synced(&self) -> Option<bool> {
+        self.shared_state.as_ref().map(SharedState::is_synced)
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
@@ -137,11 +141,7 @@ impl BlockImporter for BlockImporterAdapter {
     }
 
     fn block_stream(&self) -> BoxStream<BlockImportInfo> {
-        Box::pin(
-            BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|result| result.ok())
-                .map(BlockImportInfo::from),
-        )
+        Box::pin(self.events().map(BlockImportInfo::from))
     }
 }
 
@@ -12,6 +12,7 @@ use fuel_core_executor::{
     executor::ExecutionBlockWithSource,
     ports::MaybeCheckedTransaction,
 };
+use fuel_core_metrics::block_production::block_production_metrics;
 use fuel_core_storage::{
     transactional::StorageTransaction,
     Error as StorageError,
@@ -29,11 +30,26 @@ use fuel_core_types::{
         relayer::Event,
     },
 };
+use std::{
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
+    time::Instant,
+};
 
 impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
-        self.txpool
-            .select_transactions(gas_limit)
+        let started_at = Instant::now();
+        let transactions = self
+            .txpool
+            .select_transactions(|txs| self.tx_selector.select(txs, gas_limit));
+        if self.metrics {
+            block_production_metrics()
+                .selection_duration
+                .observe(started_at.elapsed().as_secs_f64());
+        }
+        transactions
             .into_iter()
             .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
@@ -55,8 +71,9 @@ impl ExecutorAdapter {
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self.executor.dry_run(block, utxo_validation)
+        self.executor.dry_run(block, utxo_validation, abandoned)
     }
 }
 
@@ -6,6 +6,7 @@ use crate::{
     database::Database,
     fuel_core_graphql_api::ports::{
         worker,
+        BlockImporterPort,
         BlockProducerPort,
         DatabaseMessageProof,
         P2pPort,
@@ -20,17 +21,27 @@ use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
-    service::TxStatusMessage,
+    service::{
+        SqueezedOutTransaction,
+        TxStatusMessage,
+    },
     types::TxId,
+    TxInfo,
 };
 use fuel_core_types::{
     entities::message::MerkleProof,
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        Word,
+    },
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
         executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        p2p::{
+            PeerInfo,
+            SyncStatus,
+        },
         txpool::InsertionResult,
     },
     tai64::Tai64,
@@ -57,6 +68,26 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn min_gas_price(&self) -> u64 {
+        self.service.min_gas_price()
+    }
+
+    fn pending_number(&self) -> usize {
+        self.service.pending_number()
+    }
+
+    fn pending_gas_prices(&self) -> Vec<(Word, Word)> {
+        self.service
+            .all_transactions()
+            .iter()
+            .map(|tx| (tx.price(), tx.max_gas()))
+            .collect()
+    }
+
+    fn pending_transactions(&self) -> Vec<(TxInfo, Vec<TxId>)> {
+        self.service.all_transactions_with_dependencies()
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -64,6 +95,14 @@ impl TxPoolPort for TxPoolAdapter {
         self.service.insert(txs).await
     }
 
+    async fn insert_after(
+        &self,
+        tx: Arc<Transaction>,
+        after: TxId,
+    ) -> anyhow::Result<InsertionResult> {
+        self.service.insert_after(tx, after).await
+    }
+
     fn tx_update_subscribe(
         &self,
         id: TxId,
@@ -132,6 +171,23 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    fn sync_status(&self) -> Option<SyncStatus> {
+        #[cfg(feature = "p2p")]
+        {
+            self.sync.as_ref().map(|sync| sync.status())
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            None
+        }
+    }
+}
+
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        self.events()
+    }
 }
 
 impl worker::BlockImporter for BlockImporterAdapter {
@@ -139,3 +195,15 @@ impl worker::BlockImporter for BlockImporterAdapter {
         self.events()
     }
 }
+
+impl worker::TxPool for TxPoolAdapter {
+    fn squeezed_out_events(&self) -> BoxStream<SqueezedOutTransaction> {
+        use futures::StreamExt;
+        fuel_core_services::stream::IntoBoxStream::into_boxed(
+            tokio_stream::wrappers::BroadcastStream::new(
+                self.service.squeezed_out_subscribe(),
+            )
+            .filter_map(|r| futures::future::ready(r.ok())),
+        )
+    }
+}
@@ -8,7 +8,14 @@ use crate::{
             worker,
             OffChainDatabase,
         },
-        storage::transactions::OwnedTransactionIndexCursor,
+        storage::{
+            da_compression::DaCompressedBlocks,
+            receipts::{
+                ReceiptPointer,
+                ReceiptSelector,
+            },
+            transactions::OwnedTransactionIndexCursor,
+        },
     },
 };
 use fuel_core_storage::{
@@ -20,16 +27,20 @@ use fuel_core_storage::{
     not_found,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
         TxPointer,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
+    tai64::Tai64,
 };
 
 impl OffChainDatabase for Database<OffChain> {
@@ -39,6 +50,12 @@ impl OffChainDatabase for Database<OffChain> {
             .ok_or(not_found!("TransactionId"))?
     }
 
+    fn archived_tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
+        self.get_archived_tx_status(tx_id)
+            .transpose()
+            .ok_or(not_found!("TransactionId"))?
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -53,6 +70,28 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_receipts_ids(
+        &self,
+        contract_id: ContractId,
+        selector: ReceiptSelector,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ReceiptPointer, TxId)>> {
+        self.contract_receipts(contract_id, selector, start, Some(direction))
+            .into_boxed()
+    }
+
+    fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>> {
+        self.storage::<DaCompressedBlocks>()
+            .get(height)?
+            .ok_or(not_found!(DaCompressedBlocks))
+            .map(|block| block.into_owned())
+    }
+
+    fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Database::pruned_block_bodies_height(self)
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
@@ -66,6 +105,16 @@ impl worker::OffChainDatabase for Database<OffChain> {
         Database::record_tx_id_owner(self, owner, block_height, tx_idx, tx_id)
     }
 
+    fn record_contract_receipt(
+        &mut self,
+        contract_id: &ContractId,
+        selector: ReceiptSelector,
+        pointer: ReceiptPointer,
+        tx_id: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        Database::record_contract_receipt(self, contract_id, selector, pointer, tx_id)
+    }
+
     fn update_tx_status(
         &mut self,
         id: &Bytes32,
@@ -74,7 +123,47 @@ impl worker::OffChainDatabase for Database<OffChain> {
         Database::update_tx_status(self, id, status)
     }
 
+    fn archive_tx_status(
+        &mut self,
+        id: &Bytes32,
+        status: TransactionStatus,
+        archived_at: Tai64,
+    ) -> StorageResult<()> {
+        Database::archive_tx_status(self, id, status, archived_at)
+    }
+
+    fn prune_archived_tx_statuses(
+        &mut self,
+        archived_before: Tai64,
+    ) -> StorageResult<usize> {
+        Database::prune_archived_tx_statuses(self, archived_before)
+    }
+
+    fn store_da_compressed_block(
+        &mut self,
+        height: &BlockHeight,
+        block: &[u8],
+    ) -> StorageResult<()> {
+        self.storage::<DaCompressedBlocks>().insert(height, block)?;
+        Ok(())
+    }
+
     fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64> {
         Database::increase_tx_count(self, new_txs_count)
     }
+
+    fn prune_tx_receipts(&mut self, id: &Bytes32) -> StorageResult<()> {
+        Database::prune_tx_receipts(self, id)
+    }
+
+    fn pruned_block_bodies_height(&self) -> StorageResult<Option<BlockHeight>> {
+        Database::pruned_block_bodies_height(self)
+    }
+
+    fn set_pruned_block_bodies_height(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        Database::set_pruned_block_bodies_height(self, height)
+    }
 }
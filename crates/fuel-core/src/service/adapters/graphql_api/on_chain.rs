@@ -1,6 +1,7 @@
 use crate::{
     database::Database,
     fuel_core_graphql_api::ports::{
+        worker,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseContracts,
@@ -33,13 +34,17 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        Bytes32,
         UtxoId,
     },
     fuel_types::{
         BlockHeight,
         Nonce,
     },
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStorageStats,
+    },
 };
 
 impl DatabaseBlocks for Database {
@@ -104,6 +109,13 @@ impl DatabaseContracts for Database {
             })
             .into_boxed()
     }
+
+    fn contract_storage_stats(
+        &self,
+        contract: &ContractId,
+    ) -> StorageResult<ContractStorageStats> {
+        self.contract_storage_stats(contract)
+    }
 }
 
 impl DatabaseChain for Database {
@@ -136,4 +148,34 @@ impl OnChainDatabase for Database {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn owned_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        let amount = self.owned_balance(owner, asset_id, base_asset_id)?;
+        Ok(u64::try_from(amount).unwrap_or(u64::MAX))
+    }
+
+    fn owned_balances(
+        &self,
+        owner: &Address,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<Vec<(AssetId, u64)>> {
+        let balances = self.owned_balances(owner, base_asset_id)?;
+        Ok(balances
+            .into_iter()
+            .map(|(asset_id, amount)| {
+                (asset_id, u64::try_from(amount).unwrap_or(u64::MAX))
+            })
+            .collect())
+    }
+}
+
+impl worker::BlockBodies for Database {
+    fn remove_block_body(&mut self, height: &BlockHeight) -> StorageResult<Vec<Bytes32>> {
+        Database::remove_block_body(self, height)
+    }
 }
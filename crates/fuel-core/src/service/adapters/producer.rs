@@ -43,7 +43,10 @@ use fuel_core_types::{
 };
 use std::{
     borrow::Cow,
-    sync::Arc,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
 };
 
 impl BlockProducerAdapter {
@@ -59,7 +62,12 @@ impl TxPool for TxPoolAdapter {
     type TxSource = TransactionsSource;
 
     fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
-        TransactionsSource::new(self.service.clone(), block_height)
+        TransactionsSource::new(
+            self.service.clone(),
+            self.tx_selector.clone(),
+            self.metrics,
+            block_height,
+        )
     }
 }
 
@@ -85,11 +93,13 @@ impl fuel_core_producer::ports::Executor<Vec<Transaction>> for ExecutorAdapter {
             header_to_produce,
             transactions_source,
             gas_limit,
+            coinbase_recipient,
         } = component;
         self._execute_without_commit(ExecutionTypes::Production(Components {
             header_to_produce,
             transactions_source: OnceTransactionsSource::new(transactions_source),
             gas_limit,
+            coinbase_recipient,
         }))
     }
 }
@@ -99,8 +109,9 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self._dry_run(block, utxo_validation)
+        self._dry_run(block, utxo_validation, abandoned)
     }
 }
 
@@ -0,0 +1,453 @@
+//! The admin API of the node. It serves the operational endpoints on a separate
+//! listener protected by the bearer token, so they are never exposed via the public
+//! GraphQL schema.
+
+use crate::service::{
+    snapshot_export::{
+        ExportRequest,
+        SnapshotExporter,
+    },
+    SharedState,
+};
+use axum::{
+    extract::Extension,
+    http::{
+        header::AUTHORIZATION,
+        HeaderMap,
+        Request,
+        StatusCode,
+    },
+    middleware::{
+        from_fn,
+        Next,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use fuel_core_poa::service::Mode;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+    tai64::Tai64,
+};
+use serde::Deserialize;
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    future::Future,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+};
+use tower_http::trace::TraceLayer;
+
+pub type Service = fuel_core_services::ServiceRunner<AdminService>;
+
+type SetLogFilter = dyn Fn(&str) -> anyhow::Result<()> + Send + Sync;
+
+/// Replaces the log filter of the node with the filter in the `RUST_LOG` format.
+#[derive(Clone)]
+pub struct LogFilterSetter(pub Arc<SetLogFilter>);
+
+impl Debug for LogFilterSetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LogFilterSetter")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The address of the admin listener. It should be the loopback address,
+    /// because the admin API controls the node.
+    pub addr: SocketAddr,
+    /// The bearer token required by all requests of the admin API.
+    pub token: Secret<String>,
+    /// Changes the log filter of the node. The log filter can't be changed if not set.
+    pub log_filter: Option<LogFilterSetter>,
+}
+
+#[derive(Clone)]
+pub struct AdminSharedState {
+    pub bound_address: SocketAddr,
+}
+
+pub struct AdminService {
+    bound_address: SocketAddr,
+}
+
+pub struct ServerParams {
+    router: Router,
+    listener: TcpListener,
+}
+
+pub struct Task {
+    server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for AdminService {
+    const NAME: &'static str = "Admin";
+
+    type SharedData = AdminSharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        AdminSharedState {
+            bound_address: self.bound_address,
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let ServerParams { router, listener } = params;
+
+        let server = axum::Server::from_tcp(listener)?
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async move {
+                state
+                    .while_started()
+                    .await
+                    .expect("The service is destroyed");
+            });
+
+        Ok(Task {
+            server: Box::pin(server),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(config: Config, node: SharedState) -> anyhow::Result<Service> {
+    if !config.addr.ip().is_loopback() {
+        tracing::warn!(
+            "The admin API is exposed on the non-loopback address {}",
+            config.addr
+        );
+    }
+
+    let token = config.token.clone();
+    let exporter =
+        SnapshotExporter::new(node.database.clone(), node.config.chain_conf.clone());
+    let router = Router::new()
+        .route("/peers", get(peers))
+        .route("/peers/report", post(report_peer))
+        .route("/txpool", get(txpool))
+        .route("/txpool/remove", post(remove_transactions))
+        .route("/compact", post(compact))
+        .route("/log-filter", post(log_filter))
+        .route("/produce-blocks", post(produce_blocks))
+        .route("/snapshot", get(snapshot_status).post(export_snapshot))
+        .layer(Extension(node))
+        .layer(Extension(exporter))
+        .layer(Extension(config.log_filter.clone()))
+        .layer(from_fn(move |request, next| {
+            authorize(token.clone(), request, next)
+        }))
+        .layer(TraceLayer::new_for_http());
+
+    let listener = TcpListener::bind(config.addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding the admin API to {}", bound_address);
+
+    Ok(Service::new_with_params(
+        AdminService { bound_address },
+        ServerParams { router, listener },
+    ))
+}
+
+async fn authorize<B>(
+    token: Secret<String>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if is_authorized(request.headers(), token.expose_secret()) {
+        next.run(request).await
+    } else {
+        StatusCode::UNAUTHORIZED.into_response()
+    }
+}
+
+/// Checks the bearer `token` of the request. The comparison doesn't stop at
+/// the first mismatch, so the time of the check doesn't reveal the token.
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    let Some(provided) = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return false
+    };
+
+    provided.len() == token.len()
+        && provided
+            .bytes()
+            .zip(token.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+type AdminResult = Result<Json<Value>, (StatusCode, Json<Value>)>;
+
+fn admin_error(error: anyhow::Error) -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({ "error": error.to_string() })),
+    )
+}
+
+#[cfg(feature = "p2p")]
+fn network(node: &SharedState) -> anyhow::Result<&fuel_core_p2p::service::SharedState> {
+    node.network
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("The P2P network is disabled"))
+}
+
+async fn peers(Extension(node): Extension<SharedState>) -> AdminResult {
+    #[cfg(feature = "p2p")]
+    {
+        let peers = network(&node)
+            .map_err(admin_error)?
+            .get_all_peers()
+            .await
+            .map_err(admin_error)?
+            .into_iter()
+            .map(|(peer_id, info)| {
+                json!({
+                    "id": peer_id.to_string(),
+                    "addresses": info
+                        .peer_addresses
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                    "block_height": info
+                        .heartbeat_data
+                        .block_height
+                        .map(|height| *height),
+                    "app_score": info.score,
+                })
+            })
+            .collect::<Vec<_>>();
+        Ok(Json(json!({ "peers": peers })))
+    }
+    #[cfg(not(feature = "p2p"))]
+    {
+        let _ = node;
+        Err(admin_error(anyhow::anyhow!("The P2P network is disabled")))
+    }
+}
+
+#[derive(Deserialize)]
+struct ReportPeer {
+    /// The base58 id of the peer.
+    peer_id: String,
+    /// The change of the application score of the peer. The peer with
+    /// the score below the threshold is banned.
+    score: f64,
+}
+
+async fn report_peer(
+    Extension(node): Extension<SharedState>,
+    Json(report): Json<ReportPeer>,
+) -> AdminResult {
+    #[cfg(feature = "p2p")]
+    {
+        let peer_id: fuel_core_types::services::p2p::PeerId = report
+            .peer_id
+            .parse()
+            .map_err(|e: String| admin_error(anyhow::anyhow!(e)))?;
+        network(&node)
+            .map_err(admin_error)?
+            .report_peer(peer_id, report.score, "admin")
+            .map_err(admin_error)?;
+        Ok(Json(json!({ "reported": report.peer_id })))
+    }
+    #[cfg(not(feature = "p2p"))]
+    {
+        let _ = (node, report);
+        Err(admin_error(anyhow::anyhow!("The P2P network is disabled")))
+    }
+}
+
+async fn txpool(Extension(node): Extension<SharedState>) -> AdminResult {
+    let transactions = node
+        .txpool
+        .all_transactions()
+        .iter()
+        .map(|tx| tx.id())
+        .collect::<Vec<_>>();
+    Ok(Json(json!({
+        "pending": node.txpool.pending_number(),
+        "total_consumable_gas": node.txpool.total_consumable_gas(),
+        "min_gas_price": node.txpool.min_gas_price(),
+        "transactions": transactions,
+    })))
+}
+
+#[derive(Deserialize)]
+struct RemoveTransactions {
+    tx_ids: Vec<TxId>,
+}
+
+async fn remove_transactions(
+    Extension(node): Extension<SharedState>,
+    Json(request): Json<RemoveTransactions>,
+) -> AdminResult {
+    let removed = node
+        .txpool
+        .remove(request.tx_ids)
+        .iter()
+        .map(|tx| tx.id())
+        .collect::<Vec<_>>();
+    Ok(Json(json!({ "removed": removed })))
+}
+
+async fn compact(Extension(node): Extension<SharedState>) -> AdminResult {
+    tokio::task::spawn_blocking(move || node.database.compact())
+        .await
+        .map_err(|e| admin_error(e.into()))?
+        .map_err(|e| admin_error(e.into()))?;
+    Ok(Json(json!({ "compacted": true })))
+}
+
+#[derive(Deserialize)]
+struct LogFilter {
+    /// The log filter in the `RUST_LOG` format.
+    filter: String,
+}
+
+async fn log_filter(
+    Extension(setter): Extension<Option<LogFilterSetter>>,
+    Json(request): Json<LogFilter>,
+) -> AdminResult {
+    let setter = setter
+        .ok_or_else(|| admin_error(anyhow::anyhow!("The log filter can't be changed")))?;
+    (setter.0)(&request.filter).map_err(admin_error)?;
+    Ok(Json(json!({ "filter": request.filter })))
+}
+
+#[derive(Deserialize)]
+struct ProduceBlocks {
+    number_of_blocks: u32,
+    /// The TAI64 timestamp of the first block. The current time is used if not set.
+    start_time: Option<u64>,
+}
+
+async fn produce_blocks(
+    Extension(node): Extension<SharedState>,
+    Json(request): Json<ProduceBlocks>,
+) -> AdminResult {
+    node.poa_adapter
+        .manually_produce_blocks(
+            request.start_time.map(Tai64),
+            Mode::Blocks {
+                number_of_blocks: request.number_of_blocks,
+            },
+        )
+        .await
+        .map_err(admin_error)?;
+    Ok(Json(json!({ "produced": request.number_of_blocks })))
+}
+
+#[derive(Deserialize)]
+struct ExportSnapshot {
+    /// The directory of the snapshot file on the host of the node.
+    directory: PathBuf,
+    /// The expected height of the snapshot. The latest height is used if not set.
+    height: Option<u32>,
+    /// Encrypts the snapshot by the key from the environment of the node.
+    #[serde(default)]
+    encrypt: bool,
+}
+
+async fn export_snapshot(
+    Extension(exporter): Extension<SnapshotExporter>,
+    Json(request): Json<ExportSnapshot>,
+) -> AdminResult {
+    exporter
+        .start(ExportRequest {
+            directory: request.directory,
+            height: request.height.map(BlockHeight::from),
+            encrypt: request.encrypt,
+        })
+        .map_err(admin_error)?;
+    Ok(Json(json!({ "status": exporter.status() })))
+}
+
+async fn snapshot_status(
+    Extension(exporter): Extension<SnapshotExporter>,
+) -> AdminResult {
+    Ok(Json(json!({ "status": exporter.status() })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_str(authorization).unwrap());
+        headers
+    }
+
+    #[test]
+    fn request_with_token_is_authorized() {
+        assert!(is_authorized(&headers("Bearer secret"), "secret"));
+    }
+
+    #[test]
+    fn request_with_wrong_token_is_rejected() {
+        assert!(!is_authorized(&headers("Bearer secreT"), "secret"));
+        assert!(!is_authorized(&headers("Bearer secret2"), "secret"));
+        assert!(!is_authorized(&headers("secret"), "secret"));
+    }
+
+    #[test]
+    fn request_without_token_is_rejected() {
+        assert!(!is_authorized(&HeaderMap::new(), "secret"));
+    }
+}
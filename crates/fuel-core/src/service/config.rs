@@ -1,11 +1,22 @@
+use crate::{
+    database::migration::MigrationConfig,
+    fuel_core_graphql_api::dry_run_limiter::DryRunLimits,
+};
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    StateReader,
 };
+pub use fuel_core_executor::profiler::ExecutionProfiler;
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
+    blockchain::primitives::{
+        BlockId,
+        SecretKeyWrapper,
+    },
+    fuel_types::BlockHeight,
     secrecy::Secret,
+    services::executor::ReceiptsLimits,
 };
 use std::{
     net::{
@@ -13,6 +24,7 @@ use std::{
         SocketAddr,
     },
     path::PathBuf,
+    sync::Arc,
     time::Duration,
 };
 use strum_macros::{
@@ -32,16 +44,34 @@ use fuel_core_relayer::Config as RelayerConfig;
 
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    ports::GetTime,
+    GasTargetAutotuning,
+    SystemTime,
+    Trigger,
+};
+pub use fuel_core_services::RestartPolicy;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
     pub api_request_timeout: Duration,
     pub max_database_cache_size: usize,
+    /// The number of the contract state slots and balances cached in memory.
+    /// The zero disables the cache.
+    pub state_cache_capacity: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The file where the transactions of the `TxPool` are saved during the shutdown
+    /// of the node. The transactions are inserted back into the `TxPool` at the next
+    /// start, and the file is removed. The `TxPool` is not persisted if not set.
+    pub txpool_persistence_path: Option<PathBuf>,
+    /// The migrations of the existing database to the version of this build.
+    pub database_migration: MigrationConfig,
     pub chain_conf: ChainConfig,
+    /// The source of the initial state of the genesis. The `initial_state` of
+    /// the `chain_conf` is used if not set.
+    pub state_reader: Option<Arc<dyn StateReader>>,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
@@ -49,7 +79,12 @@ pub struct Config {
     pub debug: bool,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
+    pub mode: NodeMode,
     pub block_production: Trigger,
+    /// The clock used for the timestamps of the produced blocks.
+    pub clock: Arc<dyn GetTime>,
+    /// Adjusts the gas target of produced blocks to the performance of the node if set.
+    pub gas_target_autotuning: Option<GasTargetAutotuning>,
     pub vm: VMConfig,
     pub txpool: fuel_core_txpool::Config,
     pub block_producer: fuel_core_producer::Config,
@@ -60,7 +95,14 @@ pub struct Config {
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// The file where the addresses of the peers with the good reputation are saved
+    /// during the shutdown of the node. The peers are added to the bootstrap nodes
+    /// at the next start. The peers are not persisted if not set.
+    #[cfg(feature = "p2p")]
+    pub peers_persistence_path: Option<PathBuf>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Adds the randomness beacon signed by the `consensus_key` to the produced blocks.
+    pub poa_beacon: bool,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -69,6 +111,28 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// How long the final statuses of the transactions not included in the blocks,
+    /// like squeezed out ones, are available via the GraphQL API.
+    pub tx_status_archive_retention: Duration,
+    /// The number of the latest blocks with the transactions and receipts available.
+    /// The bodies of the older blocks are pruned, while their headers and the data
+    /// required for the message proofs are kept. The pruned node can't serve the
+    /// transactions of the old blocks to the syncing peers. Nothing is pruned if not set.
+    pub block_body_retention: Option<u32>,
+    pub disk_usage: crate::service::disk_usage::Config,
+    /// The height of the trusted finalized block. The write-ahead log of the databases
+    /// is disabled until the block is imported. See [`crate::service::fast_sync`].
+    pub fast_sync_unsafe_until: Option<BlockHeight>,
+    /// The number of blocks after which the pagination cursors of the GraphQL API
+    /// expire. The cursors contain the height of the chain at the first page.
+    pub cursor_expiry: u32,
+    pub dry_run_limits: DryRunLimits,
+    /// The admin API of the node. It is disabled if not set.
+    pub admin: Option<crate::service::admin::Config>,
+    /// The services restarted with the `restart_policy` after a panic instead of
+    /// stopping the node.
+    pub restart_on_panic: Vec<RestartableService>,
+    pub restart_policy: RestartPolicy,
 }
 
 impl Config {
@@ -83,16 +147,23 @@ impl Config {
             api_request_timeout: Duration::from_secs(60),
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
+            state_cache_capacity: 0,
             database_path: Default::default(),
             #[cfg(feature = "rocksdb")]
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            txpool_persistence_path: None,
+            database_migration: Default::default(),
             debug: true,
             chain_conf: chain_conf.clone(),
+            state_reader: None,
             block_production: Trigger::Instant,
+            clock: Arc::new(SystemTime),
+            gas_target_autotuning: None,
             vm: Default::default(),
             utxo_validation,
+            mode: NodeMode::Full,
             txpool: fuel_core_txpool::Config {
                 chain_config: chain_conf,
                 min_gas_price,
@@ -108,12 +179,36 @@ impl Config {
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            peers_persistence_path: None,
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            poa_beacon: false,
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            tx_status_archive_retention: Duration::from_secs(24 * 60 * 60),
+            block_body_retention: None,
+            disk_usage: Default::default(),
+            fast_sync_unsafe_until: None,
+            cursor_expiry: 10_000,
+            dry_run_limits: Default::default(),
+            admin: None,
+            restart_on_panic: vec![],
+            restart_policy: RestartPolicy::OnPanic {
+                initial_backoff: Duration::from_secs(1),
+                max_backoff: Duration::from_secs(60),
+            },
+        }
+    }
+
+    /// Returns the restart policy of the `service`.
+    pub fn restart_policy_of(&self, service: RestartableService) -> RestartPolicy {
+        if self.restart_on_panic.contains(&service) {
+            self.restart_policy
+        } else {
+            RestartPolicy::Never
         }
     }
 
@@ -148,11 +243,15 @@ impl From<&Config> for fuel_core_poa::Config {
         fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
+            gas_target_autotuning: config.gas_target_autotuning,
             signing_key: config.consensus_key.clone(),
-            metrics: false,
+            beacon: config.poa_beacon,
+            metrics: config.block_producer.metrics,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            block_time: config.chain_conf.consensus.block_time(),
+            clock: config.clock.clone(),
         }
     }
 }
@@ -160,6 +259,26 @@ impl From<&Config> for fuel_core_poa::Config {
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// The number of threads used to validate non-conflicting transactions of
+    /// the block in parallel.
+    pub execution_threads: usize,
+    /// Record the changes made to the state by each block.
+    pub record_state_diff: bool,
+    /// Collects the execution profile of blocks if set.
+    pub profiler: Option<ExecutionProfiler>,
+    /// The limits of the receipts produced by one transaction
+    /// in produced blocks and dry runs.
+    pub receipts_limits: ReceiptsLimits,
+    /// The signatures of the inputs are not verified during the validation
+    /// of the blocks up to the trusted checkpoint, if the stored block at
+    /// the checkpoint height has the checkpoint's id.
+    pub skip_signatures_until: Option<(BlockHeight, BlockId)>,
+    /// The number of the predicates evaluated successfully by the `TxPool` that
+    /// are not evaluated again by the executor. `0` disables the cache.
+    pub predicate_cache_size: usize,
+    /// Forces failures during the execution of blocks. Used only in tests.
+    #[cfg(feature = "fault-injection")]
+    pub fault_injector: Option<fuel_core_executor::fault_injection::FaultInjector>,
 }
 
 #[derive(
@@ -170,3 +289,35 @@ pub enum DbType {
     InMemory,
     RocksDb,
 }
+
+/// The role of the node in the network.
+#[derive(
+    Clone, Copy, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum NodeMode {
+    /// Produces blocks if configured, accepts and gossips transactions.
+    Full,
+    /// Only synchronizes the blocks and serves the queries. The block production
+    /// is disabled, and the transactions are neither accepted nor gossiped.
+    ReadOnly,
+}
+
+impl NodeMode {
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, NodeMode::ReadOnly)
+    }
+}
+
+/// The services which can be restarted after a panic without stopping the node.
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum RestartableService {
+    Relayer,
+    #[strum(serialize = "p2p")]
+    #[value(name = "p2p")]
+    P2P,
+    Sync,
+}
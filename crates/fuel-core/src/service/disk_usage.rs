@@ -0,0 +1,265 @@
+//! The background task measures the disk usage of the databases. It exports the size
+//! and the growth rate of each column as metrics and warns when the volume with
+//! the databases is running out of space.
+
+use crate::combined_database::CombinedDatabase;
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+use tokio::time::MissedTickBehavior;
+
+pub type Service = ServiceRunner<Task>;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How often the disk usage is measured.
+    pub interval: Duration,
+    /// The node warns about the low disk space when the available space
+    /// of the volume with the databases falls below the threshold in bytes.
+    pub low_space_threshold: u64,
+    /// Pauses the non-critical writes while the disk space is low.
+    /// See [`WritesPause`] for the list of the non-critical writes.
+    pub pause_non_critical_writes: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            low_space_threshold: 1024 * 1024 * 1024,
+            pause_non_critical_writes: false,
+        }
+    }
+}
+
+/// The flag is set while the non-critical writes are paused because of the low
+/// disk space. The non-critical data, like the DA-compressed blocks and the archived
+/// statuses of the transactions, is not required to follow the chain.
+#[derive(Clone, Debug, Default)]
+pub struct WritesPause(Arc<AtomicBool>);
+
+impl WritesPause {
+    pub fn is_paused(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn set(&self, paused: bool) {
+        self.0.store(paused, Ordering::Relaxed)
+    }
+}
+
+pub struct Task {
+    database: CombinedDatabase,
+    config: Config,
+    pause: WritesPause,
+    interval: tokio::time::Interval,
+    /// The sizes of the columns at the previous measurement, used for the growth rate.
+    previous: HashMap<(&'static str, &'static str), u64>,
+    previous_at: Option<Instant>,
+}
+
+impl Task {
+    fn measure(&mut self) -> anyhow::Result<()> {
+        let now = Instant::now();
+        let sizes = self.database.column_sizes()?;
+        let elapsed = self
+            .previous_at
+            .map(|at| now.duration_since(at).as_secs_f64());
+
+        for (database, column, size) in sizes.iter().copied() {
+            let growth_rate = match (elapsed, self.previous.get(&(database, column))) {
+                (Some(elapsed), Some(previous)) if elapsed > 0.0 => {
+                    (size as f64 - *previous as f64) * 3600.0 / elapsed
+                }
+                _ => 0.0,
+            };
+            database_metrics().observe_column_size(database, column, size, growth_rate);
+        }
+        self.previous = sizes
+            .into_iter()
+            .map(|(database, column, size)| ((database, column), size))
+            .collect();
+        self.previous_at = Some(now);
+
+        // The in-memory databases don't use the disk.
+        let Some(path) = self.database.path() else {
+            return Ok(())
+        };
+        let available = fs2::available_space(&path)?;
+        self.observe_available_space(available, &path);
+        Ok(())
+    }
+
+    fn observe_available_space(&mut self, available: u64, path: &Path) {
+        database_metrics()
+            .available_space
+            .set(i64::try_from(available).unwrap_or(i64::MAX));
+
+        let low_space = available < self.config.low_space_threshold;
+        database_metrics().low_disk_space.set(i64::from(low_space));
+        if low_space {
+            tracing::warn!(
+                "The available disk space {available} bytes of the database at {} is below \
+                the threshold {} bytes",
+                path.display(),
+                self.config.low_space_threshold,
+            );
+        }
+        let paused = low_space && self.config.pause_non_critical_writes;
+        if paused != self.pause.is_paused() {
+            if paused {
+                tracing::warn!(
+                    "The non-critical writes are paused until the disk space is freed"
+                );
+            } else {
+                tracing::info!("The non-critical writes are resumed");
+            }
+            self.pause.set(paused);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "DiskUsage";
+
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            _ = self.interval.tick() => {
+                // The failed measurement is not the reason to stop the node.
+                if let Err(e) = self.measure() {
+                    tracing::error!("Failed to measure the disk usage: {e:?}");
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    config: Config,
+    pause: WritesPause,
+) -> Service {
+    let mut interval = tokio::time::interval(config.interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    Service::new(Task {
+        database,
+        config,
+        pause,
+        interval,
+        previous: HashMap::new(),
+        previous_at: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(config: Config) -> Task {
+        let interval = tokio::time::interval(config.interval);
+        Task {
+            database: CombinedDatabase::in_memory(),
+            config,
+            pause: WritesPause::default(),
+            interval,
+            previous: HashMap::new(),
+            previous_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn measurement_records_sizes_of_all_columns() {
+        let mut task = task(Config::default());
+
+        task.measure().unwrap();
+
+        assert!(!task.previous.is_empty());
+        assert!(task
+            .previous
+            .keys()
+            .any(|(database, _)| *database == "off_chain"));
+        assert!(!task.pause.is_paused());
+    }
+
+    #[tokio::test]
+    async fn low_space_pauses_writes_until_space_is_freed() {
+        let mut task = task(Config {
+            low_space_threshold: 100,
+            pause_non_critical_writes: true,
+            ..Default::default()
+        });
+        let path = Path::new("db");
+
+        task.observe_available_space(50, path);
+        assert!(task.pause.is_paused());
+
+        task.observe_available_space(150, path);
+        assert!(!task.pause.is_paused());
+    }
+
+    #[tokio::test]
+    async fn low_space_does_not_pause_writes_if_disabled() {
+        let mut task = task(Config {
+            low_space_threshold: 100,
+            pause_non_critical_writes: false,
+            ..Default::default()
+        });
+
+        task.observe_available_space(50, Path::new("db"));
+
+        assert!(!task.pause.is_paused());
+    }
+}
@@ -0,0 +1,160 @@
+//! The unsafe fast sync mode disables the write-ahead log of the databases while
+//! the node imports the blocks below the trusted finalized checkpoint. The import
+//! is several times faster, but the crash of the node in this mode may corrupt
+//! the databases, requiring the resync from scratch. The node switches to the durable
+//! mode after the import of the checkpoint block.
+
+use crate::combined_database::CombinedDatabase;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+
+pub type Service = ServiceRunner<Task>;
+
+pub struct Task {
+    database: CombinedDatabase,
+    /// The height of the finalized block, after which the databases are durable.
+    checkpoint: BlockHeight,
+    blocks: BoxStream<SharedImportResult>,
+    durable: bool,
+}
+
+impl Task {
+    fn on_block_imported(&mut self, height: BlockHeight) -> anyhow::Result<()> {
+        if self.durable || height < self.checkpoint {
+            return Ok(())
+        }
+        self.database.set_durable(true)?;
+        self.durable = true;
+        tracing::info!(
+            "The checkpoint {} is imported, the fast sync mode is disabled",
+            self.checkpoint
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "FastSync";
+
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.blocks.next() => {
+                if let Some(result) = result {
+                    self.on_block_imported(*result.sealed_block.entity.header().height())?;
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The unlogged data is flushed, so the node doesn't lose the imported
+        // blocks after the restart.
+        self.database.set_durable(true)?;
+        Ok(())
+    }
+}
+
+/// Creates the service if the node is below the `checkpoint`, and disables
+/// the write-ahead log of the databases until the `checkpoint` is imported.
+pub fn new_service(
+    database: CombinedDatabase,
+    checkpoint: BlockHeight,
+    current_height: BlockHeight,
+    blocks: BoxStream<SharedImportResult>,
+) -> anyhow::Result<Option<Service>> {
+    if current_height >= checkpoint {
+        return Ok(None)
+    }
+    tracing::warn!(
+        "The fast sync mode is enabled until the block {checkpoint}. The crash \
+        of the node before it may corrupt the databases"
+    );
+    database.set_durable(false)?;
+    Ok(Some(Service::new(Task {
+        database,
+        checkpoint,
+        blocks,
+        durable: false,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::stream::IntoBoxStream;
+
+    fn task(checkpoint: u32) -> Task {
+        Task {
+            database: CombinedDatabase::in_memory(),
+            checkpoint: checkpoint.into(),
+            blocks: futures::stream::empty().into_boxed(),
+            durable: false,
+        }
+    }
+
+    #[test]
+    fn durable_mode_is_restored_at_checkpoint() {
+        let mut task = task(10);
+
+        task.on_block_imported(9.into()).unwrap();
+        assert!(!task.durable);
+
+        task.on_block_imported(10.into()).unwrap();
+        assert!(task.durable);
+    }
+
+    #[test]
+    fn service_is_not_created_above_checkpoint() {
+        let service = new_service(
+            CombinedDatabase::in_memory(),
+            10.into(),
+            10.into(),
+            futures::stream::empty().into_boxed(),
+        )
+        .unwrap();
+
+        assert!(service.is_none());
+    }
+}
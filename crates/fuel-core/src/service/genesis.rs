@@ -4,6 +4,7 @@ use crate::{
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::{
+    ChainConfig,
     ContractConfig,
     GenesisCommitment,
     StateConfig,
@@ -58,6 +59,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         bytes::WORD_SIZE,
+        BlockHeight,
         Bytes32,
         ContractId,
     },
@@ -65,8 +67,10 @@ use fuel_core_types::{
         ImportResult,
         UncommittedResult as UncommittedImportResult,
     },
+    tai64::Tai64,
 };
 use itertools::Itertools;
+use std::borrow::Cow;
 
 /// Performs the importing of the genesis block from the snapshot.
 pub fn execute_genesis_block(
@@ -76,24 +80,11 @@ pub fn execute_genesis_block(
     // start a db transaction for bulk-writing
     let mut database_transaction = Transactional::transaction(original_database);
 
-    let database = database_transaction.as_mut();
-    // Initialize the chain id and height.
+    let chain_config = genesis_chain_config(config)?;
+    let genesis = init_genesis_state(&chain_config, database_transaction.as_mut())?;
 
-    let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
-
-    let genesis = Genesis {
-        chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
-    };
-
-    let block = create_genesis_block(config);
+    let block =
+        genesis_block(genesis_height(&chain_config), chain_config.genesis_time()?);
     let consensus = Consensus::Genesis(genesis);
     let block = SealedBlock {
         entity: block,
@@ -107,8 +98,114 @@ pub fn execute_genesis_block(
     Ok(result)
 }
 
-pub fn create_genesis_block(config: &Config) -> Block {
-    let block = Block::new(
+/// Verifies that the `existing` genesis from the database was created from the
+/// same chain config as the `config`. The commitments are recalculated on the
+/// in-memory database, so it takes as long as the import of the genesis state.
+pub fn verify_existing_genesis(
+    config: &Config,
+    existing: &Genesis,
+) -> anyhow::Result<()> {
+    let chain_config_hash: Bytes32 = config.chain_conf.root()?.into();
+    if chain_config_hash != existing.chain_config_hash {
+        return Err(anyhow!(
+            "The database contains the genesis of another chain: the chain config hash \
+             is {}, but the chain config `{}` has {}. Use the chain config of the \
+             database or another database",
+            existing.chain_config_hash,
+            config.chain_conf.chain_name,
+            chain_config_hash,
+        ))
+    }
+
+    let chain_config = genesis_chain_config(config)?;
+    let expected = init_genesis_state(&chain_config, &mut Database::in_memory())?;
+    let mismatches = [
+        ("coins root", existing.coins_root, expected.coins_root),
+        (
+            "contracts root",
+            existing.contracts_root,
+            expected.contracts_root,
+        ),
+        (
+            "messages root",
+            existing.messages_root,
+            expected.messages_root,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, existing, expected)| existing != expected)
+    .map(|(name, existing, expected)| {
+        format!("the {name} is {existing}, expected {expected}")
+    })
+    .collect::<Vec<_>>();
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "The database contains the genesis of the same chain with another initial \
+             state: {}",
+            mismatches.join(", ")
+        ))
+    }
+}
+
+/// Returns the chain config with the initial state read from the `state_reader`
+/// of the `config` if it is set.
+fn genesis_chain_config(config: &Config) -> anyhow::Result<Cow<'_, ChainConfig>> {
+    let Some(reader) = &config.state_reader else {
+        return Ok(Cow::Borrowed(&config.chain_conf))
+    };
+    let initial_state = reader.read()?;
+    Ok(Cow::Owned(ChainConfig {
+        initial_state: Some(initial_state),
+        ..config.chain_conf.clone()
+    }))
+}
+
+/// Writes the initial state from the `chain_config` into the `database` and returns
+/// the commitments to it.
+fn init_genesis_state(
+    chain_config: &ChainConfig,
+    database: &mut Database,
+) -> anyhow::Result<Genesis> {
+    chain_config.check_supply()?;
+
+    let chain_config_hash = chain_config.root()?.into();
+    let coins_root = init_coin_state(database, &chain_config.initial_state)?.into();
+    let contracts_root = init_contracts(database, &chain_config.initial_state)?.into();
+    let messages_root = init_da_messages(database, &chain_config.initial_state)?;
+    let messages_root = messages_root.into();
+
+    Ok(Genesis {
+        chain_config_hash,
+        coins_root,
+        contracts_root,
+        messages_root,
+    })
+}
+
+/// Creates the genesis block at the height of the initial state.
+pub fn create_genesis_block(config: &Config) -> anyhow::Result<Block> {
+    let height = match &config.state_reader {
+        Some(reader) => reader.height()?.unwrap_or_default(),
+        None => genesis_height(&config.chain_conf),
+    };
+    Ok(genesis_block(height, config.chain_conf.genesis_time()?))
+}
+
+/// The initial height is defined by the `ChainConfig`.
+/// If it is `None` then it will be zero.
+fn genesis_height(chain_config: &ChainConfig) -> BlockHeight {
+    chain_config
+        .initial_state
+        .as_ref()
+        .and_then(|state| state.height)
+        .unwrap_or_default()
+}
+
+fn genesis_block(height: BlockHeight, time: Tai64) -> Block {
+    Block::new(
         PartialBlockHeader {
             application: ApplicationHeader::<Empty> {
                 // TODO: Set `da_height` based on the chain config.
@@ -118,23 +215,15 @@ pub fn create_genesis_block(config: &Config) -> Block {
             consensus: ConsensusHeader::<Empty> {
                 // The genesis is a first block, so previous root is zero.
                 prev_root: Bytes32::zeroed(),
-                // The initial height is defined by the `ChainConfig`.
-                // If it is `None` then it will be zero.
-                height: config
-                    .chain_conf
-                    .initial_state
-                    .as_ref()
-                    .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
-                    .unwrap_or_else(|| 0u32.into()),
-                time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
+                height,
+                time,
                 generated: Empty,
             },
         },
         // Genesis block doesn't have any transaction.
         vec![],
         &[],
-    );
-    block
+    )
 }
 
 #[cfg(feature = "test-helpers")]
@@ -161,62 +250,60 @@ fn init_coin_state(
     // TODO: Store merkle sum tree root over coins with unspecified utxo ids.
     let mut generated_output_index: u64 = 0;
     if let Some(state) = &state {
-        if let Some(coins) = &state.coins {
-            for coin in coins {
-                let utxo_id = UtxoId::new(
-                    // generated transaction id([0..[out_index/255]])
-                    coin.tx_id.unwrap_or_else(|| {
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length")
-                    }),
-                    coin.output_index.unwrap_or_else(|| {
-                        generated_output_index = generated_output_index
-                            .checked_add(1)
-                            .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
-                        (generated_output_index % 255) as u8
-                    }),
-                );
-
-                let compressed_coin: CompressedCoin = CompressedCoinV1 {
-                    owner: coin.owner,
-                    amount: coin.amount,
-                    asset_id: coin.asset_id,
-                    maturity: coin.maturity.unwrap_or_default(),
-                    tx_pointer: TxPointer::new(
-                        coin.tx_pointer_block_height.unwrap_or_default(),
-                        coin.tx_pointer_tx_idx.unwrap_or_default(),
-                    ),
-                }
-                .into();
+        for coin in state.all_coins()? {
+            let utxo_id = UtxoId::new(
+                // generated transaction id([0..[out_index/255]])
+                coin.tx_id.unwrap_or_else(|| {
+                    Bytes32::try_from(
+                        (0..(Bytes32::LEN - WORD_SIZE))
+                            .map(|_| 0u8)
+                            .chain(
+                                (generated_output_index / 255)
+                                    .to_be_bytes()
+                                    .into_iter(),
+                            )
+                            .collect_vec()
+                            .as_slice(),
+                    )
+                    .expect("Incorrect genesis transaction id byte length")
+                }),
+                coin.output_index.unwrap_or_else(|| {
+                    generated_output_index = generated_output_index
+                        .checked_add(1)
+                        .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
+                    (generated_output_index % 255) as u8
+                }),
+            );
 
-                // ensure coin can't point to blocks in the future
-                if compressed_coin.tx_pointer().block_height()
-                    > state.height.unwrap_or_default()
-                {
-                    return Err(anyhow!(
-                        "coin tx_pointer height cannot be greater than genesis block"
-                    ))
-                }
+            let compressed_coin: CompressedCoin = CompressedCoinV1 {
+                owner: coin.owner,
+                amount: coin.amount,
+                asset_id: coin.asset_id,
+                maturity: coin.maturity.unwrap_or_default(),
+                tx_pointer: TxPointer::new(
+                    coin.tx_pointer_block_height.unwrap_or_default(),
+                    coin.tx_pointer_tx_idx.unwrap_or_default(),
+                ),
+            }
+            .into();
 
-                if db
-                    .storage::<Coins>()
-                    .insert(&utxo_id, &compressed_coin)?
-                    .is_some()
-                {
-                    return Err(anyhow!("Coin should not exist"))
-                }
-                coins_tree.push(compressed_coin.root()?.as_slice())
+            // ensure coin can't point to blocks in the future
+            if compressed_coin.tx_pointer().block_height()
+                > state.height.unwrap_or_default()
+            {
+                return Err(anyhow!(
+                    "coin tx_pointer height cannot be greater than genesis block"
+                ))
             }
+
+            if db
+                .storage::<Coins>()
+                .insert(&utxo_id, &compressed_coin)?
+                .is_some()
+            {
+                return Err(anyhow!("Coin should not exist"))
+            }
+            coins_tree.push(compressed_coin.root()?.as_slice())
         }
     }
     Ok(coins_tree.root())
@@ -230,9 +317,11 @@ fn init_contracts(
     // initialize contract state
     if let Some(state) = &state {
         if let Some(contracts) = &state.contracts {
-            for (generated_output_index, contract_config) in contracts.iter().enumerate()
+            let codes = state.contract_codes()?;
+            for (generated_output_index, (contract_config, code)) in
+                contracts.iter().zip(codes).enumerate()
             {
-                let contract = Contract::from(contract_config.code.as_slice());
+                let contract = Contract::from(code);
                 let salt = contract_config.salt;
                 let root = contract.root();
                 let contract_id = contract_config.contract_id;
@@ -384,9 +473,12 @@ mod tests {
         },
     };
     use fuel_core_chain_config::{
+        fixtures::StateFixture,
         ChainConfig,
         CoinConfig,
         MessageConfig,
+        SupplyLimitConfig,
+        VestingConfig,
     };
     use fuel_core_services::RunnableService;
     use fuel_core_storage::{
@@ -413,7 +505,10 @@ mod tests {
         RngCore,
         SeedableRng,
     };
-    use std::vec;
+    use std::{
+        sync::Arc,
+        vec,
+    };
 
     #[tokio::test]
     async fn config_initializes_block_height() {
@@ -441,6 +536,64 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn config_initializes_genesis_block_time() {
+        let service_config = Config {
+            chain_conf: ChainConfig {
+                genesis_time: Some(1_700_000_000),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let genesis_block = db.get_current_block().unwrap().unwrap();
+        assert_eq!(
+            genesis_block.header().time(),
+            Tai64::from_unix(1_700_000_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn state_reader_replaces_initial_state_of_chain_config() {
+        let test_height = BlockHeight::from(77u32);
+        let coin = CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Address::from([1; 32]),
+            amount: 100,
+            asset_id: AssetId::BASE,
+        };
+        let state = StateConfig {
+            coins: Some(vec![coin]),
+            height: Some(test_height),
+            ..Default::default()
+        };
+        let service_config = Config {
+            state_reader: Some(Arc::new(state)),
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(test_height, db.latest_height().unwrap());
+        let coins = db.get_coin_config().unwrap().unwrap();
+        assert_eq!(coins.len(), 1);
+        assert_eq!(coins[0].owner, Address::from([1; 32]));
+        let genesis = db.get_genesis().unwrap();
+        verify_existing_genesis(&service_config, &genesis).unwrap();
+    }
+
     #[tokio::test]
     async fn config_state_initializes_multiple_coins_with_different_owners_and_asset_ids()
     {
@@ -556,6 +709,7 @@ mod tests {
                     contracts: Some(vec![ContractConfig {
                         contract_id,
                         code: contract.into(),
+                        code_hash: None,
                         salt,
                         state: Some(state),
                         balances: None,
@@ -642,6 +796,7 @@ mod tests {
                     contracts: Some(vec![ContractConfig {
                         contract_id,
                         code: contract.into(),
+                        code_hash: None,
                         salt,
                         state: None,
                         balances: Some(balances),
@@ -720,6 +875,7 @@ mod tests {
                     contracts: Some(vec![ContractConfig {
                         contract_id: Default::default(),
                         code: contract.into(),
+                        code_hash: None,
                         salt,
                         state: None,
                         balances: Some(balances),
@@ -743,6 +899,187 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn genesis_fails_if_supply_exceeds_max_supply() {
+        let mut chain_conf = ChainConfig::local_testnet();
+        let initial_supply = chain_conf
+            .initial_state
+            .as_ref()
+            .unwrap()
+            .all_coins()
+            .unwrap()
+            .iter()
+            .map(|coin| coin.amount)
+            .sum::<u64>();
+        chain_conf.max_supply = Some(vec![SupplyLimitConfig {
+            asset_id: AssetId::BASE,
+            max_supply: initial_supply - 1,
+        }]);
+        let service_config = Config {
+            chain_conf,
+            ..Config::local_node()
+        };
+
+        let db = CombinedDatabase::default();
+        let task = Task::new(db, service_config).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        assert!(init_result.is_err())
+    }
+
+    async fn commit_genesis(config: &Config, db: &Database) {
+        let result = execute_genesis_block(config, db).unwrap();
+        fuel_core_importer::Importer::new(
+            config.block_importer.clone(),
+            db.clone(),
+            (),
+            (),
+        )
+        .commit_result(result)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn existing_genesis_is_not_imported_again() {
+        let db = CombinedDatabase::default();
+        commit_genesis(&Config::local_node(), db.on_chain()).await;
+
+        let task = Task::new(db, Config::local_node()).unwrap();
+        let init_result = task.into_task(&Default::default(), ()).await;
+
+        assert!(init_result.is_ok())
+    }
+
+    #[tokio::test]
+    async fn existing_genesis_of_another_chain_is_rejected() {
+        let config = Config::local_node();
+        let db = Database::default();
+        commit_genesis(&config, &db).await;
+        let genesis = db.get_genesis().unwrap();
+
+        let mut another_chain = config.clone();
+        another_chain.chain_conf.chain_name = "another".to_string();
+        let err = verify_existing_genesis(&another_chain, &genesis).unwrap_err();
+        assert!(err.to_string().contains("another chain"), "{err}");
+
+        let mut another_state = config.clone();
+        let coin = &mut another_state
+            .chain_conf
+            .initial_state
+            .as_mut()
+            .unwrap()
+            .coins
+            .as_mut()
+            .unwrap()[0];
+        coin.amount = coin.amount.saturating_add(1);
+        let err = verify_existing_genesis(&another_state, &genesis).unwrap_err();
+        assert!(err.to_string().contains("coins root"), "{err}");
+
+        verify_existing_genesis(&config, &genesis).unwrap();
+    }
+
+    #[tokio::test]
+    async fn config_state_initializes_vesting_coins() {
+        let owner = Address::from([1; 32]);
+        let vesting = VestingConfig::monthly(
+            owner,
+            AssetId::BASE,
+            1_000,
+            BlockHeight::from(0u32),
+            4,
+            100,
+        );
+        let service_config = Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig {
+                    coins: Some(vec![CoinConfig {
+                        tx_id: None,
+                        output_index: None,
+                        tx_pointer_block_height: None,
+                        tx_pointer_tx_idx: None,
+                        maturity: None,
+                        owner,
+                        amount: 1,
+                        asset_id: AssetId::BASE,
+                    }]),
+                    vesting: Some(vec![vesting]),
+                    ..Default::default()
+                }),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let mut coins = get_coins(&db, &owner)
+            .into_iter()
+            .map(|coin| (*coin.maturity, coin.amount))
+            .collect::<Vec<_>>();
+        coins.sort();
+        assert_eq!(
+            coins,
+            vec![(0, 1), (100, 250), (200, 250), (300, 250), (400, 250)]
+        );
+    }
+
+    #[tokio::test]
+    async fn config_state_initializes_state_from_fixture() {
+        let fixture = StateFixture {
+            seed: 42,
+            coins: 1_000,
+            contracts: 5,
+            contract_code_size: 1024,
+            contract_storage_slots: 100,
+            messages: 100,
+            height: 10u32.into(),
+            ..Default::default()
+        };
+        let chain_conf = fixture.chain_config();
+        let state = chain_conf.initial_state.clone().unwrap();
+        let service_config = Config {
+            chain_conf,
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        assert_eq!(db.latest_height().unwrap(), fixture.height);
+        for coin in state.coins.unwrap() {
+            let utxo_id = UtxoId::new(coin.tx_id.unwrap(), coin.output_index.unwrap());
+            assert!(db.storage::<Coins>().contains_key(&utxo_id).unwrap());
+        }
+        for contract in state.contracts.unwrap() {
+            let contract_id = contract.contract_id;
+            assert!(db
+                .storage::<ContractsRawCode>()
+                .contains_key(&contract_id)
+                .unwrap());
+            for (key, value) in contract.state.unwrap() {
+                let stored = db
+                    .storage::<ContractsState>()
+                    .get(&(&contract_id, &key).into())
+                    .unwrap()
+                    .unwrap()
+                    .into_owned();
+                assert_eq!(stored, value);
+            }
+        }
+        for message in state.messages.unwrap() {
+            assert!(db
+                .storage::<Messages>()
+                .contains_key(&message.nonce)
+                .unwrap());
+        }
+    }
+
     fn get_coins(db: &Database, owner: &Address) -> Vec<Coin> {
         db.owned_coins_ids(owner, None, None)
             .map(|r| {
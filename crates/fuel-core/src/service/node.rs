@@ -0,0 +1,356 @@
+//! The API to run the node as a library in the current process. The handles of the
+//! [`FuelNode`] access the services directly, without the GraphQL API, so they
+//! can be used by simulation frameworks and integration tests of SDKs.
+//!
+//! ```no_run
+//! use fuel_core::{
+//!     service::FuelNode,
+//!     types::fuel_tx::Transaction,
+//! };
+//! use futures::StreamExt;
+//!
+//! async fn run(tx: Transaction) -> anyhow::Result<()> {
+//!     let node = FuelNode::builder().start().await?;
+//!     let mut blocks = node.blocks();
+//!
+//!     let status = node.tx_submitter().submit_and_await_commit(tx).await?;
+//!     let block = blocks.next().await.expect("The block with the transaction");
+//!     let height = node.queries().latest_block_height()?;
+//!     assert_eq!(*block.sealed_block.entity.header().height(), height);
+//!
+//!     node.stop().await
+//! }
+//! ```
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::Database,
+    fuel_core_graphql_api::{
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
+        ports::OffChainDatabase,
+        IntoApiResult,
+    },
+    query::{
+        BalanceQueryData,
+        BlockQueryData,
+        SimpleBlockData,
+        SimpleTransactionData,
+    },
+    service::{
+        adapters::P2PAdapter,
+        config::{
+            GetTime,
+            Trigger,
+        },
+        Config,
+        DbType,
+        FuelService,
+        ServiceTrait,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_chain_config::ChainConfig;
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_txpool::service::{
+    SharedState as TxPoolSharedState,
+    TxStatusMessage,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_tx::{
+        Address,
+        AssetId,
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::{
+            BlockFinalized,
+            SharedImportResult,
+        },
+        txpool::{
+            InsertionResult,
+            TransactionStatus,
+        },
+    },
+};
+use futures::StreamExt;
+use std::sync::Arc;
+
+type TxPool = TxPoolSharedState<P2PAdapter, Database>;
+
+/// The node running in the current process.
+pub struct FuelNode {
+    service: FuelService,
+}
+
+impl FuelNode {
+    /// Returns the builder of the node with the config of the local node.
+    pub fn builder() -> FuelNodeBuilder {
+        FuelNodeBuilder::default()
+    }
+
+    /// Returns the handle to query the state of the blockchain.
+    pub fn queries(&self) -> QueryHandle {
+        let shared = &self.service.shared;
+        QueryHandle {
+            database: shared.database.clone(),
+            txpool: shared.txpool.clone(),
+            base_asset_id: shared.config.chain_conf.consensus_parameters.base_asset_id,
+        }
+    }
+
+    /// Returns the handle to submit the transactions into the tx pool.
+    pub fn tx_submitter(&self) -> TxSubmitter {
+        let shared = &self.service.shared;
+        TxSubmitter {
+            txpool: shared.txpool.clone(),
+            chain_id: shared.config.chain_conf.consensus_parameters.chain_id,
+        }
+    }
+
+    /// Returns the stream of the blocks imported after the call.
+    pub fn blocks(&self) -> BoxStream<SharedImportResult> {
+        self.service.shared.block_importer.events()
+    }
+
+    /// Returns the stream of the heights of the final blocks.
+    pub fn finalized_heights(&self) -> BoxStream<BlockFinalized> {
+        self.service.shared.block_importer.subscribe()
+    }
+
+    /// Returns the underlying service with the shared state of all sub-services.
+    pub fn service(&self) -> &FuelService {
+        &self.service
+    }
+
+    /// Stops the node and waits until all services are stopped.
+    pub async fn stop(self) -> anyhow::Result<()> {
+        self.service.stop_and_await().await?;
+        Ok(())
+    }
+}
+
+/// The builder of the [`FuelNode`]. By default, the node uses the in-memory database,
+/// the `local_testnet` chain config, the instant block production, and doesn't
+/// connect to the P2P network.
+pub struct FuelNodeBuilder {
+    config: Config,
+    database: Option<CombinedDatabase>,
+}
+
+impl Default for FuelNodeBuilder {
+    fn default() -> Self {
+        let mut config = Config::local_node();
+        config.database_type = DbType::InMemory;
+        #[cfg(feature = "p2p")]
+        {
+            config.p2p = None;
+        }
+        Self {
+            config,
+            database: None,
+        }
+    }
+}
+
+impl FuelNodeBuilder {
+    /// Replaces the config of the node.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the chain config of the node.
+    pub fn chain_config(mut self, chain_config: ChainConfig) -> Self {
+        self.config.block_importer.chain_id = chain_config.consensus_parameters.chain_id;
+        self.config.chain_conf = chain_config;
+        self
+    }
+
+    /// Sets the trigger of the block production.
+    pub fn block_production(mut self, trigger: Trigger) -> Self {
+        self.config.block_production = trigger;
+        self
+    }
+
+    /// Sets the clock used for the timestamps of the produced blocks.
+    pub fn clock(mut self, clock: Arc<dyn GetTime>) -> Self {
+        self.config.clock = clock;
+        self
+    }
+
+    /// Enables or disables the validation of the UTXOs of the transactions.
+    pub fn utxo_validation(mut self, utxo_validation: bool) -> Self {
+        self.config.utxo_validation = utxo_validation;
+        self
+    }
+
+    /// Sets the database of the node instead of the one from the config.
+    pub fn database(mut self, database: CombinedDatabase) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Starts the node and waits until all services are started.
+    pub async fn start(self) -> anyhow::Result<FuelNode> {
+        let service = match self.database {
+            Some(database) => {
+                FuelService::from_combined_database(database, self.config).await?
+            }
+            None => FuelService::new_node(self.config).await?,
+        };
+        Ok(FuelNode { service })
+    }
+}
+
+/// The handle to query the state of the blockchain. Each call uses the latest
+/// state of the database.
+#[derive(Clone)]
+pub struct QueryHandle {
+    database: CombinedDatabase,
+    txpool: TxPool,
+    base_asset_id: AssetId,
+}
+
+impl QueryHandle {
+    /// Returns the view of the database with the queries of the [`crate::query`].
+    pub fn view(&self) -> ReadView {
+        ReadDatabase::new(
+            self.database.on_chain().clone(),
+            self.database.off_chain().clone(),
+        )
+        .view()
+    }
+
+    pub fn latest_block_height(&self) -> StorageResult<BlockHeight> {
+        self.view().latest_block_height()
+    }
+
+    pub fn block(&self, height: &BlockHeight) -> StorageResult<Option<CompressedBlock>> {
+        self.view().block(height).into_api_result()
+    }
+
+    /// Returns the transaction from the tx pool or from the blockchain.
+    pub fn transaction(&self, id: &TxId) -> StorageResult<Option<Transaction>> {
+        if let Some(info) = self.txpool.find_one(*id) {
+            return Ok(Some(info.tx().as_ref().into()))
+        }
+        self.view().transaction(id).into_api_result()
+    }
+
+    /// Returns the status of the transaction, including the archived final statuses
+    /// of the transactions removed from the tx pool.
+    pub fn transaction_status(
+        &self,
+        id: &TxId,
+    ) -> StorageResult<Option<TransactionStatus>> {
+        let view = self.view();
+        if let Some(status) = view
+            .tx_status(id)
+            .into_api_result::<TransactionStatus, StorageError>()?
+        {
+            return Ok(Some(status))
+        }
+        if let Some(info) = self.txpool.find_one(*id) {
+            return Ok(Some(info.into()))
+        }
+        view.archived_tx_status(id).into_api_result()
+    }
+
+    /// Returns the balance of the `asset_id` owned by the `owner`.
+    pub fn balance(&self, owner: Address, asset_id: AssetId) -> StorageResult<u64> {
+        self.view()
+            .balance(owner, asset_id, self.base_asset_id)
+            .map(|balance| balance.amount)
+    }
+}
+
+/// The handle to submit the transactions into the tx pool.
+#[derive(Clone)]
+pub struct TxSubmitter {
+    txpool: TxPool,
+    chain_id: ChainId,
+}
+
+impl TxSubmitter {
+    pub async fn submit(&self, tx: Transaction) -> anyhow::Result<InsertionResult> {
+        self.txpool
+            .insert(vec![Arc::new(tx)])
+            .await
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("Nothing was inserted"))?
+    }
+
+    /// Submits the transaction and waits for its final status: the inclusion into
+    /// the block or the removal from the tx pool.
+    pub async fn submit_and_await_commit(
+        &self,
+        tx: Transaction,
+    ) -> anyhow::Result<TransactionStatus> {
+        let id = tx.id(&self.chain_id);
+        let mut updates = self.txpool.tx_update_subscribe(id)?;
+        self.submit(tx).await?;
+
+        while let Some(update) = updates.next().await {
+            match update {
+                TxStatusMessage::Status(TransactionStatus::Submitted { .. }) => continue,
+                TxStatusMessage::Status(status) => return Ok(status),
+                TxStatusMessage::FailedStatus => {
+                    return Err(anyhow!(
+                        "Failed to get the status of the transaction {id}"
+                    ))
+                }
+            }
+        }
+        Err(anyhow!(
+            "The stream of the status updates of the transaction {id} is closed"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    #[tokio::test]
+    async fn submitted_transaction_is_included_into_the_block() {
+        let node = FuelNode::builder().start().await.unwrap();
+        let mut blocks = node.blocks();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        let tx_id = tx.id(&ChainId::default());
+
+        let status = node
+            .tx_submitter()
+            .submit_and_await_commit(tx)
+            .await
+            .unwrap();
+
+        assert!(matches!(status, TransactionStatus::Success { .. }));
+        let block = blocks.next().await.unwrap();
+        let height = *block.sealed_block.entity.header().height();
+        let queries = node.queries();
+        assert_eq!(queries.latest_block_height().unwrap(), height);
+        assert!(queries.block(&height).unwrap().is_some());
+        assert!(queries.transaction(&tx_id).unwrap().is_some());
+        assert_eq!(queries.transaction_status(&tx_id).unwrap(), Some(status));
+        node.stop().await.unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+//! Saves the addresses of the peers during the shutdown of the node, so the node
+//! reconnects to them at the next start instead of discovering the network from
+//! the bootstrap nodes only. The quality of the peers is accumulated across the
+//! restarts, so the node prefers the peers that served it well before.
+
+use fuel_core_p2p::{
+    peer_manager::{
+        peer_quality::PeerQuality,
+        PeerInfo,
+    },
+    Multiaddr,
+    PeerId,
+    Protocol,
+    TryPeerId,
+};
+use fuel_core_types::services::p2p::peer_reputation::DEFAULT_APP_SCORE;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    path::Path,
+};
+
+/// The maximum number of the addresses kept in the file.
+const MAX_PERSISTED_PEERS: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum PersistedPeer {
+    /// The address saved by the previous releases without the quality.
+    Address(String),
+    WithQuality {
+        address: String,
+        quality: PeerQuality,
+    },
+}
+
+impl PersistedPeer {
+    fn into_parts(self) -> (String, PeerQuality) {
+        match self {
+            PersistedPeer::Address(address) => (address, PeerQuality::default()),
+            PersistedPeer::WithQuality { address, quality } => (address, quality),
+        }
+    }
+}
+
+/// Saves the addresses of the peers with the non-negative reputation to the file
+/// at the `path`, along with the addresses saved before. The quality of the peers
+/// is added to their history. Returns the number of the saved addresses.
+pub fn save<I>(path: &Path, peers: I) -> anyhow::Result<usize>
+where
+    I: IntoIterator<Item = (PeerId, PeerInfo)>,
+{
+    let mut history: HashMap<String, PeerQuality> = read(path)?
+        .into_iter()
+        .map(PersistedPeer::into_parts)
+        .collect();
+
+    for (peer_id, info) in peers {
+        let addresses = info
+            .peer_addresses
+            .into_iter()
+            .map(|address| with_peer_id(address, peer_id).to_string());
+        if info.score < DEFAULT_APP_SCORE {
+            for address in addresses {
+                history.remove(&address);
+            }
+            continue
+        }
+        for address in addresses {
+            let quality = history.remove(&address).unwrap_or_default();
+            history.insert(address, quality.merge(info.quality));
+        }
+    }
+
+    let peers: Vec<PersistedPeer> = ranked(history)
+        .take(MAX_PERSISTED_PEERS)
+        .map(|(address, quality)| PersistedPeer::WithQuality { address, quality })
+        .collect();
+    std::fs::write(path, serde_json::to_vec(&peers)?)?;
+    Ok(peers.len())
+}
+
+/// Loads the addresses of the peers saved by [`save`], from the best to the worst
+/// quality. The peers that failed most of the requests are skipped. Returns
+/// nothing if the file doesn't exist.
+pub fn load(path: &Path) -> anyhow::Result<Vec<Multiaddr>> {
+    let history = read(path)?.into_iter().map(PersistedPeer::into_parts);
+    ranked(history)
+        .filter(|(_, quality)| !quality.is_unreliable())
+        .map(|(address, _)| address.parse().map_err(Into::into))
+        .collect()
+}
+
+fn read(path: &Path) -> anyhow::Result<Vec<PersistedPeer>> {
+    if !path.exists() {
+        return Ok(vec![])
+    }
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+fn ranked(
+    peers: impl IntoIterator<Item = (String, PeerQuality)>,
+) -> impl Iterator<Item = (String, PeerQuality)> {
+    let mut peers: Vec<_> = peers.into_iter().collect();
+    peers.sort_by(|(_, left), (_, right)| left.rank(right));
+    peers.into_iter()
+}
+
+/// The bootstrap nodes require the id of the peer at the end of the address.
+fn with_peer_id(address: Multiaddr, peer_id: PeerId) -> Multiaddr {
+    if address.try_to_peer_id().is_some() {
+        address
+    } else {
+        address.with(Protocol::P2p(peer_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn peer(address: &str, score: f64) -> (PeerId, PeerInfo) {
+        let mut info = PeerInfo::new(10);
+        info.peer_addresses.insert(address.parse().unwrap());
+        info.score = score;
+        (PeerId::random(), info)
+    }
+
+    fn address(peer: &(PeerId, PeerInfo)) -> Multiaddr {
+        let address = peer.1.peer_addresses.iter().next().unwrap().clone();
+        with_peer_id(address, peer.0)
+    }
+
+    #[test]
+    fn only_peers_with_good_reputation_are_restored() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("peers.json");
+        let good = peer("/ip4/127.0.0.1/tcp/30333", DEFAULT_APP_SCORE);
+        let bad = peer("/ip4/127.0.0.2/tcp/30333", DEFAULT_APP_SCORE - 1.0);
+        let good_peer_id = good.0;
+
+        let saved = save(&path, vec![good, bad]).unwrap();
+        let restored = load(&path).unwrap();
+
+        assert_eq!(saved, 1);
+        assert_eq!(
+            restored,
+            vec![format!("/ip4/127.0.0.1/tcp/30333/p2p/{good_peer_id}")
+                .parse::<Multiaddr>()
+                .unwrap()]
+        );
+    }
+
+    #[test]
+    fn nothing_is_restored_without_file() {
+        let restored = load(Path::new("missing-peers.json")).unwrap();
+
+        assert!(restored.is_empty());
+    }
+
+    #[test]
+    fn peers_are_restored_from_best_to_worst_across_restarts() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("peers.json");
+        let mut fast = peer("/ip4/127.0.0.1/tcp/30333", DEFAULT_APP_SCORE);
+        let mut slow = peer("/ip4/127.0.0.2/tcp/30333", DEFAULT_APP_SCORE);
+        let mut failing = peer("/ip4/127.0.0.3/tcp/30333", DEFAULT_APP_SCORE);
+        let unknown = peer("/ip4/127.0.0.4/tcp/30333", DEFAULT_APP_SCORE);
+        for _ in 0..5 {
+            fast.1.quality.record_served(Duration::from_millis(10));
+            slow.1.quality.record_served(Duration::from_millis(500));
+            failing.1.quality.record_failed();
+        }
+
+        // The first run only knows the failing peer.
+        save(&path, vec![failing.clone()]).unwrap();
+        // The failing peer fails again in the second run and becomes unreliable.
+        save(
+            &path,
+            vec![fast.clone(), slow.clone(), failing, unknown.clone()],
+        )
+        .unwrap();
+        let restored = load(&path).unwrap();
+
+        assert_eq!(
+            restored,
+            vec![address(&fast), address(&slow), address(&unknown)]
+        );
+    }
+
+    #[test]
+    fn addresses_without_quality_are_restored() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("peers.json");
+        let peer = peer("/ip4/127.0.0.1/tcp/30333", DEFAULT_APP_SCORE);
+        let address = address(&peer);
+        std::fs::write(
+            &path,
+            serde_json::to_vec(&vec![address.to_string()]).unwrap(),
+        )
+        .unwrap();
+
+        let restored = load(&path).unwrap();
+
+        assert_eq!(restored, vec![address]);
+    }
+}
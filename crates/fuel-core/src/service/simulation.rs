@@ -0,0 +1,310 @@
+//! The deterministic simulation of the node for tests. The producer, the tx pool, and
+//! the importer run against the in-memory database, while the time is controlled by
+//! the test instead of the system clock.
+//!
+//! The simulation relies on the paused time of the `tokio` runtime, so it should run
+//! inside of `#[tokio::test(start_paused = true)]`. The timers of the services and the
+//! timestamps of the produced blocks move only when the test advances the time, or when
+//! the runtime is idle and auto-advances to the next timer.
+//!
+//! ```no_run
+//! use fuel_core::service::{
+//!     config::Trigger,
+//!     simulation::Simulation,
+//! };
+//! use std::time::Duration;
+//!
+//! async fn run() -> anyhow::Result<()> {
+//!     let block_time = Duration::from_secs(10);
+//!     let mut simulation = Simulation::builder()
+//!         .block_production(Trigger::Interval { block_time })
+//!         .start()
+//!         .await?;
+//!
+//!     simulation.advance_time(block_time).await;
+//!     assert_eq!(simulation.take_imported_blocks().len(), 1);
+//!
+//!     simulation.stop().await
+//! }
+//! ```
+
+use crate::service::{
+    adapters::PoAAdapter,
+    config::{
+        GetTime,
+        Trigger,
+    },
+    FuelNode,
+    FuelNodeBuilder,
+};
+use fuel_core_poa::service::Mode;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    services::{
+        block_importer::ImportResult,
+        txpool::InsertionResult,
+    },
+    tai64::Tai64,
+};
+use futures::StreamExt;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    time::Instant,
+};
+
+/// The number of times the simulation yields to the services to process
+/// the pending events.
+const SETTLE_ITERATIONS: usize = 64;
+
+/// The clock that follows the time of the `tokio` runtime. When the time of the
+/// runtime is paused, the clock moves only when the time is advanced.
+#[derive(Debug, Clone, Copy)]
+pub struct MockClock {
+    start_time: Tai64,
+    start_instant: Instant,
+}
+
+impl MockClock {
+    /// Creates the clock that shows the `start_time` at the current instant
+    /// of the `tokio` runtime.
+    pub fn new(start_time: Tai64) -> Self {
+        Self {
+            start_time,
+            start_instant: Instant::now(),
+        }
+    }
+
+    /// Returns the time elapsed since the creation of the clock.
+    pub fn elapsed(&self) -> Duration {
+        self.start_instant.elapsed()
+    }
+}
+
+impl GetTime for MockClock {
+    fn now(&self) -> Tai64 {
+        Tai64(self.start_time.0.saturating_add(self.elapsed().as_secs()))
+    }
+}
+
+/// The builder of the [`Simulation`]. By default, blocks are produced only manually,
+/// and the clock starts at the timestamp of the genesis block.
+pub struct SimulationBuilder {
+    node: FuelNodeBuilder,
+    start_time: Tai64,
+}
+
+impl Default for SimulationBuilder {
+    fn default() -> Self {
+        Self {
+            node: FuelNode::builder().block_production(Trigger::Never),
+            start_time: Tai64::UNIX_EPOCH,
+        }
+    }
+}
+
+impl SimulationBuilder {
+    /// Replaces the builder of the node. The clock of the node is always
+    /// replaced with the [`MockClock`].
+    pub fn node(mut self, node: FuelNodeBuilder) -> Self {
+        self.node = node;
+        self
+    }
+
+    /// Sets the trigger of the block production.
+    pub fn block_production(mut self, trigger: Trigger) -> Self {
+        self.node = self.node.block_production(trigger);
+        self
+    }
+
+    /// Sets the time of the [`MockClock`] at the start of the simulation.
+    pub fn start_time(mut self, start_time: Tai64) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Starts the node with the [`MockClock`].
+    pub async fn start(self) -> anyhow::Result<Simulation> {
+        let clock = MockClock::new(self.start_time);
+        let node = self.node.clock(Arc::new(clock)).start().await?;
+
+        // The importer waits until all listeners drop the previous block before
+        // committing the next one, so the blocks are copied instead of being held.
+        let (sender, blocks) = mpsc::unbounded_channel();
+        let mut imported_blocks = node.blocks();
+        tokio::spawn(async move {
+            while let Some(result) = imported_blocks.next().await {
+                let result = ImportResult {
+                    sealed_block: result.sealed_block.clone(),
+                    tx_status: result.tx_status.clone(),
+                    source: result.source,
+                };
+                if sender.send(result).is_err() {
+                    break
+                }
+            }
+        });
+
+        let simulation = Simulation {
+            node,
+            clock,
+            blocks,
+        };
+        simulation.settle().await;
+        Ok(simulation)
+    }
+}
+
+/// The node with the time controlled by the test.
+pub struct Simulation {
+    node: FuelNode,
+    clock: MockClock,
+    blocks: mpsc::UnboundedReceiver<ImportResult>,
+}
+
+impl Simulation {
+    pub fn builder() -> SimulationBuilder {
+        SimulationBuilder::default()
+    }
+
+    pub fn node(&self) -> &FuelNode {
+        &self.node
+    }
+
+    pub fn clock(&self) -> &MockClock {
+        &self.clock
+    }
+
+    /// Advances the time by the `duration` and waits until the services process
+    /// the fired timers. The timers fire one by one in the order of their deadlines,
+    /// so the services observe the same sequence of events as with the real time.
+    pub async fn advance_time(&mut self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+        self.settle().await;
+    }
+
+    /// Yields to the services until they process the pending events.
+    /// The time doesn't move.
+    pub async fn settle(&self) {
+        for _ in 0..SETTLE_ITERATIONS {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// Submits the transaction into the tx pool and waits until the services
+    /// process it.
+    pub async fn submit(&mut self, tx: Transaction) -> anyhow::Result<InsertionResult> {
+        let result = self.node.tx_submitter().submit(tx).await;
+        self.settle().await;
+        result
+    }
+
+    /// Produces the `number_of_blocks` blocks with the transactions from the tx pool
+    /// and returns them. Works with any trigger.
+    pub async fn produce_blocks(
+        &mut self,
+        number_of_blocks: u32,
+    ) -> anyhow::Result<Vec<ImportResult>> {
+        self.poa()
+            .manually_produce_blocks(None, Mode::Blocks { number_of_blocks })
+            .await?;
+        let mut blocks = Vec::with_capacity(number_of_blocks as usize);
+        while blocks.len() < number_of_blocks as usize {
+            let block = self.next_block().await?;
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// Waits for the next imported block. If the runtime becomes idle, the time
+    /// auto-advances to the next timer of the services.
+    pub async fn next_block(&mut self) -> anyhow::Result<ImportResult> {
+        self.blocks
+            .recv()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("The stream of the imported blocks is closed"))
+    }
+
+    /// Returns the blocks imported since the last call without waiting for new ones.
+    pub fn take_imported_blocks(&mut self) -> Vec<ImportResult> {
+        let mut blocks = vec![];
+        while let Ok(block) = self.blocks.try_recv() {
+            blocks.push(block);
+        }
+        blocks
+    }
+
+    /// Stops the node and waits until all services are stopped.
+    pub async fn stop(self) -> anyhow::Result<()> {
+        self.node.stop().await
+    }
+
+    fn poa(&self) -> &PoAAdapter {
+        &self.node.service().shared.poa_adapter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn block_time(block: &ImportResult) -> Tai64 {
+        block.sealed_block.entity.header().time()
+    }
+
+    fn block_height(block: &ImportResult) -> u32 {
+        **block.sealed_block.entity.header().height()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn interval_trigger_produces_blocks_only_when_time_is_advanced() {
+        let block_time_interval = Duration::from_secs(10);
+        let mut simulation = Simulation::builder()
+            .block_production(Trigger::Interval {
+                block_time: block_time_interval,
+            })
+            .start()
+            .await
+            .unwrap();
+
+        simulation.advance_time(Duration::from_secs(9)).await;
+        assert!(simulation.take_imported_blocks().is_empty());
+
+        simulation.advance_time(Duration::from_secs(1)).await;
+        let blocks = simulation.take_imported_blocks();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(block_height(&blocks[0]), 1);
+        assert_eq!(block_time(&blocks[0]), Tai64::UNIX_EPOCH + 10);
+
+        simulation.advance_time(block_time_interval * 2).await;
+        let blocks = simulation.take_imported_blocks();
+        assert_eq!(
+            blocks.iter().map(block_time).collect::<Vec<_>>(),
+            vec![Tai64::UNIX_EPOCH + 20, Tai64::UNIX_EPOCH + 30]
+        );
+        simulation.stop().await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn manual_production_uses_mock_clock() {
+        let mut simulation = Simulation::builder().start().await.unwrap();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        simulation.submit(tx).await.unwrap();
+
+        simulation.advance_time(Duration::from_secs(60)).await;
+        assert!(simulation.take_imported_blocks().is_empty());
+
+        let blocks = simulation.produce_blocks(1).await.unwrap();
+        assert_eq!(block_height(&blocks[0]), 1);
+        assert_eq!(block_time(&blocks[0]), Tai64::UNIX_EPOCH + 60);
+        assert_eq!(blocks[0].tx_status.len(), 2);
+        simulation.stop().await.unwrap();
+    }
+}
@@ -0,0 +1,408 @@
+//! The export of the snapshot of the chain state triggered by the admin API. The export
+//! runs in the background and writes the chain config with the current state into
+//! the target directory, while the orchestration polls its progress.
+//!
+//! The database doesn't keep the historical state, so only the latest height can be
+//! exported. The blocks imported during the export would make the snapshot inconsistent,
+//! so the export is retried if the height changes, see [`MAX_ATTEMPTS`].
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::Database,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+#[cfg(feature = "encryption")]
+use fuel_core_chain_config::encryption::SnapshotKey;
+use fuel_core_chain_config::{
+    ChainConfig,
+    StateConfig,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    tai64::Tai64,
+};
+use serde::Serialize;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The name of the snapshot file inside of the target directory.
+pub const SNAPSHOT_FILE_NAME: &str = "chain_config.json";
+/// The number of attempts to export the state without the new blocks imported.
+pub const MAX_ATTEMPTS: usize = 3;
+
+/// The step of the running export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Coins,
+    Contracts,
+    Messages,
+    Writing,
+}
+
+/// The status of the last export.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ExportStatus {
+    /// No export was requested since the start of the node.
+    Idle,
+    Running {
+        directory: PathBuf,
+        stage: Stage,
+        attempt: usize,
+        started_at: u64,
+    },
+    Completed {
+        file: PathBuf,
+        height: BlockHeight,
+        bytes: usize,
+        encrypted: bool,
+        started_at: u64,
+        finished_at: u64,
+    },
+    Failed {
+        directory: PathBuf,
+        error: String,
+        started_at: u64,
+        finished_at: u64,
+    },
+}
+
+/// The export request.
+#[derive(Clone, Debug)]
+pub struct ExportRequest {
+    /// The directory of the snapshot file. It is created if it doesn't exist.
+    pub directory: PathBuf,
+    /// The expected height of the snapshot. The export fails if the latest height differs.
+    pub height: Option<BlockHeight>,
+    /// Encrypts the snapshot by the key from the environment, see `SnapshotKey::from_env`.
+    /// Requires the `encryption` feature.
+    pub encrypt: bool,
+}
+
+/// The key of the snapshots when the node is built without the `encryption` feature.
+/// It can't be created, so the encrypted exports fail.
+#[cfg(not(feature = "encryption"))]
+enum SnapshotKey {}
+
+#[cfg(not(feature = "encryption"))]
+impl SnapshotKey {
+    fn from_env() -> anyhow::Result<Option<Self>> {
+        Err(anyhow!(
+            "The node is built without the `encryption` feature of the snapshots"
+        ))
+    }
+
+    fn encrypt(&self, _: &[u8]) -> anyhow::Result<Vec<u8>> {
+        match *self {}
+    }
+}
+
+/// Runs the exports one by one and tracks the status of the last one.
+#[derive(Clone)]
+pub struct SnapshotExporter {
+    database: CombinedDatabase,
+    chain_config: ChainConfig,
+    status: Arc<Mutex<ExportStatus>>,
+}
+
+impl SnapshotExporter {
+    pub fn new(database: CombinedDatabase, chain_config: ChainConfig) -> Self {
+        Self {
+            database,
+            chain_config,
+            status: Arc::new(Mutex::new(ExportStatus::Idle)),
+        }
+    }
+
+    pub fn status(&self) -> ExportStatus {
+        self.status.lock().expect("poisoned").clone()
+    }
+
+    /// Starts the export in the background. Fails if another export is running
+    /// or the request can't be satisfied.
+    pub fn start(&self, request: ExportRequest) -> anyhow::Result<()> {
+        let key = if request.encrypt {
+            Some(SnapshotKey::from_env()?.ok_or_else(|| {
+                anyhow!("The encryption key of the snapshots is not configured")
+            })?)
+        } else {
+            None
+        };
+        if let Some(height) = request.height {
+            let latest = self.latest_height()?;
+            if height != latest {
+                return Err(anyhow!(
+                    "Only the latest height {latest} can be exported, requested {height}"
+                ))
+            }
+        }
+
+        let started_at = now();
+        {
+            let mut status = self.status.lock().expect("poisoned");
+            if matches!(*status, ExportStatus::Running { .. }) {
+                return Err(anyhow!("Another snapshot export is running"))
+            }
+            *status = ExportStatus::Running {
+                directory: request.directory.clone(),
+                stage: Stage::Coins,
+                attempt: 1,
+                started_at,
+            };
+        }
+
+        let exporter = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let result = exporter.export(&request, key.as_ref(), started_at);
+            let status = match result {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::error!("Failed to export the snapshot: {e:?}");
+                    ExportStatus::Failed {
+                        directory: request.directory,
+                        error: format!("{e:#}"),
+                        started_at,
+                        finished_at: now(),
+                    }
+                }
+            };
+            *exporter.status.lock().expect("poisoned") = status;
+        });
+        Ok(())
+    }
+
+    fn export(
+        &self,
+        request: &ExportRequest,
+        key: Option<&SnapshotKey>,
+        started_at: u64,
+    ) -> anyhow::Result<ExportStatus> {
+        let mut attempt = 1;
+        let (state, height) = loop {
+            let height = self.latest_height()?;
+            let state = self.read_state(&request.directory, attempt, started_at)?;
+            if self.latest_height()? == height {
+                break (state, height)
+            }
+            if attempt >= MAX_ATTEMPTS {
+                return Err(anyhow!(
+                    "The new blocks were imported during each of {MAX_ATTEMPTS} attempts"
+                ))
+            }
+            attempt = attempt.saturating_add(1);
+        };
+
+        self.set_stage(&request.directory, Stage::Writing, attempt, started_at);
+        let mut state = StateConfig {
+            height: Some(height),
+            ..state
+        };
+        state.deduplicate_codes();
+        let config = ChainConfig {
+            initial_state: Some(state),
+            ..self.chain_config.clone()
+        };
+        let json = serde_json::to_vec_pretty(&config)?;
+        let bytes = match key {
+            Some(key) => key.encrypt(&json)?,
+            None => json,
+        };
+        let file = write_atomically(&request.directory, &bytes)?;
+        tracing::info!("The snapshot at the height {height} is exported to {file:?}");
+
+        Ok(ExportStatus::Completed {
+            file,
+            height,
+            bytes: bytes.len(),
+            encrypted: key.is_some(),
+            started_at,
+            finished_at: now(),
+        })
+    }
+
+    fn read_state(
+        &self,
+        directory: &Path,
+        attempt: usize,
+        started_at: u64,
+    ) -> anyhow::Result<StateConfig> {
+        let db: &Database = self.database.on_chain();
+        self.set_stage(directory, Stage::Coins, attempt, started_at);
+        let coins = db.get_coin_config()?;
+        self.set_stage(directory, Stage::Contracts, attempt, started_at);
+        let contracts = db.get_contract_config()?;
+        self.set_stage(directory, Stage::Messages, attempt, started_at);
+        let messages = db.get_message_config()?;
+        Ok(StateConfig {
+            coins,
+            contracts,
+            messages,
+            vesting: None,
+            codes: None,
+            height: None,
+        })
+    }
+
+    fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.database.on_chain().latest_height()?)
+    }
+
+    fn set_stage(&self, directory: &Path, stage: Stage, attempt: usize, started_at: u64) {
+        *self.status.lock().expect("poisoned") = ExportStatus::Running {
+            directory: directory.to_path_buf(),
+            stage,
+            attempt,
+            started_at,
+        };
+    }
+}
+
+/// Writes the snapshot into the temporary file first, so the readers of
+/// the directory never see the partially written snapshot. The temporary file
+/// is synced before the rename, so the crash never leaves the truncated snapshot
+/// under the final name, and it is removed if the write fails.
+fn write_atomically(directory: &Path, bytes: &[u8]) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(directory)
+        .with_context(|| format!("Failed to create the directory {directory:?}"))?;
+    let file = directory.join(SNAPSHOT_FILE_NAME);
+    let temporary = temporary_file(directory);
+    let result = write_and_sync(&temporary, bytes)
+        .with_context(|| format!("Failed to write the snapshot to {temporary:?}"))
+        .and_then(|_| {
+            std::fs::rename(&temporary, &file)
+                .with_context(|| format!("Failed to move the snapshot to {file:?}"))
+        });
+    if let Err(e) = result {
+        // The leftover of the interrupted export is removed by the next export.
+        let _ = std::fs::remove_file(&temporary);
+        return Err(e)
+    }
+    // Persists the rename. Not supported on all platforms, so the error is ignored.
+    if let Ok(directory) = std::fs::File::open(directory) {
+        let _ = directory.sync_all();
+    }
+    Ok(file)
+}
+
+fn temporary_file(directory: &Path) -> PathBuf {
+    directory.join(format!("{SNAPSHOT_FILE_NAME}.tmp"))
+}
+
+fn write_and_sync(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(bytes)?;
+    file.sync_all()
+}
+
+fn now() -> u64 {
+    Tai64::now().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::{
+        Config,
+        FuelService,
+    };
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    async fn wait_until_finished(exporter: &SnapshotExporter) -> ExportStatus {
+        loop {
+            let status = exporter.status();
+            if !matches!(status, ExportStatus::Running { .. }) {
+                return status
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    async fn exporter() -> (FuelService, SnapshotExporter) {
+        let node = FuelService::new_node(Config::local_node()).await.unwrap();
+        let exporter = SnapshotExporter::new(
+            node.shared.database.clone(),
+            node.shared.config.chain_conf.clone(),
+        );
+        (node, exporter)
+    }
+
+    #[tokio::test]
+    async fn export_writes_snapshot_into_directory() {
+        let (_node, exporter) = exporter().await;
+        let directory = TempDir::new().unwrap();
+        assert_eq!(exporter.status(), ExportStatus::Idle);
+
+        exporter
+            .start(ExportRequest {
+                directory: directory.path().to_path_buf(),
+                height: None,
+                encrypt: false,
+            })
+            .unwrap();
+        let status = wait_until_finished(&exporter).await;
+
+        let ExportStatus::Completed { file, height, .. } = status else {
+            panic!("Unexpected status {status:?}")
+        };
+        let config: ChainConfig = file.to_str().unwrap().parse().unwrap();
+        let state = config.initial_state.unwrap();
+        assert_eq!(state.height, Some(height));
+        assert!(state.coins.is_some_and(|coins| !coins.is_empty()));
+        assert_eq!(config.chain_name, ChainConfig::local_testnet().chain_name);
+    }
+
+    #[test]
+    fn failed_write_leaves_no_files() {
+        let dir = TempDir::new().unwrap();
+        let directory = dir.path();
+        // The snapshot can't be moved over the non-empty directory.
+        std::fs::create_dir_all(directory.join(SNAPSHOT_FILE_NAME).join("nested"))
+            .unwrap();
+
+        let result = write_atomically(directory, b"{}");
+
+        assert!(result.is_err());
+        assert!(!temporary_file(directory).exists());
+    }
+
+    #[test]
+    fn leftover_of_interrupted_export_is_replaced() {
+        let dir = TempDir::new().unwrap();
+        let directory = dir.path();
+        std::fs::write(temporary_file(directory), b"partial").unwrap();
+
+        let file = write_atomically(directory, b"{}").unwrap();
+
+        assert_eq!(std::fs::read(file).unwrap(), b"{}");
+        assert!(!temporary_file(directory).exists());
+    }
+
+    #[tokio::test]
+    async fn export_of_not_latest_height_is_rejected() {
+        let (_node, exporter) = exporter().await;
+
+        let result = exporter.start(ExportRequest {
+            directory: TempDir::new().unwrap().path().to_path_buf(),
+            height: Some(10.into()),
+            encrypt: false,
+        });
+
+        assert!(result.is_err());
+        assert_eq!(exporter.status(), ExportStatus::Idle);
+    }
+}
@@ -23,6 +23,12 @@ use crate::{
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_producer::block_producer::DryRunCache;
+use fuel_core_types::{
+    fuel_tx::Input,
+    secrecy::ExposeSecret,
+    services::predicate_cache::PredicateCache,
+};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -51,23 +57,33 @@ pub fn init_sub_services(
         .on_chain()
         .get_current_block()?
         .map(|block| block.header().clone())
-        .unwrap_or({
-            let block = create_genesis_block(config);
-            block.header().clone()
-        });
+        .map(Ok)
+        .unwrap_or_else(|| {
+            create_genesis_block(config).map(|block| block.header().clone())
+        })?;
     let last_height = *last_block_header.height();
 
+    let predicate_cache = (config.vm.predicate_cache_size > 0)
+        .then(|| PredicateCache::new(config.vm.predicate_cache_size));
+
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
         fuel_core_executor::Config {
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
-            coinbase_recipient: config
-                .block_producer
-                .coinbase_recipient
-                .unwrap_or_default(),
+            block_gas_limit: Some(config.chain_conf.block_gas_limit),
+            // The block producer sets the recipient of each block.
+            coinbase_recipient: Default::default(),
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            execution_threads: config.vm.execution_threads,
+            record_state_diff: config.vm.record_state_diff,
+            profiler: config.vm.profiler.clone(),
+            receipts_limits: config.vm.receipts_limits,
+            skip_signatures_until: config.vm.skip_signatures_until,
+            predicate_cache: predicate_cache.clone(),
+            #[cfg(feature = "fault-injection")]
+            fault_injector: config.vm.fault_injector.clone(),
         },
     );
 
@@ -81,14 +97,20 @@ pub fn init_sub_services(
     );
 
     #[cfg(feature = "relayer")]
-    let relayer_service = if let Some(config) = &config.relayer {
-        Some(fuel_core_relayer::new_service(
-            database.relayer().clone(),
-            config.clone(),
-        )?)
-    } else {
-        None
-    };
+    let relayer_service =
+        if let Some(relayer_config) = &config.relayer {
+            Some(
+                fuel_core_relayer::new_service(
+                    database.relayer().clone(),
+                    relayer_config.clone(),
+                )?
+                .with_restart_policy(config.restart_policy_of(
+                    crate::service::config::RestartableService::Relayer,
+                )),
+            )
+        } else {
+            None
+        };
 
     let relayer_adapter = MaybeRelayerAdapter {
         #[cfg(feature = "relayer")]
@@ -101,13 +123,31 @@ pub fn init_sub_services(
     };
 
     #[cfg(feature = "p2p")]
-    let mut network = config.p2p.clone().map(|p2p_config| {
+    let mut network = config.p2p.clone().map(|mut p2p_config| {
+        if let Some(path) = &config.peers_persistence_path {
+            match super::peers_persistence::load(path) {
+                Ok(peers) => {
+                    // The best peers from the previous runs are dialed first.
+                    let limit = usize::try_from(p2p_config.max_peers_connected)
+                        .unwrap_or(usize::MAX);
+                    let peers: Vec<_> = peers.into_iter().take(limit).collect();
+                    tracing::info!("Restored {} addresses of the peers", peers.len());
+                    p2p_config.bootstrap_nodes.splice(0..0, peers);
+                }
+                Err(err) => {
+                    tracing::error!("Failed to restore the addresses of the peers: {err}")
+                }
+            }
+        }
         fuel_core_p2p::service::new_service(
             config.chain_conf.consensus_parameters.chain_id,
             p2p_config,
             database.on_chain().clone(),
             importer_adapter.clone(),
         )
+        .with_restart_policy(
+            config.restart_policy_of(crate::service::config::RestartableService::P2P),
+        )
     });
 
     #[cfg(feature = "p2p")]
@@ -132,22 +172,41 @@ pub fn init_sub_services(
     #[cfg(not(feature = "p2p"))]
     let p2p_adapter = P2PAdapter::new();
 
+    // The read-only node neither receives nor gossips the transactions.
+    let txpool_p2p_adapter = if config.mode.is_read_only() {
+        p2p_adapter.disconnected()
+    } else {
+        p2p_adapter.clone()
+    };
     let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
+        fuel_core_txpool::Config {
+            predicate_cache,
+            ..config.txpool.clone()
+        },
         database.on_chain().clone(),
         importer_adapter.clone(),
-        p2p_adapter.clone(),
+        txpool_p2p_adapter,
         last_height,
     );
-    let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    let tx_pool_adapter = TxPoolAdapter::new(
+        txpool.shared.clone(),
+        config.block_producer.tx_selector.clone(),
+        config.block_producer.metrics,
+    );
 
+    let mut producer_config = config.block_producer.clone();
+    producer_config.signer = config
+        .consensus_key
+        .as_ref()
+        .map(|key| Input::owner(&key.expose_secret().public_key()));
     let block_producer = fuel_core_producer::Producer {
-        config: config.block_producer.clone(),
+        config: producer_config,
         view_provider: database.on_chain().clone(),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
         relayer: Box::new(relayer_adapter.clone()),
         lock: Mutex::new(()),
+        dry_run_cache: DryRunCache::new(config.block_producer.dry_run_cache_size),
     };
     let producer_adapter = BlockProducerAdapter::new(block_producer);
 
@@ -159,6 +218,11 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    if config.mode.is_read_only() {
+        production_enabled = false;
+        tracing::info!("Disabled block production because of the read-only mode");
+    }
+
     let poa = (production_enabled).then(|| {
         fuel_core_poa::new_service(
             &last_block_header,
@@ -182,7 +246,17 @@ pub fn init_sub_services(
             relayer_adapter,
         ),
         config.sync,
-    )?;
+    )?
+    .with_restart_policy(
+        config.restart_policy_of(crate::service::config::RestartableService::Sync),
+    );
+
+    // The sync service runs only along with the network.
+    #[cfg(feature = "p2p")]
+    let graphql_p2p_adapter =
+        p2p_adapter.with_sync(network.as_ref().map(|_| sync.shared.clone()));
+    #[cfg(not(feature = "p2p"))]
+    let graphql_p2p_adapter = p2p_adapter;
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let schema = crate::schema::dap::init(
@@ -192,9 +266,22 @@ pub fn init_sub_services(
     )
     .data(database.on_chain().clone());
 
+    let writes_pause = super::disk_usage::WritesPause::default();
+    let disk_usage = super::disk_usage::new_service(
+        database.clone(),
+        config.disk_usage.clone(),
+        writes_pause.clone(),
+    );
+
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         importer_adapter.clone(),
+        tx_pool_adapter.clone(),
         database.off_chain().clone(),
+        database.on_chain().clone(),
+        config.tx_status_archive_retention,
+        config.block_body_retention,
+        config.block_importer.finality,
+        writes_pause,
     );
 
     let graphql_config = GraphQLConfig {
@@ -202,12 +289,17 @@ pub fn init_sub_services(
         utxo_validation: config.utxo_validation,
         debug: config.debug,
         vm_backtrace: config.vm.backtrace,
-        min_gas_price: config.txpool.min_gas_price,
         max_tx: config.txpool.max_tx,
         max_depth: config.txpool.max_depth,
         chain_name: config.chain_conf.chain_name.clone(),
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        block_gas_limit: config.chain_conf.block_gas_limit,
+        consensus: config.chain_conf.consensus.clone(),
         consensus_key: config.consensus_key.clone(),
+        read_only: config.mode.is_read_only(),
+        cursor_expiry: config.cursor_expiry,
+        dry_run_limits: config.dry_run_limits.clone(),
+        finality: config.block_importer.finality,
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -218,11 +310,22 @@ pub fn init_sub_services(
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
-        Box::new(p2p_adapter),
+        Box::new(graphql_p2p_adapter),
+        Box::new(importer_adapter.clone()),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
 
+    let fast_sync = match config.fast_sync_unsafe_until {
+        Some(checkpoint) => super::fast_sync::new_service(
+            database.clone(),
+            checkpoint,
+            last_height,
+            importer_adapter.events(),
+        )?,
+        None => None,
+    };
+
     let shared = SharedState {
         poa_adapter,
         txpool: txpool.shared.clone(),
@@ -244,6 +347,13 @@ pub fn init_sub_services(
         Box::new(txpool),
     ];
 
+    if let Some(admin) = config.admin.clone() {
+        services.insert(
+            1,
+            Box::new(super::admin::new_service(admin, shared.clone())?),
+        );
+    }
+
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
@@ -262,6 +372,11 @@ pub fn init_sub_services(
     }
 
     services.push(Box::new(graphql_worker));
+    services.push(Box::new(disk_usage));
+
+    if let Some(fast_sync) = fast_sync {
+        services.push(Box::new(fast_sync));
+    }
 
     Ok((services, shared))
 }
@@ -20,9 +20,14 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
+    path::{
+        Path,
+        PathBuf,
+    },
     sync::Arc,
 };
 
+pub mod cached;
 pub mod in_memory;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
@@ -54,6 +59,15 @@ where
     }
 }
 
+impl<Description> From<Arc<cached::CachedStore<Description>>> for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<cached::CachedStore<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
 impl<Description> From<Arc<MemoryStore<Description>>> for DataSource<Description>
 where
     Description: DatabaseDescription,
@@ -87,4 +101,38 @@ pub trait TransactableStorage:
     IteratorableStore + BatchOperations + Debug + Send + Sync
 {
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Creates the consistent copy of the storage in the `path` directory.
+    fn backup(&self, path: &Path) -> DatabaseResult<()> {
+        Err(anyhow::anyhow!(
+            "The storage doesn't support the backup into {}",
+            path.display()
+        )
+        .into())
+    }
+
+    /// Compacts the data of all columns, reclaiming the space of the removed entries.
+    /// The storages without the compaction have nothing to do.
+    fn compact(&self) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Returns the approximate size in bytes of the data of each column.
+    /// The storages without the size accounting return nothing.
+    fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, u64)>> {
+        Ok(vec![])
+    }
+
+    /// Returns the directory with the data of the storage, if it is stored on the disk.
+    fn path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// Enables or disables the write-ahead log of the storage. The writes without
+    /// the log are faster, but they are lost if the process crashes before the flush.
+    /// The storage flushes the unlogged data when the durability is restored.
+    /// The storages without the log have nothing to do.
+    fn set_durable(&self, _durable: bool) -> DatabaseResult<()> {
+        Ok(())
+    }
 }
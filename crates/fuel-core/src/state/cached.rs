@@ -0,0 +1,375 @@
+//! The LRU read cache in front of the hot columns of the storage.
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Result as DatabaseResult,
+    },
+    state::{
+        DataSourceInner,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KVItem,
+        KeyValueStore,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use lru::LruCache;
+use std::{
+    fmt::Debug,
+    num::NonZeroUsize,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+/// The key of the cached value: the id of the column and the key.
+type CacheKey = (u32, Vec<u8>);
+
+#[derive(Debug)]
+struct Cache {
+    /// The values of the keys. The `None` means that the key doesn't exist.
+    entries: LruCache<CacheKey, Option<Value>>,
+    /// Increased by each write into the cached columns. The value read from
+    /// the storage is cached only if there were no writes during the reading.
+    generation: u64,
+}
+
+/// The storage that caches the values of the `columns` read from the `inner` storage.
+/// The written keys are invalidated after each write, including the commit of the block.
+#[derive(Debug)]
+pub struct CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    inner: DataSourceInner<Description::Column>,
+    /// The ids of the cached columns.
+    columns: Vec<u32>,
+    cache: Mutex<Cache>,
+}
+
+impl<Description> CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn new(
+        inner: DataSourceInner<Description::Column>,
+        columns: Vec<Description::Column>,
+        capacity: NonZeroUsize,
+    ) -> Self {
+        Self {
+            inner,
+            columns: columns.iter().map(StorageColumn::id).collect(),
+            cache: Mutex::new(Cache {
+                entries: LruCache::new(capacity),
+                generation: 0,
+            }),
+        }
+    }
+
+    fn is_cached(&self, column: Description::Column) -> bool {
+        self.columns.contains(&column.id())
+    }
+
+    fn invalidate(&self, keys: impl IntoIterator<Item = CacheKey>) {
+        let mut cache = self.cache.lock().expect("poisoned");
+        for key in keys {
+            cache.entries.pop(&key);
+        }
+        cache.generation = cache.generation.wrapping_add(1);
+    }
+
+    fn invalidate_key(&self, key: &[u8], column: Description::Column) {
+        if self.is_cached(column) {
+            self.invalidate([(column.id(), key.to_vec())]);
+        }
+    }
+}
+
+impl<Description> KeyValueStore for CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        let result = self.inner.replace(key, column, value);
+        self.invalidate_key(key, column);
+        result
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        let result = self.inner.write(key, column, buf);
+        self.invalidate_key(key, column);
+        result
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let result = self.inner.take(key, column);
+        self.invalidate_key(key, column);
+        result
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        let result = self.inner.delete(key, column);
+        self.invalidate_key(key, column);
+        result
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        if self.is_cached(column) {
+            Ok(self.get(key, column)?.map(|value| value.len()))
+        } else {
+            self.inner.size_of_value(key, column)
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        if !self.is_cached(column) {
+            return self.inner.get(key, column)
+        }
+
+        let cache_key = (column.id(), key.to_vec());
+        let generation = {
+            let mut cache = self.cache.lock().expect("poisoned");
+            if let Some(value) = cache.entries.get(&cache_key) {
+                database_metrics().state_cache_hits.inc();
+                return Ok(value.clone())
+            }
+            cache.generation
+        };
+        database_metrics().state_cache_misses.inc();
+
+        let value = self.inner.get(key, column)?;
+        let mut cache = self.cache.lock().expect("poisoned");
+        if cache.generation == generation {
+            cache.entries.put(cache_key, value.clone());
+        }
+        Ok(value)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        if !self.is_cached(column) {
+            return self.inner.read(key, column, buf)
+        }
+        self.get(key, column)?
+            .map(|value| {
+                let read = value.len();
+                if read != buf.len() {
+                    return Err(anyhow::anyhow!(
+                        "Buffer size is not equal to the value size"
+                    )
+                    .into())
+                }
+                buf.copy_from_slice(value.as_ref());
+                Ok(read)
+            })
+            .transpose()
+    }
+}
+
+impl<Description> IteratorableStore for CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl<Description> BatchOperations for CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let mut written = vec![];
+        let result = self
+            .inner
+            .batch_write(&mut entries.inspect(|(key, column, _)| {
+                if self.is_cached(*column) {
+                    written.push((column.id(), key.clone()));
+                }
+            }));
+        self.invalidate(written);
+        result
+    }
+}
+
+impl<Description> TransactableStorage for CachedStore<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        let result = self.inner.flush();
+        let mut cache = self.cache.lock().expect("poisoned");
+        cache.entries.clear();
+        cache.generation = cache.generation.wrapping_add(1);
+        result
+    }
+
+    fn backup(&self, path: &Path) -> DatabaseResult<()> {
+        self.inner.backup(path)
+    }
+
+    fn compact(&self) -> DatabaseResult<()> {
+        self.inner.compact()
+    }
+
+    fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, u64)>> {
+        self.inner.column_sizes()
+    }
+
+    fn path(&self) -> Option<PathBuf> {
+        self.inner.path()
+    }
+
+    fn set_durable(&self, durable: bool) -> DatabaseResult<()> {
+        self.inner.set_durable(durable)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use fuel_core_storage::column::Column;
+    use std::sync::Arc;
+
+    fn cached_store() -> (Arc<MemoryStore<OnChain>>, CachedStore<OnChain>) {
+        let inner = Arc::new(MemoryStore::<OnChain>::default());
+        let store = CachedStore::new(
+            inner.clone(),
+            vec![Column::ContractsState],
+            NonZeroUsize::new(2).unwrap(),
+        );
+        (inner, store)
+    }
+
+    fn value(byte: u8) -> Value {
+        Arc::new(vec![byte])
+    }
+
+    #[test]
+    fn cached_value_is_read_without_storage() {
+        let (inner, store) = cached_store();
+        inner.put(&[1], Column::ContractsState, value(1)).unwrap();
+        assert_eq!(
+            store.get(&[1], Column::ContractsState).unwrap(),
+            Some(value(1))
+        );
+
+        // The change bypassing the cache is not visible.
+        inner.put(&[1], Column::ContractsState, value(2)).unwrap();
+
+        assert_eq!(
+            store.get(&[1], Column::ContractsState).unwrap(),
+            Some(value(1))
+        );
+    }
+
+    #[test]
+    fn uncached_columns_are_read_from_storage() {
+        let (inner, store) = cached_store();
+        inner.put(&[1], Column::Coins, value(1)).unwrap();
+        assert_eq!(store.get(&[1], Column::Coins).unwrap(), Some(value(1)));
+
+        inner.put(&[1], Column::Coins, value(2)).unwrap();
+
+        assert_eq!(store.get(&[1], Column::Coins).unwrap(), Some(value(2)));
+    }
+
+    #[test]
+    fn batch_write_invalidates_written_keys() {
+        let (_, store) = cached_store();
+        store.put(&[1], Column::ContractsState, value(1)).unwrap();
+        assert_eq!(
+            store.get(&[1], Column::ContractsState).unwrap(),
+            Some(value(1))
+        );
+        assert_eq!(store.get(&[2], Column::ContractsState).unwrap(), None);
+
+        store
+            .batch_write(
+                &mut [
+                    (
+                        vec![1],
+                        Column::ContractsState,
+                        WriteOperation::Insert(value(2)),
+                    ),
+                    (
+                        vec![2],
+                        Column::ContractsState,
+                        WriteOperation::Insert(value(3)),
+                    ),
+                ]
+                .into_iter(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            store.get(&[1], Column::ContractsState).unwrap(),
+            Some(value(2))
+        );
+        assert_eq!(
+            store.get(&[2], Column::ContractsState).unwrap(),
+            Some(value(3))
+        );
+    }
+
+    #[test]
+    fn removed_key_is_not_returned() {
+        let (_, store) = cached_store();
+        store.put(&[1], Column::ContractsState, value(1)).unwrap();
+        assert!(store.exists(&[1], Column::ContractsState).unwrap());
+
+        store.delete(&[1], Column::ContractsState).unwrap();
+
+        assert!(!store.exists(&[1], Column::ContractsState).unwrap());
+    }
+}
@@ -212,6 +212,21 @@ where
         }
         Ok(())
     }
+
+    fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, u64)>> {
+        let sizes = enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let size = self.inner[column.as_usize()]
+                    .lock()
+                    .expect("poisoned")
+                    .iter()
+                    .map(|(key, value)| key.len().saturating_add(value.len()) as u64)
+                    .fold(0u64, u64::saturating_add);
+                (column.name(), size)
+            })
+            .collect();
+        Ok(sizes)
+    }
 }
 
 #[cfg(test)]
@@ -25,22 +25,26 @@ use fuel_core_storage::{
         Value,
         WriteOperation,
     },
+    Error as StorageError,
     Result as StorageResult,
 };
 use rand::RngCore;
 use rocksdb::{
+    checkpoint::Checkpoint,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
     ColumnFamilyDescriptor,
     DBCompressionType,
     DBWithThreadMode,
+    ErrorKind,
     IteratorMode,
     MultiThreaded,
     Options,
     ReadOptions,
     SliceTransform,
     WriteBatch,
+    WriteOptions,
 };
 use std::{
     env,
@@ -50,7 +54,13 @@ use std::{
         Path,
         PathBuf,
     },
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
@@ -93,6 +103,8 @@ impl Drop for ShallowTempDir {
 #[derive(Debug)]
 pub struct RocksDb<Description> {
     db: DB,
+    /// The writes skip the write-ahead log if the flag is not set.
+    durable: AtomicBool,
     _marker: core::marker::PhantomData<Description>,
 }
 
@@ -186,17 +198,39 @@ where
         .map_err(|e| DatabaseError::Other(e.into()))?;
         let rocks_db = RocksDb {
             db,
+            durable: AtomicBool::new(true),
             _marker: Default::default(),
         };
         Ok(rocks_db)
     }
 
+    /// Flushes the memtables of all columns into the SST files. The `DB::flush` only
+    /// flushes the default column family, while the data lives in the columns.
+    fn flush_memtables(&self) -> DatabaseResult<()> {
+        for column in enum_iterator::all::<Description::Column>() {
+            self.db.flush_cf(&self.cf(column)).map_err(|e| {
+                anyhow::anyhow!(
+                    "Unable to flush SST files of the column {}: {}",
+                    column.name(),
+                    e
+                )
+            })?;
+        }
+        Ok(())
+    }
+
     fn cf(&self, column: Description::Column) -> Arc<BoundColumnFamily> {
         self.db
             .cf_handle(&Self::col_name(column))
             .expect("invalid column state")
     }
 
+    fn write_options(&self) -> WriteOptions {
+        let mut options = WriteOptions::default();
+        options.disable_wal(!self.durable.load(Ordering::Relaxed));
+        options
+    }
+
     fn col_name(column: Description::Column) -> String {
         format!("col-{}", column.as_usize())
     }
@@ -311,7 +345,7 @@ where
     ) -> StorageResult<usize> {
         let r = buf.len();
         self.db
-            .put_cf(&self.cf(column), key, buf)
+            .put_cf_opt(&self.cf(column), key, buf, &self.write_options())
             .map_err(|e| DatabaseError::Other(e.into()))?;
 
         database_metrics().write_meter.inc();
@@ -322,7 +356,7 @@ where
 
     fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
         self.db
-            .delete_cf(&self.cf(column), key)
+            .delete_cf_opt(&self.cf(column), key, &self.write_options())
             .map_err(|e| DatabaseError::Other(e.into()).into())
     }
 
@@ -336,7 +370,7 @@ where
         Ok(self
             .db
             .get_pinned_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map_err(|e| read_error(e, column, key))?
             .map(|value| value.len()))
     }
 
@@ -346,7 +380,7 @@ where
         let value = self
             .db
             .get_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?;
+            .map_err(|e| read_error(e, column, key))?;
 
         if let Some(value) = &value {
             database_metrics().bytes_read.observe(value.len() as f64);
@@ -366,7 +400,7 @@ where
         let r = self
             .db
             .get_pinned_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map_err(|e| read_error(e, column, key))?
             .map(|value| {
                 let read = value.len();
                 std::io::Write::write_all(&mut buf, value.as_ref())
@@ -481,7 +515,7 @@ where
             .observe(batch.size_in_bytes() as f64);
 
         self.db
-            .write(batch)
+            .write_opt(batch, &self.write_options())
             .map_err(|e| DatabaseError::Other(e.into()).into())
     }
 }
@@ -490,6 +524,61 @@ impl<Description> TransactableStorage for RocksDb<Description>
 where
     Description: DatabaseDescription,
 {
+    fn backup(&self, path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(&path))
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Unable to create the backup at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+        Ok(())
+    }
+
+    fn compact(&self) -> DatabaseResult<()> {
+        for column in enum_iterator::all::<Description::Column>() {
+            self.db
+                .compact_range_cf(&self.cf(column), None::<&[u8]>, None::<&[u8]>);
+        }
+        Ok(())
+    }
+
+    fn column_sizes(&self) -> DatabaseResult<Vec<(&'static str, u64)>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let cf = self.cf(column);
+                let mut size = 0u64;
+                for property in [
+                    "rocksdb.total-sst-files-size",
+                    "rocksdb.size-all-mem-tables",
+                ] {
+                    let value = self
+                        .db
+                        .property_int_value_cf(&cf, property)
+                        .map_err(|e| DatabaseError::Other(e.into()))?;
+                    size = size.saturating_add(value.unwrap_or_default());
+                }
+                Ok((column.name(), size))
+            })
+            .collect()
+    }
+
+    fn path(&self) -> Option<PathBuf> {
+        Some(self.db.path().to_path_buf())
+    }
+
+    fn set_durable(&self, durable: bool) -> DatabaseResult<()> {
+        let was_durable = self.durable.swap(durable, Ordering::Relaxed);
+        if durable && !was_durable {
+            // The memtables contain the data not written to the log.
+            self.flush_memtables()?;
+        }
+        Ok(())
+    }
+
     fn flush(&self) -> DatabaseResult<()> {
         self.db
             .flush_wal(true)
@@ -502,6 +591,24 @@ where
 }
 
 /// The `None` means overflow, so there is not following prefix.
+/// Converts the error of reading the `key`. RocksDB verifies the checksums of the blocks
+/// on each read, so the damaged data is reported as [`StorageError::Corruption`].
+fn read_error<Column: StorageColumn>(
+    error: rocksdb::Error,
+    column: Column,
+    key: &[u8],
+) -> StorageError {
+    if error.kind() == ErrorKind::Corruption {
+        tracing::error!(
+            "The RocksDB data of the `{}` is damaged: {error}",
+            column.name()
+        );
+        StorageError::corruption(column, key)
+    } else {
+        DatabaseError::Other(error.into()).into()
+    }
+}
+
 fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
     for byte in prefix.iter_mut().rev() {
         if let Some(new_byte) = byte.checked_add(1) {
@@ -605,6 +712,40 @@ mod tests {
         assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
     }
 
+    #[test]
+    fn unlogged_writes_survive_restoring_durability() {
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new(vec![1, 2, 3]);
+        let tmp_dir = TempDir::new().unwrap();
+
+        let db = RocksDb::<OnChain>::default_open(tmp_dir.path(), None).unwrap();
+        db.set_durable(false).unwrap();
+        db.put(&key, Column::Metadata, value.clone()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), Some(value.clone()));
+        db.set_durable(true).unwrap();
+        // Simulates the crash: the database is not closed, so RocksDB doesn't flush
+        // the memtables on close, and only the SST files and the log are on the disk.
+        core::mem::forget(db);
+
+        let columns = enum_iterator::all::<Column>()
+            .map(RocksDb::<OnChain>::col_name)
+            .collect::<Vec<_>>();
+        let read_only = DB::open_cf_for_read_only(
+            &Options::default(),
+            tmp_dir.path(),
+            columns,
+            false,
+        )
+        .unwrap();
+        let cf = read_only
+            .cf_handle(&RocksDb::<OnChain>::col_name(Column::Metadata))
+            .unwrap();
+        assert_eq!(
+            read_only.get_cf(&cf, &key).unwrap(),
+            Some(value.as_ref().clone())
+        );
+    }
+
     #[test]
     fn can_use_unit_value() {
         let key = vec![0x00];
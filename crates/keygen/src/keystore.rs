@@ -0,0 +1,157 @@
+//! Password-encrypted keystore files in the Web3 Secret Storage format. The secret
+//! key is encrypted with AES-128-CTR by the key derived from the password by scrypt.
+
+use crate::{
+    new_key,
+    serialize_option_to_string,
+    KeyType,
+};
+use anyhow::Context;
+use fuel_core_types::{
+    fuel_crypto::{
+        rand::{
+            prelude::StdRng,
+            SeedableRng,
+        },
+        SecretKey,
+    },
+    fuel_types::Address,
+};
+use libp2p_identity::PeerId;
+use serde::Serialize;
+use std::{
+    io::{
+        stderr,
+        stdin,
+        IsTerminal,
+        Write,
+    },
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+use termion::input::TermRead;
+
+/// The environment variable with the password of the keystore files.
+pub const KEYSTORE_PASSWORD_ENV: &str = "FUEL_KEYSTORE_PASSWORD";
+
+#[derive(Clone, Debug, Serialize)]
+pub struct NewKeystoreResponse {
+    keystore: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<Address>,
+    #[serde(
+        serialize_with = "serialize_option_to_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    peer_id: Option<PeerId>,
+    #[serde(rename = "type")]
+    typ: KeyType,
+}
+
+/// Generates a new key and saves it into the encrypted keystore file inside of the `dir`.
+/// The response doesn't contain the secret key.
+pub fn new_keystore(
+    key_type: KeyType,
+    dir: &Path,
+    password: &str,
+) -> anyhow::Result<NewKeystoreResponse> {
+    let key = new_key(key_type)?;
+    let id = key
+        .address
+        .map(|address| address.to_string())
+        .or_else(|| key.peer_id.map(|peer_id| peer_id.to_string()))
+        .unwrap_or_default();
+    let name = format!("{}-{id}.json", <&str>::from(key_type));
+    let keystore = encrypt_key(&key.secret, dir, &name, password)?;
+
+    Ok(NewKeystoreResponse {
+        keystore,
+        address: key.address,
+        peer_id: key.peer_id,
+        typ: key.typ,
+    })
+}
+
+/// Encrypts the `secret` with the `password` and saves it into the `dir` as the
+/// keystore file with the `name`. Returns the path to the keystore file.
+pub fn encrypt_key(
+    secret: &SecretKey,
+    dir: &Path,
+    name: &str,
+    password: &str,
+) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let mut rng = StdRng::from_entropy();
+    eth_keystore::encrypt_key(dir, &mut rng, secret.deref(), password, Some(name))
+        .context("Failed to encrypt the keystore")?;
+    Ok(dir.join(name))
+}
+
+/// Decrypts the secret key from the keystore file at the `path` with the `password`.
+pub fn decrypt_key(path: &Path, password: &str) -> anyhow::Result<SecretKey> {
+    let secret = eth_keystore::decrypt_key(path, password)
+        .with_context(|| format!("Failed to decrypt the keystore {}", path.display()))?;
+    SecretKey::try_from(secret.as_slice()).map_err(|_| {
+        anyhow::anyhow!("The keystore {} has an invalid key", path.display())
+    })
+}
+
+/// Returns `true` if the file at the `path` is the keystore file.
+pub fn is_keystore(path: &Path) -> bool {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .is_some_and(|json| json.get("crypto").is_some())
+}
+
+/// Returns the password of the keystore files from the `password_file` if it is set,
+/// otherwise from the [`KEYSTORE_PASSWORD_ENV`] environment variable. If neither is
+/// set, prompts the password on the terminal.
+pub fn read_password(password_file: Option<&Path>) -> anyhow::Result<String> {
+    if let Some(password_file) = password_file {
+        let password = std::fs::read_to_string(password_file).with_context(|| {
+            format!(
+                "Failed to read the password file {}",
+                password_file.display()
+            )
+        })?;
+        return Ok(password.trim_end_matches(['\n', '\r']).to_string())
+    }
+
+    if let Ok(password) = std::env::var(KEYSTORE_PASSWORD_ENV) {
+        return Ok(password)
+    }
+
+    if !stdin().is_terminal() {
+        anyhow::bail!(
+            "The keystore password is not provided via the password file or the \
+            `{KEYSTORE_PASSWORD_ENV}` environment variable"
+        )
+    }
+    let mut stderr = stderr();
+    write!(stderr, "Keystore password: ")?;
+    stderr.flush()?;
+    let password = stdin().read_passwd(&mut stderr)?;
+    writeln!(stderr)?;
+    password.ok_or_else(|| anyhow::anyhow!("The keystore password is not provided"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decrypted_key_matches_encrypted() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1));
+
+        let path = encrypt_key(&secret, dir.path(), "key.json", "password").unwrap();
+
+        assert!(is_keystore(&path));
+        assert_eq!(decrypt_key(&path, "password").unwrap(), secret);
+        assert!(decrypt_key(&path, "wrong").is_err());
+    }
+}
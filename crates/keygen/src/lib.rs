@@ -24,6 +24,10 @@ use std::{
     str::FromStr,
 };
 
+mod keystore;
+
+pub use keystore::*;
+
 #[derive(Clone, Copy, Debug, Default, Serialize, ValueEnum)]
 #[serde(rename_all = "kebab-case")]
 pub enum KeyType {
@@ -0,0 +1,134 @@
+use crate::timing_buckets;
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+    registry::Registry,
+};
+use std::sync::{
+    atomic::AtomicU64,
+    OnceLock,
+};
+
+pub struct BlockProductionMetrics {
+    pub registry: Registry,
+    pub gas_used: Gauge,
+    pub gas_target: Gauge,
+    pub gas_utilization: Gauge<f64, AtomicU64>,
+    pub state_writes: Gauge,
+    pub execution_duration: Histogram,
+    pub selection_duration: Histogram,
+    pub signing_duration: Histogram,
+    pub broadcast_duration: Histogram,
+    pub slot_delay: Histogram,
+    pub late_blocks: Counter,
+    pub missed_slots: Counter,
+}
+
+impl Default for BlockProductionMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let gas_used = Gauge::default();
+        let gas_target = Gauge::default();
+        let gas_utilization = Gauge::default();
+        let state_writes = Gauge::default();
+        let execution_duration = Histogram::new(timing_buckets().iter().cloned());
+        let selection_duration = Histogram::new(timing_buckets().iter().cloned());
+        let signing_duration = Histogram::new(timing_buckets().iter().cloned());
+        let broadcast_duration = Histogram::new(timing_buckets().iter().cloned());
+        let slot_delay = Histogram::new(timing_buckets().iter().cloned());
+        let late_blocks = Counter::default();
+        let missed_slots = Counter::default();
+
+        registry.register(
+            "block_production_gas_used",
+            "The gas used by transactions of the latest produced block",
+            gas_used.clone(),
+        );
+
+        registry.register(
+            "block_production_gas_target",
+            "The soft gas target of the next produced block",
+            gas_target.clone(),
+        );
+
+        registry.register(
+            "block_production_gas_utilization",
+            "The ratio of the used gas to the gas target of the latest produced block",
+            gas_utilization.clone(),
+        );
+
+        registry.register(
+            "block_production_state_writes",
+            "The number of coins and messages spent and created by the latest produced block",
+            state_writes.clone(),
+        );
+
+        registry.register(
+            "block_production_execution_duration_s",
+            "Records the duration time of producing and executing a block",
+            execution_duration.clone(),
+        );
+
+        registry.register(
+            "block_production_selection_duration_s",
+            "The duration of the selection of the transactions from the pool for the produced block",
+            selection_duration.clone(),
+        );
+
+        registry.register(
+            "block_production_signing_duration_s",
+            "The duration of signing the produced block",
+            signing_duration.clone(),
+        );
+
+        registry.register(
+            "block_production_broadcast_duration_s",
+            "The duration of committing the produced block and broadcasting it to the network",
+            broadcast_duration.clone(),
+        );
+
+        registry.register(
+            "block_production_slot_delay_s",
+            "The delay between the start of the slot and the start of the block production",
+            slot_delay.clone(),
+        );
+
+        registry.register(
+            "block_production_late_blocks",
+            "The number of the blocks produced after the end of their slot",
+            late_blocks.clone(),
+        );
+
+        registry.register(
+            "block_production_missed_slots",
+            "The number of the slots that ended without the produced block",
+            missed_slots.clone(),
+        );
+
+        Self {
+            registry,
+            gas_used,
+            gas_target,
+            gas_utilization,
+            state_writes,
+            execution_duration,
+            selection_duration,
+            signing_duration,
+            broadcast_duration,
+            slot_delay,
+            late_blocks,
+            missed_slots,
+        }
+    }
+}
+
+// Setup a global static for accessing block production metrics
+static BLOCK_PRODUCTION_METRICS: OnceLock<BlockProductionMetrics> = OnceLock::new();
+
+pub fn block_production_metrics() -> &'static BlockProductionMetrics {
+    BLOCK_PRODUCTION_METRICS.get_or_init(BlockProductionMetrics::default)
+}
@@ -1,11 +1,25 @@
 use prometheus_client::{
+    encoding::EncodeLabelSet,
     metrics::{
         counter::Counter,
+        family::Family,
+        gauge::Gauge,
         histogram::Histogram,
     },
     registry::Registry,
 };
-use std::sync::OnceLock;
+use std::sync::{
+    atomic::AtomicU64,
+    OnceLock,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ColumnLabel {
+    // the name of the database
+    database: String,
+    // the name of the column
+    column: String,
+}
 
 pub struct DatabaseMetrics {
     pub registry: Registry,
@@ -14,6 +28,12 @@ pub struct DatabaseMetrics {
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    pub state_cache_hits: Counter,
+    pub state_cache_misses: Counter,
+    pub column_size: Family<ColumnLabel, Gauge>,
+    pub column_growth_rate: Family<ColumnLabel, Gauge<f64, AtomicU64>>,
+    pub available_space: Gauge,
+    pub low_disk_space: Gauge,
 }
 
 impl DatabaseMetrics {
@@ -22,6 +42,8 @@ impl DatabaseMetrics {
 
         let write_meter: Counter = Counter::default();
         let read_meter: Counter = Counter::default();
+        let state_cache_hits: Counter = Counter::default();
+        let state_cache_misses: Counter = Counter::default();
 
         let bytes_written = Vec::new();
         let bytes_written_histogram = Histogram::new(bytes_written.into_iter());
@@ -35,8 +57,34 @@ impl DatabaseMetrics {
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            state_cache_hits,
+            state_cache_misses,
+            column_size: Family::default(),
+            column_growth_rate: Family::default(),
+            available_space: Gauge::default(),
+            low_disk_space: Gauge::default(),
         }
     }
+
+    /// Records the `size` of the `column` and its growth rate in bytes per hour.
+    pub fn observe_column_size(
+        &self,
+        database: &str,
+        column: &str,
+        size: u64,
+        growth_rate: f64,
+    ) {
+        let label = ColumnLabel {
+            database: database.to_string(),
+            column: column.to_string(),
+        };
+        self.column_size
+            .get_or_create(&label)
+            .set(i64::try_from(size).unwrap_or(i64::MAX));
+        self.column_growth_rate
+            .get_or_create(&label)
+            .set(growth_rate);
+    }
 }
 
 pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
@@ -50,6 +98,36 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Number of database read operations",
         metrics.read_meter.clone(),
     );
+    metrics.registry.register(
+        "Database_State_Cache_Hits",
+        "Number of reads of the contract state and balances served by the cache",
+        metrics.state_cache_hits.clone(),
+    );
+    metrics.registry.register(
+        "Database_State_Cache_Misses",
+        "Number of reads of the contract state and balances missed by the cache",
+        metrics.state_cache_misses.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Size",
+        "The approximate size in bytes of the data of each column",
+        metrics.column_size.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Growth_Rate",
+        "The growth rate in bytes per hour of the data of each column",
+        metrics.column_growth_rate.clone(),
+    );
+    metrics.registry.register(
+        "Database_Available_Space",
+        "The available space in bytes of the volume with the database",
+        metrics.available_space.clone(),
+    );
+    metrics.registry.register(
+        "Database_Low_Disk_Space",
+        "Equals 1 when the available space is below the low-space threshold",
+        metrics.low_disk_space.clone(),
+    );
     metrics.registry.register(
         "Bytes_Read",
         "Histogram containing values of amount of bytes read per operation",
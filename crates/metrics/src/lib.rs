@@ -5,6 +5,7 @@
 
 use std::sync::OnceLock;
 
+pub mod block_production;
 pub mod core_metrics;
 pub mod future_tracker;
 pub mod graphql_metrics;
@@ -1,15 +1,27 @@
 use once_cell::race::OnceBox;
 use prometheus_client::{
-    metrics::counter::Counter,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+    },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PeerLabel {
+    // the id of the peer
+    peer: String,
+}
+
 pub struct P2PMetrics {
     pub gossip_sub_registry: OnceBox<Registry>,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    gossip_messages: Family<PeerLabel, Counter>,
+    gossip_duplicates: Family<PeerLabel, Counter>,
 }
 
 impl P2PMetrics {
@@ -22,6 +34,8 @@ impl P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            gossip_messages: Family::default(),
+            gossip_duplicates: Family::default(),
         };
 
         metrics.peer_metrics.register(
@@ -30,8 +44,30 @@ impl P2PMetrics {
             metrics.unique_peers.clone(),
         );
 
+        metrics.peer_metrics.register(
+            "Gossip_Messages",
+            "The number of gossip messages delivered by each peer after the `Gossipsub` deduplication",
+            metrics.gossip_messages.clone(),
+        );
+
+        metrics.peer_metrics.register(
+            "Gossip_Duplicates",
+            "The number of duplicate gossip messages delivered by each peer after the `Gossipsub` duplicate cache time",
+            metrics.gossip_duplicates.clone(),
+        );
+
         metrics
     }
+
+    /// Counts the gossip message delivered by the `peer`. The duplicate ratio of
+    /// the peer is `Gossip_Duplicates / Gossip_Messages`.
+    pub fn observe_gossip_message(&self, peer: String, duplicate: bool) {
+        let label = PeerLabel { peer };
+        self.gossip_messages.get_or_create(&label).inc();
+        if duplicate {
+            self.gossip_duplicates.get_or_create(&label).inc();
+        }
+    }
 }
 
 static P2P_METRICS: OnceLock<P2PMetrics> = OnceLock::new();
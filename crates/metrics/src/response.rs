@@ -1,4 +1,5 @@
 use crate::{
+    block_production::block_production_metrics,
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
@@ -50,6 +51,10 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &block_production_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
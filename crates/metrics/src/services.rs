@@ -24,6 +24,8 @@ pub struct ServiceLifecycle {
     /// Time is in nanoseconds.
     // TODO: Use `AtomicU128` when it is stable, otherwise, the field can overflow at some point.
     pub idle: Counter,
+    /// The number of restarts of the service after a panic.
+    pub restarts: Counter,
 }
 
 /// The register of the metrics for each service.
@@ -67,6 +69,14 @@ impl ServicesMetrics {
             format!("The busy time of the {} service", service_name),
             lifecycle.busy.clone(),
         );
+        lock.register(
+            format!("{}_restarts", service_name),
+            format!(
+                "The number of restarts of the {} service after a panic",
+                service_name
+            ),
+            lifecycle.restarts.clone(),
+        );
 
         lifecycle
     }
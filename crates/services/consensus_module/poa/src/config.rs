@@ -1,20 +1,36 @@
+use crate::{
+    gas_target::GasTargetAutotuning,
+    ports::GetTime,
+};
+use fuel_core_chain_config::BlockTimeConfig;
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
+    tai64::Tai64,
 };
+use std::sync::Arc;
 use tokio::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
+    /// Adjusts the gas target of produced blocks between the minimum from the policy
+    /// and the `block_gas_limit` if set.
+    pub gas_target_autotuning: Option<GasTargetAutotuning>,
     pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    /// Adds the randomness beacon signed by the `signing_key` to the produced blocks.
+    pub beacon: bool,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The rules of the timestamps of the produced blocks.
+    pub block_time: BlockTimeConfig,
+    /// The clock used for the timestamps of the produced blocks.
+    pub clock: Arc<dyn GetTime>,
 }
 
 impl Default for Config {
@@ -22,15 +38,29 @@ impl Default for Config {
         Config {
             trigger: Trigger::default(),
             block_gas_limit: 0,
+            gas_target_autotuning: None,
             signing_key: None,
+            beacon: false,
             metrics: false,
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            block_time: BlockTimeConfig::default(),
+            clock: Arc::new(SystemTime),
         }
     }
 }
 
+/// The clock that uses the system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemTime;
+
+impl GetTime for SystemTime {
+    fn now(&self) -> Tai64 {
+        Tai64::now()
+    }
+}
+
 /// Block production trigger for PoA operation
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Trigger {
@@ -0,0 +1,195 @@
+//! The soft gas target of produced blocks. The block producer fills the block
+//! with transactions only up to the target, which is less or equal to the block gas
+//! limit from the chain configuration. The automatic adjustment of the target allows
+//! devnets to follow the performance of the hardware: the target is decreased when
+//! the block takes more time than the budget and increased when blocks are full
+//! and executed fast enough.
+
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Receipt,
+        Transaction,
+    },
+    services::executor::TransactionExecutionStatus,
+};
+use std::time::Duration;
+
+/// The block is considered full when it uses at least this percent of the gas target.
+const FULL_BLOCK_UTILIZATION_PERCENT: u128 = 90;
+
+/// The policy of the automatic adjustment of the gas target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasTargetAutotuning {
+    /// The gas target is never decreased below this value.
+    pub min_gas_target: Word,
+    /// The desired max duration of the production of one block.
+    pub execution_time_budget: Duration,
+    /// The percent of the current gas target by which it changes after each block.
+    pub adjustment_percent: u8,
+}
+
+impl Default for GasTargetAutotuning {
+    fn default() -> Self {
+        Self {
+            min_gas_target: 0,
+            execution_time_budget: Duration::from_secs(1),
+            adjustment_percent: 10,
+        }
+    }
+}
+
+impl GasTargetAutotuning {
+    /// Returns the gas target for the next block based on the utilization of the
+    /// previous block. The result is always in the `[min_gas_target, max_gas_target]`
+    /// range.
+    pub fn next_gas_target(
+        &self,
+        gas_target: Word,
+        max_gas_target: Word,
+        utilization: &BlockUtilization,
+    ) -> Word {
+        let min_gas_target = self.min_gas_target.min(max_gas_target);
+        let step = (gas_target as u128)
+            .saturating_mul(self.adjustment_percent as u128)
+            .checked_div(100)
+            .unwrap_or_default()
+            .max(1);
+        let step = Word::try_from(step).unwrap_or(Word::MAX);
+
+        let next_gas_target = if utilization.execution_time > self.execution_time_budget {
+            gas_target.saturating_sub(step)
+        } else if utilization.is_full(gas_target)
+            && utilization.execution_time
+                <= self
+                    .execution_time_budget
+                    .checked_div(2)
+                    .unwrap_or_default()
+        {
+            gas_target.saturating_add(step)
+        } else {
+            gas_target
+        };
+
+        next_gas_target.clamp(min_gas_target, max_gas_target)
+    }
+}
+
+/// The resources used by the produced block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlockUtilization {
+    /// The gas used by the scripts of the block.
+    pub gas_used: Word,
+    /// The time spent on the production and execution of the block.
+    pub execution_time: Duration,
+    /// The number of coins and messages spent and created by the block.
+    pub state_writes: u64,
+}
+
+impl BlockUtilization {
+    pub fn new(
+        block: &Block,
+        tx_status: &[TransactionExecutionStatus],
+        execution_time: Duration,
+    ) -> Self {
+        let gas_used = tx_status
+            .iter()
+            .flat_map(|status| status.result.receipts())
+            .filter_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .fold(0, Word::saturating_add);
+        let state_writes = block
+            .transactions()
+            .iter()
+            .map(|tx| match tx {
+                Transaction::Script(tx) => {
+                    tx.inputs().len().saturating_add(tx.outputs().len())
+                }
+                Transaction::Create(tx) => {
+                    tx.inputs().len().saturating_add(tx.outputs().len())
+                }
+                Transaction::Mint(_) => 1,
+            })
+            .fold(0u64, |sum, writes| sum.saturating_add(writes as u64));
+
+        Self {
+            gas_used,
+            execution_time,
+            state_writes,
+        }
+    }
+
+    /// Returns the ratio of the used gas to the `gas_target`.
+    pub fn gas_utilization(&self, gas_target: Word) -> f64 {
+        if gas_target == 0 {
+            return 0.0
+        }
+        self.gas_used as f64 / gas_target as f64
+    }
+
+    fn is_full(&self, gas_target: Word) -> bool {
+        (self.gas_used as u128).saturating_mul(100)
+            >= (gas_target as u128).saturating_mul(FULL_BLOCK_UTILIZATION_PERCENT)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const BUDGET: Duration = Duration::from_millis(100);
+
+    fn autotuning() -> GasTargetAutotuning {
+        GasTargetAutotuning {
+            min_gas_target: 500,
+            execution_time_budget: BUDGET,
+            adjustment_percent: 10,
+        }
+    }
+
+    fn utilization(gas_used: Word, execution_time: Duration) -> BlockUtilization {
+        BlockUtilization {
+            gas_used,
+            execution_time,
+            state_writes: 0,
+        }
+    }
+
+    #[test_case(1000, utilization(1000, BUDGET * 2) => 900; "slow block decreases the target")]
+    #[test_case(1000, utilization(950, BUDGET / 4) => 1100; "fast full block increases the target")]
+    #[test_case(1000, utilization(500, BUDGET / 4) => 1000; "fast not full block keeps the target")]
+    #[test_case(1000, utilization(1000, BUDGET * 3 / 4) => 1000; "full block within the budget keeps the target")]
+    #[test_case(520, utilization(520, BUDGET * 2) => 500; "target is not decreased below the min")]
+    #[test_case(1950, utilization(1950, BUDGET / 4) => 2000; "target is not increased above the max")]
+    #[test_case(5, utilization(5, BUDGET / 4) => 500; "target is raised to the min")]
+    fn next_gas_target(gas_target: Word, utilization: BlockUtilization) -> Word {
+        autotuning().next_gas_target(gas_target, 2000, &utilization)
+    }
+
+    #[test]
+    fn min_gas_target_is_bounded_by_the_max() {
+        let autotuning = GasTargetAutotuning {
+            min_gas_target: 5000,
+            ..autotuning()
+        };
+
+        let next = autotuning.next_gas_target(1000, 2000, &utilization(0, BUDGET * 2));
+
+        assert_eq!(next, 2000);
+    }
+
+    #[test]
+    fn gas_utilization_of_zero_target_is_zero() {
+        assert_eq!(utilization(10, BUDGET).gas_utilization(0), 0.0);
+        assert_eq!(utilization(10, BUDGET).gas_utilization(40), 0.25);
+    }
+}
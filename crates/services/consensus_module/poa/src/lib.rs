@@ -11,14 +11,18 @@ mod sync;
 mod service_test;
 
 pub mod config;
+pub mod gas_target;
 pub mod ports;
 pub mod service;
+pub mod slot_timing;
 pub mod verifier;
 
 pub use config::{
     Config,
+    SystemTime,
     Trigger,
 };
+pub use gas_target::GasTargetAutotuning;
 pub use service::{
     new_service,
     Service,
@@ -27,6 +27,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use std::fmt::Debug;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
@@ -40,6 +41,12 @@ pub trait TransactionPool: Send + Sync {
     fn transaction_status_events(&self) -> BoxStream<TxId>;
 }
 
+/// The source of the current time used for the timestamps of the produced blocks.
+pub trait GetTime: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Tai64;
+}
+
 #[cfg(test)]
 use fuel_core_storage::test_helpers::EmptyStorage;
 
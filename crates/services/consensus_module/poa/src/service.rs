@@ -3,17 +3,24 @@ use crate::{
         DeadlineClock,
         OnConflict,
     },
+    gas_target::{
+        BlockUtilization,
+        GasTargetAutotuning,
+    },
     ports::{
         BlockImporter,
         BlockProducer,
+        GetTime,
         P2pPort,
         TransactionPool,
         TransactionsSource,
     },
+    slot_timing::SlotTiming,
     sync::{
         SyncState,
         SyncTask,
     },
+    verifier::verify_block_time,
     Config,
     Trigger,
 };
@@ -21,6 +28,8 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_chain_config::BlockTimeConfig;
+use fuel_core_metrics::block_production::block_production_metrics;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -34,7 +43,10 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::{
-            poa::PoAConsensus,
+            poa::{
+                PoABeacon,
+                PoAConsensus,
+            },
             Consensus,
         },
         header::BlockHeader,
@@ -64,12 +76,14 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
     sync::{
         mpsc,
         oneshot,
+        watch,
     },
     time::Instant,
 };
@@ -79,9 +93,15 @@ pub type Service<T, B, I> = ServiceRunner<MainTask<T, B, I>>;
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
+    sync_state: watch::Receiver<SyncState>,
 }
 
 impl SharedState {
+    /// Returns `true` if the node is synced with the network and produces blocks.
+    pub fn is_synced(&self) -> bool {
+        matches!(*self.sync_state.borrow(), SyncState::Synced(_))
+    }
+
     pub async fn manually_produce_block(
         &self,
         start_time: Option<Tai64>,
@@ -130,8 +150,14 @@ pub(crate) enum RequestType {
 }
 
 pub struct MainTask<T, B, I> {
+    /// The soft gas target of the next block.
     block_gas_limit: Word,
+    /// The gas limit from the chain configuration.
+    max_block_gas_limit: Word,
+    gas_target_autotuning: Option<GasTargetAutotuning>,
+    metrics: bool,
     signing_key: Option<Secret<SecretKeyWrapper>>,
+    beacon: bool,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -142,6 +168,10 @@ pub struct MainTask<T, B, I> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     trigger: Trigger,
+    /// The rules of the block timestamps from the consensus config.
+    block_time: BlockTimeConfig,
+    /// The clock used for the timestamps of the blocks.
+    clock: Arc<dyn GetTime>,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
@@ -163,17 +193,22 @@ where
         let tx_status_update_stream = txpool.transaction_status_events();
         let (request_sender, request_receiver) = mpsc::channel(1024);
         let (last_height, last_timestamp, last_block_created) =
-            Self::extract_block_info(last_block);
+            Self::extract_block_info(config.clock.as_ref(), last_block);
 
         let block_stream = block_importer.block_stream();
         let peer_connections_stream = p2p_port.reserved_peers_count();
 
         let Config {
             block_gas_limit,
+            gas_target_autotuning,
             signing_key,
+            beacon,
+            metrics,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            block_time,
+            clock,
             ..
         } = config;
 
@@ -187,28 +222,46 @@ where
 
         let sync_task_handle = ServiceRunner::new(sync_task);
 
+        if metrics {
+            block_production_metrics()
+                .gas_target
+                .set(block_gas_limit as i64);
+        }
+
         Self {
             block_gas_limit,
+            max_block_gas_limit: block_gas_limit,
+            gas_target_autotuning,
+            metrics,
             signing_key,
+            beacon,
             txpool,
             block_producer,
             block_importer,
             tx_status_update_stream,
             request_receiver,
-            shared_state: SharedState { request_sender },
+            shared_state: SharedState {
+                request_sender,
+                sync_state: sync_task_handle.shared.clone(),
+            },
             last_height,
             last_timestamp,
             last_block_created,
             trigger,
+            block_time,
+            clock,
             timer: DeadlineClock::new(),
             sync_task_handle,
         }
     }
 
-    fn extract_block_info(last_block: &BlockHeader) -> (BlockHeight, Tai64, Instant) {
+    fn extract_block_info(
+        clock: &dyn GetTime,
+        last_block: &BlockHeader,
+    ) -> (BlockHeight, Tai64, Instant) {
         let last_timestamp = last_block.time();
         let duration =
-            Duration::from_secs(Tai64::now().0.saturating_sub(last_timestamp.0));
+            Duration::from_secs(clock.now().0.saturating_sub(last_timestamp.0));
         let last_block_created = Instant::now()
             .checked_sub(duration)
             .unwrap_or(Instant::now());
@@ -223,7 +276,7 @@ where
     }
 
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
-        match request_type {
+        let time = match request_type {
             RequestType::Manual => match self.trigger {
                 Trigger::Never | Trigger::Instant => {
                     let duration = self.last_block_created.elapsed();
@@ -234,13 +287,51 @@ where
                 }
             },
             RequestType::Trigger => {
-                let now = Tai64::now();
+                let now = self.clock.now();
                 if now > self.last_timestamp {
                     Ok(now)
                 } else {
                     self.next_time(RequestType::Manual)
                 }
             }
+        }?;
+        let min_time = increase_time(
+            self.last_timestamp,
+            Duration::from_secs(self.block_time.min_block_interval),
+        )?;
+        Ok(time.max(min_time))
+    }
+
+    /// Records the utilization of the produced block and adjusts the gas target
+    /// of the next block.
+    fn on_block_utilization(&mut self, utilization: &BlockUtilization) {
+        let gas_target = self.block_gas_limit;
+        if let Some(autotuning) = &self.gas_target_autotuning {
+            self.block_gas_limit = autotuning.next_gas_target(
+                gas_target,
+                self.max_block_gas_limit,
+                utilization,
+            );
+            if self.block_gas_limit != gas_target {
+                tracing::info!(
+                    "The block gas target is changed from {} to {}",
+                    gas_target,
+                    self.block_gas_limit
+                );
+            }
+        }
+
+        if self.metrics {
+            let metrics = block_production_metrics();
+            metrics.gas_used.set(utilization.gas_used as i64);
+            metrics.gas_target.set(self.block_gas_limit as i64);
+            metrics
+                .gas_utilization
+                .set(utilization.gas_utilization(gas_target));
+            metrics.state_writes.set(utilization.state_writes as i64);
+            metrics
+                .execution_duration
+                .observe(utilization.execution_time.as_secs_f64());
         }
     }
 }
@@ -308,6 +399,7 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(height = *height), err)]
     async fn produce_block(
         &mut self,
         height: BlockHeight,
@@ -324,8 +416,15 @@ where
         if self.last_timestamp > block_time {
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
+        verify_block_time(
+            &self.block_time,
+            self.last_timestamp,
+            block_time,
+            self.clock.now(),
+        )?;
 
         // Ask the block producer to create the block
+        let production_started_at = Instant::now();
         let (
             ExecutionResult {
                 block,
@@ -337,6 +436,8 @@ where
             .signal_produce_block(height, block_time, source)
             .await?
             .into();
+        let utilization =
+            BlockUtilization::new(&block, &tx_status, production_started_at.elapsed());
 
         let mut tx_ids_to_remove = Vec::with_capacity(skipped_transactions.len());
         for (tx_id, err) in skipped_transactions {
@@ -350,23 +451,36 @@ where
         self.txpool.remove_txs(tx_ids_to_remove);
 
         // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        let signing_started_at = Instant::now();
+        let seal = seal_block(&self.signing_key, self.beacon, &block)?;
+        let signing_duration = signing_started_at.elapsed();
         let block = SealedBlock {
             entity: block,
             consensus: seal,
         };
         // Import the sealed block
+        let broadcast_started_at = Instant::now();
         self.block_importer
             .commit_result(Uncommitted::new(
                 ImportResult::new_from_local(block, tx_status),
                 db_transaction,
             ))
             .await?;
+        if self.metrics {
+            let metrics = block_production_metrics();
+            metrics
+                .signing_duration
+                .observe(signing_duration.as_secs_f64());
+            metrics
+                .broadcast_duration
+                .observe(broadcast_started_at.elapsed().as_secs_f64());
+        }
 
         // Update last block time
         self.last_height = height;
         self.last_timestamp = block_time;
         self.last_block_created = last_block_created;
+        self.on_block_utilization(&utilization);
 
         // Set timer for the next block
         match (self.trigger, request_type) {
@@ -404,14 +518,30 @@ where
         }
     }
 
-    async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
+    async fn on_timer(&mut self, at: Instant) -> anyhow::Result<()> {
         match self.trigger {
             Trigger::Instant | Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
             }
             // In the Interval mode the timer expires only when a new block should be created.
-            Trigger::Interval { .. } => {
+            Trigger::Interval { block_time } => {
+                let delay = at.elapsed();
                 self.produce_next_block().await?;
+                let timing = SlotTiming::new(delay, at.elapsed(), block_time);
+                if timing.is_late() {
+                    tracing::warn!(
+                        "The block was produced after the end of its slot, {} slots are missed",
+                        timing.missed_slots
+                    );
+                }
+                if self.metrics {
+                    let metrics = block_production_metrics();
+                    metrics.slot_delay.observe(timing.delay.as_secs_f64());
+                    if timing.is_late() {
+                        metrics.late_blocks.inc();
+                        metrics.missed_slots.inc_by(timing.missed_slots);
+                    }
+                }
                 Ok(())
             }
         }
@@ -473,7 +603,7 @@ where
                 _ = self.sync_task_handle.shared.changed() => {
                     if let SyncState::Synced(block_header) = &*self.sync_task_handle.shared.borrow() {
                         let (last_height, last_timestamp, last_block_created) =
-                            Self::extract_block_info(block_header);
+                            Self::extract_block_info(self.clock.as_ref(), block_header);
                         self.last_height = last_height;
                         self.last_timestamp = last_timestamp;
                         self.last_block_created = last_block_created;
@@ -555,6 +685,7 @@ where
 
 fn seal_block(
     signing_key: &Option<Secret<SecretKeyWrapper>>,
+    beacon: bool,
     block: &Block,
 ) -> anyhow::Result<Consensus> {
     if let Some(key) = signing_key {
@@ -565,7 +696,13 @@ fn seal_block(
         let signing_key = key.expose_secret().deref();
 
         let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
+        let consensus = PoAConsensus::new(poa_signature);
+        let seal = if beacon {
+            let beacon = PoABeacon::new(signing_key, block.header().height());
+            Consensus::PoAWithBeacon(consensus, beacon)
+        } else {
+            Consensus::PoA(consensus)
+        };
         Ok(seal)
     } else {
         Err(anyhow!("no PoA signing key configured"))
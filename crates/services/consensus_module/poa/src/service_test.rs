@@ -10,6 +10,7 @@ use crate::{
     },
     service::MainTask,
     Config,
+    GasTargetAutotuning,
     Service,
     Trigger,
 };
@@ -37,7 +38,10 @@ use fuel_core_types::{
         BlockHeight,
         ChainId,
     },
-    secrecy::Secret,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
     services::executor::{
         Error as ExecutorError,
         ExecutionResult,
@@ -395,6 +399,131 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     task.on_txpool_event().await.unwrap();
 }
 
+#[tokio::test]
+async fn gas_target_is_decreased_after_slow_blocks() {
+    let gas_limits = Arc::new(Mutex::new(vec![]));
+
+    let mut block_producer = MockBlockProducer::default();
+    let recorded_gas_limits = gas_limits.clone();
+    block_producer.expect_produce_and_execute_block().returning(
+        move |_, _, _, gas_limit| {
+            recorded_gas_limits.lock().unwrap().push(gas_limit);
+            // The block takes more time than the budget.
+            std::thread::sleep(Duration::from_millis(1));
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        },
+    );
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().returning(|_| Ok(()));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000,
+        gas_target_autotuning: Some(GasTargetAutotuning {
+            min_gas_target: 850,
+            execution_time_budget: Duration::ZERO,
+            adjustment_percent: 10,
+        }),
+        signing_key: Some(test_signing_key()),
+        metrics: true,
+        ..Default::default()
+    };
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+    );
+
+    for _ in 0..3 {
+        task.produce_next_block().await.unwrap();
+    }
+
+    assert_eq!(*gas_limits.lock().unwrap(), vec![1000, 900, 850]);
+}
+
+#[tokio::test]
+async fn produced_blocks_contain_beacon_if_enabled() {
+    for beacon in [false, true] {
+        let mut block_producer = MockBlockProducer::default();
+        block_producer
+            .expect_produce_and_execute_block()
+            .returning(|_, _, _, _| {
+                Ok(UncommittedResult::new(
+                    ExecutionResult {
+                        block: Default::default(),
+                        skipped_transactions: Default::default(),
+                        tx_status: Default::default(),
+                    },
+                    StorageTransaction::new(EmptyStorage),
+                ))
+            });
+
+        let seals = Arc::new(Mutex::new(vec![]));
+        let recorded_seals = seals.clone();
+        let mut block_importer = MockBlockImporter::default();
+        block_importer
+            .expect_commit_result()
+            .returning(move |result| {
+                let consensus = result.result().sealed_block.consensus.clone();
+                recorded_seals.lock().unwrap().push(consensus);
+                Ok(())
+            });
+        block_importer
+            .expect_block_stream()
+            .returning(|| Box::pin(tokio_stream::pending()));
+
+        let mut txpool = MockTransactionPool::no_tx_updates();
+        txpool.expect_remove_txs().returning(|_| vec![]);
+
+        let signing_key = test_signing_key();
+        let signer = Input::owner(&signing_key.expose_secret().public_key());
+        let config = Config {
+            trigger: Trigger::Instant,
+            signing_key: Some(signing_key),
+            beacon,
+            ..Default::default()
+        };
+
+        let mut task = MainTask::new(
+            &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+            config,
+            txpool,
+            block_producer,
+            block_importer,
+            generate_p2p_port(),
+        );
+        task.produce_next_block().await.unwrap();
+
+        let seals = seals.lock().unwrap();
+        match (beacon, seals[0].beacon()) {
+            (true, Some(produced)) => {
+                assert!(produced.verify(&0u32.into(), &signer));
+                assert!(!produced.verify(&1u32.into(), &signer));
+            }
+            (false, None) => {}
+            _ => panic!("Unexpected seal {:?}", seals[0]),
+        }
+    }
+}
+
 fn test_signing_key() -> Secret<SecretKeyWrapper> {
     let mut rng = StdRng::seed_from_u64(0);
     let secret_key = SecretKey::random(&mut rng);
@@ -1,4 +1,5 @@
 use crate::service::Mode;
+use fuel_core_chain_config::BlockTimeConfig;
 use fuel_core_types::{
     blockchain::block::Block,
     tai64::Tai64,
@@ -117,3 +118,101 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+fn context_with_block_time(
+    block_time: BlockTimeConfig,
+) -> (TestContext, tokio::sync::mpsc::Receiver<Tai64>) {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signing_key: Some(test_signing_key()),
+        block_time,
+        ..Default::default()
+    });
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(vec![]);
+    ctx_builder.with_txpool(txpool);
+
+    let mut importer = MockBlockImporter::default();
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    importer.expect_commit_result().returning(move |r| {
+        tx.try_send(r.into_result().sealed_block.entity.header().time())
+            .unwrap();
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .returning(|_, time, _, _| {
+            let mut block = Block::default();
+            block.header_mut().set_time(time);
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+    (ctx_builder.build(), rx)
+}
+
+#[tokio::test]
+async fn manually_produced_blocks_respect_min_block_interval() {
+    let (ctx, mut rx) = context_with_block_time(BlockTimeConfig {
+        max_future_drift: None,
+        min_block_interval: 5,
+    });
+    let start_time = Tai64::now() + 100;
+
+    ctx.service
+        .shared
+        .manually_produce_block(
+            Some(start_time),
+            Mode::Blocks {
+                number_of_blocks: 3,
+            },
+        )
+        .await
+        .unwrap();
+
+    for t in [start_time, start_time + 5, start_time + 10] {
+        assert_eq!(rx.recv().await.unwrap(), t);
+    }
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn block_too_far_in_future_is_not_produced() {
+    let (ctx, mut rx) = context_with_block_time(BlockTimeConfig {
+        max_future_drift: Some(10),
+        min_block_interval: 0,
+    });
+
+    let result = ctx
+        .service
+        .shared
+        .manually_produce_block(
+            Some(Tai64::now() + 100),
+            Mode::Blocks {
+                number_of_blocks: 1,
+            },
+        )
+        .await;
+
+    let err = result.unwrap_err();
+    assert!(
+        format!("{err:#}").contains("is ahead of the maximum"),
+        "Unexpected error: {err:#}"
+    );
+    assert!(rx.try_recv().is_err());
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
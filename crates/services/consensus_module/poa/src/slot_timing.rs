@@ -0,0 +1,66 @@
+//! The timing of the blocks produced by the `Interval` trigger relative to their
+//! slots. The slot starts when the timer of the trigger expires and lasts for
+//! the block time.
+
+use std::time::Duration;
+
+/// The timing of the block produced in the slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotTiming {
+    /// The delay between the start of the slot and the start of the production.
+    pub delay: Duration,
+    /// The number of the slots that ended before the block was produced.
+    pub missed_slots: u64,
+}
+
+impl SlotTiming {
+    /// Creates the timing of the block, which production started after the `delay`
+    /// and finished after the `elapsed` time since the start of the slot.
+    pub fn new(delay: Duration, elapsed: Duration, block_time: Duration) -> Self {
+        let missed_slots = elapsed
+            .as_nanos()
+            .checked_div(block_time.as_nanos())
+            .map_or(0, |slots| u64::try_from(slots).unwrap_or(u64::MAX));
+        Self {
+            delay,
+            missed_slots,
+        }
+    }
+
+    /// Returns `true` if the block was produced after the end of its slot.
+    pub fn is_late(&self) -> bool {
+        self.missed_slots > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCK_TIME: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn block_produced_within_slot_is_on_time() {
+        let timing = SlotTiming::new(
+            Duration::from_millis(10),
+            Duration::from_millis(900),
+            BLOCK_TIME,
+        );
+
+        assert!(!timing.is_late());
+        assert_eq!(timing.missed_slots, 0);
+        assert_eq!(timing.delay, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn block_produced_after_slot_misses_ended_slots() {
+        let timing = SlotTiming::new(
+            Duration::from_millis(10),
+            Duration::from_millis(2500),
+            BLOCK_TIME,
+        );
+
+        assert!(timing.is_late());
+        assert_eq!(timing.missed_slots, 2);
+    }
+}
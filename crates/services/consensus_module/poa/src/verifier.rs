@@ -1,13 +1,21 @@
 use crate::ports::Database;
 use anyhow::ensure;
-use fuel_core_chain_config::ConsensusConfig;
+use core::fmt;
+use fuel_core_chain_config::{
+    BlockTimeConfig,
+    ConsensusConfig,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
-        consensus::poa::PoAConsensus,
+        consensus::poa::{
+            PoABeacon,
+            PoAConsensus,
+        },
         header::BlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
 
 #[cfg(test)]
@@ -20,7 +28,7 @@ pub fn verify_consensus(
     consensus: &PoAConsensus,
 ) -> bool {
     match consensus_config {
-        ConsensusConfig::PoA { signing_key } => {
+        ConsensusConfig::PoA { signing_key, .. } => {
             let id = header.id();
             let m = id.as_message();
             consensus
@@ -31,8 +39,75 @@ pub fn verify_consensus(
     }
 }
 
+/// Verifies that the randomness beacon of the block is produced by the PoA signer.
+pub fn verify_beacon(
+    consensus_config: &ConsensusConfig,
+    header: &BlockHeader,
+    beacon: &PoABeacon,
+) -> bool {
+    match consensus_config {
+        ConsensusConfig::PoA { signing_key, .. } => {
+            beacon.verify(header.height(), signing_key)
+        }
+    }
+}
+
+/// The violation of the [`BlockTimeConfig`] rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockTimeError {
+    /// The block time is lower than the time of the previous block plus
+    /// the minimum block interval.
+    TooEarly { time: Tai64, min_time: Tai64 },
+    /// The block time is ahead of the local clock by more than the maximum drift.
+    TooFarInFuture { time: Tai64, max_time: Tai64 },
+}
+
+impl fmt::Display for BlockTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockTimeError::TooEarly { time, min_time } => write!(
+                f,
+                "The block time {} is lower than the minimum {}",
+                time.0, min_time.0
+            ),
+            BlockTimeError::TooFarInFuture { time, max_time } => write!(
+                f,
+                "The block time {} is ahead of the maximum {} allowed by the local clock",
+                time.0, max_time.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BlockTimeError {}
+
+/// Verifies the `time` of the block produced after the block with the `prev_time`
+/// against the rules of the `config`. The `now` is the time of the local clock.
+pub fn verify_block_time(
+    config: &BlockTimeConfig,
+    prev_time: Tai64,
+    time: Tai64,
+    now: Tai64,
+) -> Result<(), BlockTimeError> {
+    let min_time = Tai64(prev_time.0.saturating_add(config.min_block_interval));
+    if time < min_time {
+        return Err(BlockTimeError::TooEarly { time, min_time })
+    }
+    if let Some(max_future_drift) = config.max_future_drift {
+        let max_time = Tai64(now.0.saturating_add(max_future_drift));
+        if time > max_time {
+            return Err(BlockTimeError::TooFarInFuture { time, max_time })
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the fields of the imported block. The `now` is the time of the local clock,
+/// used to verify the drift of the block time.
 pub fn verify_block_fields<D: Database>(
     database: &D,
+    block_time: &BlockTimeConfig,
+    now: Tai64,
     block: &Block,
 ) -> anyhow::Result<()> {
     let height = *block.header().height();
@@ -56,10 +131,7 @@ pub fn verify_block_fields<D: Database>(
         "The `da_height` of the next block can't be lower"
     );
 
-    ensure!(
-        header.time() >= prev_header.time(),
-        "The `time` of the next block can't be lower"
-    );
+    verify_block_time(block_time, prev_header.time(), header.time(), now)?;
 
     ensure!(
         header.application_hash() == &header.application().hash(),
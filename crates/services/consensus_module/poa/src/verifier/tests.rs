@@ -8,9 +8,14 @@ use fuel_core_types::{
         GeneratedConsensusFields,
         PartialBlockHeader,
     },
+    fuel_crypto::SecretKey,
     fuel_tx::Transaction,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -113,5 +118,67 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     b.header_mut().set_consensus_header(ch);
     b.header_mut().set_application_header(ah);
     *b.transactions_mut() = txs;
-    verify_block_fields(&d, &b)
+    verify_block_fields(&d, &BlockTimeConfig::default(), Tai64(2), &b)
+}
+
+fn block_time_config(
+    max_future_drift: Option<u64>,
+    min_block_interval: u64,
+) -> BlockTimeConfig {
+    BlockTimeConfig {
+        max_future_drift,
+        min_block_interval,
+    }
+}
+
+#[test_case(block_time_config(None, 0), 10, 10 => Ok(()) ; "same time as previous block")]
+#[test_case(
+    block_time_config(None, 0), 10, 9
+    => Err(BlockTimeError::TooEarly { time: Tai64(9), min_time: Tai64(10) })
+    ; "time before previous block"
+)]
+#[test_case(block_time_config(None, 5), 10, 15 => Ok(()) ; "minimum interval")]
+#[test_case(
+    block_time_config(None, 5), 10, 14
+    => Err(BlockTimeError::TooEarly { time: Tai64(14), min_time: Tai64(15) })
+    ; "less than minimum interval"
+)]
+#[test_case(block_time_config(None, 0), 10, 1000 => Ok(()) ; "unlimited drift")]
+#[test_case(block_time_config(Some(3), 0), 10, 103 => Ok(()) ; "maximum drift")]
+#[test_case(
+    block_time_config(Some(3), 0), 10, 104
+    => Err(BlockTimeError::TooFarInFuture { time: Tai64(104), max_time: Tai64(103) })
+    ; "more than maximum drift"
+)]
+fn test_verify_block_time(
+    config: BlockTimeConfig,
+    prev_time: u64,
+    time: u64,
+) -> Result<(), BlockTimeError> {
+    // The local clock is at `100`.
+    verify_block_time(&config, Tai64(prev_time), Tai64(time), Tai64(100))
+}
+
+#[test]
+fn beacon_is_verified_against_signing_key() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret_key = SecretKey::random(&mut rng);
+    let signing_key = fuel_core_types::fuel_tx::Input::owner(&secret_key.public_key());
+    let config = ConsensusConfig::PoA {
+        signing_key,
+        block_time: None,
+    };
+    let mut header = BlockHeader::default();
+    header.set_block_height(5u32.into());
+
+    let beacon = PoABeacon::new(&secret_key, header.height());
+    assert!(verify_beacon(&config, &header, &beacon));
+
+    // The beacon of another height is rejected.
+    let other_height = PoABeacon::new(&secret_key, &6u32.into());
+    assert!(!verify_beacon(&config, &header, &other_height));
+
+    // The beacon of another signer is rejected.
+    let other_signer = PoABeacon::new(&SecretKey::random(&mut rng), header.height());
+    assert!(!verify_beacon(&config, &header, &other_signer));
 }
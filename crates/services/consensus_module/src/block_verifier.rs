@@ -62,11 +62,22 @@ where
                     .as_ref()
                     .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
                     .unwrap_or_else(|| 0u32.into());
-                verify_genesis_block_fields(expected_genesis_height, block.header())
+                let expected_genesis_time = self.config.chain_config.genesis_time()?;
+                verify_genesis_block_fields(
+                    expected_genesis_height,
+                    expected_genesis_time,
+                    block.header(),
+                )
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::PoAWithBeacon(..) => {
                 let view = self.view_provider.latest_view();
-                fuel_core_poa::verifier::verify_block_fields(&view, block)
+                let block_time = self.config.chain_config.consensus.block_time();
+                fuel_core_poa::verifier::verify_block_fields(
+                    &view,
+                    &block_time,
+                    Tai64::now(),
+                    block,
+                )
             }
             _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
         }
@@ -85,6 +96,11 @@ where
                 header,
                 consensus,
             ),
+            Consensus::PoAWithBeacon(consensus, beacon) => {
+                let config = &self.config.chain_config.consensus;
+                fuel_core_poa::verifier::verify_consensus(config, header, consensus)
+                    && fuel_core_poa::verifier::verify_beacon(config, header, beacon)
+            }
             _ => false,
         }
     }
@@ -92,6 +108,7 @@ where
 
 fn verify_genesis_block_fields(
     expected_genesis_height: BlockHeight,
+    expected_genesis_time: Tai64,
     header: &BlockHeader,
 ) -> anyhow::Result<()> {
     let actual_genesis_height = *header.height();
@@ -101,8 +118,8 @@ fn verify_genesis_block_fields(
         "The genesis previous root should be zeroed"
     );
     ensure!(
-        header.time() == Tai64::UNIX_EPOCH,
-        "The genesis time should be unix epoch time"
+        header.time() == expected_genesis_time,
+        "The genesis time is not as expected"
     );
     ensure!(
         // TODO: Set `da_height` based on the chain config.
@@ -65,5 +65,9 @@ fn test_verify_genesis_block_fields(
     header: BlockHeader,
     expected_genesis_height: u32,
 ) -> anyhow::Result<()> {
-    verify_genesis_block_fields(expected_genesis_height.into(), &header)
+    verify_genesis_block_fields(
+        expected_genesis_height.into(),
+        Tai64::UNIX_EPOCH,
+        &header,
+    )
 }
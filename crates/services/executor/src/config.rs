@@ -1,16 +1,57 @@
-use fuel_core_types::fuel_tx::{
-    ConsensusParameters,
-    ContractId,
+use crate::profiler::ExecutionProfiler;
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+    services::{
+        executor::ReceiptsLimits,
+        predicate_cache::PredicateCache,
+    },
 };
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     /// Network-wide common parameters used for validating the chain
     pub consensus_parameters: ConsensusParameters,
-    /// The `ContractId` of the fee recipient.
+    /// The `ContractId` of the fee recipient of the blocks that don't set their own.
     pub coinbase_recipient: ContractId,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// The number of threads used to validate non-conflicting transactions of
+    /// the block in parallel. The signatures of the block's transactions are also
+    /// recovered in up to this number of chunks on the rayon thread pool before
+    /// the execution. Values less than `2` disable parallel execution.
+    pub execution_threads: usize,
+    /// Record the changes made to the state by each block into the `StateDiffs` table.
+    pub record_state_diff: bool,
+    /// Collects the execution profile of each block if set.
+    pub profiler: Option<ExecutionProfiler>,
+    /// The limits of the receipts produced by one transaction. The transaction
+    /// above the limits is skipped during block production and fails the dry run.
+    /// They are not applied to the validation of blocks produced by other nodes.
+    pub receipts_limits: ReceiptsLimits,
+    /// The hard gas limit of the block from the chain config. The validation of
+    /// the block using more gas fails. Not enforced if not set.
+    pub block_gas_limit: Option<Word>,
+    /// The height and the id of the block of the trusted finalized checkpoint.
+    /// The signatures of the inputs are not verified during the validation of
+    /// the blocks up to the checkpoint, because the blocks were already verified
+    /// when they were finalized. The signatures are skipped only when the database
+    /// contains the block at the checkpoint height with the same id, so the replayed
+    /// blocks belong to the trusted chain. The validation fails if the ids differ.
+    /// It never affects the produced blocks and dry runs.
+    pub skip_signatures_until: Option<(BlockHeight, BlockId)>,
+    /// The cache of the successful evaluations of the predicates shared with the `TxPool`.
+    pub predicate_cache: Option<PredicateCache>,
+    /// Forces failures during the execution of blocks.
+    #[cfg(feature = "fault-injection")]
+    pub fault_injector: Option<crate::fault_injection::FaultInjector>,
 }
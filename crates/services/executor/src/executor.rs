@@ -1,11 +1,14 @@
 use crate::{
+    parallel,
     ports::{
         ExecutorDatabaseTrait,
         MaybeCheckedTransaction,
         RelayerPort,
         TransactionsSource,
     },
+    profiler::ExecutionProfile,
     refs::ContractRef,
+    signatures,
     Config,
 };
 use block_component::*;
@@ -18,13 +21,17 @@ use fuel_core_storage::{
         Messages,
         ProcessedTransactions,
         SpentMessages,
+        StateDiffs,
     },
     transactional::{
         AtomicView,
         StorageTransaction,
         Transactional,
     },
-    vm_storage::VmStorage,
+    vm_storage::{
+        ContractsChanges,
+        VmStorage,
+    },
     StorageAsMut,
     StorageAsRef,
 };
@@ -35,10 +42,14 @@ use fuel_core_types::{
             PartialFuelBlock,
         },
         header::PartialBlockHeader,
-        primitives::DaBlockHeight,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
     },
     entities::{
         coins::coin::{
+            Coin,
             CompressedCoin,
             CompressedCoinV1,
         },
@@ -115,12 +126,16 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ContractBalanceChange,
+            ContractSlotChange,
             Error as ExecutorError,
             ExecutionKind,
             ExecutionResult,
             ExecutionType,
             ExecutionTypes,
+            FeeReport,
             Result as ExecutorResult,
+            StateDiff,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -132,7 +147,14 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Instant,
 };
 use tracing::{
     debug,
@@ -192,6 +214,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            abandoned: Default::default(),
         };
         executor.execute_and_commit(block)
     }
@@ -211,6 +234,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            abandoned: Default::default(),
         };
         let mut block_transaction = executor.database.transaction();
         executor.execute_block(block_transaction.as_mut(), block)
@@ -228,14 +252,18 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options: self.config.as_ref().into(),
+            abandoned: Default::default(),
         };
         executor.execute_inner(block)
     }
 
+    /// Executes transactions without committing the changes. The dry run stops
+    /// with an error before the next transaction once the `abandoned` is set.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
@@ -248,6 +276,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            abandoned,
         };
         executor.dry_run(component)
     }
@@ -262,9 +291,65 @@ pub struct ExecutionData {
     found_mint: bool,
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
+    /// The changes made to the state by the block. `None` if the recording is disabled.
+    state_changes: Option<StateChanges>,
+    /// The execution profile of the block. `None` if the profiling is disabled.
+    profile: Option<ExecutionProfile>,
+    /// The recipient of the fees of the block.
+    coinbase_recipient: ContractId,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
 }
 
+/// The accumulator of the changes made to the state by transactions of the block.
+#[derive(Default)]
+struct StateChanges {
+    created_coins: Vec<Coin>,
+    spent_coins: Vec<UtxoId>,
+    contracts: ContractsChanges,
+}
+
+impl StateChanges {
+    fn extend(&mut self, other: StateChanges) {
+        self.created_coins.extend(other.created_coins);
+        self.spent_coins.extend(other.spent_coins);
+        self.extend_contracts(other.contracts);
+    }
+
+    fn extend_contracts(&mut self, contracts: ContractsChanges) {
+        self.contracts.state.extend(contracts.state);
+        self.contracts.balances.extend(contracts.balances);
+    }
+}
+
+impl From<StateChanges> for StateDiff {
+    fn from(changes: StateChanges) -> Self {
+        Self {
+            created_coins: changes.created_coins,
+            spent_coins: changes.spent_coins,
+            contract_slots: changes
+                .contracts
+                .state
+                .into_iter()
+                .map(|((contract_id, key), value)| ContractSlotChange {
+                    contract_id,
+                    key,
+                    value,
+                })
+                .collect(),
+            contract_balances: changes
+                .contracts
+                .balances
+                .into_iter()
+                .map(|((contract_id, asset_id), amount)| ContractBalanceChange {
+                    contract_id,
+                    asset_id,
+                    amount,
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Per-block execution options
 #[derive(Copy, Clone, Default, Debug)]
 pub struct ExecutionOptions {
@@ -290,6 +375,9 @@ struct ExecutionInstance<R, D> {
     pub database: D,
     pub config: Arc<Config>,
     pub options: ExecutionOptions,
+    /// Set by the caller that no longer waits for the result of the dry run.
+    /// The execution is stopped before the next transaction.
+    pub abandoned: Arc<AtomicBool>,
 }
 
 impl<R, D> ExecutionInstance<R, D>
@@ -311,6 +399,7 @@ where
                 header_to_produce: block.header,
                 transactions_source: OnceTransactionsSource::new(block.transactions),
                 gas_limit: u64::MAX,
+                coinbase_recipient: None,
             }),
             ExecutionTypes::Validation(block) => ExecutionTypes::Validation(block),
         };
@@ -346,6 +435,7 @@ where
                 component.transactions_source,
             ),
             gas_limit: component.gas_limit,
+            coinbase_recipient: component.coinbase_recipient,
         };
 
         let (
@@ -377,6 +467,9 @@ pub mod block_component {
         pub empty_block: &'a mut PartialFuelBlock,
         pub transactions_source: TxSource,
         pub gas_limit: u64,
+        /// The recipient of the fees of the block overriding the recipient
+        /// from the config.
+        pub coinbase_recipient: Option<ContractId>,
         /// The private marker to allow creation of the type only by constructor.
         _marker: core::marker::PhantomData<()>,
     }
@@ -384,10 +477,16 @@ pub mod block_component {
     impl<'a> PartialBlockComponent<'a, OnceTransactionsSource> {
         pub fn from_partial_block(block: &'a mut PartialFuelBlock) -> Self {
             let transaction = core::mem::take(&mut block.transactions);
+            // The recipient is recorded in the `Mint` transaction of the block.
+            let coinbase_recipient = match transaction.last() {
+                Some(Transaction::Mint(mint)) => Some(mint.input_contract().contract_id),
+                _ => None,
+            };
             Self {
                 empty_block: block,
                 transactions_source: OnceTransactionsSource::new(transaction),
                 gas_limit: u64::MAX,
+                coinbase_recipient,
                 _marker: Default::default(),
             }
         }
@@ -398,12 +497,14 @@ pub mod block_component {
             block: &'a mut PartialFuelBlock,
             transactions_source: TxSource,
             gas_limit: u64,
+            coinbase_recipient: Option<ContractId>,
         ) -> Self {
             debug_assert!(block.transactions.is_empty());
             PartialBlockComponent {
                 empty_block: block,
                 transactions_source,
                 gas_limit,
+                coinbase_recipient,
                 _marker: Default::default(),
             }
         }
@@ -441,6 +542,7 @@ where
                     &mut block,
                     component.transactions_source,
                     component.gas_limit,
+                    component.coinbase_recipient,
                 );
 
                 let execution_data = self.execute_block(
@@ -456,6 +558,7 @@ where
                     &mut block,
                     component.transactions_source,
                     component.gas_limit,
+                    component.coinbase_recipient,
                 );
 
                 let execution_data = self.execute_block(
@@ -531,6 +634,9 @@ where
             found_mint: false,
             message_ids: Vec::new(),
             tx_status: Vec::new(),
+            state_changes: self.config.record_state_diff.then(Default::default),
+            profile: self.config.profiler.as_ref().map(|_| Default::default()),
+            coinbase_recipient: self.config.coinbase_recipient,
             skipped_transactions: Vec::new(),
         };
         let execution_data = &mut data;
@@ -540,6 +646,9 @@ where
         let block = component.empty_block;
         let source = component.transactions_source;
         let mut remaining_gas_limit = component.gas_limit;
+        if let Some(coinbase_recipient) = component.coinbase_recipient {
+            execution_data.coinbase_recipient = coinbase_recipient;
+        }
         let block_height = *block.header.height();
 
         if self.relayer.enabled() {
@@ -551,6 +660,58 @@ where
         debug_assert!(block.transactions.is_empty());
         let mut iter = source.next(remaining_gas_limit).into_iter().peekable();
 
+        // During validation, all transactions of the block are known in advance,
+        // so their signatures are recovered in parallel before the execution.
+        if execution_kind == ExecutionKind::Validation
+            && self.options.utxo_validation
+            && self.config.execution_threads > 1
+            && !self.skips_signatures(
+                execution_kind,
+                &block_height,
+                block_st_transaction,
+            )?
+        {
+            iter = signatures::recover_signatures_in_parallel(
+                iter.collect(),
+                block_height,
+                &self.config.consensus_parameters,
+                self.config.execution_threads,
+            )?
+            .into_iter()
+            .peekable();
+        }
+
+        // During validation, all transactions of the block are known in advance,
+        // and it is possible to execute non-conflicting transactions in parallel.
+        if execution_kind == ExecutionKind::Validation
+            && self.config.execution_threads > 1
+        {
+            let mut transactions = iter.collect::<Vec<_>>();
+            // The `Mint` transaction depends on the fees of all previous transactions,
+            // so it and everything after it are executed sequentially.
+            let mint_position = transactions
+                .iter()
+                .position(|tx| {
+                    matches!(
+                        tx,
+                        MaybeCheckedTransaction::Transaction(Transaction::Mint(_))
+                            | MaybeCheckedTransaction::CheckedTransaction(
+                                CheckedTransaction::Mint(_)
+                            )
+                    )
+                })
+                .unwrap_or(transactions.len());
+            let sequential = transactions.split_off(mint_position);
+            let executed = self.execute_transactions_in_parallel(
+                block_st_transaction,
+                &block.header,
+                execution_data,
+                transactions,
+            )?;
+            block.transactions.extend(executed);
+            iter = sequential.into_iter().peekable();
+        }
+
         let mut execute_transaction = |execution_data: &mut ExecutionData,
                                        tx: MaybeCheckedTransaction|
          -> ExecutorResult<()> {
@@ -587,9 +748,7 @@ where
                     Ok(tx) => tx,
                 };
 
-                if let Err(err) = tx_st_transaction.commit() {
-                    return Err(err.into())
-                }
+                self.commit(tx_st_transaction)?;
                 tx
             };
 
@@ -603,6 +762,9 @@ where
 
         while iter.peek().is_some() {
             for transaction in iter {
+                if self.abandoned.load(Ordering::Relaxed) {
+                    return Err(ExecutorError::DryRunAbandoned)
+                }
                 execute_transaction(&mut *execution_data, transaction)?;
             }
 
@@ -612,10 +774,23 @@ where
             iter = source.next(remaining_gas_limit).into_iter().peekable();
         }
 
+        // The producers select the transactions below their own soft gas targets,
+        // but only the hard limit of the chain is a consensus rule.
+        if execution_kind == ExecutionKind::Validation {
+            if let Some(limit) = self.config.block_gas_limit {
+                if execution_data.used_gas > limit {
+                    return Err(ExecutorError::BlockGasLimitExceeded {
+                        used: execution_data.used_gas,
+                        limit,
+                    })
+                }
+            }
+        }
+
         // After the execution of all transactions in production mode, we can set the final fee.
         if execution_kind == ExecutionKind::Production {
-            let amount_to_mint = if self.config.coinbase_recipient != ContractId::zeroed()
-            {
+            let coinbase_recipient = execution_data.coinbase_recipient;
+            let amount_to_mint = if coinbase_recipient != ContractId::zeroed() {
                 execution_data.coinbase
             } else {
                 0
@@ -628,7 +803,7 @@ where
                     balance_root: Bytes32::zeroed(),
                     state_root: Bytes32::zeroed(),
                     tx_pointer: TxPointer::new(BlockHeight::new(0), 0),
-                    contract_id: self.config.coinbase_recipient,
+                    contract_id: coinbase_recipient,
                 },
                 output::contract::Contract {
                     input_index: 0,
@@ -649,9 +824,138 @@ where
             return Err(ExecutorError::MintMissing)
         }
 
+        if let Some(state_changes) = data.state_changes.take() {
+            block_st_transaction
+                .storage::<StateDiffs>()
+                .insert(&block_height, &state_changes.into())?;
+        }
+
+        if let (Some(profiler), Some(profile)) =
+            (self.config.profiler.as_ref(), data.profile.take())
+        {
+            if execution_kind != ExecutionKind::DryRun {
+                profiler.on_block(block_height, profile);
+            }
+        }
+
         Ok(data)
     }
 
+    /// Executes `transactions` on `Config::execution_threads` threads.
+    ///
+    /// Transactions are split into batches without conflicting inputs and outputs.
+    /// Transactions of one batch are executed concurrently on top of the state
+    /// committed by previous batches. The execution data is merged in the order of
+    /// transactions in the block, so the result is the same as for sequential execution.
+    fn execute_transactions_in_parallel(
+        &self,
+        block_st_transaction: &mut D,
+        header: &PartialBlockHeader,
+        execution_data: &mut ExecutionData,
+        transactions: Vec<MaybeCheckedTransaction>,
+    ) -> ExecutorResult<Vec<Transaction>> {
+        let chain_id = self.config.consensus_parameters.chain_id;
+        let batches = parallel::conflict_free_batches(&transactions, &chain_id);
+        let first_tx_index = execution_data.tx_count;
+        let coinbase_recipient = execution_data.coinbase_recipient;
+        let mut pending = transactions.into_iter().map(Some).collect::<Vec<_>>();
+        let mut executed = (0..pending.len()).map(|_| None).collect::<Vec<_>>();
+
+        for batch in batches {
+            for chunk in batch.chunks(self.config.execution_threads) {
+                let results = std::thread::scope(|scope| {
+                    let db = &*block_st_transaction;
+                    let handles = chunk
+                        .iter()
+                        .map(|&index| {
+                            let tx = pending[index]
+                                .take()
+                                .expect("Each transaction belongs to only one batch");
+                            let tx_index = u16::try_from(index)
+                                .ok()
+                                .and_then(|index| first_tx_index.checked_add(index));
+                            scope.spawn(move || {
+                                let tx_index =
+                                    tx_index.ok_or(ExecutorError::TooManyTransactions)?;
+                                let mut tx_st_transaction = db.transaction();
+                                let tx_id = tx.id(&chain_id);
+                                let mut data = ExecutionData {
+                                    tx_count: tx_index,
+                                    coinbase_recipient,
+                                    state_changes: self
+                                        .config
+                                        .record_state_diff
+                                        .then(Default::default),
+                                    profile: self
+                                        .config
+                                        .profiler
+                                        .as_ref()
+                                        .map(|_| Default::default()),
+                                    ..Default::default()
+                                };
+                                let tx = self.execute_transaction(
+                                    tx,
+                                    &tx_id,
+                                    header,
+                                    &mut data,
+                                    ExecutionKind::Validation,
+                                    &mut tx_st_transaction,
+                                )?;
+                                Ok((tx, data, tx_st_transaction))
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    handles
+                        .into_iter()
+                        .map(|handle| {
+                            handle
+                                .join()
+                                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                        })
+                        .collect::<Vec<ExecutorResult<_>>>()
+                });
+
+                // Transactions of the batch don't conflict,
+                // so the order of commits doesn't affect the final state.
+                for (&index, result) in chunk.iter().zip(results) {
+                    let (tx, data, tx_st_transaction) = result?;
+                    self.commit(tx_st_transaction)?;
+                    executed[index] = Some((tx, data));
+                }
+            }
+        }
+
+        let mut transactions = Vec::with_capacity(executed.len());
+        for (tx, data) in executed.into_iter().flatten() {
+            execution_data.coinbase = execution_data
+                .coinbase
+                .checked_add(data.coinbase)
+                .ok_or(ExecutorError::FeeOverflow)?;
+            execution_data.used_gas =
+                execution_data.used_gas.saturating_add(data.used_gas);
+            execution_data.message_ids.extend(data.message_ids);
+            execution_data.tx_status.extend(data.tx_status);
+            if let (Some(state_changes), Some(changes)) =
+                (execution_data.state_changes.as_mut(), data.state_changes)
+            {
+                state_changes.extend(changes);
+            }
+            if let (Some(profile), Some(tx_profile)) =
+                (execution_data.profile.as_mut(), data.profile)
+            {
+                profile.merge(&tx_profile);
+            }
+            execution_data.tx_count = execution_data
+                .tx_count
+                .checked_add(1)
+                .ok_or(ExecutorError::TooManyTransactions)?;
+            transactions.push(tx);
+        }
+
+        Ok(transactions)
+    }
+
     fn process_da(
         &self,
         block_st_transaction: &mut D,
@@ -835,8 +1139,11 @@ where
             let mut vm_db = VmStorage::new(
                 sub_block_db_commit.as_mut(),
                 &header.consensus,
-                self.config.coinbase_recipient,
+                mint.input_contract().contract_id,
             );
+            if self.config.record_state_diff {
+                vm_db = vm_db.with_recording();
+            }
 
             fuel_vm::interpreter::contract::balance_increase(
                 &mut vm_db,
@@ -846,7 +1153,8 @@ where
             )
             .map_err(|e| anyhow::anyhow!(format!("{e}")))
             .map_err(ExecutorError::CoinbaseCannotIncreaseBalance)?;
-            sub_block_db_commit.commit()?;
+            let contracts_changes = vm_db.take_changes();
+            self.commit(sub_block_db_commit)?;
 
             self.persist_output_utxos(
                 block_height,
@@ -889,6 +1197,12 @@ where
                 *mint.input_contract_mut() = input;
                 *mint.output_contract_mut() = output;
             }
+
+            if let (Some(state_changes), Some(contracts_changes)) =
+                (execution_data.state_changes.as_mut(), contracts_changes)
+            {
+                state_changes.extend_contracts(contracts_changes);
+            }
         }
 
         let tx = mint.into();
@@ -898,6 +1212,7 @@ where
             result: TransactionExecutionResult::Success {
                 result: None,
                 receipts: vec![],
+                fee: Default::default(),
             },
         });
 
@@ -929,16 +1244,25 @@ where
         let max_fee = checked_tx.metadata().max_fee();
 
         if self.options.utxo_validation {
-            checked_tx = checked_tx
-                .check_predicates(&CheckPredicateParams::from(
-                    &self.config.consensus_parameters,
-                ))
-                .map_err(|_| {
-                    ExecutorError::TransactionValidity(
-                        TransactionValidityError::InvalidPredicate(tx_id),
-                    )
-                })?;
-            debug_assert!(checked_tx.checks().contains(Checks::Predicates));
+            // The predicates evaluated successfully by the `TxPool` are not evaluated again.
+            let cached_predicates = !checked_tx.checks().contains(Checks::Predicates)
+                && self
+                    .config
+                    .predicate_cache
+                    .as_ref()
+                    .is_some_and(|cache| cache.contains(checked_tx.transaction()));
+            if !cached_predicates {
+                checked_tx = checked_tx
+                    .check_predicates(&CheckPredicateParams::from(
+                        &self.config.consensus_parameters,
+                    ))
+                    .map_err(|_| {
+                        ExecutorError::TransactionValidity(
+                            TransactionValidityError::InvalidPredicate(tx_id),
+                        )
+                    })?;
+                debug_assert!(checked_tx.checks().contains(Checks::Predicates));
+            }
 
             // validate utxos exist and maturity is properly set
             self.verify_input_state(
@@ -948,10 +1272,16 @@ where
                 header.da_height,
             )?;
             // validate transaction signature
-            checked_tx = checked_tx
-                .check_signatures(&self.config.consensus_parameters.chain_id)
-                .map_err(TransactionValidityError::from)?;
-            debug_assert!(checked_tx.checks().contains(Checks::Signatures));
+            if !self.skips_signatures(
+                execution_kind,
+                header.height(),
+                tx_st_transaction.as_ref(),
+            )? {
+                checked_tx = checked_tx
+                    .check_signatures(&self.config.consensus_parameters.chain_id)
+                    .map_err(TransactionValidityError::from)?;
+                debug_assert!(checked_tx.checks().contains(Checks::Signatures));
+            }
         }
 
         // execute transaction
@@ -960,16 +1290,30 @@ where
         let sub_db_view = sub_block_db_commit.as_mut();
 
         // execution vm
-        let vm_db = VmStorage::new(
+        let mut vm_db = VmStorage::new(
             sub_db_view.clone(),
             &header.consensus,
-            self.config.coinbase_recipient,
+            execution_data.coinbase_recipient,
         );
+        if self.config.record_state_diff {
+            vm_db = vm_db.with_recording();
+        }
 
         let mut vm = Interpreter::with_storage(
             vm_db,
             InterpreterParams::from(&self.config.consensus_parameters),
         );
+        #[cfg(feature = "profile-gas")]
+        let gas_collector = crate::profiler::GasCollector::default();
+        #[cfg(feature = "profile-gas")]
+        if execution_data.profile.is_some() {
+            vm.with_profiler(gas_collector.clone());
+        }
+        #[cfg(feature = "fault-injection")]
+        if let Some(fault_injector) = &self.config.fault_injector {
+            fault_injector.on_vm_execution(&tx_id)?;
+        }
+        let vm_started_at = Instant::now();
         let vm_result: StateTransition<_> = vm
             .transact(checked_tx.clone())
             .map_err(|error| ExecutorError::VmExecution {
@@ -977,7 +1321,18 @@ where
                 transaction_id: tx_id,
             })?
             .into();
+        let vm_time = vm_started_at.elapsed();
+        if execution_kind != ExecutionKind::Validation {
+            self.config
+                .receipts_limits
+                .check(vm_result.receipts())
+                .map_err(|reason| ExecutorError::ReceiptsLimitExceeded {
+                    transaction_id: tx_id,
+                    reason,
+                })?;
+        }
         let reverted = vm_result.should_revert();
+        let contracts_changes = vm.as_mut().take_changes();
 
         let (state, mut tx, receipts) = vm_result.into_inner();
         #[cfg(debug_assertions)]
@@ -1002,11 +1357,23 @@ where
 
         // only commit state changes if execution was a success
         if !reverted {
-            sub_block_db_commit.commit()?;
+            self.commit(sub_block_db_commit)?;
         }
 
         // update block commitment
         let (used_gas, tx_fee) = self.total_fee_paid(&tx, max_fee, &receipts)?;
+        let fee = FeeReport {
+            script_gas_used: used_gas,
+            predicate_gas_used: tx
+                .inputs()
+                .iter()
+                .filter_map(|input| input.predicate_gas_used())
+                .fold(0, Word::saturating_add),
+            gas_price: tx.price(),
+            max_fee,
+            fee: tx_fee,
+            refunded: max_fee.saturating_sub(tx_fee),
+        };
 
         // Check or set the executed transaction.
         match execution_kind {
@@ -1055,6 +1422,55 @@ where
         )?;
         *tx.outputs_mut() = outputs;
 
+        let state_changes = match contracts_changes {
+            Some(contracts_changes) => {
+                let mut state_changes = self.coins_changes(
+                    &tx_id,
+                    tx.inputs(),
+                    tx.outputs(),
+                    tx_st_transaction.as_ref(),
+                )?;
+                // Changes of the reverted transaction are not committed.
+                if !reverted {
+                    state_changes.contracts = contracts_changes;
+                }
+                Some(state_changes)
+            }
+            None => None,
+        };
+
+        let tx_profile = if execution_data.profile.is_some() {
+            let contracts = tx.inputs().iter().filter_map(|input| match input {
+                Input::Contract(Contract { contract_id, .. }) => Some(contract_id),
+                _ => None,
+            });
+            #[allow(unused_mut)]
+            let mut tx_profile =
+                ExecutionProfile::transaction(used_gas, vm_time, contracts);
+            #[cfg(feature = "profile-gas")]
+            if let Some(gas) = gas_collector.take() {
+                use fuel_core_storage::tables::ContractsRawCode;
+                use fuel_core_types::fuel_tx::field::Script as _;
+
+                tx_profile.add_gas_profile(&gas, |contract_id| match contract_id {
+                    Some(contract_id) => Ok(tx_st_transaction
+                        .as_ref()
+                        .storage::<ContractsRawCode>()
+                        .get(contract_id)?
+                        .map(|code| code.as_ref().as_ref().to_vec())
+                        .unwrap_or_default()),
+                    None => ExecutorResult::Ok(
+                        tx.as_script()
+                            .map(|script| script.script().clone())
+                            .unwrap_or_default(),
+                    ),
+                })?;
+            }
+            Some(tx_profile)
+        } else {
+            None
+        };
+
         let final_tx = tx.into();
 
         // Store tx into the block db transaction
@@ -1072,18 +1488,30 @@ where
         execution_data
             .message_ids
             .extend(receipts.iter().filter_map(|r| r.message_id()));
+        if let (Some(all_changes), Some(state_changes)) =
+            (execution_data.state_changes.as_mut(), state_changes)
+        {
+            all_changes.extend(state_changes);
+        }
+        if let (Some(profile), Some(tx_profile)) =
+            (execution_data.profile.as_mut(), tx_profile)
+        {
+            profile.merge(&tx_profile);
+        }
 
         let status = if reverted {
             self.log_backtrace(&vm, &receipts);
             TransactionExecutionResult::Failed {
                 result: Some(state),
                 receipts,
+                fee,
             }
         } else {
             // else tx was a success
             TransactionExecutionResult::Success {
                 result: Some(state),
                 receipts,
+                fee,
             }
         };
 
@@ -1096,6 +1524,52 @@ where
         Ok(final_tx)
     }
 
+    /// Commits the changes into the parent storage transaction.
+    fn commit<S>(&self, transaction: StorageTransaction<S>) -> ExecutorResult<()> {
+        #[cfg(feature = "fault-injection")]
+        if let Some(fault_injector) = &self.config.fault_injector {
+            fault_injector.on_storage_write()?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Returns `true` if the signatures of the inputs are not verified, because
+    /// the block is the finalized block replayed up to the trusted checkpoint.
+    /// The checkpoint is trusted only if the block stored at its height has
+    /// the same id, otherwise the validation fails.
+    fn skips_signatures(
+        &self,
+        execution_kind: ExecutionKind,
+        height: &BlockHeight,
+        db: &D,
+    ) -> ExecutorResult<bool> {
+        if execution_kind != ExecutionKind::Validation {
+            return Ok(false)
+        }
+        let Some((checkpoint_height, checkpoint_id)) = self.config.skip_signatures_until
+        else {
+            return Ok(false)
+        };
+        if *height > checkpoint_height {
+            return Ok(false)
+        }
+        let Some(checkpoint_block) =
+            db.storage::<FuelBlocks>().get(&checkpoint_height)?
+        else {
+            return Ok(false)
+        };
+        let found: BlockId = checkpoint_block.header().id();
+        if found != checkpoint_id {
+            return Err(ExecutorError::TrustedCheckpointMismatch {
+                height: checkpoint_height,
+                expected: checkpoint_id,
+                found,
+            })
+        }
+        Ok(true)
+    }
+
     fn verify_input_state(
         &self,
         db: &D,
@@ -1224,6 +1698,35 @@ where
         Ok(())
     }
 
+    /// Returns coins spent by the `inputs` and created by the `outputs`.
+    fn coins_changes(
+        &self,
+        tx_id: &TxId,
+        inputs: &[Input],
+        outputs: &[Output],
+        db: &D,
+    ) -> ExecutorResult<StateChanges> {
+        let mut state_changes = StateChanges::default();
+        for input in inputs {
+            if let Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) = input
+            {
+                state_changes.spent_coins.push(*utxo_id);
+            }
+        }
+        for output_index in 0..outputs.len() {
+            let index = u8::try_from(output_index)
+                .expect("Transaction can have only up to `u8::MAX` outputs");
+            let utxo_id = UtxoId::new(*tx_id, index);
+            if let Some(coin) = db.storage::<Coins>().get(&utxo_id)? {
+                state_changes
+                    .created_coins
+                    .push(coin.into_owned().uncompress(utxo_id));
+            }
+        }
+        Ok(state_changes)
+    }
+
     fn total_fee_paid<Tx: Chargeable>(
         &self,
         tx: &Tx,
@@ -0,0 +1,96 @@
+//! The deterministic failures injected into the block execution. It allows testing
+//! the rollback of the block production and import in the case of the execution
+//! error without the real broken storage or invalid transactions.
+//!
+//! Each fault is triggered only once, so the next execution of the same block
+//! succeeds if no other faults are injected.
+
+use fuel_core_types::{
+    fuel_asm::PanicReason,
+    fuel_tx::TxId,
+    fuel_vm::InterpreterError,
+    services::executor::{
+        Error as ExecutorError,
+        Result as ExecutorResult,
+    },
+};
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// The failure forced during the block execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Fails the `nth`(starting from zero, counting from the injection of the fault)
+    /// write of changes into the storage. The executor writes the changes after each
+    /// execution of the VM and after each executed transaction.
+    StorageWrite { nth: usize },
+    /// Fails the execution of the transaction with the VM panic.
+    VmPanic { tx_id: TxId, reason: PanicReason },
+}
+
+/// The shared set of faults that should be triggered by the executor.
+#[derive(Debug, Default, Clone)]
+pub struct FaultInjector {
+    faults: Arc<Mutex<Vec<Fault>>>,
+}
+
+impl FaultInjector {
+    /// Creates the injector without faults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `fault` to be triggered during the following executions.
+    pub fn inject(&self, fault: Fault) {
+        self.faults.lock().push(fault);
+    }
+
+    /// Removes all not triggered faults.
+    pub fn clear(&self) {
+        self.faults.lock().clear();
+    }
+
+    /// Returns faults that are not triggered yet.
+    pub fn pending(&self) -> Vec<Fault> {
+        self.faults.lock().clone()
+    }
+
+    pub(crate) fn on_storage_write(&self) -> ExecutorResult<()> {
+        let mut faults = self.faults.lock();
+        let mut triggered = false;
+        faults.retain_mut(|fault| match fault {
+            Fault::StorageWrite { nth } if *nth == 0 => {
+                triggered = true;
+                false
+            }
+            Fault::StorageWrite { nth } => {
+                *nth = nth.saturating_sub(1);
+                true
+            }
+            Fault::VmPanic { .. } => true,
+        });
+
+        if triggered {
+            Err(ExecutorError::StorageError(anyhow::anyhow!(
+                "The storage write failure is injected"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn on_vm_execution(&self, tx_id: &TxId) -> ExecutorResult<()> {
+        let mut faults = self.faults.lock();
+        let position = faults.iter().position(
+            |fault| matches!(fault, Fault::VmPanic { tx_id: id, .. } if id == tx_id),
+        );
+
+        match position.map(|position| faults.remove(position)) {
+            Some(Fault::VmPanic { reason, .. }) => Err(ExecutorError::VmExecution {
+                error: InterpreterError::Panic(reason),
+                transaction_id: *tx_id,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
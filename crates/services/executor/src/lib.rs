@@ -4,10 +4,16 @@
 #![deny(warnings)]
 
 mod config;
+mod parallel;
+mod signatures;
 
 pub mod executor;
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
 pub mod ports;
+pub mod profiler;
 pub mod refs;
+pub mod upgradable;
 
 pub struct BlockExecutor {}
 
@@ -0,0 +1,221 @@
+//! The dependency analysis of the block's transactions. It splits transactions into
+//! batches where transactions of one batch don't conflict with each other and can be
+//! executed in parallel on top of the state produced by previous batches.
+
+use crate::ports::MaybeCheckedTransaction;
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Input,
+        Output,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        ChainId,
+        ContractId,
+        Nonce,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+};
+use std::collections::HashMap;
+
+/// The part of the state that is read or modified by the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Resource {
+    /// The coin spent by the transaction.
+    Coin(UtxoId),
+    /// The contract used or created by the transaction.
+    Contract(ContractId),
+    /// The message spent by the transaction.
+    Message(Nonce),
+    /// Outputs created by the transaction with this id.
+    Outputs(TxId),
+}
+
+/// Splits `transactions` into batches of indexes. Transactions inside of the batch
+/// don't touch the same resources, while all transactions that conflict with a
+/// transaction are placed in previous or following batches, respecting the order
+/// of the block. Transactions without a known set of resources(like `Mint`) get a
+/// separate batch that acts as a barrier for all transactions around it.
+///
+/// The result is deterministic and depends only on the order of `transactions`.
+pub(crate) fn conflict_free_batches(
+    transactions: &[MaybeCheckedTransaction],
+    chain_id: &ChainId,
+) -> Vec<Vec<usize>> {
+    let mut batches: Vec<Vec<usize>> = vec![];
+    let mut last_batch_of_resource = HashMap::<Resource, usize>::new();
+    let mut barrier = 0usize;
+
+    for (index, tx) in transactions.iter().enumerate() {
+        let batch = match resources(tx, chain_id) {
+            Some(resources) => {
+                let batch = resources
+                    .iter()
+                    .filter_map(|resource| last_batch_of_resource.get(resource))
+                    .map(|batch| batch.saturating_add(1))
+                    .max()
+                    .unwrap_or_default()
+                    .max(barrier);
+                for resource in resources {
+                    last_batch_of_resource.insert(resource, batch);
+                }
+                batch
+            }
+            None => {
+                let batch = batches.len();
+                barrier = batch.saturating_add(1);
+                batch
+            }
+        };
+
+        if batch == batches.len() {
+            batches.push(vec![]);
+        }
+        batches[batch].push(index);
+    }
+
+    batches
+}
+
+fn resources(tx: &MaybeCheckedTransaction, chain_id: &ChainId) -> Option<Vec<Resource>> {
+    let (inputs, outputs) = match tx {
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(tx)) => {
+            (tx.transaction().inputs(), tx.transaction().outputs())
+        }
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Create(tx)) => {
+            (tx.transaction().inputs(), tx.transaction().outputs())
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Script(tx)) => {
+            (tx.inputs(), tx.outputs())
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Create(tx)) => {
+            (tx.inputs(), tx.outputs())
+        }
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(_))
+        | MaybeCheckedTransaction::Transaction(Transaction::Mint(_)) => return None,
+    };
+
+    let mut resources = vec![Resource::Outputs(tx.id(chain_id))];
+    for input in inputs {
+        match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                resources.push(Resource::Coin(*utxo_id));
+                // The coin can be created by the transaction from the same block.
+                resources.push(Resource::Outputs(*utxo_id.tx_id()));
+            }
+            Input::Contract(Contract { contract_id, .. }) => {
+                resources.push(Resource::Contract(*contract_id));
+            }
+            Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+            | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                resources.push(Resource::Message(*nonce));
+            }
+        }
+    }
+    for output in outputs {
+        if let Output::ContractCreated { contract_id, .. } = output {
+            resources.push(Resource::Contract(*contract_id));
+        }
+    }
+
+    Some(resources)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::AssetId,
+        fuel_vm::util::test_helpers::TestBuilder as TxBuilder,
+    };
+
+    fn independent_tx(seed: u64) -> MaybeCheckedTransaction {
+        let tx = TxBuilder::new(seed)
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        MaybeCheckedTransaction::Transaction(tx.into())
+    }
+
+    fn tx_spending_output_of(
+        parent: &MaybeCheckedTransaction,
+        seed: u64,
+    ) -> MaybeCheckedTransaction {
+        let parent_id = parent.id(&ChainId::default());
+        let mut tx = TxBuilder::new(seed)
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        if let Some(Input::CoinSigned(CoinSigned { utxo_id, .. })) =
+            tx.inputs_mut().first_mut()
+        {
+            *utxo_id = UtxoId::new(parent_id, 0);
+        }
+        MaybeCheckedTransaction::Transaction(tx.into())
+    }
+
+    #[test]
+    fn independent_transactions_are_in_one_batch() {
+        let transactions = (0..5).map(independent_tx).collect::<Vec<_>>();
+
+        let batches = conflict_free_batches(&transactions, &ChainId::default());
+
+        assert_eq!(batches, vec![vec![0, 1, 2, 3, 4]]);
+    }
+
+    #[test]
+    fn dependent_transaction_is_in_the_next_batch() {
+        let parent = independent_tx(1);
+        let child = tx_spending_output_of(&parent, 4);
+        let transactions = vec![parent, independent_tx(2), child, independent_tx(3)];
+
+        let batches = conflict_free_batches(&transactions, &ChainId::default());
+
+        assert_eq!(batches, vec![vec![0, 1, 3], vec![2]]);
+    }
+
+    #[test]
+    fn mint_is_a_barrier() {
+        let mint = MaybeCheckedTransaction::Transaction(
+            Transaction::mint(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                0,
+                Default::default(),
+            )
+            .into(),
+        );
+        let transactions = vec![independent_tx(1), mint, independent_tx(2)];
+
+        let batches = conflict_free_batches(&transactions, &ChainId::default());
+
+        assert_eq!(batches, vec![vec![0], vec![1], vec![2]]);
+    }
+}
@@ -10,6 +10,7 @@ use fuel_core_storage::{
         Messages,
         ProcessedTransactions,
         SpentMessages,
+        StateDiffs,
     },
     transactional::Transactional,
     Error as StorageError,
@@ -60,7 +61,7 @@ pub trait TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction>;
 }
 
-pub trait RelayerPort {
+pub trait RelayerPort: Send + Sync {
     /// Returns `true` if the relayer is enabled.
     fn enabled(&self) -> bool;
 
@@ -82,7 +83,10 @@ pub trait ExecutorDatabaseTrait<D>:
     + StorageMutate<ContractsInfo, Error = StorageError>
     + MerkleRootStorage<ContractId, ContractsState, Error = StorageError>
     + StorageBatchMutate<ContractsState, Error = StorageError>
+    + StorageMutate<StateDiffs, Error = StorageError>
     + Transactional<Storage = D>
     + Clone
+    + Send
+    + Sync
 {
 }
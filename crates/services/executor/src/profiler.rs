@@ -0,0 +1,282 @@
+//! The opt-in profiling of the block execution. It aggregates gas and wall-time
+//! spent per opcode and per called contract to guide the recalibration of the gas
+//! schedule and to find hot contracts.
+//!
+//! The gas per opcode and per contract is collected by the VM only if the
+//! `profile-gas` feature is enabled. Without it, the profile contains the wall-time
+//! and the gas of the transactions.
+
+use core::fmt;
+use fuel_core_types::{
+    fuel_asm::Opcode,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::Duration,
+};
+
+#[cfg(feature = "profile-gas")]
+pub(crate) use gas::GasCollector;
+
+/// The gas and wall-time spent on the part of the execution.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The gas spent. The gas of contracts is collected only with the
+    /// `profile-gas` feature.
+    pub gas: u64,
+    /// The wall-time spent by the VM. The VM doesn't measure time per call, so
+    /// the time of the contract is the time of transactions using it.
+    pub time: Duration,
+    /// The number of transactions.
+    pub transactions: u64,
+}
+
+impl Usage {
+    fn merge(&mut self, other: &Usage) {
+        self.gas = self.gas.saturating_add(other.gas);
+        self.time = self.time.saturating_add(other.time);
+        self.transactions = self.transactions.saturating_add(other.transactions);
+    }
+}
+
+/// The aggregated profile of the execution of one or several blocks.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExecutionProfile {
+    /// The number of profiled blocks.
+    pub blocks: u64,
+    /// The gas and wall-time spent by the VM on all transactions.
+    pub total: Usage,
+    /// The gas spent per opcode. Collected only with the `profile-gas` feature.
+    pub opcodes: HashMap<Opcode, u64>,
+    /// The usage per called contract.
+    pub contracts: HashMap<ContractId, Usage>,
+}
+
+impl ExecutionProfile {
+    /// Creates the profile of one transaction that used `contracts`.
+    pub(crate) fn transaction<'a>(
+        gas: u64,
+        time: Duration,
+        contracts: impl Iterator<Item = &'a ContractId>,
+    ) -> Self {
+        let usage = Usage {
+            gas,
+            time,
+            transactions: 1,
+        };
+        let contracts = contracts
+            .map(|contract_id| (*contract_id, Usage { gas: 0, ..usage }))
+            .collect();
+        Self {
+            blocks: 0,
+            total: usage,
+            opcodes: HashMap::new(),
+            contracts,
+        }
+    }
+
+    /// Merges `other` profile into this one.
+    pub fn merge(&mut self, other: &ExecutionProfile) {
+        self.blocks = self.blocks.saturating_add(other.blocks);
+        self.total.merge(&other.total);
+        for (opcode, gas) in &other.opcodes {
+            let entry = self.opcodes.entry(*opcode).or_default();
+            *entry = entry.saturating_add(*gas);
+        }
+        for (contract_id, usage) in &other.contracts {
+            self.contracts.entry(*contract_id).or_default().merge(usage);
+        }
+    }
+
+    /// Returns opcodes sorted by the spent gas, starting from the most expensive.
+    pub fn hot_opcodes(&self) -> Vec<(Opcode, u64)> {
+        let mut opcodes = self
+            .opcodes
+            .iter()
+            .map(|(opcode, gas)| (*opcode, *gas))
+            .collect::<Vec<_>>();
+        opcodes.sort_by(|(a_op, a_gas), (b_op, b_gas)| {
+            b_gas
+                .cmp(a_gas)
+                .then_with(|| (*a_op as u8).cmp(&(*b_op as u8)))
+        });
+        opcodes
+    }
+
+    /// Returns contracts sorted by the spent gas and time, starting from the hottest.
+    pub fn hot_contracts(&self) -> Vec<(ContractId, Usage)> {
+        let mut contracts = self
+            .contracts
+            .iter()
+            .map(|(contract_id, usage)| (*contract_id, *usage))
+            .collect::<Vec<_>>();
+        contracts.sort_by(|(a_id, a), (b_id, b)| {
+            b.gas
+                .cmp(&a.gas)
+                .then_with(|| b.time.cmp(&a.time))
+                .then_with(|| a_id.cmp(b_id))
+        });
+        contracts
+    }
+}
+
+impl fmt::Display for ExecutionProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "blocks: {}, transactions: {}, gas: {}, time: {:?}",
+            self.blocks, self.total.transactions, self.total.gas, self.total.time
+        )?;
+        for (contract_id, usage) in self.hot_contracts() {
+            writeln!(
+                f,
+                "contract {contract_id}: gas: {}, time: {:?}, transactions: {}",
+                usage.gas, usage.time, usage.transactions
+            )?;
+        }
+        for (opcode, gas) in self.hot_opcodes() {
+            writeln!(f, "opcode {opcode:?}: gas: {gas}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The shared collector of the execution profiles. Reports are available on demand
+/// via [`ExecutionProfiler::last_block`] and [`ExecutionProfiler::take_report`].
+#[derive(Debug, Default, Clone)]
+pub struct ExecutionProfiler {
+    /// Dump the profile of each block into logs.
+    log_blocks: bool,
+    state: Arc<Mutex<ProfilerState>>,
+}
+
+#[derive(Debug, Default)]
+struct ProfilerState {
+    last_block: Option<(BlockHeight, ExecutionProfile)>,
+    report: ExecutionProfile,
+}
+
+impl ExecutionProfiler {
+    /// Creates a new profiler. If `log_blocks` is set, the profile of each executed
+    /// block is dumped into logs.
+    pub fn new(log_blocks: bool) -> Self {
+        Self {
+            log_blocks,
+            state: Default::default(),
+        }
+    }
+
+    /// Returns the profile of the last executed block.
+    pub fn last_block(&self) -> Option<(BlockHeight, ExecutionProfile)> {
+        self.state.lock().last_block.clone()
+    }
+
+    /// Returns the profile aggregated since the start or the previous call and
+    /// starts a new one.
+    pub fn take_report(&self) -> ExecutionProfile {
+        core::mem::take(&mut self.state.lock().report)
+    }
+
+    pub(crate) fn on_block(&self, height: BlockHeight, mut profile: ExecutionProfile) {
+        profile.blocks = 1;
+        if self.log_blocks {
+            tracing::info!("Execution profile of the block {height}:\n{profile}");
+        }
+        let mut state = self.state.lock();
+        state.report.merge(&profile);
+        state.last_block = Some((height, profile));
+    }
+}
+
+#[cfg(feature = "profile-gas")]
+mod gas {
+    use super::{
+        ExecutionProfile,
+        Usage,
+    };
+    use fuel_core_types::{
+        fuel_asm::Opcode,
+        fuel_types::ContractId,
+        fuel_vm::{
+            profiler::{
+                GasProfilingData,
+                ProfileReceiver,
+                ProfilingData,
+            },
+            state::ProgramState,
+            InterpreterError,
+        },
+    };
+    use parking_lot::Mutex;
+    use std::{
+        collections::HashMap,
+        sync::Arc,
+    };
+
+    /// Receives the gas profile of the transaction from the VM.
+    #[derive(Clone, Default)]
+    pub(crate) struct GasCollector(Arc<Mutex<Option<GasProfilingData>>>);
+
+    impl GasCollector {
+        pub(crate) fn take(&self) -> Option<GasProfilingData> {
+            self.0.lock().take()
+        }
+    }
+
+    impl ProfileReceiver for GasCollector {
+        fn on_transaction(
+            &mut self,
+            _: Result<&ProgramState, InterpreterError<String>>,
+            data: &ProfilingData,
+        ) {
+            *self.0.lock() = Some(data.gas().clone());
+        }
+    }
+
+    impl ExecutionProfile {
+        /// Attributes the gas from the VM profile to opcodes and contracts.
+        /// The `code` returns the bytecode of the contract or of the script for `None`.
+        pub(crate) fn add_gas_profile<F, E>(
+            &mut self,
+            data: &GasProfilingData,
+            mut code: F,
+        ) -> Result<(), E>
+        where
+            F: FnMut(Option<&ContractId>) -> Result<Vec<u8>, E>,
+        {
+            let mut per_context = HashMap::<Option<ContractId>, Vec<(u64, u64)>>::new();
+            for (location, gas) in data.iter() {
+                per_context
+                    .entry(location.context())
+                    .or_default()
+                    .push((location.offset(), *gas));
+            }
+
+            for (context, locations) in per_context {
+                let code = code(context.as_ref())?;
+                for (offset, gas) in locations {
+                    let opcode = usize::try_from(offset)
+                        .ok()
+                        .and_then(|offset| code.get(offset))
+                        .and_then(|byte| Opcode::try_from(*byte).ok());
+                    if let Some(opcode) = opcode {
+                        let entry = self.opcodes.entry(opcode).or_default();
+                        *entry = entry.saturating_add(gas);
+                    }
+                    if let Some(contract_id) = context {
+                        let usage: &mut Usage =
+                            self.contracts.entry(contract_id).or_default();
+                        usage.gas = usage.gas.saturating_add(gas);
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
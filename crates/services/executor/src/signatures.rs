@@ -0,0 +1,178 @@
+//! The verification of the signatures of the block's transactions before their execution.
+//! The recovery of the public keys from the signatures is the most expensive part of
+//! the validation of the blocks with many transfers. The signatures don't depend on
+//! the state, so they are recovered for all transactions of the block at once on
+//! the rayon thread pool instead of one by one during the execution. It is not
+//! a batch verification: each signature is still recovered separately.
+
+use crate::ports::MaybeCheckedTransaction;
+use fuel_core_types::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::BlockHeight,
+    fuel_vm::checked_transaction::{
+        CheckedTransaction,
+        IntoChecked,
+    },
+    services::executor::{
+        Result as ExecutorResult,
+        TransactionValidityError,
+    },
+};
+use rayon::prelude::*;
+
+/// Recovers the signatures of the `transactions` in up to `threads` chunks, processed
+/// concurrently on the global rayon thread pool, the same pool the block importer
+/// executes blocks on. Returns the transactions checked with the signatures, so
+/// the execution doesn't verify them again. If several transactions are invalid,
+/// the error of the first one in the order of the block is returned.
+pub(crate) fn recover_signatures_in_parallel(
+    transactions: Vec<MaybeCheckedTransaction>,
+    block_height: BlockHeight,
+    consensus_parameters: &ConsensusParameters,
+    threads: usize,
+) -> ExecutorResult<Vec<MaybeCheckedTransaction>> {
+    let chunk_size = transactions.len().div_ceil(threads.max(1)).max(1);
+    let mut chunks = vec![];
+    let mut iter = transactions.into_iter();
+    loop {
+        let chunk = iter.by_ref().take(chunk_size).collect::<Vec<_>>();
+        if chunk.is_empty() {
+            break
+        }
+        chunks.push(chunk);
+    }
+
+    let results = chunks
+        .into_par_iter()
+        .map(|chunk| {
+            chunk
+                .into_iter()
+                .map(|tx| verify(tx, block_height, consensus_parameters))
+                .collect::<ExecutorResult<Vec<_>>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut verified = vec![];
+    for result in results {
+        verified.extend(result?);
+    }
+    Ok(verified)
+}
+
+fn verify(
+    tx: MaybeCheckedTransaction,
+    block_height: BlockHeight,
+    consensus_parameters: &ConsensusParameters,
+) -> ExecutorResult<MaybeCheckedTransaction> {
+    let checked_tx = match tx {
+        MaybeCheckedTransaction::Transaction(tx) => tx
+            .into_checked_basic(block_height, consensus_parameters)?
+            .into(),
+        MaybeCheckedTransaction::CheckedTransaction(checked_tx) => checked_tx,
+    };
+    let chain_id = &consensus_parameters.chain_id;
+    let checked_tx = match checked_tx {
+        CheckedTransaction::Script(script) => CheckedTransaction::Script(
+            script
+                .check_signatures(chain_id)
+                .map_err(TransactionValidityError::from)?,
+        ),
+        CheckedTransaction::Create(create) => CheckedTransaction::Create(
+            create
+                .check_signatures(chain_id)
+                .map_err(TransactionValidityError::from)?,
+        ),
+        CheckedTransaction::Mint(mint) => CheckedTransaction::Mint(mint),
+    };
+    Ok(MaybeCheckedTransaction::CheckedTransaction(checked_tx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::{
+            field::Witnesses,
+            AssetId,
+            Transaction,
+            UniqueIdentifier,
+        },
+        fuel_vm::{
+            checked_transaction::Checks,
+            util::test_helpers::TestBuilder as TxBuilder,
+        },
+        services::executor::Error as ExecutorError,
+    };
+
+    fn signed_tx(seed: u64) -> Transaction {
+        TxBuilder::new(seed)
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+            .into()
+    }
+
+    fn tx_with_invalid_signature(seed: u64) -> Transaction {
+        let mut tx = TxBuilder::new(seed)
+            .coin_input(AssetId::default(), 100)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone();
+        tx.witnesses_mut()[0] = vec![0; 64].into();
+        tx.into()
+    }
+
+    fn verify_all(
+        transactions: Vec<Transaction>,
+        threads: usize,
+    ) -> ExecutorResult<Vec<MaybeCheckedTransaction>> {
+        recover_signatures_in_parallel(
+            transactions
+                .into_iter()
+                .map(MaybeCheckedTransaction::Transaction)
+                .collect(),
+            Default::default(),
+            &Default::default(),
+            threads,
+        )
+    }
+
+    #[test]
+    fn verified_transactions_keep_the_order_of_the_block() {
+        let transactions = (0..10).map(signed_tx).collect::<Vec<_>>();
+        let expected = transactions
+            .iter()
+            .map(|tx| tx.id(&Default::default()))
+            .collect::<Vec<_>>();
+
+        let verified = verify_all(transactions, 3).unwrap();
+
+        let ids = verified
+            .iter()
+            .map(|tx| tx.id(&Default::default()))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, expected);
+        for tx in verified {
+            let MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(
+                script,
+            )) = tx
+            else {
+                panic!("The transaction should be checked");
+            };
+            assert!(script.checks().contains(Checks::Signatures));
+        }
+    }
+
+    #[test]
+    fn invalid_signature_fails_the_block() {
+        let mut transactions = (0..10).map(signed_tx).collect::<Vec<_>>();
+        transactions[7] = tx_with_invalid_signature(7);
+
+        let result = verify_all(transactions, 4);
+
+        assert!(matches!(result, Err(ExecutorError::TransactionValidity(_))));
+    }
+}
@@ -0,0 +1,260 @@
+//! The executor is versioned to support forkless upgrades of the execution rules.
+//! The [`UpgradableExecutor`] selects the implementation of the rules by the height
+//! of the block. It allows switching to a new implementation(for example, a WASM
+//! executor compiled for a specific network version) at the activation height
+//! and replaying historical blocks with the rules that were active at that time.
+
+use crate::{
+    executor::{
+        ExecutionBlockWithSource,
+        Executor,
+    },
+    ports::{
+        ExecutorDatabaseTrait,
+        MaybeCheckedTransaction,
+        RelayerPort,
+        TransactionsSource,
+    },
+};
+use fuel_core_storage::transactional::{
+    AtomicView,
+    StorageTransaction,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+    services::{
+        block_producer::Components,
+        executor::{
+            ExecutionTypes,
+            Result as ExecutorResult,
+            TransactionExecutionStatus,
+            UncommittedResult,
+        },
+    },
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
+};
+
+/// The version of the execution rules.
+pub type ExecutorVersion = u32;
+
+/// The version of the rules implemented by the native [`Executor`].
+pub const NATIVE_EXECUTOR_VERSION: ExecutorVersion = 0;
+
+/// The source of transactions erased to be passed into any version of the executor.
+pub type BoxedTransactionsSource<'a> = Box<dyn TransactionsSource + 'a>;
+
+impl TransactionsSource for BoxedTransactionsSource<'_> {
+    fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
+        self.as_ref().next(gas_limit)
+    }
+}
+
+/// The implementation of the specific version of the execution rules.
+pub trait VersionedExecutor<View>: Send + Sync {
+    /// Returns the version of the execution rules.
+    fn version(&self) -> ExecutorVersion;
+
+    /// Executes the block and returns the result of the execution without committing
+    /// the changes.
+    fn execute_without_commit(
+        &self,
+        block: ExecutionBlockWithSource<BoxedTransactionsSource<'_>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>;
+
+    /// Executes transactions without committing the changes. The dry run is stopped
+    /// once the `abandoned` is set.
+    fn dry_run(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
+}
+
+impl<D, R, View> VersionedExecutor<View> for Executor<D, R>
+where
+    R: AtomicView<Height = DaBlockHeight> + Send + Sync,
+    R::View: RelayerPort,
+    D: AtomicView<View = View, Height = BlockHeight> + Send + Sync,
+    D::View: ExecutorDatabaseTrait<View>,
+{
+    fn version(&self) -> ExecutorVersion {
+        NATIVE_EXECUTOR_VERSION
+    }
+
+    fn execute_without_commit(
+        &self,
+        block: ExecutionBlockWithSource<BoxedTransactionsSource<'_>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>> {
+        Executor::execute_without_commit(self, block)
+    }
+
+    fn dry_run(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        Executor::dry_run(self, component, utxo_validation, abandoned)
+    }
+}
+
+/// The executor that delegates the execution of the block to the version of
+/// the executor active at the height of the block.
+pub struct UpgradableExecutor<View> {
+    /// Executors by their activation heights.
+    executors: BTreeMap<BlockHeight, Arc<dyn VersionedExecutor<View>>>,
+}
+
+impl<View> Clone for UpgradableExecutor<View> {
+    fn clone(&self) -> Self {
+        Self {
+            executors: self.executors.clone(),
+        }
+    }
+}
+
+impl<View> fmt::Debug for UpgradableExecutor<View> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.executors
+                    .iter()
+                    .map(|(height, executor)| (height, executor.version())),
+            )
+            .finish()
+    }
+}
+
+impl<View> UpgradableExecutor<View> {
+    /// Creates the executor that uses `genesis_executor` for all blocks until
+    /// the first upgrade.
+    pub fn new(genesis_executor: Arc<dyn VersionedExecutor<View>>) -> Self {
+        Self {
+            executors: BTreeMap::from([(BlockHeight::new(0), genesis_executor)]),
+        }
+    }
+
+    /// Uses `executor` for blocks starting from the `activation_height` until the
+    /// next upgrade. Returns the executor previously registered at this height.
+    pub fn upgrade(
+        &mut self,
+        activation_height: BlockHeight,
+        executor: Arc<dyn VersionedExecutor<View>>,
+    ) -> Option<Arc<dyn VersionedExecutor<View>>> {
+        self.executors.insert(activation_height, executor)
+    }
+
+    /// Returns the executor active at the `height`.
+    pub fn executor_at(&self, height: &BlockHeight) -> &Arc<dyn VersionedExecutor<View>> {
+        self.executors
+            .range(..=*height)
+            .next_back()
+            .map(|(_, executor)| executor)
+            .expect("The genesis executor is registered at the zero height")
+    }
+
+    /// Returns the version of the execution rules active at the `height`.
+    pub fn version_at(&self, height: &BlockHeight) -> ExecutorVersion {
+        self.executor_at(height).version()
+    }
+
+    /// Executes the block with the executor active at the height of the block.
+    pub fn execute_without_commit<TxSource>(
+        &self,
+        block: ExecutionBlockWithSource<TxSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>
+    where
+        TxSource: TransactionsSource,
+    {
+        let height = match &block {
+            ExecutionTypes::DryRun(component) | ExecutionTypes::Production(component) => {
+                *component.header_to_produce.height()
+            }
+            ExecutionTypes::Validation(block) => *block.header().height(),
+        };
+        let block = block.map_p(|component| Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: Box::new(component.transactions_source)
+                as BoxedTransactionsSource<'_>,
+            gas_limit: component.gas_limit,
+            coinbase_recipient: component.coinbase_recipient,
+        });
+        self.executor_at(&height).execute_without_commit(block)
+    }
+
+    /// Executes transactions with the executor active at the height of the block
+    /// from the `component`.
+    pub fn dry_run(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        let height = *component.header_to_produce.height();
+        self.executor_at(&height)
+            .dry_run(component, utxo_validation, abandoned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeExecutor(ExecutorVersion);
+
+    impl VersionedExecutor<()> for FakeExecutor {
+        fn version(&self) -> ExecutorVersion {
+            self.0
+        }
+
+        fn execute_without_commit(
+            &self,
+            _: ExecutionBlockWithSource<BoxedTransactionsSource<'_>>,
+        ) -> ExecutorResult<UncommittedResult<StorageTransaction<()>>> {
+            panic!("The tests only select the executor and never execute blocks")
+        }
+
+        fn dry_run(
+            &self,
+            _: Components<Vec<Transaction>>,
+            _: Option<bool>,
+            _: Arc<AtomicBool>,
+        ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn executor_is_selected_by_the_height() {
+        let mut executor = UpgradableExecutor::new(Arc::new(FakeExecutor(0)));
+        executor.upgrade(10u32.into(), Arc::new(FakeExecutor(1)));
+        executor.upgrade(20u32.into(), Arc::new(FakeExecutor(2)));
+
+        assert_eq!(executor.version_at(&0u32.into()), 0);
+        assert_eq!(executor.version_at(&9u32.into()), 0);
+        assert_eq!(executor.version_at(&10u32.into()), 1);
+        assert_eq!(executor.version_at(&19u32.into()), 1);
+        assert_eq!(executor.version_at(&20u32.into()), 2);
+        assert_eq!(executor.version_at(&u32::MAX.into()), 2);
+    }
+
+    #[test]
+    fn upgrade_replaces_the_executor_at_the_same_height() {
+        let mut executor = UpgradableExecutor::new(Arc::new(FakeExecutor(0)));
+
+        let previous = executor.upgrade(0u32.into(), Arc::new(FakeExecutor(1)));
+
+        assert_eq!(previous.map(|executor| executor.version()), Some(0));
+        assert_eq!(executor.version_at(&0u32.into()), 1);
+    }
+}
@@ -1,11 +1,16 @@
 use fuel_core_chain_config::ChainConfig;
-use fuel_core_types::fuel_types::ChainId;
+use fuel_core_types::{
+    fuel_types::ChainId,
+    services::block_importer::Finality,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
     pub chain_id: ChainId,
+    /// The rule of the finality of the imported blocks.
+    pub finality: Finality,
 }
 
 impl Config {
@@ -14,6 +19,7 @@ impl Config {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
             chain_id: chain_config.consensus_parameters.chain_id,
+            finality: Finality::default(),
         }
     }
 }
@@ -25,6 +31,7 @@ impl Default for Config {
             max_block_notify_buffer: 1,
             metrics: false,
             chain_id: ChainId::default(),
+            finality: Finality::default(),
         }
     }
 }
@@ -28,8 +28,10 @@ use fuel_core_types::{
     },
     services::{
         block_importer::{
+            BlockFinalized,
+            Finality,
             ImportResult,
-            SharedImportResult,
+            ImporterEvent,
             UncommittedResult,
         },
         executor,
@@ -114,7 +116,9 @@ pub struct Importer<D, E, V> {
     executor: Arc<E>,
     verifier: Arc<V>,
     chain_id: ChainId,
-    broadcast: broadcast::Sender<SharedImportResult>,
+    finality: Finality,
+    /// The channel of the [`ImporterEvent`]s.
+    broadcast: broadcast::Sender<ImporterEvent>,
     /// The channel to notify about the end of the processing of the previous block by all listeners.
     /// It is used to await until all receivers of the notification process the `SharedImportResult`
     /// before starting committing a new block.
@@ -124,20 +128,24 @@ pub struct Importer<D, E, V> {
 
 impl<D, E, V> Importer<D, E, V> {
     pub fn new(config: Config, database: D, executor: E, verifier: V) -> Self {
-        let (broadcast, _) = broadcast::channel(config.max_block_notify_buffer);
+        // Each imported block produces the `BlockImported` and `BlockFinalized` events.
+        let (broadcast, _) =
+            broadcast::channel(config.max_block_notify_buffer.saturating_mul(2));
 
         Self {
             database,
             executor: Arc::new(executor),
             verifier: Arc::new(verifier),
             chain_id: config.chain_id,
+            finality: config.finality,
             broadcast,
             prev_block_process_result: Default::default(),
             guard: tokio::sync::Semaphore::new(1),
         }
     }
 
-    pub fn subscribe(&self) -> broadcast::Receiver<SharedImportResult> {
+    /// Subscribes to the events of the block importer.
+    pub fn subscribe(&self) -> broadcast::Receiver<ImporterEvent> {
         self.broadcast.subscribe()
     }
 
@@ -233,7 +241,7 @@ where
                 }
                 actual_next_height
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::PoAWithBeacon(..) => {
                 if actual_next_height == BlockHeight::from(0u32) {
                     return Err(Error::ZeroNonGenericHeight)
                 }
@@ -298,8 +306,17 @@ where
         // The `tokio::sync::oneshot::Sender` is used to notify about the end
         // of the processing of a new block by all listeners.
         let (sender, receiver) = oneshot::channel();
-        let _ = self.broadcast.send(Arc::new(Awaiter::new(result, sender)));
+        let _ =
+            self.broadcast
+                .send(ImporterEvent::BlockImported(Arc::new(Awaiter::new(
+                    result, sender,
+                ))));
         *self.prev_block_process_result.lock().expect("poisoned") = Some(receiver);
+        if let Some(height) = self.finality.finalized_height(actual_next_height) {
+            let _ = self
+                .broadcast
+                .send(ImporterEvent::BlockFinalized(BlockFinalized { height }));
+        }
 
         Ok(())
     }
@@ -357,6 +374,7 @@ where
         )
     }
 
+    #[tracing::instrument(skip_all, err)]
     fn verify_and_execute_block_inner(
         executor: Arc<E>,
         verifier: Arc<V>,
@@ -421,6 +439,11 @@ where
 {
     /// The method validates the `Block` fields and commits the `SealedBlock`.
     /// It is a combination of the [`Importer::verify_and_execute_block`] and [`Importer::commit_result`].
+    #[tracing::instrument(
+        skip_all,
+        fields(height = **sealed_block.entity.header().height()),
+        err
+    )]
     pub async fn execute_and_commit(
         &self,
         sealed_block: SealedBlock,
@@ -429,7 +452,10 @@ where
 
         let executor = self.executor.clone();
         let verifier = self.verifier.clone();
-        let (result, execute_time) = tokio_rayon::spawn_fifo(|| {
+        // The execution runs on the rayon thread, so the span is passed explicitly.
+        let span = tracing::Span::current();
+        let (result, execute_time) = tokio_rayon::spawn_fifo(move || {
+            let _entered = span.enter();
             let start = Instant::now();
             let result =
                 Self::verify_and_execute_block_inner(executor, verifier, sealed_block);
@@ -6,6 +6,7 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -30,7 +31,9 @@ use fuel_core_types::{
     },
     services::{
         block_importer::{
+            Finality,
             ImportResult,
+            ImporterEvent,
             UncommittedResult,
         },
         executor::{
@@ -369,8 +372,21 @@ async fn commit_result_assert(
     let result = importer.commit_result(uncommitted_result).await;
 
     if result.is_ok() {
-        let actual_sealed_block = imported_blocks.try_recv().unwrap();
+        let ImporterEvent::BlockImported(actual_sealed_block) =
+            imported_blocks.try_recv().unwrap()
+        else {
+            panic!("We should broadcast the imported block first");
+        };
         assert_eq!(actual_sealed_block.sealed_block, expected_to_broadcast);
+        let ImporterEvent::BlockFinalized(finalized) =
+            imported_blocks.try_recv().unwrap()
+        else {
+            panic!("We should broadcast the finalized height after the block");
+        };
+        assert_eq!(
+            finalized.height,
+            *expected_to_broadcast.entity.header().height()
+        );
         if let Err(err) = imported_blocks.try_recv() {
             assert_eq!(err, TryRecvError::Empty);
         } else {
@@ -394,8 +410,21 @@ async fn execute_and_commit_assert(
     let result = importer.execute_and_commit(sealed_block).await;
 
     if result.is_ok() {
-        let actual_sealed_block = imported_blocks.try_recv().unwrap();
+        let ImporterEvent::BlockImported(actual_sealed_block) =
+            imported_blocks.try_recv().unwrap()
+        else {
+            panic!("We should broadcast the imported block first");
+        };
         assert_eq!(actual_sealed_block.sealed_block, expected_to_broadcast);
+        let ImporterEvent::BlockFinalized(finalized) =
+            imported_blocks.try_recv().unwrap()
+        else {
+            panic!("We should broadcast the finalized height after the block");
+        };
+        assert_eq!(
+            finalized.height,
+            *expected_to_broadcast.entity.header().height()
+        );
 
         if let Err(err) = imported_blocks.try_recv() {
             assert_eq!(err, TryRecvError::Empty);
@@ -406,6 +435,37 @@ async fn execute_and_commit_assert(
 
     result
 }
+#[test_case(Finality::Confirmations(2), 113 => Some(111u32.into()) ; "confirmed block")]
+#[test_case(Finality::Confirmations(200), 113 => None ; "no confirmed blocks yet")]
+#[tokio::test]
+async fn finalized_height_is_behind_by_confirmations(
+    finality: Finality,
+    height: u32,
+) -> Option<BlockHeight> {
+    let config = Config {
+        finality,
+        ..Default::default()
+    };
+    let previous_height = height.checked_sub(1).unwrap_or_default();
+    let importer =
+        Importer::new(config, underlying_db(ok(Some(previous_height)))(), (), ());
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(height), vec![]),
+        StorageTransaction::new(executor_db(ok(Some(previous_height)), ok(true), 1)()),
+    );
+
+    let mut events = importer.subscribe();
+    importer.commit_result(uncommitted_result).await.unwrap();
+
+    let Ok(ImporterEvent::BlockImported(_)) = events.try_recv() else {
+        panic!("We should broadcast the imported block first");
+    };
+    match events.try_recv() {
+        Ok(ImporterEvent::BlockFinalized(finalized)) => Some(finalized.height),
+        Err(TryRecvError::Empty) => None,
+        event => panic!("Unexpected event {event:?}"),
+    }
+}
 
 #[tokio::test]
 async fn commit_result_fail_when_locked() {
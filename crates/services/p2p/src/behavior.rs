@@ -3,7 +3,13 @@ use crate::{
         postcard::PostcardCodec,
         NetworkCodec,
     },
-    config::Config,
+    config::{
+        network_identity::{
+            agent_version,
+            NetworkIdentity,
+        },
+        Config,
+    },
     discovery,
     gossipsub::{
         config::build_gossipsub_behaviour,
@@ -65,7 +71,11 @@ pub struct FuelBehaviour {
 }
 
 impl FuelBehaviour {
-    pub(crate) fn new(p2p_config: &Config, codec: PostcardCodec) -> Self {
+    pub(crate) fn new(
+        p2p_config: &Config,
+        network_identity: &NetworkIdentity,
+        codec: PostcardCodec,
+    ) -> Self {
         let local_public_key = p2p_config.keypair.public();
         let local_peer_id = PeerId::from_public_key(&local_public_key);
 
@@ -97,9 +107,10 @@ impl FuelBehaviour {
 
         let identify = {
             let identify_config = identify::Config::new(
-                "/fuel/1.0".to_string(),
+                network_identity.protocol(),
                 p2p_config.keypair.public(),
-            );
+            )
+            .with_agent_version(agent_version());
             if let Some(interval) = p2p_config.identify_interval {
                 identify::Behaviour::new(identify_config.with_interval(interval))
             } else {
@@ -7,6 +7,8 @@ use crate::{
         GossipTopicTag,
         GossipsubBroadcastRequest,
         GossipsubMessage,
+        TxGossipVersion,
+        TX_GOSSIP_VERSION_MARKER,
     },
     request_response::messages::{
         RequestMessage,
@@ -15,6 +17,7 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+use fuel_core_types::fuel_tx::Transaction;
 use futures::{
     AsyncRead,
     AsyncReadExt,
@@ -45,6 +48,8 @@ pub struct PostcardCodec {
     /// Necessary in order to avoid DoS attacks
     /// Currently the size mostly depends on the max size of the Block
     max_response_size: usize,
+    /// The encoding of the published transactions
+    tx_gossip_version: TxGossipVersion,
 }
 
 impl PostcardCodec {
@@ -56,8 +61,15 @@ impl PostcardCodec {
 
         Self {
             max_response_size: max_block_size,
+            tx_gossip_version: TxGossipVersion::default(),
         }
     }
+
+    /// Sets the encoding of the published transactions.
+    pub fn with_tx_gossip_version(mut self, tx_gossip_version: TxGossipVersion) -> Self {
+        self.tx_gossip_version = tx_gossip_version;
+        self
+    }
 }
 
 /// Since Postcard does not support async reads or writes out of the box
@@ -141,10 +153,18 @@ impl GossipsubCodec for PostcardCodec {
 
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
-            GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewTx(tx) => match self.tx_gossip_version {
+                TxGossipVersion::V0 => serialize(&*tx)?,
+                version @ TxGossipVersion::V1 => {
+                    let mut encoded_data =
+                        vec![TX_GOSSIP_VERSION_MARKER, version.as_u8()];
+                    encoded_data.extend(serialize(&*tx)?);
+                    encoded_data
+                }
+            },
         };
 
-        encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+        Ok(encoded_data)
     }
 
     fn decode(
@@ -153,13 +173,31 @@ impl GossipsubCodec for PostcardCodec {
         gossipsub_tag: GossipTopicTag,
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
-            GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::NewTx => GossipsubMessage::NewTx(decode_tx(encoded_data)?),
         };
 
         Ok(decoded_response)
     }
 }
 
+/// Decodes the gossiped transaction of any supported version.
+fn decode_tx(encoded_data: &[u8]) -> Result<Transaction, io::Error> {
+    match encoded_data {
+        [TX_GOSSIP_VERSION_MARKER, version, payload @ ..] => {
+            match TxGossipVersion::try_from(*version)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            {
+                TxGossipVersion::V0 => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "The plain transaction can't have the version header",
+                )),
+                TxGossipVersion::V1 => deserialize(payload),
+            }
+        }
+        _ => deserialize(encoded_data),
+    }
+}
+
 impl NetworkCodec for PostcardCodec {
     fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol {
         MessageExchangePostcardProtocol {}
@@ -179,6 +217,35 @@ impl AsRef<str> for MessageExchangePostcardProtocol {
 mod tests {
     use super::*;
     use crate::request_response::messages::MAX_REQUEST_SIZE;
+    use std::sync::Arc;
+
+    fn gossiped_tx(version: TxGossipVersion) -> Vec<u8> {
+        let codec = PostcardCodec::new(1024).with_tx_gossip_version(version);
+        let tx = Arc::new(Transaction::default_test_tx());
+        codec.encode(GossipsubBroadcastRequest::NewTx(tx)).unwrap()
+    }
+
+    #[test]
+    fn all_tx_gossip_versions_are_decoded() {
+        let codec = PostcardCodec::new(1024);
+
+        for version in [TxGossipVersion::V0, TxGossipVersion::V1] {
+            let encoded = gossiped_tx(version);
+            let GossipsubMessage::NewTx(tx) =
+                codec.decode(&encoded, GossipTopicTag::NewTx).unwrap();
+
+            assert_eq!(tx, Transaction::default_test_tx());
+        }
+    }
+
+    #[test]
+    fn unknown_tx_gossip_version_is_rejected() {
+        let codec = PostcardCodec::new(1024);
+        let mut encoded = gossiped_tx(TxGossipVersion::V1);
+        encoded[1] = 100;
+
+        assert!(codec.decode(&encoded, GossipTopicTag::NewTx).is_err());
+    }
 
     #[test]
     fn test_request_size_fits() {
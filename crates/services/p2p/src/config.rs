@@ -1,5 +1,9 @@
 use crate::{
-    gossipsub::config::default_gossipsub_config,
+    gossipsub::{
+        config::default_gossipsub_config,
+        messages::TxGossipVersion,
+        topics::TxGossipSharding,
+    },
     heartbeat,
     peer_manager::ConnectionState,
     TryPeerId,
@@ -50,6 +54,7 @@ mod connection_tracker;
 mod fuel_authenticated;
 pub(crate) mod fuel_upgrade;
 mod guarded_node;
+pub(crate) mod network_identity;
 
 const REQ_RES_TIMEOUT: Duration = Duration::from_secs(20);
 
@@ -120,6 +125,13 @@ pub struct Config<State = Initialized> {
 
     // `Gossipsub` config
     pub gossipsub_config: gossipsub::Config,
+    /// The number of the latest gossip messages remembered to ignore their duplicates
+    /// arriving after the `Gossipsub` duplicate cache time.
+    pub gossip_seen_cache_size: usize,
+    /// The encoding of the transactions published by the node
+    pub tx_gossip_version: TxGossipVersion,
+    /// The shards of the transaction gossip topic relayed by the node
+    pub tx_gossip_sharding: TxGossipSharding,
 
     pub heartbeat_config: heartbeat::Config,
 
@@ -178,6 +190,9 @@ impl Config<NotInitialized> {
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
+            gossip_seen_cache_size: self.gossip_seen_cache_size,
+            tx_gossip_version: self.tx_gossip_version,
+            tx_gossip_sharding: self.tx_gossip_sharding,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
@@ -224,6 +239,9 @@ impl Config<NotInitialized> {
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
             gossipsub_config: default_gossipsub_config(),
+            gossip_seen_cache_size: 10_000,
+            tx_gossip_version: TxGossipVersion::default(),
+            tx_gossip_sharding: TxGossipSharding::default(),
             heartbeat_config: heartbeat::Config::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
             set_connection_keep_alive: REQ_RES_TIMEOUT,
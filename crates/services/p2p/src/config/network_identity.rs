@@ -0,0 +1,156 @@
+use crate::config::fuel_upgrade::Checksum;
+use fuel_core_types::fuel_types::ChainId;
+
+/// The version of the p2p protocol. The nodes refuse the peers with another
+/// major version.
+pub const PROTOCOL_VERSION: &str = "1.0";
+
+/// The protocol version sent by the previous releases, without the network of
+/// the node. The network of such peers is verified only by the [`Checksum`]
+/// during the connection upgrade.
+const LEGACY_PROTOCOL: &str = "/fuel/1.0";
+
+/// Returns the agent version sent to the peers during the identification.
+pub fn agent_version() -> String {
+    format!("fuel-core/{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// The network of the node, exchanged with the peers during the identification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkIdentity {
+    protocol_version: String,
+    chain_id: ChainId,
+    genesis: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IdentityMismatch {
+    #[error("the protocol `{0}` is unknown")]
+    UnknownProtocol(String),
+    #[error("the protocol version {remote} is incompatible with {local}")]
+    ProtocolVersion { local: String, remote: String },
+    #[error("the chain id {remote} differs from {local}")]
+    ChainId { local: u64, remote: u64 },
+    #[error("the genesis {remote} differs from {local}")]
+    Genesis { local: String, remote: String },
+}
+
+impl NetworkIdentity {
+    pub fn new(chain_id: ChainId, genesis: Checksum) -> Self {
+        Self {
+            protocol_version: PROTOCOL_VERSION.to_string(),
+            chain_id,
+            genesis: hex::encode(genesis.as_ref()),
+        }
+    }
+
+    /// Returns the protocol version sent to the peers during the identification.
+    pub fn protocol(&self) -> String {
+        format!(
+            "/fuel/{}/chain/{}/genesis/{}",
+            self.protocol_version, *self.chain_id, self.genesis
+        )
+    }
+
+    fn parse(protocol: &str) -> Option<Self> {
+        let mut parts = protocol.strip_prefix("/fuel/")?.split('/');
+        let protocol_version = parts.next()?.to_string();
+        let chain_id = match (parts.next()?, parts.next()?) {
+            ("chain", chain_id) => ChainId::new(chain_id.parse().ok()?),
+            _ => return None,
+        };
+        let genesis = match (parts.next()?, parts.next()?) {
+            ("genesis", genesis) => genesis.to_string(),
+            _ => return None,
+        };
+        if parts.next().is_some() {
+            return None
+        }
+        Some(Self {
+            protocol_version,
+            chain_id,
+            genesis,
+        })
+    }
+
+    /// Checks that the peer with the `remote` protocol belongs to the same network.
+    pub fn check(&self, remote: &str) -> Result<(), IdentityMismatch> {
+        if remote == LEGACY_PROTOCOL {
+            return Ok(())
+        }
+        let remote = Self::parse(remote)
+            .ok_or_else(|| IdentityMismatch::UnknownProtocol(remote.to_string()))?;
+        let major = |version: &str| version.split('.').next().map(str::to_string);
+        if major(&self.protocol_version) != major(&remote.protocol_version) {
+            return Err(IdentityMismatch::ProtocolVersion {
+                local: self.protocol_version.clone(),
+                remote: remote.protocol_version,
+            })
+        }
+        if self.chain_id != remote.chain_id {
+            return Err(IdentityMismatch::ChainId {
+                local: *self.chain_id,
+                remote: *remote.chain_id,
+            })
+        }
+        if self.genesis != remote.genesis {
+            return Err(IdentityMismatch::Genesis {
+                local: self.genesis.clone(),
+                remote: remote.genesis,
+            })
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> NetworkIdentity {
+        NetworkIdentity::new(ChainId::new(7), [1u8; 32].into())
+    }
+
+    #[test]
+    fn peer_of_same_network_is_accepted() {
+        let identity = identity();
+
+        assert_eq!(identity.check(&identity.protocol()), Ok(()));
+        assert_eq!(identity.check(LEGACY_PROTOCOL), Ok(()));
+    }
+
+    #[test]
+    fn peer_of_another_network_is_refused() {
+        let identity = identity();
+        let other_chain = NetworkIdentity::new(ChainId::new(8), [1u8; 32].into());
+        let other_genesis = NetworkIdentity::new(ChainId::new(7), [2u8; 32].into());
+
+        assert!(matches!(
+            identity.check(&other_chain.protocol()),
+            Err(IdentityMismatch::ChainId { .. })
+        ));
+        assert!(matches!(
+            identity.check(&other_genesis.protocol()),
+            Err(IdentityMismatch::Genesis { .. })
+        ));
+    }
+
+    #[test]
+    fn peer_with_incompatible_protocol_is_refused() {
+        let identity = identity();
+        let mut newer = identity.clone();
+        newer.protocol_version = "1.3".to_string();
+        let mut incompatible = identity.clone();
+        incompatible.protocol_version = "2.0".to_string();
+
+        assert_eq!(identity.check(&newer.protocol()), Ok(()));
+        assert!(matches!(
+            identity.check(&incompatible.protocol()),
+            Err(IdentityMismatch::ProtocolVersion { .. })
+        ));
+        assert!(matches!(
+            identity.check("/ipfs/0.1.0"),
+            Err(IdentityMismatch::UnknownProtocol(_))
+        ));
+    }
+}
@@ -1,3 +1,4 @@
 pub mod config;
 pub mod messages;
+pub mod seen_cache;
 pub mod topics;
@@ -13,7 +13,6 @@ use libp2p::gossipsub::{
     MetricsConfig,
     PeerScoreParams,
     PeerScoreThresholds,
-    Topic,
     TopicScoreParams,
 };
 use sha2::{
@@ -22,10 +21,7 @@ use sha2::{
 };
 use std::time::Duration;
 
-use super::topics::{
-    GossipTopic,
-    NEW_TX_GOSSIP_TOPIC,
-};
+use super::topics::GossipTopic;
 
 // The number of slots in each epoch.
 const SLOTS_PER_EPOCH: u64 = 32;
@@ -226,12 +222,18 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         .with_peer_score(peer_score_params, peer_score_thresholds)
         .expect("gossipsub initialized with peer score");
 
-    let topics = vec![(NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT)];
+    let sharding = &p2p_config.tx_gossip_sharding;
+    let topics: Vec<(GossipTopic, f64)> = sharding
+        .subscribed()
+        .into_iter()
+        .map(|shard| {
+            let topic = sharding.topic(&p2p_config.network_name, shard);
+            (topic, NEW_TX_GOSSIP_WEIGHT)
+        })
+        .collect();
 
     // subscribe to gossipsub topics with the network name suffix
-    for (topic, weight) in topics {
-        let t: GossipTopic = Topic::new(format!("{}/{}", topic, p2p_config.network_name));
-
+    for (t, weight) in topics {
         gossipsub
             .set_topic_params(t.clone(), initialize_topic_score_params(weight))
             .expect("First time initializing Topic Score");
@@ -26,3 +26,45 @@ pub enum GossipsubBroadcastRequest {
 pub enum GossipsubMessage {
     NewTx(Transaction),
 }
+
+/// The first byte of the versioned encoding of the gossiped transaction.
+/// The plain encoding starts with the index of the `Transaction` variant,
+/// so it never starts with this byte.
+pub const TX_GOSSIP_VERSION_MARKER: u8 = 0xFF;
+
+/// The encoding of the gossiped transactions. The node decodes all known versions,
+/// but publishes the transactions with the configured one. The network upgrades
+/// the version when all nodes understand it, so nodes of adjacent releases
+/// still relay the transactions of each other.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum TxGossipVersion {
+    /// The plain `postcard` encoding of the transaction, used by the previous releases.
+    #[default]
+    V0,
+    /// The `postcard` encoding of the transaction prefixed with the
+    /// [`TX_GOSSIP_VERSION_MARKER`] and the version byte.
+    V1,
+}
+
+impl TxGossipVersion {
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            TxGossipVersion::V0 => 0,
+            TxGossipVersion::V1 => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for TxGossipVersion {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TxGossipVersion::V0),
+            1 => Ok(TxGossipVersion::V1),
+            version => Err(format!(
+                "Unsupported version {version} of the gossiped transaction"
+            )),
+        }
+    }
+}
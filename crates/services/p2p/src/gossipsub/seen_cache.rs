@@ -0,0 +1,81 @@
+use libp2p::gossipsub::MessageId;
+use std::collections::{
+    HashSet,
+    VecDeque,
+};
+
+/// The bounded cache of the ids of the gossip messages delivered to the node.
+/// `Gossipsub` filters the duplicates only within its `duplicate_cache_time`.
+/// The cache catches the duplicates that arrive later, so they are not processed again.
+/// The oldest ids are evicted when the cache is full.
+#[derive(Debug)]
+pub struct SeenCache {
+    capacity: usize,
+    ids: HashSet<MessageId>,
+    order: VecDeque<MessageId>,
+}
+
+impl SeenCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ids: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the message `id`. Returns `true` if the message was already seen.
+    pub fn observe(&mut self, id: &MessageId) -> bool {
+        if self.capacity == 0 {
+            return false
+        }
+        if self.ids.contains(id) {
+            return true
+        }
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.ids.insert(id.clone());
+        self.order.push_back(id.clone());
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(i: u8) -> MessageId {
+        MessageId::new(&[i])
+    }
+
+    #[test]
+    fn second_delivery_is_duplicate() {
+        let mut cache = SeenCache::new(10);
+
+        assert!(!cache.observe(&id(1)));
+        assert!(cache.observe(&id(1)));
+        assert!(!cache.observe(&id(2)));
+    }
+
+    #[test]
+    fn oldest_message_is_evicted_when_full() {
+        let mut cache = SeenCache::new(2);
+        cache.observe(&id(1));
+        cache.observe(&id(2));
+        cache.observe(&id(3));
+
+        assert!(!cache.observe(&id(1)));
+        assert!(cache.observe(&id(3)));
+    }
+
+    #[test]
+    fn empty_cache_sees_no_duplicates() {
+        let mut cache = SeenCache::new(0);
+        cache.observe(&id(1));
+
+        assert!(!cache.observe(&id(1)));
+    }
+}
@@ -8,24 +8,113 @@ use super::messages::{
     GossipTopicTag,
     GossipsubBroadcastRequest,
 };
+use fuel_core_types::{
+    fuel_tx::UniqueIdentifier,
+    fuel_types::{
+        Bytes32,
+        ChainId,
+    },
+};
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 
+/// Splits the transaction gossip topic into the shards by the first byte of the
+/// transaction id. The nodes relay only the transactions of the subscribed shards,
+/// so the gossip bandwidth of the network scales with the number of the relay nodes.
+/// All nodes of the network should use the same number of the shards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxGossipSharding {
+    /// The number of the shards. The topic isn't split with one shard.
+    shards: u8,
+    /// The shards subscribed by the node. `None` subscribes to all shards.
+    subscribed: Option<Vec<u8>>,
+}
+
+impl Default for TxGossipSharding {
+    fn default() -> Self {
+        Self {
+            shards: 1,
+            subscribed: None,
+        }
+    }
+}
+
+impl TxGossipSharding {
+    pub fn new(shards: u8, subscribed: Option<Vec<u8>>) -> Result<Self, String> {
+        if shards == 0 {
+            return Err(
+                "The number of the transaction gossip shards should be positive"
+                    .to_string(),
+            )
+        }
+        if let Some(shard) = subscribed.iter().flatten().find(|shard| **shard >= shards) {
+            return Err(format!(
+                "The subscribed shard {shard} should be less than the number of the shards {shards}"
+            ))
+        }
+        Ok(Self { shards, subscribed })
+    }
+
+    /// The number of the shards.
+    pub fn shards(&self) -> u8 {
+        self.shards
+    }
+
+    /// Returns the shard of the transaction with the `tx_id`.
+    pub fn shard_of(&self, tx_id: &Bytes32) -> u8 {
+        tx_id[0].checked_rem(self.shards).unwrap_or_default()
+    }
+
+    /// Returns the shards subscribed by the node.
+    pub fn subscribed(&self) -> Vec<u8> {
+        match &self.subscribed {
+            Some(subscribed) => subscribed.clone(),
+            None => (0..self.shards).collect(),
+        }
+    }
+
+    /// Returns the topic of the `shard` in the network with the `network_name`.
+    pub fn topic(&self, network_name: &str, shard: u8) -> GossipTopic {
+        if self.shards == 1 {
+            Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"))
+        } else {
+            Topic::new(format!(
+                "{NEW_TX_GOSSIP_TOPIC}_{shard}_of_{}/{network_name}",
+                self.shards
+            ))
+        }
+    }
+}
+
 /// Holds used Gossipsub Topics
-/// Each field contains TopicHash and GossipTopic itself
+/// Each topic is stored with its TopicHash
 /// in order to avoid converting GossipTopic to TopicHash on each received message
 #[derive(Debug)]
 pub struct GossipsubTopics {
-    new_tx_topic: (TopicHash, GossipTopic),
+    chain_id: ChainId,
+    tx_gossip_sharding: TxGossipSharding,
+    /// The topics of the transaction gossip shards, indexed by the shard.
+    new_tx_topics: Vec<(TopicHash, GossipTopic)>,
 }
 
 impl GossipsubTopics {
-    pub fn new(network_name: &str) -> Self {
-        let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+    pub fn new(
+        network_name: &str,
+        chain_id: ChainId,
+        tx_gossip_sharding: TxGossipSharding,
+    ) -> Self {
+        let new_tx_topics = (0..tx_gossip_sharding.shards())
+            .map(|shard| {
+                let topic = tx_gossip_sharding.topic(network_name, shard);
+                (topic.hash(), topic)
+            })
+            .collect();
 
         Self {
-            new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            chain_id,
+            tx_gossip_sharding,
+            new_tx_topics,
         }
     }
 
@@ -34,12 +123,10 @@ impl GossipsubTopics {
         &self,
         incoming_topic: &TopicHash,
     ) -> Option<GossipTopicTag> {
-        let GossipsubTopics { new_tx_topic } = &self;
-
-        match incoming_topic {
-            hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
-            _ => None,
-        }
+        self.new_tx_topics
+            .iter()
+            .any(|(hash, _)| hash == incoming_topic)
+            .then_some(GossipTopicTag::NewTx)
     }
 
     /// Given a `GossipsubBroadcastRequest` retruns a `GossipTopic`
@@ -49,7 +136,11 @@ impl GossipsubTopics {
         outgoing_request: &GossipsubBroadcastRequest,
     ) -> GossipTopic {
         match outgoing_request {
-            GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::NewTx(tx) => {
+                let tx_id = tx.id(&self.chain_id);
+                let shard = self.tx_gossip_sharding.shard_of(&tx_id);
+                self.new_tx_topics[usize::from(shard)].1.clone()
+            }
         }
     }
 }
@@ -57,7 +148,10 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -67,10 +161,14 @@ mod tests {
         let new_tx_topic: GossipTopic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
 
-        let gossipsub_topics = GossipsubTopics::new(network_name);
+        let gossipsub_topics = GossipsubTopics::new(
+            network_name,
+            ChainId::default(),
+            TxGossipSharding::default(),
+        );
 
         // Test matching Topic Hashes
-        assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
+        assert_eq!(gossipsub_topics.new_tx_topics[0].0, new_tx_topic.hash());
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
@@ -86,4 +184,41 @@ mod tests {
             new_tx_topic.hash()
         );
     }
+
+    #[test]
+    fn transactions_are_published_to_topic_of_their_shard() {
+        let network_name = "fuel_test_network";
+        let chain_id = ChainId::new(7);
+        let sharding = TxGossipSharding::new(4, Some(vec![1])).unwrap();
+        let gossipsub_topics =
+            GossipsubTopics::new(network_name, chain_id, sharding.clone());
+
+        for maturity in 0..16u32 {
+            let tx = TransactionBuilder::script(vec![], vec![])
+                .maturity(maturity.into())
+                .finalize_as_transaction();
+            let shard = tx.id(&chain_id)[0] % 4;
+            let expected = sharding.topic(network_name, shard);
+
+            let topic = gossipsub_topics
+                .get_gossipsub_topic(&GossipsubBroadcastRequest::NewTx(Arc::new(tx)));
+
+            assert_eq!(topic.hash(), expected.hash());
+            // The transactions of all shards are accepted from the peers.
+            assert_eq!(
+                gossipsub_topics.get_gossipsub_tag(&topic.hash()),
+                Some(GossipTopicTag::NewTx)
+            );
+        }
+    }
+
+    #[test]
+    fn sharding_rejects_unknown_shards() {
+        assert!(TxGossipSharding::new(0, None).is_err());
+        assert!(TxGossipSharding::new(4, Some(vec![4])).is_err());
+        assert_eq!(
+            TxGossipSharding::new(4, None).unwrap().subscribed(),
+            vec![0, 1, 2, 3]
+        );
+    }
 }
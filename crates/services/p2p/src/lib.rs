@@ -7,6 +7,8 @@ pub mod config;
 pub mod discovery;
 pub mod gossipsub;
 pub mod heartbeat;
+#[cfg(feature = "test-helpers")]
+pub mod network_conditions;
 pub mod p2p_service;
 pub mod peer_manager;
 pub mod peer_report;
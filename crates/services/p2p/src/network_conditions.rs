@@ -0,0 +1,167 @@
+//! Simulation of the degraded network for the tests of the in-process nodes.
+
+use libp2p::PeerId;
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        RwLock,
+    },
+    time::Duration,
+};
+use tokio::sync::mpsc;
+
+/// The conditions of the network applied by the p2p service to the messages of
+/// the peers. By default, the network is perfect, and the messages are
+/// delivered as they are.
+#[derive(Default, Debug, Clone)]
+pub struct NetworkConditions(Arc<RwLock<Conditions>>);
+
+#[derive(Default, Debug)]
+struct Conditions {
+    /// The delay before the processing of each message from the network.
+    latency: Duration,
+    /// The probability to lose the message.
+    packet_loss: f64,
+    /// The peers on the other side of the partition.
+    partitioned: HashSet<PeerId>,
+}
+
+impl NetworkConditions {
+    /// Delays the processing of each message from the network by the `latency`.
+    pub fn set_latency(&self, latency: Duration) {
+        self.write(|conditions| conditions.latency = latency);
+    }
+
+    /// Loses the messages with the `probability` from `0.0` to `1.0`.
+    pub fn set_packet_loss(&self, probability: f64) {
+        self.write(|conditions| conditions.packet_loss = probability.clamp(0.0, 1.0));
+    }
+
+    /// Loses all messages to and from the `peers`. The connections stay alive,
+    /// so the node only stops hearing from the peers.
+    pub fn partition(&self, peers: impl IntoIterator<Item = PeerId>) {
+        self.write(|conditions| conditions.partitioned.extend(peers));
+    }
+
+    /// Removes the partitions.
+    pub fn heal(&self) {
+        self.write(|conditions| conditions.partitioned.clear());
+    }
+
+    /// Restores the perfect network.
+    pub fn reset(&self) {
+        self.write(|conditions| *conditions = Conditions::default());
+    }
+
+    /// Returns the delay before the processing of the message.
+    pub fn latency(&self) -> Duration {
+        self.read(|conditions| conditions.latency)
+    }
+
+    /// Returns `true` if the message to or from the `peer_id` is lost.
+    pub fn is_lost(&self, peer_id: Option<&PeerId>) -> bool {
+        self.read(|conditions| {
+            let partitioned =
+                peer_id.is_some_and(|peer_id| conditions.partitioned.contains(peer_id));
+            partitioned
+                || (conditions.packet_loss > 0.0
+                    && rand::random::<f64>() < conditions.packet_loss)
+        })
+    }
+
+    fn read<T>(&self, f: impl FnOnce(&Conditions) -> T) -> T {
+        let conditions = self.0.read().unwrap_or_else(|e| e.into_inner());
+        f(&conditions)
+    }
+
+    fn write(&self, f: impl FnOnce(&mut Conditions)) {
+        let mut conditions = self.0.write().unwrap_or_else(|e| e.into_inner());
+        f(&mut conditions)
+    }
+}
+
+/// The messages delayed by the latency of the network. Each message is delayed
+/// separately, so the delay doesn't block the processing of other messages.
+#[derive(Debug)]
+pub(crate) struct DelayedMessages<T> {
+    sender: mpsc::UnboundedSender<T>,
+    receiver: mpsc::UnboundedReceiver<T>,
+}
+
+impl<T> Default for DelayedMessages<T> {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self { sender, receiver }
+    }
+}
+
+impl<T> DelayedMessages<T>
+where
+    T: Send + 'static,
+{
+    /// Delivers the `message` after the `latency`.
+    pub(crate) fn delay(&self, message: T, latency: Duration) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(latency).await;
+            let _ = sender.send(message);
+        });
+    }
+
+    /// Returns the next message after its delay.
+    pub(crate) async fn next(&mut self) -> Option<T> {
+        self.receiver.recv().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_network_loses_nothing() {
+        let conditions = NetworkConditions::default();
+
+        assert!(!conditions.is_lost(Some(&PeerId::random())));
+        assert!(!conditions.is_lost(None));
+        assert_eq!(conditions.latency(), Duration::ZERO);
+    }
+
+    #[test]
+    fn partition_loses_messages_of_partitioned_peers_until_healed() {
+        let conditions = NetworkConditions::default();
+        let partitioned = PeerId::random();
+        let connected = PeerId::random();
+
+        conditions.partition([partitioned]);
+
+        assert!(conditions.is_lost(Some(&partitioned)));
+        assert!(!conditions.is_lost(Some(&connected)));
+        conditions.heal();
+        assert!(!conditions.is_lost(Some(&partitioned)));
+    }
+
+    #[test]
+    fn full_packet_loss_loses_all_messages() {
+        let conditions = NetworkConditions::default();
+
+        conditions.set_packet_loss(2.0);
+
+        assert!(conditions.is_lost(Some(&PeerId::random())));
+        assert!(conditions.is_lost(None));
+        conditions.reset();
+        assert!(!conditions.is_lost(None));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_messages_are_delivered_after_own_latency() {
+        let mut messages = DelayedMessages::default();
+
+        messages.delay(1, Duration::from_secs(2));
+        messages.delay(2, Duration::from_secs(1));
+
+        assert_eq!(messages.next().await, Some(2));
+        assert_eq!(messages.next().await, Some(1));
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     },
     config::{
         build_transport_function,
+        network_identity::NetworkIdentity,
         Config,
     },
     gossipsub::{
@@ -16,6 +17,7 @@ use crate::{
             GossipsubBroadcastRequest,
             GossipsubMessage as FuelGossipsubMessage,
         },
+        seen_cache::SeenCache,
         topics::GossipsubTopics,
     },
     heartbeat,
@@ -36,7 +38,10 @@ use crate::{
 };
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
     services::p2p::peer_reputation::AppScore,
 };
 use futures::prelude::*;
@@ -67,7 +72,10 @@ use std::{
     collections::HashMap,
     time::Duration,
 };
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    time::Instant,
+};
 use tracing::{
     debug,
     warn,
@@ -102,8 +110,9 @@ pub struct FuelP2PService {
     /// must provide a channel to receive the response.
     /// Whenever a response (or an error) is received from the p2p network,
     /// the request is removed from this table, and the channel is used to
-    /// send the result to the caller.
-    outbound_requests_table: HashMap<OutboundRequestId, ResponseSender>,
+    /// send the result to the caller. The time of the request is used to
+    /// measure the response time of the peer.
+    outbound_requests_table: HashMap<OutboundRequestId, (ResponseSender, Instant)>,
 
     /// Holds active inbound requests and associated oneshot channels.
     /// Whenever we're done processing the request, it's removed from this table,
@@ -122,6 +131,12 @@ pub struct FuelP2PService {
 
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
+
+    /// The ids of the gossip messages already delivered to the node
+    seen_cache: SeenCache,
+
+    /// The network of the node, checked against the network of each identified peer
+    network_identity: NetworkIdentity,
 }
 
 #[derive(Debug)]
@@ -164,17 +179,22 @@ pub enum FuelP2PEvent {
 
 impl FuelP2PService {
     pub fn new(
+        chain_id: ChainId,
         reserved_peers_updates: broadcast::Sender<usize>,
         config: Config,
         codec: PostcardCodec,
     ) -> Self {
-        let gossipsub_data =
-            GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
+        let gossipsub_data = GossipsubData::with_topics(GossipsubTopics::new(
+            &config.network_name,
+            chain_id,
+            config.tx_gossip_sharding.clone(),
+        ));
         let network_metadata = NetworkMetadata { gossipsub_data };
 
         // configure and build P2P Service
         let (transport_function, connection_state) = build_transport_function(&config);
-        let behaviour = FuelBehaviour::new(&config, codec.clone());
+        let network_identity = NetworkIdentity::new(chain_id, config.checksum);
+        let behaviour = FuelBehaviour::new(&config, &network_identity, codec.clone());
 
         let mut swarm = SwarmBuilder::with_existing_identity(config.keypair.clone())
             .with_tokio()
@@ -221,6 +241,8 @@ impl FuelP2PService {
                 connection_state,
                 config.max_peers_connected as usize,
             ),
+            seen_cache: SeenCache::new(config.gossip_seen_cache_size),
+            network_identity,
         }
     }
 
@@ -323,7 +345,8 @@ impl FuelP2PService {
             .behaviour_mut()
             .send_request_msg(message_request, &peer_id);
 
-        self.outbound_requests_table.insert(request_id, on_response);
+        self.outbound_requests_table
+            .insert(request_id, (on_response, Instant::now()));
 
         Ok(request_id)
     }
@@ -472,6 +495,21 @@ impl FuelP2PService {
             message_id,
         } = event
         {
+            let duplicate = self.seen_cache.observe(&message_id);
+            if self.metrics {
+                p2p_metrics()
+                    .observe_gossip_message(propagation_source.to_string(), duplicate);
+            }
+            if duplicate {
+                debug!(target: "fuel-p2p", "Ignoring the duplicate message. ID: {}", message_id);
+                self.report_message_validation_result(
+                    &message_id,
+                    propagation_source,
+                    MessageAcceptance::Ignore,
+                );
+                return None
+            }
+
             if let Some(correct_topic) = self
                 .network_metadata
                 .gossipsub_data
@@ -570,12 +608,22 @@ impl FuelP2PService {
                     request_id,
                     response,
                 } => {
-                    let Some(channel) = self.outbound_requests_table.remove(&request_id)
+                    let Some((channel, sent_at)) =
+                        self.outbound_requests_table.remove(&request_id)
                     else {
                         debug!("Send channel not found for {:?}", request_id);
                         return None;
                     };
 
+                    let served = match &response {
+                        ResponseMessage::SealedHeaders(headers) => headers.is_some(),
+                        ResponseMessage::Transactions(transactions) => {
+                            transactions.is_some()
+                        }
+                    };
+                    let latency = served.then(|| sent_at.elapsed());
+                    self.peer_manager.record_request_outcome(&peer, latency);
+
                     let send_ok = match channel {
                         ResponseSender::SealedHeaders(c) => match response {
                             ResponseMessage::SealedHeaders(v) => {
@@ -625,7 +673,10 @@ impl FuelP2PService {
             } => {
                 tracing::error!("RequestResponse outbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
 
-                if let Some(channel) = self.outbound_requests_table.remove(&request_id) {
+                self.peer_manager.record_request_outcome(&peer, None);
+                if let Some((channel, _)) =
+                    self.outbound_requests_table.remove(&request_id)
+                {
                     match channel {
                         ResponseSender::SealedHeaders(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
@@ -651,8 +702,20 @@ impl FuelP2PService {
                 let mut addresses = info.listen_addrs;
                 let agent_version = info.agent_version;
 
+                if let Err(mismatch) = self.network_identity.check(&info.protocol_version)
+                {
+                    warn!(
+                        target: "fuel-p2p",
+                        "Refusing the peer {:?} running {:?} because {}",
+                        peer_id, agent_version, mismatch
+                    );
+                    self.swarm.behaviour_mut().block_peer(peer_id);
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                    return None
+                }
+
                 if addresses.len() > MAX_IDENTIFY_ADDRESSES {
-                    let protocol_version = info.protocol_version;
+                    let protocol_version = &info.protocol_version;
                     debug!(
                         target: "fuel-p2p",
                         "Node {:?} has reported more than {} addresses; it is identified by {:?} and {:?}",
@@ -702,6 +765,7 @@ impl FuelP2PService {
 #[cfg(test)]
 mod tests {
     use super::{
+        ChainId,
         FuelP2PService,
         PublishError,
     };
@@ -784,8 +848,12 @@ mod tests {
         let (sender, _) =
             broadcast::channel(p2p_config.reserved_nodes.len().saturating_add(1));
 
-        let mut service =
-            FuelP2PService::new(sender, p2p_config, PostcardCodec::new(max_block_size));
+        let mut service = FuelP2PService::new(
+            ChainId::default(),
+            sender,
+            p2p_config,
+            PostcardCodec::new(max_block_size),
+        );
         service.start().await.unwrap();
         service
     }
@@ -1112,6 +1180,49 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes with the same Fuel Upgrade checksum but the different chain ids
+    // So they connect, but refuse each other after the identification
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_refuse_peers_of_another_chain() {
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_refuse_peers_of_another_chain");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B with the different chain id
+        p2p_config.keypair = Keypair::generate_secp256k1();
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let max_block_size = p2p_config.max_block_size;
+        let (sender, _) = broadcast::channel(1);
+        let mut node_b = FuelP2PService::new(
+            ChainId::new(1),
+            sender,
+            p2p_config,
+            PostcardCodec::new(max_block_size),
+        );
+        node_b.start().await.unwrap();
+        let node_a_id = node_a.local_peer_id;
+
+        let refused = async {
+            loop {
+                tokio::select! {
+                    node_b_event = node_b.next_event() => {
+                        if let Some(FuelP2PEvent::PeerDisconnected(peer_id)) = node_b_event {
+                            assert_eq!(peer_id, node_a_id);
+                            break
+                        }
+                        tracing::info!("Node B Event: {:?}", node_b_event);
+                    },
+                    _ = node_a.next_event() => {},
+                };
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(10), refused)
+            .await
+            .expect("Node B should refuse Node A");
+    }
+
     // Simulates 2 p2p nodes that are on the same network but their Fuel Upgrade checksum is different
     // (different chain id or chain config)
     // So they are not able to connect
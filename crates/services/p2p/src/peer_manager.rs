@@ -22,6 +22,7 @@ use std::{
         Arc,
         RwLock,
     },
+    time::Duration,
 };
 use tracing::{
     debug,
@@ -30,10 +31,14 @@ use tracing::{
 
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        heartbeat_data::HeartbeatData,
+        peer_quality::PeerQuality,
+    },
 };
 
 pub mod heartbeat_data;
+pub mod peer_quality;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
@@ -45,6 +50,8 @@ pub struct PeerInfo {
     pub client_version: Option<String>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
+    /// The responses of the peer to the requests of the node.
+    pub quality: PeerQuality,
 }
 
 impl PeerInfo {
@@ -54,6 +61,7 @@ impl PeerInfo {
             client_version: None,
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
+            quality: PeerQuality::default(),
         }
     }
 }
@@ -177,6 +185,28 @@ impl PeerManager {
         }
     }
 
+    /// Records the outcome of the request sent to the peer: the response time
+    /// if the peer served the request, or `None` if it didn't.
+    pub fn record_request_outcome(
+        &mut self,
+        peer_id: &PeerId,
+        latency: Option<Duration>,
+    ) {
+        let peer = if self.reserved_peers.contains(peer_id) {
+            self.reserved_connected_peers.get_mut(peer_id)
+        } else {
+            self.non_reserved_connected_peers.get_mut(peer_id)
+        };
+        let Some(peer) = peer else {
+            log_missing_peer(peer_id);
+            return
+        };
+        match latency {
+            Some(latency) => peer.quality.record_served(latency),
+            None => peer.quality.record_failed(),
+        }
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
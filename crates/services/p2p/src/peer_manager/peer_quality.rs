@@ -0,0 +1,131 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    cmp::Ordering,
+    time::Duration,
+};
+
+/// The number of the requests after which the success rate of the peer is
+/// trusted enough to consider the peer unreliable.
+pub const MIN_REQUESTS_FOR_RELIABILITY: u32 = 10;
+
+/// The quality of the peer as the source of the blocks, measured by its
+/// responses to the requests of the node.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerQuality {
+    /// The number of the requests served by the peer.
+    pub served: u32,
+    /// The number of the requests failed or answered without the data.
+    pub failed: u32,
+    /// The moving average of the response time of the peer.
+    pub average_latency_ms: Option<u64>,
+}
+
+impl PeerQuality {
+    /// Records the request served after the `latency`.
+    pub fn record_served(&mut self, latency: Duration) {
+        self.served = self.served.saturating_add(1);
+        let latency = u64::try_from(latency.as_millis()).unwrap_or(u64::MAX);
+        // The latest response has the weight of 1/4 in the average.
+        let average = match self.average_latency_ms {
+            Some(average) => average.saturating_mul(3).saturating_add(latency) / 4,
+            None => latency,
+        };
+        self.average_latency_ms = Some(average);
+    }
+
+    /// Records the request failed or answered without the data.
+    pub fn record_failed(&mut self) {
+        self.failed = self.failed.saturating_add(1);
+    }
+
+    /// The share of the served requests, if the peer received any.
+    pub fn success_rate(&self) -> Option<f64> {
+        let requests = self.served.saturating_add(self.failed);
+        (requests > 0).then(|| f64::from(self.served) / f64::from(requests))
+    }
+
+    /// Returns `true` if the peer failed most of the requests.
+    pub fn is_unreliable(&self) -> bool {
+        self.served.saturating_add(self.failed) >= MIN_REQUESTS_FOR_RELIABILITY
+            && self.failed > self.served
+    }
+
+    /// Adds the `newer` history to the history of the previous runs.
+    pub fn merge(self, newer: PeerQuality) -> PeerQuality {
+        PeerQuality {
+            served: self.served.saturating_add(newer.served),
+            failed: self.failed.saturating_add(newer.failed),
+            average_latency_ms: newer.average_latency_ms.or(self.average_latency_ms),
+        }
+    }
+
+    /// Orders the peers from the best to the worst: by the success rate, and
+    /// then by the response time. The peers without the history are ranked
+    /// as the peers serving a half of the requests.
+    pub fn rank(&self, other: &PeerQuality) -> Ordering {
+        let rate = |quality: &PeerQuality| quality.success_rate().unwrap_or(0.5);
+        rate(other).total_cmp(&rate(self)).then_with(|| {
+            let latency =
+                |quality: &PeerQuality| quality.average_latency_ms.unwrap_or(u64::MAX);
+            latency(self).cmp(&latency(other))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quality(served: u32, failed: u32, latency_ms: Option<u64>) -> PeerQuality {
+        PeerQuality {
+            served,
+            failed,
+            average_latency_ms: latency_ms,
+        }
+    }
+
+    #[test]
+    fn latency_is_moving_average_of_served_requests() {
+        let mut quality = PeerQuality::default();
+
+        quality.record_served(Duration::from_millis(100));
+        quality.record_served(Duration::from_millis(500));
+        quality.record_failed();
+
+        assert_eq!(quality.served, 2);
+        assert_eq!(quality.failed, 1);
+        assert_eq!(quality.average_latency_ms, Some(200));
+    }
+
+    #[test]
+    fn peer_failing_most_requests_is_unreliable() {
+        assert!(!quality(0, 9, None).is_unreliable());
+        assert!(quality(4, 6, None).is_unreliable());
+        assert!(!quality(5, 5, None).is_unreliable());
+    }
+
+    #[test]
+    fn peers_are_ranked_by_success_rate_and_latency() {
+        let mut peers = [
+            quality(0, 0, None),
+            quality(10, 0, Some(300)),
+            quality(1, 9, Some(10)),
+            quality(10, 0, Some(100)),
+        ];
+
+        peers.sort_by(PeerQuality::rank);
+
+        assert_eq!(
+            peers,
+            [
+                quality(10, 0, Some(100)),
+                quality(10, 0, Some(300)),
+                quality(0, 0, None),
+                quality(1, 9, Some(10)),
+            ]
+        );
+    }
+}
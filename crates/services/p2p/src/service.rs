@@ -1,3 +1,8 @@
+#[cfg(feature = "test-helpers")]
+use crate::network_conditions::{
+    DelayedMessages,
+    NetworkConditions,
+};
 use crate::{
     codecs::postcard::PostcardCodec,
     config::{
@@ -325,6 +330,54 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    network_events: NetworkEvents,
+}
+
+/// The source of the events from the network. With the `test-helpers`, it applies
+/// the simulated conditions of the network to the events.
+#[derive(Default)]
+struct NetworkEvents {
+    #[cfg(feature = "test-helpers")]
+    conditions: NetworkConditions,
+    /// The events delayed by the simulated latency.
+    #[cfg(feature = "test-helpers")]
+    delayed: DelayedMessages<FuelP2PEvent>,
+}
+
+impl NetworkEvents {
+    /// Returns the next event from the network.
+    #[cfg(not(feature = "test-helpers"))]
+    async fn next<P: TaskP2PService>(
+        &mut self,
+        p2p_service: &mut P,
+    ) -> Option<FuelP2PEvent> {
+        p2p_service.next_event().await
+    }
+
+    /// Returns the next event from the network under the simulated conditions.
+    /// The lost events are returned as `None`, and the events delayed by the latency
+    /// are returned later, without blocking the processing of other events.
+    #[cfg(feature = "test-helpers")]
+    async fn next<P: TaskP2PService>(
+        &mut self,
+        p2p_service: &mut P,
+    ) -> Option<FuelP2PEvent> {
+        tokio::select! {
+            event = p2p_service.next_event() => {
+                let event = event?;
+                if self.conditions.is_lost(event_peer_id(&event)) {
+                    return None
+                }
+                let latency = self.conditions.latency();
+                if latency.is_zero() {
+                    return Some(event)
+                }
+                self.delayed.delay(event, latency);
+                None
+            }
+            event = self.delayed.next() => event,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -363,6 +416,8 @@ impl<V> UninitializedTask<V, SharedState> {
                 tx_broadcast,
                 reserved_peers_broadcast,
                 block_height_broadcast,
+                #[cfg(feature = "test-helpers")]
+                network_conditions: NetworkConditions::default(),
             },
             config,
         }
@@ -412,6 +467,18 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
     }
 }
 
+/// Returns the peer that sent the message, if it is known.
+#[cfg(feature = "test-helpers")]
+fn event_peer_id(event: &FuelP2PEvent) -> Option<&PeerId> {
+    match event {
+        FuelP2PEvent::GossipsubMessage { peer_id, .. }
+        | FuelP2PEvent::PeerInfoUpdated { peer_id, .. }
+        | FuelP2PEvent::PeerConnected(peer_id)
+        | FuelP2PEvent::PeerDisconnected(peer_id) => Some(peer_id),
+        FuelP2PEvent::InboundRequestMessage { .. } => None,
+    }
+}
+
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
     let inner = Vec::from(*peer_id);
     Ok(FuelPeerId::from(inner))
@@ -453,6 +520,7 @@ where
         let Config {
             max_block_size,
             max_headers_per_request,
+            tx_gossip_version,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
@@ -467,9 +535,10 @@ where
         };
 
         let mut p2p_service = FuelP2PService::new(
+            chain_id,
             broadcast.reserved_peers_broadcast.clone(),
             config,
-            PostcardCodec::new(max_block_size),
+            PostcardCodec::new(max_block_size).with_tx_gossip_version(tx_gossip_version),
         );
         p2p_service.start().await?;
 
@@ -478,6 +547,12 @@ where
                 "The heartbeat check interval should be small enough to do frequently",
             );
 
+        let network_events = NetworkEvents {
+            #[cfg(feature = "test-helpers")]
+            conditions: broadcast.network_conditions.clone(),
+            #[cfg(feature = "test-helpers")]
+            delayed: Default::default(),
+        };
         let task = Task {
             chain_id,
             p2p_service,
@@ -491,6 +566,7 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            network_events,
         };
         Ok(task)
     }
@@ -539,6 +615,11 @@ where
                         // validity in `SharedState::get_sealed_block_headers`.
                         let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
                         let peer = self.p2p_service.get_peer_id_with_height(&height);
+                        #[cfg(feature = "test-helpers")]
+                        if self.network_events.conditions.is_lost(peer.as_ref()) {
+                            tracing::debug!("The request of the headers at height {:?} is lost", height);
+                            return Ok(true)
+                        }
                         if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
                             tracing::warn!("No peers found for block at height {:?}", height);
                         }
@@ -546,6 +627,11 @@ where
                     Some(TaskRequest::GetTransactions { block_height_range, from_peer, channel }) => {
                         let channel = ResponseSender::Transactions(channel);
                         let request_msg = RequestMessage::Transactions(block_height_range);
+                        #[cfg(feature = "test-helpers")]
+                        if self.network_events.conditions.is_lost(Some(&from_peer)) {
+                            tracing::debug!("The request of the transactions from {:?} is lost", from_peer);
+                            return Ok(true)
+                        }
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
@@ -567,7 +653,7 @@ where
                     }
                 }
             }
-            p2p_event = self.p2p_service.next_event() => {
+            p2p_event = self.network_events.next(&mut self.p2p_service) => {
                 should_continue = true;
                 match p2p_event {
                     Some(FuelP2PEvent::PeerInfoUpdated { peer_id, block_height }) => {
@@ -680,6 +766,9 @@ pub struct SharedState {
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// The simulated conditions of the network.
+    #[cfg(feature = "test-helpers")]
+    network_conditions: NetworkConditions,
 }
 
 impl SharedState {
@@ -790,6 +879,13 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    /// Returns the simulated conditions of the network, used to degrade the
+    /// network between the nodes in the tests.
+    #[cfg(feature = "test-helpers")]
+    pub fn network_conditions(&self) -> &NetworkConditions {
+        &self.network_conditions
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
@@ -1112,6 +1208,7 @@ pub mod tests {
             client_version: None,
             heartbeat_data,
             score: 100.0,
+            quality: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService { peer_info };
@@ -1146,6 +1243,7 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            network_events: Default::default(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1193,6 +1291,7 @@ pub mod tests {
             client_version: None,
             heartbeat_data,
             score: 100.0,
+            quality: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService { peer_info };
@@ -1227,6 +1326,7 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            network_events: Default::default(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
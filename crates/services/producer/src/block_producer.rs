@@ -25,6 +25,7 @@ use fuel_core_types::{
     fuel_types::{
         BlockHeight,
         Bytes32,
+        ContractId,
     },
     services::{
         block_producer::Components,
@@ -35,10 +36,19 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+    Arc,
+};
 use tokio::sync::Mutex;
 use tracing::debug;
 
+pub use dry_run_cache::DryRunCache;
+
+mod dry_run_cache;
 #[cfg(test)]
 mod tests;
 
@@ -74,6 +84,7 @@ pub struct Producer<ViewProvider, TxPool, Executor> {
     // use a tokio lock since we want callers to yield until the previous block
     // execution has completed (which may take a while).
     pub lock: Mutex<()>,
+    pub dry_run_cache: DryRunCache,
 }
 
 impl<ViewProvider, TxPool, Executor> Producer<ViewProvider, TxPool, Executor>
@@ -82,6 +93,7 @@ where
     ViewProvider::View: BlockProducerDatabase,
 {
     /// Produces and execute block for the specified height.
+    #[tracing::instrument(skip_all, fields(height = *height), err)]
     async fn produce_and_execute<TxSource, ExecutorDB>(
         &self,
         height: BlockHeight,
@@ -111,6 +123,7 @@ where
             header_to_produce: header,
             transactions_source: source,
             gas_limit: max_gas,
+            coinbase_recipient: Some(self.coinbase_recipient(height)),
         };
 
         // Store the context string in case we error.
@@ -136,12 +149,18 @@ where
     Executor: ports::Executor<TxSource, Database = ExecutorDB> + 'static,
 {
     /// Produces and execute block for the specified height with transactions from the `TxPool`.
+    /// The transactions are selected up to the `max_gas` or the soft gas target
+    /// from the config, whichever is lower.
     pub async fn produce_and_execute_block_txpool(
         &self,
         height: BlockHeight,
         block_time: Tai64,
         max_gas: Word,
     ) -> anyhow::Result<UncommittedResult<StorageTransaction<ExecutorDB>>> {
+        let max_gas = self
+            .config
+            .block_gas_target
+            .map_or(max_gas, |target| target.min(max_gas));
         self.produce_and_execute(
             height,
             block_time,
@@ -181,6 +200,7 @@ where
     /// Simulates multiple transactions without altering any state. Does not acquire the production lock.
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
+    #[tracing::instrument(skip_all, fields(transactions = transactions.len()), err)]
     pub async fn dry_run(
         &self,
         transactions: Vec<Transaction>,
@@ -200,21 +220,52 @@ where
         // It is deterministic from the result perspective, plus it is more performant
         // because we don't need to wait for the relayer to sync.
         let header = self._new_header(height, Tai64::now())?;
+
+        let state_root = if self.dry_run_cache.is_enabled() {
+            let latest_height = self.view_provider.latest_height();
+            let state_root = self
+                .view_provider
+                .latest_view()
+                .block_header_merkle_root(&latest_height)?;
+            if let Some(tx_statuses) = self.dry_run_cache.get(
+                &state_root,
+                &transactions,
+                height,
+                utxo_validation,
+            ) {
+                return Ok(tx_statuses)
+            }
+            Some(state_root)
+        } else {
+            None
+        };
+
         let component = Components {
             header_to_produce: header,
             transactions_source: transactions.clone(),
             gas_limit: u64::MAX,
+            coinbase_recipient: Some(self.coinbase_recipient(height)),
         };
 
         let executor = self.executor.clone();
 
+        // The dry run is stopped if the caller stops waiting for it, like when the API
+        // request times out, so abandoned requests don't occupy the VM. The queued dry run
+        // is skipped, and the running one is stopped before its next transaction.
+        let abandoned = AbandonOnDrop::default();
+        let is_abandoned = abandoned.0.clone();
+
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let tx_statuses = tokio_rayon::spawn_fifo(
             move || -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-                Ok(executor.dry_run(component, utxo_validation)?)
+                if is_abandoned.load(Ordering::Relaxed) {
+                    return Err(anyhow!("The dry run was abandoned by the caller"))
+                }
+                Ok(executor.dry_run(component, utxo_validation, is_abandoned)?)
             },
         )
         .await?;
+        drop(abandoned);
 
         if transactions
             .iter()
@@ -225,6 +276,15 @@ where
         {
             Err(anyhow!("Expected at least one set of receipts"))
         } else {
+            if let Some(state_root) = state_root {
+                self.dry_run_cache.insert(
+                    state_root,
+                    &transactions,
+                    height,
+                    utxo_validation,
+                    tx_statuses.clone(),
+                );
+            }
             Ok(tx_statuses)
         }
     }
@@ -249,6 +309,15 @@ where
         Ok(block_header)
     }
 
+    /// Returns the recipient of the fees of the block at the `height`. The zero
+    /// recipient means the fees are not collected.
+    fn coinbase_recipient(&self, height: BlockHeight) -> ContractId {
+        self.config
+            .coinbase_recipient
+            .recipient(height, self.config.signer.as_ref())
+            .unwrap_or_default()
+    }
+
     async fn select_new_da_height(
         &self,
         previous_da_height: DaBlockHeight,
@@ -316,3 +385,13 @@ struct PreviousBlockInfo {
     prev_root: Bytes32,
     da_height: DaBlockHeight,
 }
+
+/// Marks the work as abandoned when dropped before the work is done.
+#[derive(Default)]
+struct AbandonOnDrop(Arc<AtomicBool>);
+
+impl Drop for AbandonOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
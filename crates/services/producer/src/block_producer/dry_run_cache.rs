@@ -0,0 +1,124 @@
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_tx::Transaction,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+        Bytes32,
+    },
+    services::executor::TransactionExecutionStatus,
+};
+use parking_lot::Mutex;
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+/// The key of the dry run request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    /// The hash of the serialized transactions, including witnesses.
+    transactions: Bytes32,
+    height: BlockHeight,
+    utxo_validation: Option<bool>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The root of the latest block on top of which results were calculated.
+    state_root: Bytes32,
+    results: HashMap<Key, Vec<TransactionExecutionStatus>>,
+    /// The keys of the `results` from the oldest to the newest.
+    order: VecDeque<Key>,
+}
+
+/// The cache of the dry run results. Wallets request the dry run of the same
+/// transaction several times to estimate fees, while the result depends only on
+/// the transaction and the state of the blockchain. The cache is invalidated when
+/// a new block changes the state.
+///
+/// The time of the block is not part of the key, so the cached result contains
+/// the time of the first execution. When the cache is full, the oldest result
+/// is evicted.
+#[derive(Debug, Default)]
+pub struct DryRunCache {
+    capacity: usize,
+    state: Mutex<State>,
+}
+
+impl DryRunCache {
+    /// Creates the cache storing up to `capacity` results. The zero capacity
+    /// disables the cache.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Default::default(),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    pub(crate) fn get(
+        &self,
+        state_root: &Bytes32,
+        transactions: &[Transaction],
+        height: BlockHeight,
+        utxo_validation: Option<bool>,
+    ) -> Option<Vec<TransactionExecutionStatus>> {
+        let state = self.state.lock();
+        if &state.state_root != state_root {
+            return None
+        }
+        state
+            .results
+            .get(&Key::new(transactions, height, utxo_validation))
+            .cloned()
+    }
+
+    pub(crate) fn insert(
+        &self,
+        state_root: Bytes32,
+        transactions: &[Transaction],
+        height: BlockHeight,
+        utxo_validation: Option<bool>,
+        result: Vec<TransactionExecutionStatus>,
+    ) {
+        let key = Key::new(transactions, height, utxo_validation);
+        let mut state = self.state.lock();
+        if state.state_root != state_root {
+            state.state_root = state_root;
+            state.results.clear();
+            state.order.clear();
+        }
+        if state.results.insert(key, result).is_some() {
+            return
+        }
+        state.order.push_back(key);
+        while state.results.len() > self.capacity {
+            let Some(evicted) = state.order.pop_front() else {
+                break
+            };
+            state.results.remove(&evicted);
+        }
+    }
+}
+
+impl Key {
+    fn new(
+        transactions: &[Transaction],
+        height: BlockHeight,
+        utxo_validation: Option<bool>,
+    ) -> Self {
+        let mut hasher = Hasher::default();
+        for transaction in transactions {
+            hasher.input(transaction.to_bytes());
+        }
+        Self {
+            transactions: hasher.digest(),
+            height,
+            utxo_validation,
+        }
+    }
+}
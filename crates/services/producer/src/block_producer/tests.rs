@@ -1,8 +1,13 @@
 use crate::{
-    block_producer::Error,
+    block_producer::{
+        DryRunCache,
+        Error,
+    },
     mocks::{
         FailingMockExecutor,
+        GasLimitRecorder,
         MockDb,
+        MockDryRunner,
         MockExecutor,
         MockRelayer,
         MockTxPool,
@@ -23,6 +28,7 @@ use fuel_core_types::{
             PartialBlockHeader,
         },
     },
+    fuel_tx::Transaction,
     services::executor::Error as ExecutorError,
     tai64::Tai64,
 };
@@ -32,6 +38,7 @@ use rand::{
     SeedableRng,
 };
 use std::sync::{
+    atomic::Ordering,
     Arc,
     Mutex,
 };
@@ -200,6 +207,90 @@ async fn production_fails_on_execution_error() {
     );
 }
 
+#[tokio::test]
+async fn transactions_are_selected_up_to_soft_gas_target() {
+    let mut ctx = TestContext::default_from_executor(GasLimitRecorder::default());
+    ctx.config.block_gas_target = Some(1_000);
+    let executor = ctx.executor.clone();
+    let producer = ctx.producer();
+
+    producer
+        .produce_and_execute_block_txpool(1u32.into(), Tai64::now(), 1_000_000_000)
+        .await
+        .expect("Should produce the block");
+    producer
+        .produce_and_execute_block_txpool(1u32.into(), Tai64::now(), 500)
+        .await
+        .expect("Should produce the block");
+
+    assert_eq!(*executor.0.lock().unwrap(), vec![1_000, 500]);
+}
+
+#[tokio::test]
+async fn dry_run_result_is_cached_until_the_next_block() {
+    let db = TestContext::<MockDryRunner>::default_db();
+    let mut ctx =
+        TestContext::default_from_db_and_executor(db.clone(), MockDryRunner::default());
+    ctx.config.dry_run_cache_size = 10;
+    let executor = ctx.executor.clone();
+    let producer = ctx.producer();
+    let transaction = Transaction::default_test_tx();
+
+    producer
+        .dry_run(vec![transaction.clone()], None, None)
+        .await
+        .expect("Should dry run");
+    producer
+        .dry_run(vec![transaction.clone()], None, None)
+        .await
+        .expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 1);
+
+    // The different `utxo_validation` is a different request.
+    producer
+        .dry_run(vec![transaction.clone()], None, Some(true))
+        .await
+        .expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 2);
+
+    // The new block invalidates the cache.
+    db.blocks
+        .lock()
+        .unwrap()
+        .insert(1u32.into(), CompressedBlock::default());
+    producer
+        .dry_run(vec![transaction], None, None)
+        .await
+        .expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn oldest_dry_run_result_is_evicted_from_full_cache() {
+    let mut ctx = TestContext::default_from_executor(MockDryRunner::default());
+    ctx.config.dry_run_cache_size = 2;
+    let executor = ctx.executor.clone();
+    let producer = ctx.producer();
+    let transaction = Transaction::default_test_tx();
+    // The different `utxo_validation` is a different request.
+    let dry_run = |utxo_validation| {
+        producer.dry_run(vec![transaction.clone()], None, utxo_validation)
+    };
+    dry_run(None).await.expect("Should dry run");
+    dry_run(Some(true)).await.expect("Should dry run");
+
+    // When
+    dry_run(Some(false)).await.expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 3);
+
+    // Then
+    dry_run(Some(true)).await.expect("Should dry run");
+    dry_run(Some(false)).await.expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 3);
+    dry_run(None).await.expect("Should dry run");
+    assert_eq!(executor.0.load(Ordering::SeqCst), 4);
+}
+
 struct TestContext<Executor> {
     config: Config,
     db: MockDb,
@@ -249,6 +340,7 @@ impl<Executor> TestContext<Executor> {
     }
 
     pub fn producer(self) -> Producer<MockDb, MockTxPool, Executor> {
+        let dry_run_cache = DryRunCache::new(self.config.dry_run_cache_size);
         Producer {
             config: self.config,
             view_provider: self.db,
@@ -256,6 +348,7 @@ impl<Executor> TestContext<Executor> {
             executor: self.executor,
             relayer: Box::new(self.relayer),
             lock: Default::default(),
+            dry_run_cache,
         }
     }
 }
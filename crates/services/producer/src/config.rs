@@ -1,8 +1,143 @@
-use fuel_core_types::fuel_types::ContractId;
+use crate::tx_selector::{
+    FeeRateSelector,
+    TxSelector,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ContractId,
+    },
+};
+use std::sync::Arc;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Config {
     pub utxo_validation: bool,
-    pub coinbase_recipient: Option<ContractId>,
+    pub coinbase_recipient: CoinbaseRecipient,
+    /// The address of the block signer, used by the [`CoinbaseRecipient::Signer`].
+    pub signer: Option<Address>,
     pub metrics: bool,
+    /// The max number of cached dry run results. The zero disables the cache.
+    pub dry_run_cache_size: usize,
+    /// The strategy to select the transactions for the produced blocks.
+    pub tx_selector: Arc<dyn TxSelector>,
+    /// The soft gas target of the produced blocks. The producer selects the
+    /// transactions up to the target, below the hard gas limit of the chain.
+    pub block_gas_target: Option<Word>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            utxo_validation: false,
+            coinbase_recipient: CoinbaseRecipient::None,
+            signer: None,
+            metrics: false,
+            dry_run_cache_size: 0,
+            tx_selector: Arc::new(FeeRateSelector),
+            block_gas_target: None,
+        }
+    }
+}
+
+/// The recipient of the fees of the produced blocks. With the UTXO validation, the mint
+/// transaction spends the UTXO of the recipient contract, so the contract must be
+/// deployed before the node produces blocks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CoinbaseRecipient {
+    /// The fees are not collected.
+    #[default]
+    None,
+    /// The contract receives the fees of all blocks.
+    Static(ContractId),
+    /// The contracts receive the fees of the blocks in turn, by the height of the block.
+    Rotating(Vec<ContractId>),
+    /// The contract with the id equal to the address of the block signer receives
+    /// the fees.
+    Signer,
+}
+
+impl CoinbaseRecipient {
+    /// Returns the recipient of the fees of the block at the `height` signed by
+    /// the `signer`, or `None` if the fees of the block are not collected.
+    pub fn recipient(
+        &self,
+        height: BlockHeight,
+        signer: Option<&Address>,
+    ) -> Option<ContractId> {
+        match self {
+            CoinbaseRecipient::None => None,
+            CoinbaseRecipient::Static(contract_id) => Some(*contract_id),
+            CoinbaseRecipient::Rotating(contract_ids) => {
+                let index = usize::try_from(u32::from(height))
+                    .ok()?
+                    .checked_rem(contract_ids.len())?;
+                contract_ids.get(index).copied()
+            }
+            CoinbaseRecipient::Signer => signer.map(|address| ContractId::new(**address)),
+        }
+    }
+
+    /// Returns all contracts receiving the fees of the blocks signed by the `signer`.
+    pub fn contracts(&self, signer: Option<&Address>) -> Vec<ContractId> {
+        match self {
+            CoinbaseRecipient::None => vec![],
+            CoinbaseRecipient::Static(contract_id) => vec![*contract_id],
+            CoinbaseRecipient::Rotating(contract_ids) => contract_ids.clone(),
+            CoinbaseRecipient::Signer => signer
+                .map(|address| ContractId::new(**address))
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotating_recipient_depends_on_block_height() {
+        let first = ContractId::new([1; 32]);
+        let second = ContractId::new([2; 32]);
+        let recipient = CoinbaseRecipient::Rotating(vec![first, second]);
+
+        assert_eq!(recipient.recipient(10.into(), None), Some(first));
+        assert_eq!(recipient.recipient(11.into(), None), Some(second));
+        assert_eq!(
+            CoinbaseRecipient::Rotating(vec![]).recipient(10.into(), None),
+            None
+        );
+    }
+
+    #[test]
+    fn signer_recipient_is_derived_from_signer_address() {
+        let signer = Address::new([3; 32]);
+
+        assert_eq!(
+            CoinbaseRecipient::Signer.recipient(1.into(), Some(&signer)),
+            Some(ContractId::new([3; 32]))
+        );
+        assert_eq!(CoinbaseRecipient::Signer.recipient(1.into(), None), None);
+    }
+
+    #[test]
+    fn contracts_include_all_possible_recipients() {
+        let first = ContractId::new([1; 32]);
+        let second = ContractId::new([2; 32]);
+        let signer = Address::new([3; 32]);
+
+        assert_eq!(
+            CoinbaseRecipient::Rotating(vec![first, second]).contracts(None),
+            vec![first, second]
+        );
+        assert_eq!(
+            CoinbaseRecipient::Signer.contracts(Some(&signer)),
+            vec![ContractId::new([3; 32])]
+        );
+        assert_eq!(CoinbaseRecipient::Signer.contracts(None), vec![]);
+        assert_eq!(CoinbaseRecipient::None.contracts(Some(&signer)), vec![]);
+    }
 }
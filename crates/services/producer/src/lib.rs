@@ -7,9 +7,17 @@
 pub mod block_producer;
 pub mod config;
 pub mod ports;
+pub mod tx_selector;
 
 pub use block_producer::Producer;
-pub use config::Config;
+pub use config::{
+    CoinbaseRecipient,
+    Config,
+};
+pub use tx_selector::{
+    FeeRateSelector,
+    TxSelector,
+};
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mocks;
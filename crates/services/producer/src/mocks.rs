@@ -1,5 +1,6 @@
 use crate::ports::{
     BlockProducerDatabase,
+    DryRunner,
     Executor,
     Relayer,
     TxPool,
@@ -21,6 +22,11 @@ use fuel_core_types::{
         },
         primitives::DaBlockHeight,
     },
+    fuel_tx::{
+        Receipt,
+        Transaction as FuelTransaction,
+        UniqueIdentifier,
+    },
     fuel_types::{
         Address,
         BlockHeight,
@@ -33,6 +39,8 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -43,6 +51,11 @@ use std::{
     collections::HashMap,
     ops::Deref,
     sync::{
+        atomic::{
+            AtomicBool,
+            AtomicUsize,
+            Ordering,
+        },
         Arc,
         Mutex,
     },
@@ -184,6 +197,57 @@ impl Executor<Vec<ArcPoolTx>> for FailingMockExecutor {
     }
 }
 
+/// Records the gas limits of the produced blocks.
+#[derive(Default)]
+pub struct GasLimitRecorder(pub Mutex<Vec<u64>>);
+
+impl Executor<Vec<ArcPoolTx>> for GasLimitRecorder {
+    type Database = MockDb;
+
+    fn execute_without_commit(
+        &self,
+        component: Components<Vec<ArcPoolTx>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.0.lock().unwrap().push(component.gas_limit);
+        let block = to_block(component);
+        Ok(UncommittedResult::new(
+            ExecutionResult {
+                block,
+                skipped_transactions: vec![],
+                tx_status: vec![],
+            },
+            StorageTransaction::new(MockDb::default()),
+        ))
+    }
+}
+
+/// Counts the dry runs and returns a successful status with one receipt per transaction.
+#[derive(Default)]
+pub struct MockDryRunner(pub AtomicUsize);
+
+impl DryRunner for MockDryRunner {
+    fn dry_run(
+        &self,
+        block: Components<Vec<FuelTransaction>>,
+        _: Option<bool>,
+        _: Arc<AtomicBool>,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(block
+            .transactions_source
+            .iter()
+            .map(|tx| TransactionExecutionStatus {
+                id: tx.id(&ChainId::default()),
+                result: TransactionExecutionResult::Success {
+                    result: None,
+                    receipts: vec![Receipt::ret(Default::default(), 0, 0, 0)],
+                    fee: Default::default(),
+                },
+            })
+            .collect())
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MockDb {
     pub blocks: Arc<Mutex<HashMap<BlockHeight, CompressedBlock>>>,
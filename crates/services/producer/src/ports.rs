@@ -22,7 +22,13 @@ use fuel_core_types::{
         },
     },
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::AtomicBool,
+        Arc,
+    },
+};
 
 pub trait BlockProducerDatabase: Send + Sync {
     /// Gets the committed block at the `height`.
@@ -70,10 +76,11 @@ pub trait Executor<TxSource>: Send + Sync {
 pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The execution is stopped once the `abandoned` is set.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        abandoned: Arc<AtomicBool>,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
 }
@@ -2,46 +2,64 @@ use fuel_core_types::{
     fuel_types::Word,
     services::txpool::ArcPoolTx,
 };
+use std::fmt::Debug;
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
-
-// Expects sorted by gas price transactions, highest first
-pub fn select_transactions(
-    includable_txs: impl Iterator<Item = ArcPoolTx>,
-    max_gas: u64,
-) -> Vec<ArcPoolTx> {
-    // Select all txs that fit into the block, preferring ones with higher gas price.
-    //
-    // Future improvements to this algorithm may take into account the parallel nature of
-    // transactions to maximize throughput.
-    let mut used_block_space: Word = 0;
-    // The type of the index for the transaction is `u16`, so we need to
-    // limit it to `MAX` value minus 1(because of the `Mint` transaction).
-    let takes_txs = u16::MAX - 1;
-
-    // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .filter(|tx| {
-            let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
-                    used_block_space = new_used_space;
-                    true
+/// The strategy of the block producer to select the transactions for the block
+/// from the transaction pool, e.g. to order them fairly or to keep the bundles
+/// together.
+pub trait TxSelector: Debug + Send + Sync {
+    /// Selects the transactions for the block with the `max_gas` from the
+    /// `includable_txs`, sorted by the gas price, highest first. The selected
+    /// transactions are executed in the returned order and removed from the pool.
+    fn select(
+        &self,
+        includable_txs: &mut dyn Iterator<Item = ArcPoolTx>,
+        max_gas: u64,
+    ) -> Vec<ArcPoolTx>;
+}
+
+/// The default selector, preferring the transactions with the higher gas price.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FeeRateSelector;
+
+impl TxSelector for FeeRateSelector {
+    fn select(
+        &self,
+        includable_txs: &mut dyn Iterator<Item = ArcPoolTx>,
+        max_gas: u64,
+    ) -> Vec<ArcPoolTx> {
+        // Select all txs that fit into the block, preferring ones with higher gas price.
+        //
+        // Future improvements to this algorithm may take into account the parallel nature of
+        // transactions to maximize throughput.
+        let mut used_block_space: Word = 0;
+        // The type of the index for the transaction is `u16`, so we need to
+        // limit it to `MAX` value minus 1(because of the `Mint` transaction).
+        let takes_txs = u16::MAX - 1;
+
+        // Pick as many transactions as we can fit into the block (greedy)
+        includable_txs
+            .filter(|tx| {
+                let tx_block_space = tx.max_gas();
+                if let Some(new_used_space) = used_block_space.checked_add(tx_block_space)
+                {
+                    if new_used_space <= max_gas {
+                        used_block_space = new_used_space;
+                        true
+                    } else {
+                        false
+                    }
                 } else {
                     false
                 }
-            } else {
-                false
-            }
-        })
-        .take(takes_txs as usize)
-        .collect()
+            })
+            .take(takes_txs as usize)
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use fuel_core_txpool as _;
     use fuel_core_types::{
         fuel_asm::{
             op,
@@ -74,7 +92,7 @@ mod tests {
     }
 
     /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
+    /// the [`FeeRateSelector`] against that, returning the list of selected gas price, limit pairs
     fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
         let mut rng = thread_rng();
 
@@ -115,7 +133,8 @@ mod tests {
             .collect::<Vec<ArcPoolTx>>();
         txs.sort_by_key(|a| core::cmp::Reverse(a.price()));
 
-        select_transactions(txs.into_iter(), block_gas_limit)
+        FeeRateSelector
+            .select(&mut txs.into_iter(), block_gas_limit)
             .into_iter()
             .map(|tx| TxGas {
                 limit: tx.script_gas_limit().unwrap_or_default(),
@@ -142,6 +142,11 @@ where
         }
     }
 
+    #[tracing::instrument(
+        skip_all,
+        fields(oldest = eth_sync_gap.oldest(), latest = eth_sync_gap.latest()),
+        err
+    )]
     async fn download_logs(
         &mut self,
         eth_sync_gap: &state::EthSyncGap,
@@ -41,6 +41,7 @@ pub mod stream {
 
 pub use service::{
     EmptyShared,
+    RestartPolicy,
     RunnableService,
     RunnableTask,
     Service,
@@ -11,6 +11,7 @@ use fuel_core_metrics::{
     },
 };
 use futures::FutureExt;
+use std::time::Duration;
 use tokio::sync::watch;
 use tracing::Instrument;
 
@@ -31,6 +32,40 @@ impl<T> Clone for SharedMutex<T> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct EmptyShared;
 
+/// The reaction of the `ServiceRunner` on the panic inside of the [`RunnableTask::run`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// The service is stopped with the error.
+    #[default]
+    Never,
+    /// The `run` loop of the task is resumed after the backoff. The backoff starts
+    /// from the `initial_backoff` and doubles after each consecutive panic up to the
+    /// `max_backoff`. The successful iteration of the loop resets the backoff.
+    OnPanic {
+        /// The backoff before the restart after the first panic.
+        initial_backoff: Duration,
+        /// The max backoff between the restarts.
+        max_backoff: Duration,
+    },
+}
+
+impl RestartPolicy {
+    /// Returns the backoff before the restart after the `consecutive_panics` number of
+    /// panics in a row, or `None` if the service should be stopped.
+    pub fn backoff(&self, consecutive_panics: u32) -> Option<Duration> {
+        match self {
+            RestartPolicy::Never => None,
+            RestartPolicy::OnPanic {
+                initial_backoff,
+                max_backoff,
+            } => {
+                let multiplier = 2u32.saturating_pow(consecutive_panics);
+                Some(initial_backoff.saturating_mul(multiplier).min(*max_backoff))
+            }
+        }
+    }
+}
+
 /// Trait for service runners, providing a minimal interface for managing
 /// the lifecycle of services such as start/stop and health status.
 #[async_trait::async_trait]
@@ -124,6 +159,7 @@ where
     /// The shared state of the service
     pub shared: S::SharedData,
     state: Shared<watch::Sender<State>>,
+    restart_policy: SharedMutex<RestartPolicy>,
 }
 
 impl<S> Drop for ServiceRunner<S>
@@ -154,8 +190,20 @@ where
     pub fn new_with_params(service: S, params: S::TaskParams) -> Self {
         let shared = service.shared_data();
         let metric = services_metrics().register_service(S::NAME);
-        let state = initialize_loop(service, params, metric);
-        Self { shared, state }
+        let restart_policy = SharedMutex::new(RestartPolicy::default());
+        let state = initialize_loop(service, params, metric, restart_policy.clone());
+        Self {
+            shared,
+            state,
+            restart_policy,
+        }
+    }
+
+    /// Sets the reaction of the service on the panic inside of the task.
+    /// By default, the service is stopped.
+    pub fn with_restart_policy(self, restart_policy: RestartPolicy) -> Self {
+        self.restart_policy.apply(|policy| *policy = restart_policy);
+        self
     }
 
     async fn _await_start_or_stop(
@@ -255,6 +303,7 @@ fn initialize_loop<S>(
     service: S,
     params: S::TaskParams,
     metric: ServiceLifecycle,
+    restart_policy: SharedMutex<RestartPolicy>,
 ) -> Shared<watch::Sender<State>>
 where
     S: RunnableService + 'static,
@@ -271,6 +320,7 @@ where
                 stop_sender.clone(),
                 params,
                 metric,
+                restart_policy,
             ));
             tracing::debug!("awaiting run");
             let result = run.catch_unwind().await;
@@ -312,6 +362,7 @@ async fn run<S>(
     sender: Shared<watch::Sender<State>>,
     params: S::TaskParams,
     metric: ServiceLifecycle,
+    restart_policy: SharedMutex<RestartPolicy>,
 ) where
     S: RunnableService + 'static,
 {
@@ -343,17 +394,35 @@ async fn run<S>(
     });
 
     let mut got_panic = None;
+    let mut consecutive_panics = 0u32;
 
     while state.borrow_and_update().started() {
-        let tracked_task = FutureTracker::new(task.run(&mut state));
+        // The `run` is called inside of the future to catch the panic of the call.
+        let tracked_task = FutureTracker::new(async { task.run(&mut state).await });
         let task = std::panic::AssertUnwindSafe(tracked_task);
         let panic_result = task.catch_unwind().await;
 
         if let Err(panic) = panic_result {
             tracing::debug!("got a panic");
-            got_panic = Some(panic);
-            break
+            let policy = restart_policy.apply(|policy| *policy);
+            let Some(backoff) = policy.backoff(consecutive_panics) else {
+                got_panic = Some(panic);
+                break
+            };
+            let panic_information = panic_to_string(panic);
+            tracing::error!(
+                "The service {} panicked: {panic_information}. Restarting in {backoff:?}",
+                S::NAME
+            );
+            metric.restarts.inc();
+            consecutive_panics = consecutive_panics.saturating_add(1);
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = state.while_started() => {}
+            }
+            continue
         }
+        consecutive_panics = 0;
 
         let tracked_result = panic_result.expect("Checked the panic above");
 
@@ -547,6 +616,87 @@ mod tests {
         assert!(matches!(state, State::StoppedWithError(s) if s.contains("Should fail")));
     }
 
+    /// The task panics in the first `panics` iterations and stops after.
+    struct PanickingTask {
+        panics: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableService for PanickingTask {
+        const NAME: &'static str = "PanickingService";
+
+        type SharedData = EmptyShared;
+        type Task = PanickingTask;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> EmptyShared {
+            EmptyShared
+        }
+
+        async fn into_task(
+            self,
+            _: &StateWatcher,
+            _: Self::TaskParams,
+        ) -> anyhow::Result<Self::Task> {
+            Ok(self)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableTask for PanickingTask {
+        async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+            if self.panics > 0 {
+                self.panics = self.panics.saturating_sub(1);
+                panic!("Should be restarted")
+            }
+            let should_continue = false;
+            Ok(should_continue)
+        }
+
+        async fn shutdown(self) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn panic_during_run_with_restart_policy() {
+        let service = ServiceRunner::new(PanickingTask { panics: 2 })
+            .with_restart_policy(RestartPolicy::OnPanic {
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(10),
+            });
+        service.start_and_await().await.unwrap();
+
+        let state = service.await_stop().await.unwrap();
+        assert!(matches!(state, State::Stopped));
+    }
+
+    #[tokio::test]
+    async fn panic_during_run_without_restart_policy() {
+        let service = ServiceRunner::new(PanickingTask { panics: 1 });
+        service.start_and_await().await.unwrap();
+
+        let state = service.await_stop().await.unwrap();
+        assert!(
+            matches!(state, State::StoppedWithError(s) if s.contains("Should be restarted"))
+        );
+    }
+
+    #[test]
+    fn restart_backoff_doubles_up_to_max() {
+        let policy = RestartPolicy::OnPanic {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+
+        assert_eq!(RestartPolicy::Never.backoff(0), None);
+        assert_eq!(policy.backoff(0), Some(Duration::from_secs(1)));
+        assert_eq!(policy.backoff(1), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_secs(4)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_secs(5)));
+        assert_eq!(policy.backoff(100), Some(Duration::from_secs(5)));
+    }
+
     #[tokio::test]
     async fn panic_during_shutdown() {
         let mut mock = MockService::default();
@@ -43,6 +43,7 @@ use crate::{
         PeerReportReason,
         PeerToPeerPort,
     },
+    progress::Progress,
     state::State,
     tracing_helpers::TraceErr,
 };
@@ -81,6 +82,8 @@ impl Default for Config {
 pub struct Import<P, E, C> {
     /// Shared state between import and sync tasks.
     state: SharedMutex<State>,
+    /// The progress of the stages of the import, reported to the users.
+    progress: SharedMutex<Progress>,
     /// Notify import when sync has new work.
     notify: Arc<Notify>,
     /// Configuration parameters.
@@ -106,6 +109,7 @@ impl<P, E, C> Import<P, E, C> {
     ) -> Self {
         Self {
             state,
+            progress: SharedMutex::new(Progress::default()),
             notify,
             params,
             p2p,
@@ -114,6 +118,12 @@ impl<P, E, C> Import<P, E, C> {
         }
     }
 
+    /// Reports the progress of the import to the shared `progress`.
+    pub fn with_progress(mut self, progress: SharedMutex<Progress>) -> Self {
+        self.progress = progress;
+        self
+    }
+
     /// Signal other asynchronous tasks that an import event has occurred.
     pub fn notify_one(&self) {
         self.notify.notify_one()
@@ -174,6 +184,7 @@ where
                 let incomplete_range = range.start().saturating_add(count)..=*range.end();
                 self.state
                     .apply(|s| s.failed_to_process(incomplete_range.clone()));
+                self.progress.apply(|p| p.failed());
                 Err(anyhow::anyhow!(
                     "Failed to import range of blocks: {:?}",
                     incomplete_range
@@ -197,6 +208,7 @@ where
     ) -> usize {
         let Self {
             state,
+            progress,
             params,
             p2p,
             executor,
@@ -208,8 +220,13 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let block_stream = get_block_stream(
+            range.clone(),
+            params,
+            p2p.clone(),
+            consensus.clone(),
+            progress.clone(),
+        );
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -251,6 +268,11 @@ where
                         results,
                     } = batch;
 
+                    if let Some(block) = results.last() {
+                        let height = **block.entity.header().height();
+                        progress.apply(|p| p.block_downloaded(height));
+                    }
+
                     let mut done = vec![];
                     for sealed_block in results {
                         let res = execute_and_commit(executor.as_ref(), state, sealed_block).await;
@@ -303,6 +325,7 @@ fn get_block_stream<
     params: &Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
+    progress: SharedMutex<Progress>,
 ) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> + '_ {
     let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
     header_stream
@@ -321,6 +344,10 @@ fn get_block_stream<
                         check_sealed_header(header, peer.clone(), &p2p, &consensus)
                     })
                     .collect::<Vec<_>>();
+                if let Some(header) = checked_headers.last() {
+                    let height = **header.entity.height();
+                    progress.apply(|p| p.header_checked(height));
+                }
                 Batch::new(peer, range, checked_headers)
             }
         })
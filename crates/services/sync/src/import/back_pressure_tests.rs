@@ -107,6 +107,7 @@ async fn test_back_pressure(input: Input, state: State, params: Config) -> Count
 
     let import = Import {
         state,
+        progress: SharedMutex::new(Default::default()),
         notify,
         params,
         p2p,
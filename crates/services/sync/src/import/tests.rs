@@ -943,6 +943,7 @@ async fn test_import_inner(
 
     let import = Import {
         state,
+        progress: SharedMutex::new(Default::default()),
         notify,
         params,
         p2p,
@@ -1105,6 +1106,7 @@ impl PeerReportTestBuilder {
 
         let import = Import {
             state,
+            progress: SharedMutex::new(Default::default()),
             notify,
             params,
             p2p,
@@ -0,0 +1,200 @@
+//! Progress of the sync service, reported to the users of the node.
+
+use fuel_core_types::services::p2p::{
+    SyncStage,
+    SyncStatus,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+#[derive(Debug, Default, Clone)]
+/// Tracks how far each stage of the sync pipeline got.
+pub struct Progress {
+    /// The height of the latest committed block.
+    committed: Option<u32>,
+    /// The highest height reported by the peers.
+    observed: Option<u32>,
+    /// The height of the latest header with the checked consensus.
+    headers: Option<u32>,
+    /// The height of the latest downloaded block.
+    blocks: Option<u32>,
+    /// The time and the committed height when the node fell behind the peers.
+    behind_since: Option<(Instant, u32)>,
+}
+
+impl Progress {
+    /// Creates the progress of the node with the `committed` height.
+    pub fn new(committed: Option<u32>) -> Self {
+        Self {
+            committed,
+            ..Default::default()
+        }
+    }
+
+    /// Records the height reported by the peers.
+    pub fn observe(&mut self, height: u32, now: Instant) {
+        if self.observed.is_some_and(|observed| observed >= height) {
+            return
+        }
+        self.observed = Some(height);
+        if self.behind_since.is_none() && !self.is_synced() {
+            self.behind_since = Some((now, self.committed.unwrap_or_default()));
+        }
+    }
+
+    /// Records the committed block.
+    pub fn commit(&mut self, height: u32) {
+        if self.committed.is_some_and(|committed| committed >= height) {
+            return
+        }
+        self.committed = Some(height);
+        if self.is_synced() {
+            self.behind_since = None;
+        }
+    }
+
+    /// Records the header with the checked consensus.
+    pub fn header_checked(&mut self, height: u32) {
+        self.headers = self.headers.max(Some(height));
+    }
+
+    /// Records the downloaded block.
+    pub fn block_downloaded(&mut self, height: u32) {
+        self.blocks = self.blocks.max(Some(height));
+    }
+
+    /// Forgets the headers and blocks that were not committed, because
+    /// the import of the range failed and they are requested again.
+    pub fn failed(&mut self) {
+        self.headers = None;
+        self.blocks = None;
+    }
+
+    fn is_synced(&self) -> bool {
+        match (self.committed, self.observed) {
+            (_, None) => true,
+            (Some(committed), Some(observed)) => committed >= observed,
+            (None, Some(_)) => false,
+        }
+    }
+
+    /// Returns the status of the sync at the time `now`.
+    pub fn status(&self, now: Instant) -> SyncStatus {
+        let next = self
+            .committed
+            .map_or(Some(0), |committed| committed.checked_add(1));
+        let reached = |stage: Option<u32>| {
+            stage.is_some_and(|height| next.is_some_and(|next| height >= next))
+        };
+        let stage = if self.observed.is_none() {
+            SyncStage::Connecting
+        } else if self.is_synced() {
+            SyncStage::Synced
+        } else if reached(self.blocks) {
+            SyncStage::Execution
+        } else if reached(self.headers) {
+            SyncStage::BlockDownload
+        } else {
+            SyncStage::HeaderSync
+        };
+
+        let blocks_per_second = match self.behind_since {
+            Some((since, start)) if stage != SyncStage::Synced => {
+                let elapsed = now.saturating_duration_since(since).as_secs_f64();
+                let committed = self.committed.unwrap_or_default().saturating_sub(start);
+                if elapsed > 0.0 {
+                    f64::from(committed) / elapsed
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+        let remaining = self
+            .observed
+            .unwrap_or_default()
+            .saturating_sub(self.committed.unwrap_or_default());
+        let eta = match stage {
+            SyncStage::Synced => Some(Duration::ZERO),
+            _ if blocks_per_second > 0.0 => Some(Duration::from_secs_f64(
+                f64::from(remaining) / blocks_per_second,
+            )),
+            _ => None,
+        };
+
+        SyncStatus {
+            stage,
+            local_height: self.committed.map(Into::into),
+            best_known_height: self.observed.map(Into::into),
+            blocks_per_second,
+            eta,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn node_without_peers_is_connecting() {
+        let progress = Progress::new(Some(10));
+
+        let status = progress.status(Instant::now());
+
+        assert_eq!(status.stage, SyncStage::Connecting);
+        assert_eq!(status.local_height, Some(10u32.into()));
+        assert_eq!(status.best_known_height, None);
+    }
+
+    #[test_case(None, None => SyncStage::HeaderSync; "nothing is fetched")]
+    #[test_case(Some(15), None => SyncStage::BlockDownload; "headers are checked")]
+    #[test_case(Some(15), Some(12) => SyncStage::Execution; "blocks are downloaded")]
+    #[test_case(Some(10), Some(10) => SyncStage::HeaderSync; "only committed blocks are fetched")]
+    fn stage_is_the_stage_of_next_block(
+        headers: Option<u32>,
+        blocks: Option<u32>,
+    ) -> SyncStage {
+        let mut progress = Progress::new(Some(10));
+        progress.observe(20, Instant::now());
+        if let Some(height) = headers {
+            progress.header_checked(height);
+        }
+        if let Some(height) = blocks {
+            progress.block_downloaded(height);
+        }
+
+        progress.status(Instant::now()).stage
+    }
+
+    #[test]
+    fn rate_and_eta_are_computed_since_node_fell_behind() {
+        let start = Instant::now();
+        let mut progress = Progress::new(Some(10));
+        progress.observe(50, start);
+
+        progress.commit(20);
+        let status = progress.status(start + Duration::from_secs(5));
+
+        assert_eq!(status.stage, SyncStage::HeaderSync);
+        assert_eq!(status.blocks_per_second, 2.0);
+        assert_eq!(status.eta, Some(Duration::from_secs(15)));
+    }
+
+    #[test]
+    fn synced_node_has_no_rate() {
+        let start = Instant::now();
+        let mut progress = Progress::new(Some(10));
+        progress.observe(12, start);
+
+        progress.commit(12);
+        let status = progress.status(start + Duration::from_secs(5));
+
+        assert_eq!(status.stage, SyncStage::Synced);
+        assert_eq!(status.blocks_per_second, 0.0);
+        assert_eq!(status.eta, Some(Duration::ZERO));
+    }
+}
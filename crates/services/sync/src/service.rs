@@ -12,6 +12,7 @@ use crate::{
         ConsensusPort,
         PeerToPeerPort,
     },
+    progress::Progress,
     state::State,
     sync::SyncHeights,
 };
@@ -28,8 +29,12 @@ use fuel_core_services::{
     SharedMutex,
     StateWatcher,
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::SyncStatus,
+};
 use futures::StreamExt;
+use std::time::Instant;
 use tokio::sync::Notify;
 
 #[cfg(test)]
@@ -51,10 +56,12 @@ where
     let height_stream = p2p.height_stream();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
+    let progress = Progress::new(Some(current_fuel_block_height.into()));
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
         state,
+        progress,
         params,
         p2p,
         executor,
@@ -62,6 +69,19 @@ where
     )?))
 }
 
+/// The state of the sync service shared with the other services.
+#[derive(Clone)]
+pub struct SharedState {
+    progress: SharedMutex<Progress>,
+}
+
+impl SharedState {
+    /// Returns the current progress of the sync.
+    pub fn status(&self) -> SyncStatus {
+        self.progress.apply(|p| p.status(Instant::now()))
+    }
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -72,6 +92,7 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    shared: SharedState,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -82,10 +103,12 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
         state: State,
+        progress: Progress,
         params: Config,
         p2p: P,
         executor: E,
@@ -93,6 +116,7 @@ where
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
+        let progress = SharedMutex::new(progress);
         let p2p = Arc::new(p2p);
         let executor = Arc::new(executor);
         let consensus = Arc::new(consensus);
@@ -100,13 +124,16 @@ where
             height_stream,
             committed_height_stream,
             state.clone(),
+            progress.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let import = Import::new(state, notify, params, p2p, executor, consensus)
+            .with_progress(progress.clone());
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
             import_task_handle,
+            shared: SharedState { progress },
         })
     }
 }
@@ -138,12 +165,14 @@ where
 {
     const NAME: &'static str = "SyncTask";
 
-    type SharedData = ();
+    type SharedData = SharedState;
 
     type Task = SyncTask<P, E, C>;
     type TaskParams = ();
 
-    fn shared_data(&self) -> Self::SharedData {}
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
 
     async fn into_task(
         mut self,
@@ -17,6 +17,7 @@ async fn test_sync() {
     let mut s = SyncHeights {
         height_stream,
         state,
+        progress: SharedMutex::new(Default::default()),
         notify,
     };
 
@@ -1,4 +1,6 @@
+use crate::policy::Policy;
 use fuel_core_chain_config::ChainConfig;
+use fuel_core_types::services::predicate_cache::PredicateCache;
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -7,7 +9,8 @@ pub struct Config {
     pub max_tx: usize,
     /// max depth of connected UTXO excluding contracts
     pub max_depth: usize,
-    /// The minimum allowed gas price
+    /// The minimum allowed gas price at the start of the service. It can be changed
+    /// later via the `SharedState::set_min_gas_price`.
     pub min_gas_price: u64,
     /// Flag to disable utxo existence and signature checks
     pub utxo_validation: bool,
@@ -19,6 +22,16 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// How long the transaction submitted after another transaction waits for it,
+    /// if the inputs of the transaction are not found.
+    pub dependency_wait_timeout: Duration,
+    /// The operator-supplied admission policy. All transactions are accepted if not set.
+    pub policy: Option<Policy>,
+    /// Enables the static checks of the scripts before the insertion into the pool.
+    pub script_static_checks: bool,
+    /// The cache of the successful evaluations of the predicates shared with
+    /// the executor, so the executor doesn't evaluate them again.
+    pub predicate_cache: Option<PredicateCache>,
 }
 
 impl Default for Config {
@@ -31,6 +44,7 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let dependency_wait_timeout = Duration::from_secs(10);
         Self::new(
             max_tx,
             max_depth,
@@ -40,6 +54,7 @@ impl Default for Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            dependency_wait_timeout,
         )
     }
 }
@@ -55,6 +70,7 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        dependency_wait_timeout: Duration,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -67,6 +83,10 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            dependency_wait_timeout,
+            policy: None,
+            script_static_checks: false,
+            predicate_cache: None,
         }
     }
 }
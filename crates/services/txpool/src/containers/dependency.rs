@@ -472,13 +472,14 @@ impl Dependency {
     }
 
     /// insert tx inside dependency
-    /// return list of transactions that are removed from txpool
+    /// return list of transactions that are removed from txpool. Each group starts with the
+    /// collided transaction followed by the transactions that depend on it.
     pub(crate) fn insert<'a, DB>(
         &'a mut self,
         txs: &'a HashMap<TxId, TxInfo>,
         db: &DB,
         tx: &'a ArcPoolTx,
-    ) -> anyhow::Result<Vec<ArcPoolTx>>
+    ) -> anyhow::Result<Vec<Vec<ArcPoolTx>>>
     where
         DB: TxPoolDb,
     {
@@ -491,7 +492,7 @@ impl Dependency {
             let collided = txs
                 .get(&collided)
                 .expect("Collided should be present in txpool");
-            removed_tx.extend(
+            removed_tx.push(
                 self.recursively_remove_all_dependencies(txs, collided.tx().clone()),
             );
         }
@@ -571,6 +572,38 @@ impl Dependency {
         Ok(removed_tx)
     }
 
+    /// Returns the ids of the pending transactions whose outputs are spent by the `tx`.
+    pub(crate) fn parents(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        tx: &ArcPoolTx,
+    ) -> Vec<TxId> {
+        let mut parents = Vec::new();
+        for input in tx.inputs() {
+            let parent = match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    Some(*utxo_id.tx_id())
+                }
+                Input::Contract(Contract { contract_id, .. }) => self
+                    .contracts
+                    .get(contract_id)
+                    .and_then(|state| state.origin)
+                    .map(|origin| *origin.tx_id()),
+                Input::MessageCoinSigned(_)
+                | Input::MessageCoinPredicate(_)
+                | Input::MessageDataSigned(_)
+                | Input::MessageDataPredicate(_) => None,
+            };
+            if let Some(parent) = parent {
+                if txs.contains_key(&parent) && !parents.contains(&parent) {
+                    parents.push(parent);
+                }
+            }
+        }
+        parents
+    }
+
     /// Remove all pending txs that depend on the outputs of the provided tx
     pub(crate) fn recursively_remove_all_dependencies<'a>(
         &'a mut self,
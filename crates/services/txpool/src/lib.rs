@@ -17,9 +17,10 @@ use std::{
 
 pub mod config;
 mod containers;
+pub mod policy;
 pub mod ports;
 pub mod service;
-mod transaction_selector;
+pub mod static_checks;
 pub mod txpool;
 pub mod types;
 
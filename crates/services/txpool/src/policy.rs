@@ -0,0 +1,126 @@
+//! The admission policy allows the operator of the node to define custom rules for
+//! the transactions accepted by the `TxPool`, like the allowlist of the owners.
+
+use fuel_core_types::fuel_tx::{
+    field::Inputs,
+    input::{
+        coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        message::{
+            MessageCoinPredicate,
+            MessageCoinSigned,
+            MessageDataPredicate,
+            MessageDataSigned,
+        },
+    },
+    Address,
+    Input,
+    Transaction,
+};
+use std::{
+    collections::HashSet,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    sync::Arc,
+};
+
+/// The rule checked for each transaction before the insertion into the `TxPool`.
+pub trait TransactionPolicy: Send + Sync {
+    /// Returns the reason of the rejection if the `tx` is not allowed.
+    fn check(&self, tx: &Transaction) -> Result<(), String>;
+}
+
+/// The policy shared by the `TxPool` configuration.
+#[derive(Clone)]
+pub struct Policy(pub Arc<dyn TransactionPolicy>);
+
+impl Policy {
+    pub fn new<P: TransactionPolicy + 'static>(policy: P) -> Self {
+        Self(Arc::new(policy))
+    }
+
+    pub fn check(&self, tx: &Transaction) -> Result<(), String> {
+        self.0.check(tx)
+    }
+}
+
+impl Debug for Policy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Policy")
+    }
+}
+
+/// Accepts only transactions where all coins and messages are spent by the allowed owners.
+#[derive(Debug, Clone, Default)]
+pub struct AllowedOwners(pub HashSet<Address>);
+
+impl TransactionPolicy for AllowedOwners {
+    fn check(&self, tx: &Transaction) -> Result<(), String> {
+        let inputs = match tx {
+            Transaction::Script(script) => script.inputs(),
+            Transaction::Create(create) => create.inputs(),
+            Transaction::Mint(_) => return Ok(()),
+        };
+
+        for input in inputs {
+            let owner = match input {
+                Input::CoinSigned(CoinSigned { owner, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, .. }) => owner,
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient, ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient, ..
+                }) => recipient,
+                Input::Contract(_) => continue,
+            };
+            if !self.0.contains(owner) {
+                return Err(format!("The owner {owner:#x} is not allowed"))
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn tx_spent_by(owner: Address) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                Default::default(),
+                owner,
+                10,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ))
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn allowed_owner_is_accepted() {
+        let owner = Address::from([1; 32]);
+        let policy = AllowedOwners([owner].into_iter().collect());
+
+        assert!(policy.check(&tx_spent_by(owner)).is_ok());
+    }
+
+    #[test]
+    fn unknown_owner_is_rejected() {
+        let policy = AllowedOwners([Address::from([1; 32])].into_iter().collect());
+
+        let result = policy.check(&tx_spent_by(Address::from([2; 32])));
+
+        assert!(result.is_err());
+    }
+}
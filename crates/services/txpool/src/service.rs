@@ -4,7 +4,6 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
-    transaction_selector::select_transactions,
     txpool::{
         check_single_tx,
         check_transactions,
@@ -55,11 +54,20 @@ use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::services::block_importer::SharedImportResult;
 use parking_lot::Mutex as ParkingMutex;
 use std::{
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
     time::Duration,
 };
 use tokio::{
-    sync::broadcast,
+    sync::broadcast::{
+        self,
+        error::RecvError,
+    },
     time::MissedTickBehavior,
 };
 use tokio_stream::StreamExt;
@@ -74,18 +82,28 @@ mod update_sender;
 
 pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 
+/// The transaction removed from the tx pool without the inclusion into the block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SqueezedOutTransaction {
+    pub tx_id: TxId,
+    pub reason: String,
+}
+
 #[derive(Clone)]
 pub struct TxStatusChange {
     new_tx_notification_sender: broadcast::Sender<TxId>,
+    squeezed_out_sender: broadcast::Sender<SqueezedOutTransaction>,
     update_sender: UpdateSender,
 }
 
 impl TxStatusChange {
     pub fn new(capacity: usize, ttl: Duration) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
+        let (squeezed_out_sender, _) = broadcast::channel(capacity);
         let update_sender = UpdateSender::new(capacity, ttl);
         Self {
             new_tx_notification_sender,
+            squeezed_out_sender,
             update_sender,
         }
     }
@@ -111,11 +129,14 @@ impl TxStatusChange {
 
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
+        let reason = reason.to_string();
+        let _ = self.squeezed_out_sender.send(SqueezedOutTransaction {
+            tx_id: id,
+            reason: reason.clone(),
+        });
         self.update_sender.send(TxUpdate::new(
             id,
-            TxStatusMessage::Status(TransactionStatus::SqueezedOut {
-                reason: reason.to_string(),
-            }),
+            TxStatusMessage::Status(TransactionStatus::SqueezedOut { reason }),
         ));
     }
 }
@@ -126,6 +147,7 @@ pub struct SharedState<P2P, ViewProvider> {
     p2p: Arc<P2P>,
     consensus_params: ConsensusParameters,
     current_height: Arc<ParkingMutex<BlockHeight>>,
+    min_gas_price: Arc<AtomicU64>,
     config: Config,
 }
 
@@ -137,6 +159,7 @@ impl<P2P, ViewProvider> Clone for SharedState<P2P, ViewProvider> {
             p2p: self.p2p.clone(),
             consensus_params: self.consensus_params.clone(),
             current_height: self.current_height.clone(),
+            min_gas_price: self.min_gas_price.clone(),
             config: self.config.clone(),
         }
     }
@@ -232,7 +255,8 @@ where
                     let current_height = *self.shared.current_height.lock();
 
                     // verify tx
-                    let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
+                    let min_gas_price = self.shared.min_gas_price();
+                    let checked_tx = check_single_tx(tx, current_height, &self.shared.config, min_gas_price).await;
 
                     let acceptance = match checked_tx {
                         Ok(tx) => {
@@ -314,14 +338,47 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find_one(&id)
     }
 
+    /// Returns all transactions of the pool in the order of their submission.
+    pub fn all_transactions(&self) -> Vec<ArcPoolTx> {
+        let mut txs = self
+            .txpool
+            .lock()
+            .txs()
+            .values()
+            .map(|info| (info.submitted_time(), info.tx().clone()))
+            .collect::<Vec<_>>();
+        txs.sort_by_key(|(submitted_time, _)| *submitted_time);
+        txs.into_iter().map(|(_, tx)| tx).collect()
+    }
+
+    /// Returns all transactions of the pool in the order of their submission together with
+    /// the ids of the pending transactions they depend on.
+    pub fn all_transactions_with_dependencies(&self) -> Vec<(TxInfo, Vec<TxId>)> {
+        let txpool = self.txpool.lock();
+        let mut txs = txpool
+            .txs()
+            .values()
+            .map(|info| {
+                let parents = txpool.dependency().parents(txpool.txs(), info.tx());
+                (info.clone(), parents)
+            })
+            .collect::<Vec<_>>();
+        txs.sort_by_key(|(info, _)| info.submitted_time());
+        txs
+    }
+
     pub fn find_dependent(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().find_dependent(&ids)
     }
 
-    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+    /// Removes the transactions chosen by the `select` from the includable
+    /// transactions, sorted by the gas price, highest first.
+    pub fn select_transactions<F>(&self, select: F) -> Vec<ArcPoolTx>
+    where
+        F: FnOnce(&mut dyn Iterator<Item = ArcPoolTx>) -> Vec<ArcPoolTx>,
+    {
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let sorted_txs = select(&mut guard.includable());
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
@@ -333,10 +390,25 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
 
+    /// Returns the minimum gas price of the transactions accepted by the pool.
+    pub fn min_gas_price(&self) -> u64 {
+        self.min_gas_price.load(Ordering::Relaxed)
+    }
+
+    /// Changes the minimum gas price of the newly submitted transactions.
+    /// The transactions already inserted into the pool are not affected.
+    pub fn set_min_gas_price(&self, min_gas_price: u64) {
+        self.min_gas_price.store(min_gas_price, Ordering::Relaxed)
+    }
+
     pub fn new_tx_notification_subscribe(&self) -> broadcast::Receiver<TxId> {
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
 
+    pub fn squeezed_out_subscribe(&self) -> broadcast::Receiver<SqueezedOutTransaction> {
+        self.tx_status_sender.squeezed_out_sender.subscribe()
+    }
+
     pub fn tx_update_subscribe(&self, tx_id: Bytes32) -> anyhow::Result<TxStatusStream> {
         self.tx_status_sender
             .update_sender
@@ -351,7 +423,11 @@ where
     ViewProvider: AtomicView<View = View>,
     View: TxPoolDb,
 {
-    #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
+    #[tracing::instrument(
+        name = "insert_submitted_txn",
+        skip_all,
+        fields(transactions = txs.len())
+    )]
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -359,7 +435,9 @@ where
         // verify txs
         let current_height = *self.current_height.lock();
 
-        let checked_txs = check_transactions(&txs, current_height, &self.config).await;
+        let checked_txs =
+            check_transactions(&txs, current_height, &self.config, self.min_gas_price())
+                .await;
 
         let mut valid_txs = vec![];
 
@@ -407,6 +485,69 @@ where
             })
             .collect()
     }
+
+    /// Inserts the `tx` submitted after the `after` transaction. If the inputs of
+    /// the `tx` are not found while the `after` transaction is not in the pool,
+    /// the `tx` waits for the insertion of the `after` transaction up to
+    /// the `dependency_wait_timeout` and is inserted again. It allows submitting
+    /// the chains of the dependent transactions without waiting for each of them.
+    pub async fn insert_after(
+        &self,
+        tx: Arc<Transaction>,
+        after: TxId,
+    ) -> anyhow::Result<InsertionResult> {
+        // Subscribe before the first attempt to not miss the insertion of `after`.
+        let mut new_txs = self.new_tx_notification_subscribe();
+
+        let result = self.insert_one(tx.clone()).await;
+        if !is_missing_input(&result) || self.find_one(after).is_some() {
+            return result
+        }
+
+        let wait_for_after = async {
+            loop {
+                match new_txs.recv().await {
+                    Ok(tx_id) if tx_id == after => return true,
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(_)) => {
+                        if self.find_one(after).is_some() {
+                            return true
+                        }
+                    }
+                    Err(RecvError::Closed) => return false,
+                }
+            }
+        };
+
+        match tokio::time::timeout(self.config.dependency_wait_timeout, wait_for_after)
+            .await
+        {
+            Ok(true) => self.insert_one(tx).await,
+            _ => result,
+        }
+    }
+
+    async fn insert_one(&self, tx: Arc<Transaction>) -> anyhow::Result<InsertionResult> {
+        self.insert(vec![tx])
+            .await
+            .pop()
+            .expect("The result of the insertion of one transaction")
+    }
+}
+
+/// Returns `true` if the transaction is rejected because its inputs are not found.
+fn is_missing_input(result: &anyhow::Result<InsertionResult>) -> bool {
+    matches!(
+        result
+            .as_ref()
+            .err()
+            .and_then(|e| e.downcast_ref::<Error>()),
+        Some(
+            Error::NotInsertedInputUtxoIdNotExisting(_)
+                | Error::NotInsertedInputMessageUnknown(_)
+                | Error::NotInsertedInputContractNotExisting(_)
+        )
+    )
 }
 
 #[derive(Debug, Clone)]
@@ -472,6 +613,7 @@ where
             p2p,
             consensus_params,
             current_height: Arc::new(ParkingMutex::new(current_height)),
+            min_gas_price: Arc::new(AtomicU64::new(config.min_gas_price)),
             config,
         },
         ttl_timer,
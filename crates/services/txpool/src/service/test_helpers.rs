@@ -16,10 +16,13 @@ use fuel_core_types::{
         SeedableRng,
     },
     fuel_tx::{
+        AssetId,
         Cacheable,
         Input,
+        Output,
         Transaction,
         TransactionBuilder,
+        UtxoId,
         Word,
     },
     services::{
@@ -62,6 +65,43 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    /// Creates the transaction with the coin output and the transaction spending it.
+    pub fn setup_dependent_txs(&self) -> (Transaction, Transaction) {
+        let input = crate::test_helpers::random_predicate(
+            &mut self.rng.borrow_mut(),
+            AssetId::BASE,
+            1,
+            None,
+        );
+        let output = Output::coin(*input.input_owner().unwrap(), 1, AssetId::BASE);
+
+        let (_, gas_coin) = self.setup_coin();
+        let mut parent = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .script_gas_limit(1000)
+            .add_input(gas_coin)
+            .add_output(output)
+            .finalize_as_transaction();
+        parent
+            .precompute(&Default::default())
+            .expect("Should be able to cache");
+
+        let input = crate::test_helpers::UnsetInput::new(input)
+            .into_input(UtxoId::new(parent.id(&Default::default()), 0));
+        let (_, gas_coin) = self.setup_coin();
+        let mut child = TransactionBuilder::script(vec![], vec![])
+            .gas_price(1)
+            .script_gas_limit(1000)
+            .add_input(input)
+            .add_input(gas_coin)
+            .finalize_as_transaction();
+        child
+            .precompute(&Default::default())
+            .expect("Should be able to cache");
+
+        (parent, child)
+    }
 }
 
 mockall::mock! {
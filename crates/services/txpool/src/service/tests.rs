@@ -50,6 +50,24 @@ async fn test_find() {
     service.stop_and_await().await.unwrap();
 }
 
+#[tokio::test]
+async fn test_insert_with_updated_min_gas_price() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+
+    let service = ctx.service();
+    service.shared.set_min_gas_price(15);
+
+    let out = service.shared.insert(vec![tx1, tx2]).await;
+
+    assert_eq!(service.shared.min_gas_price(), 15);
+    assert!(out[0].is_err(), "Tx1 should be rejected:{out:?}");
+    assert!(out[1].is_ok(), "Tx2 should be OK, got err:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
+
 #[tokio::test(start_paused = true)]
 async fn test_prune_transactions() {
     const TIMEOUT: u64 = 10;
@@ -267,3 +285,59 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn insert_after_waits_for_the_dependency() {
+    let ctx = TestContext::new().await;
+    let (parent, child) = ctx.setup_dependent_txs();
+    let parent_id = parent.id(&Default::default());
+
+    let service = ctx.service();
+    let shared = service.shared.clone();
+    let child_insertion =
+        tokio::spawn(
+            async move { shared.insert_after(Arc::new(child), parent_id).await },
+        );
+    // Let the first attempt of the child fail before the parent is inserted.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let out = service.shared.insert(vec![Arc::new(parent)]).await;
+
+    assert!(out[0].is_ok(), "Parent should be OK, got err:{out:?}");
+    let child_result = child_insertion.await.unwrap();
+    assert!(
+        child_result.is_ok(),
+        "Child should be OK, got err:{child_result:?}"
+    );
+    assert_eq!(service.shared.pending_number(), 2);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn insert_after_fails_if_the_dependency_does_not_arrive() {
+    let config = Config {
+        dependency_wait_timeout: Duration::from_secs(1),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+    let (parent, child) = ctx.setup_dependent_txs();
+
+    let service = ctx.service();
+    let result = service
+        .shared
+        .insert_after(Arc::new(child), parent.id(&Default::default()))
+        .await;
+
+    let err = result.expect_err("Child without the parent should be rejected");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedInputUtxoIdNotExisting(_))
+    ));
+    assert_eq!(service.shared.pending_number(), 0);
+
+    service.stop_and_await().await.unwrap();
+}
@@ -48,6 +48,7 @@ fn test_update_sender_reg() {
                 time: Tai64(0),
                 result: None,
                 receipts: vec![],
+                fee: Default::default(),
             }),
         ),
         Recv(0),
@@ -64,6 +64,7 @@ fn test_send_reg() {
             time: Tai64(0),
             result: None,
             receipts: vec![],
+            fee: Default::default(),
         }),
     };
     test_send_inner(
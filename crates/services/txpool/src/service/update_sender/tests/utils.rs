@@ -8,12 +8,14 @@ pub fn transaction_status_strategy() -> impl Strategy<Value = TransactionStatus>
             time: Tai64(0),
             result: None,
             receipts: vec![],
+            fee: Default::default(),
         }),
         Just(TransactionStatus::Failed {
             block_id: Default::default(),
             time: Tai64(0),
             result: None,
             receipts: vec![],
+            fee: Default::default(),
         }),
         Just(TransactionStatus::SqueezedOut {
             reason: Default::default(),
@@ -0,0 +1,223 @@
+//! Cheap static checks of the script bytecode performed before the insertion into the `TxPool`.
+//! They reject the scripts that fail for sure, so the scripts don't waste the space in the block.
+
+use fuel_core_types::fuel_asm::{
+    self,
+    Instruction,
+    RegId,
+};
+
+/// The statically known jump of the script.
+#[derive(Debug, Clone, Copy)]
+struct Jump {
+    /// The index of the target instruction. `None` if the target is before the script.
+    target: Option<usize>,
+    /// The jump is performed regardless of the state of the registers.
+    unconditional: bool,
+}
+
+impl Jump {
+    fn absolute(target: usize, unconditional: bool) -> Self {
+        Self {
+            target: Some(target),
+            unconditional,
+        }
+    }
+
+    fn forwards(index: usize, offset: usize, unconditional: bool) -> Self {
+        Self {
+            target: index.checked_add(offset).and_then(|i| i.checked_add(1)),
+            unconditional,
+        }
+    }
+
+    fn backwards(index: usize, offset: usize, unconditional: bool) -> Self {
+        Self {
+            target: offset
+                .checked_add(1)
+                .and_then(|offset| index.checked_sub(offset)),
+            unconditional,
+        }
+    }
+}
+
+/// Returns the jump performed by the `instruction` at the `index`, if its target
+/// doesn't depend on the registers.
+fn jump(index: usize, instruction: Instruction) -> Option<Jump> {
+    let jump = match instruction {
+        Instruction::JI(op) => Jump::absolute(to_usize(op.unpack().into()), true),
+        Instruction::JNEI(op) => {
+            let (_, _, target) = op.unpack();
+            Jump::absolute(u16::from(target).into(), false)
+        }
+        Instruction::JNZI(op) => {
+            let (cond, target) = op.unpack();
+            Jump::absolute(to_usize(target.into()), cond == RegId::ONE)
+        }
+        Instruction::JMPF(op) => {
+            let (dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::forwards(index, to_usize(fixed.into()), true)
+        }
+        Instruction::JMPB(op) => {
+            let (dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::backwards(index, to_usize(fixed.into()), true)
+        }
+        Instruction::JNZF(op) => {
+            let (cond, dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::forwards(index, u16::from(fixed).into(), cond == RegId::ONE)
+        }
+        Instruction::JNZB(op) => {
+            let (cond, dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::backwards(index, u16::from(fixed).into(), cond == RegId::ONE)
+        }
+        Instruction::JNEF(op) => {
+            let (_, _, dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::forwards(index, u8::from(fixed).into(), false)
+        }
+        Instruction::JNEB(op) => {
+            let (_, _, dynamic, fixed) = op.unpack();
+            if dynamic != RegId::ZERO {
+                return None
+            }
+            Jump::backwards(index, u8::from(fixed).into(), false)
+        }
+        _ => return None,
+    };
+    Some(jump)
+}
+
+fn to_usize(value: u32) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+/// Checks that all statically known jumps of the `script` target the instructions
+/// of the script and that the unconditional jumps don't form an infinite loop.
+///
+/// The bytes that are not valid instructions are skipped, because the script
+/// may contain the data section.
+pub fn check_script(script: &[u8]) -> Result<(), String> {
+    let jumps: Vec<_> = fuel_asm::from_bytes(script.iter().copied())
+        .enumerate()
+        .map(|(index, instruction)| instruction.ok().and_then(|i| jump(index, i)))
+        .collect();
+    let len = jumps.len();
+
+    for (index, jump) in jumps.iter().enumerate() {
+        if let Some(jump) = jump {
+            match jump.target {
+                Some(target) if target < len => {}
+                Some(target) => {
+                    return Err(format!(
+                        "The jump at the instruction {index} targets the instruction \
+                        {target} outside of the script with {len} instructions"
+                    ))
+                }
+                None => {
+                    return Err(format!(
+                        "The jump at the instruction {index} targets the memory before the script"
+                    ))
+                }
+            }
+        }
+    }
+
+    // Each unconditional jump has exactly one successor, so the jumps form
+    // a functional graph where the loop is found by the single walk per node.
+    #[derive(Clone, Copy)]
+    enum State {
+        NotVisited,
+        InProgress,
+        Done,
+    }
+    let mut states = vec![State::NotVisited; len];
+    for start in 0..len {
+        let mut current = start;
+        let mut path = vec![];
+        let looped = loop {
+            match states[current] {
+                State::InProgress => break true,
+                State::Done => break false,
+                State::NotVisited => {}
+            }
+            states[current] = State::InProgress;
+            path.push(current);
+            match jumps[current] {
+                Some(Jump {
+                    target: Some(target),
+                    unconditional: true,
+                }) => current = target,
+                _ => break false,
+            }
+        };
+        if looped {
+            return Err(format!(
+                "The unconditional jump at the instruction {current} leads to an infinite loop"
+            ))
+        }
+        for index in path {
+            states[index] = State::Done;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_asm::op;
+
+    fn script(ops: Vec<Instruction>) -> Vec<u8> {
+        ops.into_iter().collect()
+    }
+
+    #[test]
+    fn valid_script_passes() {
+        let script = script(vec![
+            op::movi(0x10, 3),
+            op::subi(0x10, 0x10, 1),
+            op::jnzb(0x10, RegId::ZERO, 0),
+            op::ret(RegId::ONE),
+        ]);
+
+        assert_eq!(check_script(&script), Ok(()));
+    }
+
+    #[test]
+    fn jump_outside_of_script_is_rejected() {
+        let script = script(vec![op::ji(10), op::ret(RegId::ONE)]);
+
+        assert!(check_script(&script).is_err());
+    }
+
+    #[test]
+    fn jump_before_script_is_rejected() {
+        let script = script(vec![op::jmpb(RegId::ZERO, 0), op::ret(RegId::ONE)]);
+
+        assert!(check_script(&script).is_err());
+    }
+
+    #[test]
+    fn unconditional_loop_is_rejected() {
+        let self_loop = script(vec![op::ji(0)]);
+        let cycle = script(vec![op::ji(1), op::ji(0)]);
+
+        assert!(check_script(&self_loop).is_err());
+        assert!(check_script(&cycle).is_err());
+    }
+}
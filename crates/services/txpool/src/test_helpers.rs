@@ -174,6 +174,10 @@ pub(crate) fn random_predicate(
 pub struct UnsetInput(Input);
 
 impl UnsetInput {
+    pub fn new(input: Input) -> Self {
+        Self(input)
+    }
+
     pub fn into_input(self, new_utxo_id: UtxoId) -> Input {
         let mut input = self.0;
         match &mut input {
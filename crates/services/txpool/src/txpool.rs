@@ -6,6 +6,7 @@ use crate::{
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    static_checks,
     types::*,
     Config,
     Error,
@@ -13,6 +14,7 @@ use crate::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        field::Script as ScriptField,
         Chargeable,
         Transaction,
     },
@@ -221,7 +223,10 @@ impl<ViewProvider> TxPool<ViewProvider> {
             tx_status_sender.send_squeezed_out(*tx_id, Error::Removed);
             for dependent_tx in rem.iter() {
                 if tx_id != &dependent_tx.id() {
-                    tx_status_sender.send_squeezed_out(dependent_tx.id(), Error::Removed);
+                    tx_status_sender.send_squeezed_out(
+                        dependent_tx.id(),
+                        Error::DependencyRemoved(*tx_id),
+                    );
                 }
             }
             removed.extend(rem.into_iter());
@@ -265,16 +270,17 @@ where
         tx: Checked<Transaction>,
     ) -> anyhow::Result<InsertionResult> {
         let view = self.database.latest_view();
-        self.insert_inner(tx, &view)
+        self.insert_inner(tx, &view).map(|(result, _)| result)
     }
 
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
     // this is atomic operation. Return removed(pushed out/replaced) transactions
+    // with the reason of the removal for each of them.
     fn insert_inner(
         &mut self,
         tx: Checked<Transaction>,
         view: &View,
-    ) -> anyhow::Result<InsertionResult> {
+    ) -> anyhow::Result<(InsertionResult, Vec<Error>)> {
         let tx: CheckedTransaction = tx.into();
 
         let tx = Arc::new(match tx {
@@ -329,30 +335,36 @@ where
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
 
+        let mut removed = Vec::new();
+        let mut reasons = Vec::new();
         // if some transaction were removed so we don't need to check limit
-        let removed = if rem.is_empty() {
+        if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
                 let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
-                vec![rem_tx]
-            } else {
-                Vec::new()
+                let group = self.remove_inner(&rem_tx);
+                reasons.extend(removal_reasons(&group, Error::PushedOut(tx.id())));
+                removed.extend(group);
             }
         } else {
             // remove ret from by_hash and from by_price
-            for rem in rem.iter() {
-                self.remove_tx(&rem.id());
+            for group in rem {
+                for rem in group.iter() {
+                    self.remove_tx(&rem.id());
+                }
+                reasons.extend(removal_reasons(&group, Error::Replaced(tx.id())));
+                removed.extend(group);
             }
+        }
 
-            rem
-        };
-
-        Ok(InsertionResult {
-            inserted: tx,
-            submitted_time,
-            removed,
-        })
+        Ok((
+            InsertionResult {
+                inserted: tx,
+                submitted_time,
+                removed,
+            },
+            reasons,
+        ))
     }
 
     #[tracing::instrument(level = "info", skip_all)]
@@ -372,42 +384,65 @@ where
         }
 
         // announce to subscribers
-        for ret in res.iter() {
-            match ret {
-                Ok(InsertionResult {
-                    removed,
-                    inserted,
-                    submitted_time,
-                }) => {
-                    for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+        res.into_iter()
+            .map(|ret| match ret {
+                Ok((
+                    InsertionResult {
+                        removed,
+                        inserted,
+                        submitted_time,
+                    },
+                    reasons,
+                )) => {
+                    for (removed, reason) in removed.iter().zip(reasons) {
+                        tx_status_sender.send_squeezed_out(removed.id(), reason);
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
                         Tai64::from_unix(submitted_time.as_secs() as i64),
                     );
+                    Ok(InsertionResult {
+                        removed,
+                        inserted,
+                        submitted_time,
+                    })
                 }
-                Err(_) => {
-                    // @dev should not broadcast tx if error occurred
-                }
-            }
-        }
-        res
+                // @dev should not broadcast tx if error occurred
+                Err(err) => Err(err),
+            })
+            .collect()
     }
 }
 
+/// The first transaction of the `group` is removed because of the `reason`, while the rest
+/// of the `group` are removed because they depend on it.
+fn removal_reasons(group: &[ArcPoolTx], reason: Error) -> Vec<Error> {
+    let mut reasons = Vec::with_capacity(group.len());
+    if let Some(first) = group.first() {
+        reasons.push(reason);
+        reasons.extend(
+            group
+                .iter()
+                .skip(1)
+                .map(|_| Error::DependencyRemoved(first.id())),
+        );
+    }
+    reasons
+}
+
 pub async fn check_transactions(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
     config: &Config,
+    min_gas_price: u64,
 ) -> Vec<anyhow::Result<Checked<Transaction>>> {
     let mut checked_txs = Vec::with_capacity(txs.len());
 
     for tx in txs.iter() {
-        checked_txs
-            .push(check_single_tx(tx.deref().clone(), current_height, config).await);
+        checked_txs.push(
+            check_single_tx(tx.deref().clone(), current_height, config, min_gas_price)
+                .await,
+        );
     }
 
     checked_txs
@@ -417,12 +452,26 @@ pub async fn check_single_tx(
     tx: Transaction,
     current_height: BlockHeight,
     config: &Config,
+    min_gas_price: u64,
 ) -> anyhow::Result<Checked<Transaction>> {
     if tx.is_mint() {
         return Err(Error::NotSupportedTransactionType.into())
     }
 
-    verify_tx_min_gas_price(&tx, config)?;
+    verify_tx_min_gas_price(&tx, config, min_gas_price)?;
+
+    if let Some(policy) = &config.policy {
+        policy
+            .check(&tx)
+            .map_err(Error::NotInsertedRejectedByPolicy)?;
+    }
+
+    if config.script_static_checks {
+        if let Transaction::Script(script) = &tx {
+            static_checks::check_script(script.script())
+                .map_err(Error::NotInsertedInvalidScript)?;
+        }
+    }
 
     let tx: Checked<Transaction> = if config.utxo_validation {
         let consensus_params = &config.chain_config.consensus_parameters;
@@ -442,6 +491,14 @@ pub async fn check_single_tx(
 
         debug_assert!(tx.checks().contains(Checks::all()));
 
+        if let Some(cache) = &config.predicate_cache {
+            match tx.transaction() {
+                Transaction::Script(script) => cache.insert(script),
+                Transaction::Create(create) => cache.insert(create),
+                Transaction::Mint(_) => {}
+            }
+        }
+
         tx
     } else {
         tx.into_checked_basic(current_height, &config.chain_config.consensus_parameters)
@@ -451,7 +508,11 @@ pub async fn check_single_tx(
     Ok(tx)
 }
 
-fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Error> {
+fn verify_tx_min_gas_price(
+    tx: &Transaction,
+    config: &Config,
+    min_gas_price: u64,
+) -> Result<(), Error> {
     let price = match tx {
         Transaction::Script(script) => script.price(),
         Transaction::Create(create) => create.price(),
@@ -463,7 +524,7 @@ fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Erro
         // price if there is no minimum gas price
         txpool_metrics().gas_price_histogram.observe(price as f64);
     }
-    if price < config.min_gas_price {
+    if price < min_gas_price {
         return Err(Error::NotInsertedGasPriceTooLow)
     }
     Ok(())
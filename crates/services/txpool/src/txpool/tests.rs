@@ -1,4 +1,8 @@
 use crate::{
+    policy::{
+        AllowedOwners,
+        Policy,
+    },
     test_helpers::{
         IntoEstimated,
         TextContext,
@@ -13,6 +17,7 @@ use crate::{
     Config,
     Error,
 };
+use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -20,10 +25,12 @@ use fuel_core_types::{
         Word,
     },
     fuel_tx::{
+        field::ScriptGasLimit,
         input::coin::CoinPredicate,
         Address,
         AssetId,
         Contract,
+        Finalizable,
         Input,
         Output,
         Transaction,
@@ -33,6 +40,7 @@ use fuel_core_types::{
     },
     fuel_types::ChainId,
     fuel_vm::checked_transaction::Checked,
+    services::predicate_cache::PredicateCache,
 };
 use std::{
     cmp::Reverse,
@@ -45,7 +53,7 @@ use super::check_single_tx;
 const GAS_LIMIT: Word = 1000;
 
 async fn check_unwrap_tx(tx: Transaction, config: &Config) -> Checked<Transaction> {
-    check_single_tx(tx, Default::default(), config)
+    check_single_tx(tx, Default::default(), config, config.min_gas_price)
         .await
         .expect("Transaction should be checked")
 }
@@ -54,7 +62,7 @@ async fn check_tx(
     tx: Transaction,
     config: &Config,
 ) -> anyhow::Result<Checked<Transaction>> {
-    check_single_tx(tx, Default::default(), config).await
+    check_single_tx(tx, Default::default(), config, config.min_gas_price).await
 }
 
 #[tokio::test]
@@ -478,6 +486,104 @@ async fn more_priced_tx3_removes_tx1_and_dependent_tx2() {
     assert_eq!(vec.removed[1].id(), tx2_id, "Tx2 id should be removed");
 }
 
+#[tokio::test]
+async fn removal_reasons_distinguish_replaced_and_dependent_txs() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+
+    let (output, unset_input) = context.create_output_and_input(10);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(9)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be OK, got Err");
+    let view = txpool.database.latest_view();
+    let (_, reasons) = txpool
+        .insert_inner(tx3, &view)
+        .expect("Tx3 should be OK, got Err");
+
+    assert_eq!(
+        reasons,
+        vec![Error::Replaced(tx3_id), Error::DependencyRemoved(tx1_id)]
+    );
+    assert!(!txpool.txs().contains_key(&tx2_id));
+}
+
+#[tokio::test]
+async fn dependent_tx_lists_its_parent() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+
+    let (output, unset_input) = context.create_output_and_input(10);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(9)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be OK, got Err");
+
+    let tx1 = txpool.txs()[&tx1_id].tx().clone();
+    let tx2 = txpool.txs()[&tx2_id].tx().clone();
+    assert!(txpool.dependency().parents(txpool.txs(), &tx1).is_empty());
+    assert_eq!(
+        txpool.dependency().parents(txpool.txs(), &tx2),
+        vec![tx1_id]
+    );
+}
+
 #[tokio::test]
 async fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     let mut context = TextContext::default();
@@ -770,6 +876,58 @@ async fn tx_below_min_gas_price_is_not_insertable() {
     ));
 }
 
+#[tokio::test]
+async fn tx_rejected_by_policy_is_not_insertable() {
+    let mut context = TextContext::default();
+
+    let gas_coin = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let err = check_tx(
+        tx,
+        &Config {
+            policy: Some(Policy::new(AllowedOwners::default())),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("expected insertion failure");
+
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedRejectedByPolicy(_)
+    ));
+}
+
+#[tokio::test]
+async fn script_failing_static_checks_is_not_insertable() {
+    let mut context = TextContext::default();
+
+    let gas_coin = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let tx = TransactionBuilder::script(vec![op::ji(0)].into_iter().collect(), vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let err = check_tx(
+        tx,
+        &Config {
+            script_static_checks: true,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("expected insertion failure");
+
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedInvalidScript(_)
+    ));
+}
+
 #[tokio::test]
 async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
     let mut context = TextContext::default();
@@ -1062,3 +1220,57 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn valid_predicates_are_cached() {
+    let mut context = TextContext::default();
+    let gas_coin = context.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize();
+    let cache = PredicateCache::new(10);
+    let config = Config {
+        predicate_cache: Some(cache.clone()),
+        ..Default::default()
+    };
+
+    assert!(!cache.contains(&tx));
+    check_tx(tx.clone().into(), &config)
+        .await
+        .expect("Transaction should be valid");
+
+    assert!(cache.contains(&tx));
+    // Any change of the transaction misses the cache.
+    let mut changed = tx;
+    *changed.script_gas_limit_mut() = GAS_LIMIT - 1;
+    assert!(!cache.contains(&changed));
+}
+
+#[tokio::test]
+async fn invalid_predicates_are_not_cached() {
+    let mut context = TextContext::default();
+    let coin = context
+        .custom_predicate(
+            AssetId::BASE,
+            TEST_COIN_AMOUNT,
+            vec![op::ret(RegId::ZERO)].into_iter().collect(),
+            None,
+        )
+        .into_default_estimated();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize();
+    let cache = PredicateCache::new(10);
+    let config = Config {
+        predicate_cache: Some(cache.clone()),
+        ..Default::default()
+    };
+
+    check_tx(tx.clone().into(), &config)
+        .await
+        .expect_err("Transaction should be invalid");
+
+    assert!(!cache.contains(&tx));
+}
@@ -17,6 +17,7 @@ use crate::{
     Result as StorageResult,
 };
 
+pub mod deduplicated;
 pub mod plain;
 pub mod sparse;
 
@@ -95,7 +96,8 @@ where
         storage
             .get(key_bytes.as_ref(), column)?
             .map(|value| {
-                Self::ValueCodec::decode_from_value(value).map_err(crate::Error::Codec)
+                Self::ValueCodec::decode_from_value(value)
+                    .map_err(|_| crate::Error::corruption(column, key_bytes.as_ref()))
             })
             .transpose()
     }
@@ -0,0 +1,213 @@
+//! The module defines the `Deduplicated` blueprint for the storage.
+//! The `Deduplicated` blueprint stores each unique value only once in the `Values` table
+//! and keeps the hash of the value under the key of the table.
+
+use crate::{
+    blueprint::{
+        Blueprint,
+        SupportsBatching,
+    },
+    codec::{
+        raw::Raw,
+        Decode,
+        Encode,
+        Encoder,
+    },
+    kv_store::{
+        BatchOperations,
+        KeyValueStore,
+        StorageColumn,
+        WriteOperation,
+    },
+    structured_storage::TableWithBlueprint,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+use std::sync::Arc;
+
+/// The `Deduplicated` blueprint works like a [`Plain`](super::plain::Plain) blueprint
+/// with the raw bytes values, but stores the value in the `Values` table under the hash
+/// of the value. The table itself maps the key to the hash, so the same value inserted
+/// under many keys occupies the space only once.
+///
+/// The values are never removed from the `Values` table, because other keys may refer
+/// to them. The iteration over the table yields the hashes instead of the values.
+pub struct Deduplicated<KeyCodec, Values> {
+    _marker: core::marker::PhantomData<(KeyCodec, Values)>,
+}
+
+impl<KeyCodec, Values> Deduplicated<KeyCodec, Values> {
+    /// Returns the hash under which the `value` is stored in the `Values` table.
+    pub fn value_hash(value: &[u8]) -> Bytes32 {
+        Hasher::hash(value)
+    }
+
+    fn get_by_hash<M, S>(
+        storage: &S,
+        column: S::Column,
+        key_bytes: &[u8],
+    ) -> StorageResult<Option<M::OwnedValue>>
+    where
+        M: Mappable,
+        S: KeyValueStore,
+        Values: TableWithBlueprint<Column = S::Column>,
+        Raw: Decode<M::OwnedValue>,
+    {
+        let Some(hash) = storage.get(key_bytes, column)? else {
+            return Ok(None)
+        };
+        let value = storage
+            .get(hash.as_slice(), Values::column())?
+            .ok_or_else(|| StorageError::corruption(column, key_bytes))?;
+        Raw::decode_from_value(value)
+            .map(Some)
+            .map_err(|_| StorageError::corruption(Values::column(), hash.as_slice()))
+    }
+}
+
+impl<M, S, KeyCodec, Values> Blueprint<M, S> for Deduplicated<KeyCodec, Values>
+where
+    M: Mappable<Value = [u8]>,
+    S: KeyValueStore,
+    KeyCodec: Encode<M::Key> + Decode<M::OwnedKey>,
+    Raw: Decode<M::OwnedValue>,
+    Values: TableWithBlueprint<Column = S::Column>,
+{
+    type KeyCodec = KeyCodec;
+    type ValueCodec = Raw;
+
+    fn put(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+        value: &M::Value,
+    ) -> StorageResult<()> {
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        let hash = Self::value_hash(value);
+        if !storage.exists(hash.as_ref(), Values::column())? {
+            storage.put(hash.as_ref(), Values::column(), Arc::new(value.to_vec()))?;
+        }
+        storage.put(key_bytes.as_ref(), column, Arc::new(hash.to_vec()))
+    }
+
+    fn replace(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+        value: &M::Value,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        let old = <Self as Blueprint<M, S>>::get(storage, key, column)?;
+        <Self as Blueprint<M, S>>::put(storage, key, column, value)?;
+        Ok(old)
+    }
+
+    fn take(
+        storage: &mut S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        let old = <Self as Blueprint<M, S>>::get(storage, key, column)?;
+        <Self as Blueprint<M, S>>::delete(storage, key, column)?;
+        Ok(old)
+    }
+
+    fn delete(storage: &mut S, key: &M::Key, column: S::Column) -> StorageResult<()> {
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        storage.delete(key_bytes.as_ref(), column)
+    }
+
+    fn size_of_value(
+        storage: &S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<usize>> {
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        let Some(hash) = storage.get(key_bytes.as_ref(), column)? else {
+            return Ok(None)
+        };
+        storage.size_of_value(hash.as_slice(), Values::column())
+    }
+
+    fn get(
+        storage: &S,
+        key: &M::Key,
+        column: S::Column,
+    ) -> StorageResult<Option<M::OwnedValue>> {
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        Self::get_by_hash::<M, S>(storage, column, key_bytes.as_ref())
+    }
+}
+
+impl<Column, M, S, KeyCodec, Values> SupportsBatching<M, S>
+    for Deduplicated<KeyCodec, Values>
+where
+    Column: StorageColumn,
+    S: BatchOperations<Column = Column>,
+    M: Mappable<Value = [u8]>
+        + TableWithBlueprint<Blueprint = Deduplicated<KeyCodec, Values>, Column = Column>,
+    KeyCodec: Encode<M::Key> + Decode<M::OwnedKey>,
+    Raw: Decode<M::OwnedValue>,
+    Values: TableWithBlueprint<Column = Column>,
+{
+    fn init<'a, Iter>(storage: &mut S, column: S::Column, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a M::Key, &'a M::Value)>,
+        M::Key: 'a,
+        M::Value: 'a,
+    {
+        <Self as SupportsBatching<M, S>>::insert(storage, column, set)
+    }
+
+    fn insert<'a, Iter>(
+        storage: &mut S,
+        column: S::Column,
+        set: Iter,
+    ) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = (&'a M::Key, &'a M::Value)>,
+        M::Key: 'a,
+        M::Value: 'a,
+    {
+        // The repeated values are overwritten by the same bytes.
+        storage.batch_write(&mut set.flat_map(|(key, value)| {
+            let key_bytes = KeyCodec::encode(key).as_bytes().to_vec();
+            let hash = Self::value_hash(value);
+            [
+                (
+                    hash.to_vec(),
+                    Values::column(),
+                    WriteOperation::Insert(Arc::new(value.to_vec())),
+                ),
+                (
+                    key_bytes,
+                    column,
+                    WriteOperation::Insert(Arc::new(hash.to_vec())),
+                ),
+            ]
+        }))
+    }
+
+    fn remove<'a, Iter>(
+        storage: &mut S,
+        column: S::Column,
+        set: Iter,
+    ) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = &'a M::Key>,
+        M::Key: 'a,
+    {
+        storage.batch_write(&mut set.map(|key| {
+            let key_bytes = KeyCodec::encode(key).as_bytes().to_vec();
+            (key_bytes, column, WriteOperation::Remove)
+        }))
+    }
+}
@@ -65,7 +65,8 @@ where
         storage
             .replace(key_bytes.as_ref(), column, value)?
             .map(|value| {
-                ValueCodec::decode_from_value(value).map_err(StorageError::Codec)
+                ValueCodec::decode_from_value(value)
+                    .map_err(|_| StorageError::corruption(column, key_bytes.as_ref()))
             })
             .transpose()
     }
@@ -80,7 +81,8 @@ where
         storage
             .take(key_bytes.as_ref(), column)?
             .map(|value| {
-                ValueCodec::decode_from_value(value).map_err(StorageError::Codec)
+                ValueCodec::decode_from_value(value)
+                    .map_err(|_| StorageError::corruption(column, key_bytes.as_ref()))
             })
             .transpose()
     }
@@ -207,7 +207,8 @@ where
         let prev = storage
             .replace(key_bytes.as_ref(), column, value.clone())?
             .map(|value| {
-                ValueCodec::decode_from_value(value).map_err(StorageError::Codec)
+                ValueCodec::decode_from_value(value)
+                    .map_err(|_| StorageError::corruption(column, key_bytes.as_ref()))
             })
             .transpose()?;
 
@@ -225,7 +226,8 @@ where
         let prev = storage
             .take(key_bytes.as_ref(), column)?
             .map(|value| {
-                ValueCodec::decode_from_value(value).map_err(StorageError::Codec)
+                ValueCodec::decode_from_value(value)
+                    .map_err(|_| StorageError::corruption(column, key_bytes.as_ref()))
             })
             .transpose()?;
         Self::remove_from_tree(storage, key, key_bytes.as_ref())?;
@@ -72,6 +72,16 @@ pub enum Column {
     OwnedCoins = 20,
     /// The column of the table that stores `true` if `owner` owns `Message` with `message_id`
     OwnedMessageIds = 21,
+    /// See [`StateDiffs`](crate::tables::StateDiffs)
+    StateDiffs = 22,
+    /// The column of the table that stores the total amount of the `Coin`s per `owner` and `asset_id`
+    OwnedBalances = 23,
+    /// The column of the table that stores the total amount of the spendable `Message`s per `owner`
+    OwnedMessageBalances = 24,
+    /// The column of the table that stores the number and the size of the storage slots per contract
+    ContractsStorageStats = 25,
+    /// See [`ContractsCode`](crate::tables::ContractsCode)
+    ContractsCode = 26,
 }
 
 impl Column {
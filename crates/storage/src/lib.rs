@@ -59,12 +59,42 @@ pub enum Error {
     /// This error should be created with `not_found` macro.
     #[display(fmt = "resource of type `{_0}` was not found at the: {_1}")]
     NotFound(&'static str, &'static str),
+    /// The stored value can't be read because it is damaged.
+    /// This error should be created with [`Error::corruption`].
+    #[display(
+        fmt = "the value of the key `0x{key}` in the table `{table}` is corrupted"
+    )]
+    Corruption {
+        /// The name of the table.
+        table: &'static str,
+        /// The hex of the encoded key.
+        key: String,
+    },
     // TODO: Do we need this type at all?
     /// Unknown or not expected(by architecture) error.
     #[from]
     Other(anyhow::Error),
 }
 
+impl Error {
+    /// Creates the [`Error::Corruption`] for the value of the encoded `key` in the `column`.
+    pub fn corruption<Column: kv_store::StorageColumn>(
+        column: Column,
+        key: &[u8],
+    ) -> Self {
+        use core::fmt::Write;
+
+        let hex = key.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+        Self::Corruption {
+            table: column.name(),
+            key: hex,
+        }
+    }
+}
+
 impl From<Error> for anyhow::Error {
     fn from(error: Error) -> Self {
         anyhow::Error::msg(error)
@@ -1,4 +1,4 @@
-//! The module contains implementations and tests for the `FuelBlocks` table.
+//! The module contains implementations and tests for the `FuelBlocks` and `StateDiffs` tables.
 
 use crate::{
     blueprint::plain::Plain,
@@ -8,7 +8,10 @@ use crate::{
     },
     column::Column,
     structured_storage::TableWithBlueprint,
-    tables::FuelBlocks,
+    tables::{
+        FuelBlocks,
+        StateDiffs,
+    },
 };
 
 impl TableWithBlueprint for FuelBlocks {
@@ -26,3 +29,55 @@ crate::basic_storage_tests!(
     <FuelBlocks as crate::Mappable>::Key::default(),
     <FuelBlocks as crate::Mappable>::Value::default()
 );
+
+impl TableWithBlueprint for StateDiffs {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::StateDiffs
+    }
+}
+
+#[cfg(test)]
+crate::basic_storage_tests!(
+    StateDiffs,
+    <StateDiffs as crate::Mappable>::Key::default(),
+    <StateDiffs as crate::Mappable>::Value::default()
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        kv_store::KeyValueStore,
+        structured_storage::{
+            test::InMemoryStorage,
+            StructuredStorage,
+        },
+        Error as StorageError,
+        StorageAsRef,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn damaged_block_is_reported_as_corruption() {
+        let storage = StructuredStorage::new(InMemoryStorage::<Column>::default());
+        let height = 7u32.into();
+        storage
+            .as_ref()
+            .put(&[0, 0, 0, 7], Column::FuelBlocks, Arc::new(vec![0xff; 3]))
+            .unwrap();
+
+        let err = storage.storage::<FuelBlocks>().get(&height).unwrap_err();
+
+        assert!(
+            matches!(
+                &err,
+                StorageError::Corruption { table, key }
+                    if *table == "FuelBlocks" && key == "00000007"
+            ),
+            "{err}"
+        );
+    }
+}
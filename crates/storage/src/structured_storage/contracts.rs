@@ -1,7 +1,10 @@
 //! The module contains implementations and tests for the contracts tables.
 
 use crate::{
-    blueprint::plain::Plain,
+    blueprint::{
+        deduplicated::Deduplicated,
+        plain::Plain,
+    },
     codec::{
         postcard::Postcard,
         raw::Raw,
@@ -13,10 +16,12 @@ use crate::{
         TableWithBlueprint,
     },
     tables::{
+        ContractsCode,
         ContractsInfo,
         ContractsLatestUtxo,
         ContractsRawCode,
     },
+    Error as StorageError,
     StorageRead,
 };
 use core::ops::Deref;
@@ -26,8 +31,10 @@ use fuel_core_types::fuel_tx::ContractId;
 // and deserialization and uses `Raw` codec. Because the value is a contract byte code represented
 // by bytes, we don't use `serde::Deserialization` and `serde::Serialization` for `Vec`,
 // because we don't need to store the size of the contract. We store/load raw bytes.
+// The factories deploy many contracts with the same bytecode, so the bytecode is
+// deduplicated in the `ContractsCode` table.
 impl TableWithBlueprint for ContractsRawCode {
-    type Blueprint = Plain<Raw, Raw>;
+    type Blueprint = Deduplicated<Raw, ContractsCode>;
     type Column = Column;
 
     fn column() -> Column {
@@ -35,6 +42,27 @@ impl TableWithBlueprint for ContractsRawCode {
     }
 }
 
+impl TableWithBlueprint for ContractsCode {
+    type Blueprint = Plain<Raw, Raw>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::ContractsCode
+    }
+}
+
+impl<S> StructuredStorage<S>
+where
+    S: KeyValueStore<Column = Column>,
+{
+    /// Returns the hash of the bytecode of the contract in the `ContractsCode` table.
+    fn code_hash(&self, key: &ContractId) -> Result<Option<Vec<u8>>, StorageError> {
+        self.storage
+            .get(key.as_ref(), Column::ContractsRawCode)
+            .map(|value| value.map(|value| value.deref().clone()))
+    }
+}
+
 impl<S> StorageRead<ContractsRawCode> for StructuredStorage<S>
 where
     S: KeyValueStore<Column = Column>,
@@ -44,13 +72,18 @@ where
         key: &ContractId,
         buf: &mut [u8],
     ) -> Result<Option<usize>, Self::Error> {
-        self.storage
-            .read(key.as_ref(), Column::ContractsRawCode, buf)
+        let Some(hash) = self.code_hash(key)? else {
+            return Ok(None)
+        };
+        self.storage.read(&hash, Column::ContractsCode, buf)
     }
 
     fn read_alloc(&self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let Some(hash) = self.code_hash(key)? else {
+            return Ok(None)
+        };
         self.storage
-            .get(key.as_ref(), Column::ContractsRawCode)
+            .get(&hash, Column::ContractsCode)
             .map(|value| value.map(|value| value.deref().clone()))
     }
 }
@@ -76,12 +109,175 @@ impl TableWithBlueprint for ContractsLatestUtxo {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{
+        structured_storage::test::InMemoryStorage,
+        StorageAsMut,
+        StorageBatchMutate,
+        StorageInspect,
+        StorageSize,
+    };
+    use fuel_core_types::fuel_vm::Contract;
+
+    fn contract_id(i: u8) -> ContractId {
+        ContractId::from([i; 32])
+    }
+
+    /// Returns the storage with one copy of each code referred by the contracts.
+    fn deduplicated(contracts: &[(ContractId, &[u8])]) -> InMemoryStorage<Column> {
+        let storage = InMemoryStorage::<Column>::default();
+        for (contract_id, code) in contracts {
+            let hash = Deduplicated::<Raw, ContractsCode>::value_hash(code);
+            storage
+                .put(hash.as_ref(), Column::ContractsCode, code.to_vec().into())
+                .unwrap();
+            storage
+                .put(
+                    contract_id.as_ref(),
+                    Column::ContractsRawCode,
+                    hash.to_vec().into(),
+                )
+                .unwrap();
+        }
+        storage
+    }
+
+    #[test]
+    fn raw_code_roundtrip() {
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut structured_storage = StructuredStorage::new(&mut storage);
+        let code = vec![32u8; 10];
+
+        structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id(1), &code)
+            .unwrap();
+
+        let returned = structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .get(&contract_id(1))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(returned, Contract::from(code.clone()));
+        assert_eq!(
+            StorageSize::<ContractsRawCode>::size_of_value(
+                &structured_storage,
+                &contract_id(1)
+            )
+            .unwrap(),
+            Some(code.len())
+        );
+        let mut buf = vec![0; code.len()];
+        StorageRead::<ContractsRawCode>::read(
+            &structured_storage,
+            &contract_id(1),
+            &mut buf,
+        )
+        .unwrap();
+        assert_eq!(buf, code);
+        assert_eq!(
+            StorageRead::<ContractsRawCode>::read_alloc(
+                &structured_storage,
+                &contract_id(1)
+            )
+            .unwrap(),
+            Some(code)
+        );
+    }
+
+    #[test]
+    fn same_raw_code_is_stored_once() {
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut structured_storage = StructuredStorage::new(&mut storage);
+        let code = vec![32u8; 10];
+
+        for i in 0..3 {
+            structured_storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(&contract_id(i), &code)
+                .unwrap();
+        }
+        structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id(3), &[1u8; 8])
+            .unwrap();
+
+        let expected = deduplicated(&[
+            (contract_id(0), &code),
+            (contract_id(1), &code),
+            (contract_id(2), &code),
+            (contract_id(3), &[1u8; 8]),
+        ]);
+        assert_eq!(storage, expected);
+    }
+
+    #[test]
+    fn removed_raw_code_keeps_code_of_other_contracts() {
+        let mut storage = InMemoryStorage::<Column>::default();
+        let mut structured_storage = StructuredStorage::new(&mut storage);
+        let code = vec![32u8; 10];
+        structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id(1), &code)
+            .unwrap();
+        structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id(2), &code)
+            .unwrap();
+
+        structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .remove(&contract_id(1))
+            .unwrap();
+
+        assert!(!StorageInspect::<ContractsRawCode>::contains_key(
+            &structured_storage,
+            &contract_id(1)
+        )
+        .unwrap());
+        let returned = structured_storage
+            .storage_as_mut::<ContractsRawCode>()
+            .get(&contract_id(2))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(returned, Contract::from(code));
+    }
+
+    #[test]
+    fn batch_insert_of_raw_code_matches_single_inserts() {
+        let code = vec![32u8; 10];
+        let ids = (0..10).map(contract_id).collect::<Vec<_>>();
+
+        let mut batch_storage = InMemoryStorage::<Column>::default();
+        StorageBatchMutate::<ContractsRawCode>::insert_batch(
+            &mut StructuredStorage::new(&mut batch_storage),
+            &mut ids.iter().map(|id| (id, code.as_slice())),
+        )
+        .unwrap();
+
+        let mut single_storage = InMemoryStorage::<Column>::default();
+        let mut structured_storage = StructuredStorage::new(&mut single_storage);
+        for id in &ids {
+            structured_storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(id, &code)
+                .unwrap();
+        }
+
+        assert_eq!(batch_storage, single_storage);
+        let expected = ids
+            .iter()
+            .map(|id| (*id, code.as_slice()))
+            .collect::<Vec<_>>();
+        assert_eq!(batch_storage, deduplicated(&expected));
+    }
 
     crate::basic_storage_tests!(
-        ContractsRawCode,
-        <ContractsRawCode as crate::Mappable>::Key::from([1u8; 32]),
+        ContractsCode,
+        <ContractsCode as crate::Mappable>::Key::from([1u8; 32]),
         vec![32u8],
-        <ContractsRawCode as crate::Mappable>::OwnedValue::from(vec![32u8])
+        <ContractsCode as crate::Mappable>::OwnedValue::from(vec![32u8])
     );
 
     crate::basic_storage_tests!(
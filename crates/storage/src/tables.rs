@@ -19,9 +19,12 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
     },
+    fuel_vm::Contract,
+    services::executor::StateDiff,
 };
 pub use fuel_vm_private::storage::{
     ContractsAssets,
@@ -42,6 +45,19 @@ impl Mappable for FuelBlocks {
     type OwnedValue = CompressedBlock;
 }
 
+/// The unique bytecode of the contracts by its hash. The [`ContractsRawCode`] table
+/// refers to the bytecode by the hash, so the contracts deployed from the same
+/// bytecode share one entry.
+pub struct ContractsCode;
+
+impl Mappable for ContractsCode {
+    /// The hash of the bytecode.
+    type Key = Self::OwnedKey;
+    type OwnedKey = Bytes32;
+    type Value = [u8];
+    type OwnedValue = Contract;
+}
+
 /// The latest UTXO info of the contract. The contract's UTXO represents the unique id of the state.
 /// After each transaction, old UTXO is consumed, and new UTXO is produced. UTXO is used as an
 /// input to the next transaction related to the `ContractId` smart contract.
@@ -117,6 +133,17 @@ impl Mappable for ProcessedTransactions {
     type OwnedValue = ();
 }
 
+/// The storage table of changes made to the state by each block.
+/// The table is filled only if the executor is configured to record state diffs.
+pub struct StateDiffs;
+
+impl Mappable for StateDiffs {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = StateDiff;
+}
+
 /// The module contains definition of merkle-related tables.
 pub mod merkle {
     use crate::{
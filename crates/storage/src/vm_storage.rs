@@ -33,6 +33,7 @@ use fuel_core_types::{
         StorageSlot,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
         ContractId,
@@ -44,7 +45,10 @@ use fuel_core_types::{
 };
 use itertools::Itertools;
 use primitive_types::U256;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+};
 
 /// Used to store metadata relevant during the execution of a transaction.
 #[derive(Clone, Debug)]
@@ -53,6 +57,16 @@ pub struct VmStorage<D> {
     current_timestamp: Tai64,
     coinbase: ContractId,
     database: D,
+    changes: Option<ContractsChanges>,
+}
+
+/// The changes made to the contracts' state and balances through the [`VmStorage`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractsChanges {
+    /// The latest values of the modified storage slots. `None` means the slot was removed.
+    pub state: BTreeMap<(ContractId, Bytes32), Option<Bytes32>>,
+    /// The latest balances of the modified assets.
+    pub balances: BTreeMap<(ContractId, AssetId), Word>,
 }
 
 /// The trait around the `U256` type allows increasing the key by one.
@@ -79,6 +93,7 @@ impl<D: Default> Default for VmStorage<D> {
             current_timestamp: Tai64::now(),
             coinbase: Default::default(),
             database: D::default(),
+            changes: None,
         }
     }
 }
@@ -95,6 +110,40 @@ impl<D> VmStorage<D> {
             current_timestamp: header.time,
             coinbase,
             database,
+            changes: None,
+        }
+    }
+
+    /// Enables recording of the changes made to the contracts' state and balances.
+    pub fn with_recording(mut self) -> Self {
+        self.changes = Some(Default::default());
+        self
+    }
+
+    /// Takes the recorded changes. Returns `None` if the recording is disabled.
+    pub fn take_changes(&mut self) -> Option<ContractsChanges> {
+        self.changes.as_mut().map(core::mem::take)
+    }
+
+    fn record_state(
+        &mut self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+        value: Option<Bytes32>,
+    ) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.state.insert((*contract_id, *key), value);
+        }
+    }
+
+    fn record_balance(
+        &mut self,
+        contract_id: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) {
+        if let Some(changes) = self.changes.as_mut() {
+            changes.balances.insert((*contract_id, *asset_id), value);
         }
     }
 
@@ -171,9 +220,24 @@ where
     }
 }
 
-impl<D> ContractsAssetsStorage for VmStorage<D> where
-    D: MerkleRootStorage<ContractId, ContractsAssets, Error = StorageError>
+impl<D> ContractsAssetsStorage for VmStorage<D>
+where
+    D: MerkleRootStorage<ContractId, ContractsAssets, Error = StorageError>,
 {
+    fn merkle_contract_asset_id_balance_insert(
+        &mut self,
+        contract: &ContractId,
+        asset_id: &AssetId,
+        value: Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        let previous = StorageMutate::<ContractsAssets>::insert(
+            &mut self.database,
+            &(contract, asset_id).into(),
+            &value,
+        )?;
+        self.record_balance(contract, asset_id, value);
+        Ok(previous)
+    }
 }
 
 impl<D> InterpreterStorage for VmStorage<D>
@@ -236,7 +300,39 @@ where
         self.database.init_contract_state(
             id,
             slots.iter().map(|slot| (*slot.key(), *slot.value())),
-        )
+        )?;
+        for slot in slots {
+            self.record_state(id, slot.key(), Some(*slot.value()));
+        }
+        Ok(())
+    }
+
+    fn merkle_contract_state_insert(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+        value: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        let previous = StorageMutate::<ContractsState>::insert(
+            &mut self.database,
+            &(contract, key).into(),
+            value,
+        )?;
+        self.record_state(contract, key, Some(*value));
+        Ok(previous)
+    }
+
+    fn merkle_contract_state_remove(
+        &mut self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> Result<Option<Bytes32>, Self::DataError> {
+        let previous = StorageMutate::<ContractsState>::remove(
+            &mut self.database,
+            &(contract, key).into(),
+        )?;
+        self.record_state(contract, key, None);
+        Ok(previous)
     }
 
     fn merkle_contract_state_range(
@@ -287,6 +383,7 @@ where
                     .checked_add(1)
                     .expect("We've checked it above via `values.len()`");
             }
+            self.record_state(contract_id, &key_bytes, Some(*value));
 
             current_key.increase()?;
         }
@@ -314,6 +411,7 @@ where
                 .remove(&(contract_id, &key_bytes).into())?;
 
             found_unset |= option.is_none();
+            self.record_state(contract_id, &key_bytes, None);
 
             current_key.increase()?;
         }
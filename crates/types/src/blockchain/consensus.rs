@@ -12,7 +12,10 @@ use crate::{
 // Different types of consensus are represented as separate modules
 pub mod poa;
 
-use poa::PoAConsensus;
+use poa::{
+    PoABeacon,
+    PoAConsensus,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24,6 +27,9 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Proof of authority consensus with the randomness beacon of the block.
+    /// It is the separate variant, so the seals without the beacon keep the encoding.
+    PoAWithBeacon(PoAConsensus, PoABeacon),
 }
 
 impl Consensus {
@@ -31,7 +37,7 @@ impl Consensus {
     pub fn block_producer(&self, block_id: &BlockId) -> anyhow::Result<Address> {
         match &self {
             Consensus::Genesis(_) => Ok(Address::zeroed()),
-            Consensus::PoA(poa_data) => {
+            Consensus::PoA(poa_data) | Consensus::PoAWithBeacon(poa_data, _) => {
                 let public_key = poa_data
                     .signature
                     .recover(block_id.as_message())
@@ -41,6 +47,14 @@ impl Consensus {
             }
         }
     }
+
+    /// Returns the randomness beacon of the block, if any.
+    pub fn beacon(&self) -> Option<&PoABeacon> {
+        match self {
+            Consensus::PoAWithBeacon(_, beacon) => Some(beacon),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Consensus {
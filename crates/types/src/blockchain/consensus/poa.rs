@@ -1,6 +1,19 @@
 //! Proof of authority
 
-use crate::fuel_crypto::Signature;
+use crate::{
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
+};
 
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17,3 +30,49 @@ impl PoAConsensus {
         Self { signature }
     }
 }
+
+/// The randomness beacon of the block produced by the PoA signer.
+///
+/// The proof is the signature of the block height, and the value of the beacon is
+/// the hash of the proof. The value is unknown before the block is produced, and
+/// the signer can't choose it as long as it uses the deterministic signatures.
+/// Because the signer knows the future values, the beacon is suitable only for
+/// the networks with the trusted signer, like devnets.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoABeacon {
+    /// The signature of the beacon message of the block height.
+    pub proof: Signature,
+}
+
+impl PoABeacon {
+    const DOMAIN: &'static [u8] = b"FUEL_POA_BEACON";
+
+    /// Produces the beacon of the block at the `height`.
+    pub fn new(signing_key: &SecretKey, height: &BlockHeight) -> Self {
+        Self {
+            proof: Signature::sign(signing_key, &Self::message(height)),
+        }
+    }
+
+    /// The message signed by the PoA signer for the block at the `height`.
+    pub fn message(height: &BlockHeight) -> Message {
+        let mut hasher = Hasher::default();
+        hasher.input(Self::DOMAIN);
+        hasher.input(height.to_bytes());
+        Message::from_bytes(*hasher.digest())
+    }
+
+    /// The random value of the beacon.
+    pub fn value(&self) -> Bytes32 {
+        Hasher::hash(self.proof.as_ref())
+    }
+
+    /// Returns `true` if the beacon of the block at the `height` is produced
+    /// by the `signer`.
+    pub fn verify(&self, height: &BlockHeight, signer: &Address) -> bool {
+        self.proof
+            .recover(&Self::message(height))
+            .is_ok_and(|public_key| Input::owner(&public_key) == *signer)
+    }
+}
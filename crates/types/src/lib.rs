@@ -59,4 +59,8 @@ pub mod fuel_vm {
         storage::ContractsStateKey,
         util,
     };
+
+    #[cfg(feature = "profile-gas")]
+    #[doc(no_inline)]
+    pub use fuel_vm_private::profiler;
 }
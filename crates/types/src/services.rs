@@ -5,6 +5,7 @@ pub mod block_producer;
 pub mod executor;
 pub mod graphql_api;
 pub mod p2p;
+pub mod predicate_cache;
 pub mod relayer;
 pub mod txpool;
 
@@ -3,8 +3,10 @@
 use crate::{
     blockchain::{
         header::BlockHeader,
+        primitives::BlockId,
         SealedBlock,
     },
+    fuel_types::BlockHeight,
     services::{
         executor::TransactionExecutionStatus,
         Uncommitted,
@@ -117,3 +119,124 @@ impl From<BlockHeader> for BlockImportInfo {
         }
     }
 }
+
+/// The event published by the block importer.
+#[derive(Clone)]
+pub enum ImporterEvent {
+    /// The block is imported and committed into the database.
+    BlockImported(SharedImportResult),
+    /// The block is removed from the canonical chain. The PoA consensus doesn't
+    /// have forks, so the event is not published by the block importer yet.
+    BlockReorgedOut(BlockReorgedOut),
+    /// The blocks up to the height are final and can't be reorged out.
+    BlockFinalized(BlockFinalized),
+}
+
+impl core::fmt::Debug for ImporterEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ImporterEvent::BlockImported(result) => f
+                .debug_tuple("BlockImported")
+                .field(result.sealed_block.entity.header().height())
+                .finish(),
+            ImporterEvent::BlockReorgedOut(event) => {
+                f.debug_tuple("BlockReorgedOut").field(event).finish()
+            }
+            ImporterEvent::BlockFinalized(event) => {
+                f.debug_tuple("BlockFinalized").field(event).finish()
+            }
+        }
+    }
+}
+
+/// The block removed from the canonical chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockReorgedOut {
+    /// The height of the removed block.
+    pub height: BlockHeight,
+    /// The id of the removed block.
+    pub block_id: BlockId,
+}
+
+/// The rule that decides which blocks are final. The finality anchored to the DA
+/// height isn't supported: it requires the importer to follow the relayer and
+/// to finalize blocks when the DA height changes without new blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Finality {
+    /// The block is final when the number of blocks imported on top of it reaches
+    /// the confirmations. The PoA consensus doesn't have forks, so with zero
+    /// confirmations each block is final right after the import.
+    Confirmations(u32),
+}
+
+impl Default for Finality {
+    fn default() -> Self {
+        Finality::Confirmations(0)
+    }
+}
+
+impl Finality {
+    /// Returns the height of the latest final block when the `latest` block is imported.
+    /// Returns `None` if no block is final yet.
+    pub fn finalized_height(&self, latest: BlockHeight) -> Option<BlockHeight> {
+        match self {
+            Finality::Confirmations(confirmations) => u32::from(latest)
+                .checked_sub(*confirmations)
+                .map(Into::into),
+        }
+    }
+
+    /// Returns `true` if the block at the `height` is final when the `latest` block
+    /// is imported.
+    pub fn is_final(&self, height: BlockHeight, latest: BlockHeight) -> bool {
+        self.finalized_height(latest)
+            .is_some_and(|finalized| height <= finalized)
+    }
+}
+
+/// The height up to which the blocks are final.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFinalized {
+    /// The height of the latest final block.
+    pub height: BlockHeight,
+}
+
+/// The payload of one kind of the [`ImporterEvent`]. It allows subscribing only
+/// to the events of this kind.
+pub trait ImporterEventKind: Sized {
+    /// Returns the payload of the `event` if the event is of this kind.
+    fn from_event(event: ImporterEvent) -> Option<Self>;
+}
+
+impl ImporterEventKind for ImporterEvent {
+    fn from_event(event: ImporterEvent) -> Option<Self> {
+        Some(event)
+    }
+}
+
+impl ImporterEventKind for SharedImportResult {
+    fn from_event(event: ImporterEvent) -> Option<Self> {
+        match event {
+            ImporterEvent::BlockImported(result) => Some(result),
+            _ => None,
+        }
+    }
+}
+
+impl ImporterEventKind for BlockReorgedOut {
+    fn from_event(event: ImporterEvent) -> Option<Self> {
+        match event {
+            ImporterEvent::BlockReorgedOut(event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+impl ImporterEventKind for BlockFinalized {
+    fn from_event(event: ImporterEvent) -> Option<Self> {
+        match event {
+            ImporterEvent::BlockFinalized(event) => Some(event),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Types related to block producer service.
 
-use crate::blockchain::header::PartialBlockHeader;
+use crate::{
+    blockchain::header::PartialBlockHeader,
+    fuel_types::ContractId,
+};
 
 /// The components required to produce a block.
 #[derive(Debug)]
@@ -13,4 +16,7 @@ pub struct Components<Source> {
     pub transactions_source: Source,
     /// The gas limit of the block.
     pub gas_limit: u64,
+    /// The recipient of the fees of the block. If not set, the executor uses
+    /// the recipient from its config.
+    pub coinbase_recipient: Option<ContractId>,
 }
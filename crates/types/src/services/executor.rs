@@ -8,6 +8,8 @@ use crate::{
         },
         primitives::BlockId,
     },
+    entities::coins::coin::Coin,
+    fuel_asm::Word,
     fuel_tx::{
         Receipt,
         TxId,
@@ -15,6 +17,8 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
@@ -47,6 +51,45 @@ pub struct ExecutionResult {
     pub tx_status: Vec<TransactionExecutionStatus>,
 }
 
+/// The changes made to the state by the execution of the block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StateDiff {
+    /// Coins created by the block. Coins created and spent within the same block
+    /// are present here and in the `spent_coins`.
+    pub created_coins: Vec<Coin>,
+    /// Coins spent by the block.
+    pub spent_coins: Vec<UtxoId>,
+    /// The final values of the contracts' storage slots modified by the block.
+    pub contract_slots: Vec<ContractSlotChange>,
+    /// The final balances of the contracts' assets modified by the block.
+    pub contract_balances: Vec<ContractBalanceChange>,
+}
+
+/// The new value of the contract's storage slot.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSlotChange {
+    /// The contract that owns the slot.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The new value of the slot. `None` if the slot was removed.
+    pub value: Option<Bytes32>,
+}
+
+/// The new balance of the contract's asset.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractBalanceChange {
+    /// The contract that owns the balance.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The new amount of the asset.
+    pub amount: Word,
+}
+
 /// The status of a transaction after it is executed.
 #[derive(Debug, Clone)]
 pub struct TransactionExecutionStatus {
@@ -65,6 +108,8 @@ pub enum TransactionExecutionResult {
         result: Option<ProgramState>,
         /// The receipts generated by the executed transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeReport,
     },
     /// The execution of the transaction failed.
     Failed {
@@ -72,9 +117,82 @@ pub enum TransactionExecutionResult {
         result: Option<ProgramState>,
         /// The receipts generated by the executed transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeReport,
     },
 }
 
+/// The breakdown of the fee charged for the transaction. The `max_fee` is charged
+/// from the inputs before the execution, and the unused part of it is refunded
+/// after the execution. The `Mint` transaction doesn't pay the fee.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FeeReport {
+    /// The gas used by the script of the transaction.
+    pub script_gas_used: Word,
+    /// The gas used by the predicates of the transaction.
+    pub predicate_gas_used: Word,
+    /// The gas price set by the transaction.
+    pub gas_price: Word,
+    /// The max fee charged before the execution.
+    pub max_fee: Word,
+    /// The fee paid for the transaction.
+    pub fee: Word,
+    /// The part of the `max_fee` refunded after the execution.
+    pub refunded: Word,
+}
+
+/// The limits of the receipts produced by one transaction. The same limits are applied
+/// to the dry runs and to the production of blocks, so the transaction accepted by
+/// the dry run is not skipped by the block producer because of the receipts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiptsLimits {
+    /// The max number of receipts.
+    pub max_receipts: Option<usize>,
+    /// The max total number of bytes of the data logged by the `LOGD` instruction.
+    pub max_log_data_bytes: Option<u64>,
+}
+
+impl ReceiptsLimits {
+    /// Checks the `receipts` of the transaction against the limits.
+    pub fn check(
+        &self,
+        receipts: &[Receipt],
+    ) -> core::result::Result<(), ReceiptsLimitExceeded> {
+        if let Some(limit) = self.max_receipts {
+            if receipts.len() > limit {
+                return Err(ReceiptsLimitExceeded::TooManyReceipts {
+                    count: receipts.len(),
+                    limit,
+                })
+            }
+        }
+        if let Some(limit) = self.max_log_data_bytes {
+            let bytes = receipts
+                .iter()
+                .map(|receipt| match receipt {
+                    Receipt::LogData { len, .. } => *len,
+                    _ => 0,
+                })
+                .fold(0u64, u64::saturating_add);
+            if bytes > limit {
+                return Err(ReceiptsLimitExceeded::TooMuchLogData { bytes, limit })
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The limit of [`ReceiptsLimits`] exceeded by the transaction.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum ReceiptsLimitExceeded {
+    #[display(fmt = "{count} receipts, above the limit of {limit}")]
+    TooManyReceipts { count: usize, limit: usize },
+    #[display(fmt = "{bytes} bytes of the log data, above the limit of {limit} bytes")]
+    TooMuchLogData { bytes: u64, limit: u64 },
+}
+
 impl TransactionExecutionResult {
     /// Get the receipts generated by the executed transaction.
     pub fn receipts(&self) -> &[Receipt] {
@@ -84,6 +202,14 @@ impl TransactionExecutionResult {
         }
     }
 
+    /// Get the fee charged for the transaction.
+    pub fn fee(&self) -> &FeeReport {
+        match self {
+            TransactionExecutionResult::Success { fee, .. }
+            | TransactionExecutionResult::Failed { fee, .. } => fee,
+        }
+    }
+
     /// Get the reason of the failed transaction execution.
     pub fn reason(receipts: &[Receipt], state: &Option<ProgramState>) -> String {
         receipts
@@ -310,6 +436,23 @@ pub enum Error {
         error: InterpreterError<anyhow::Error>,
         transaction_id: Bytes32,
     },
+    #[display(fmt = "The dry run was abandoned by the caller")]
+    DryRunAbandoned,
+    #[display(
+        fmt = "The block {found:#x} at the height {height} of the trusted checkpoint doesn't match the checkpoint's block {expected:#x}"
+    )]
+    TrustedCheckpointMismatch {
+        height: BlockHeight,
+        expected: BlockId,
+        found: BlockId,
+    },
+    #[display(fmt = "The block used {used} gas, above the block gas limit of {limit}")]
+    BlockGasLimitExceeded { used: u64, limit: u64 },
+    #[display(fmt = "Transaction({transaction_id:#x}) produced {reason}")]
+    ReceiptsLimitExceeded {
+        transaction_id: Bytes32,
+        reason: ReceiptsLimitExceeded,
+    },
     #[display(fmt = "{_0:?}")]
     InvalidTransaction(CheckError),
     #[display(fmt = "Execution error with backtrace")]
@@ -21,3 +21,13 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The size of the state of the contract.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractStorageStats {
+    /// The number of the storage slots.
+    pub slots: u64,
+    /// The total size of the keys and the values of the storage slots in bytes.
+    pub bytes: u64,
+}
@@ -12,7 +12,10 @@ use std::{
         Formatter,
     },
     str::FromStr,
-    time::SystemTime,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 
 /// Contains types and logic for Peer Reputation
@@ -190,3 +193,35 @@ pub struct HeartbeatData {
     /// The instant representing when the latest heartbeat was received.
     pub last_heartbeat: SystemTime,
 }
+
+/// The stage of the synchronization of the blocks with the network.
+/// The stages are pipelined, so the stage is the one of the next block to commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncStage {
+    /// No peer has reported the height of its chain yet.
+    Connecting,
+    /// The header of the next block is requested from the peers.
+    HeaderSync,
+    /// The transactions of the next block are requested from the peers.
+    BlockDownload,
+    /// The next block is downloaded and is being executed.
+    Execution,
+    /// The node has all blocks known to the peers.
+    Synced,
+}
+
+/// The progress of the synchronization of the blocks with the network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SyncStatus {
+    /// The current stage of the synchronization.
+    pub stage: SyncStage,
+    /// The height of the latest committed block, if any.
+    pub local_height: Option<BlockHeight>,
+    /// The highest height reported by the peers, if any.
+    pub best_known_height: Option<BlockHeight>,
+    /// The average number of the blocks committed per second since the node
+    /// fell behind the peers. It is zero while the node is synced.
+    pub blocks_per_second: f64,
+    /// The estimated time until the node is synced, if the rate is known.
+    pub eta: Option<Duration>,
+}
@@ -0,0 +1,125 @@
+//! The cache of the successful evaluations of the predicates.
+
+use crate::{
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        field::Inputs,
+        Input,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        Bytes32,
+    },
+};
+use std::{
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    fmt::{
+        Debug,
+        Formatter,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The bounded cache of the predicates evaluated successfully by the node. The same
+/// spend is validated at the insertion into the `TxPool` and again when the block with
+/// the transaction is imported, so the second evaluation is skipped on the cache hit.
+///
+/// The key of each predicate is the hash of the predicate root, the hash of the
+/// predicate data and the digest of the whole transaction. Predicates can read any
+/// field of the transaction, so any change of it misses the cache. The oldest keys
+/// are evicted when the cache is full.
+#[derive(Clone)]
+pub struct PredicateCache(Arc<Mutex<Inner>>);
+
+struct Inner {
+    capacity: usize,
+    keys: HashSet<Bytes32>,
+    order: VecDeque<Bytes32>,
+}
+
+impl PredicateCache {
+    /// Creates the cache of up to `capacity` predicates.
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(Inner {
+            capacity,
+            keys: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        })))
+    }
+
+    /// Records that all predicates of the `tx` are evaluated successfully.
+    pub fn insert<Tx>(&self, tx: &Tx)
+    where
+        Tx: Inputs + Serialize,
+    {
+        let keys = keys(tx);
+        let mut inner = self.0.lock().expect("The lock is not poisoned");
+        if inner.capacity == 0 {
+            return
+        }
+        for key in keys {
+            if !inner.keys.insert(key) {
+                continue
+            }
+            inner.order.push_back(key);
+            if inner.order.len() > inner.capacity {
+                if let Some(oldest) = inner.order.pop_front() {
+                    inner.keys.remove(&oldest);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the `tx` has predicates and all of them
+    /// were evaluated successfully before.
+    pub fn contains<Tx>(&self, tx: &Tx) -> bool
+    where
+        Tx: Inputs + Serialize,
+    {
+        let keys = keys(tx);
+        if keys.is_empty() {
+            return false
+        }
+        let inner = self.0.lock().expect("The lock is not poisoned");
+        keys.iter().all(|key| inner.keys.contains(key))
+    }
+}
+
+impl Debug for PredicateCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PredicateCache")
+    }
+}
+
+/// Returns the keys of all predicates of the `tx`.
+fn keys<Tx>(tx: &Tx) -> Vec<Bytes32>
+where
+    Tx: Inputs + Serialize,
+{
+    let predicates = tx
+        .inputs()
+        .iter()
+        .filter_map(Input::predicate)
+        .collect::<Vec<_>>();
+    if predicates.is_empty() {
+        return vec![]
+    }
+
+    let digest = Hasher::hash(tx.to_bytes());
+    predicates
+        .into_iter()
+        .map(|(predicate, predicate_data, _)| {
+            let mut hasher = Hasher::default();
+            hasher.input(Input::predicate_owner(predicate));
+            hasher.input(Hasher::hash(predicate_data));
+            hasher.input(digest);
+            hasher.digest()
+        })
+        .collect()
+}
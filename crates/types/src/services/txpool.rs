@@ -31,7 +31,10 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
-    services::executor::TransactionExecutionResult,
+    services::executor::{
+        FeeReport,
+        TransactionExecutionResult,
+    },
 };
 use fuel_vm_private::checked_transaction::CheckedTransaction;
 use std::{
@@ -187,6 +190,8 @@ pub enum TransactionStatus {
         result: Option<ProgramState>,
         /// The receipts generated during execution of the transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeReport,
     },
     /// Transaction was squeezed of the txpool
     SqueezedOut {
@@ -203,6 +208,8 @@ pub enum TransactionStatus {
         result: Option<ProgramState>,
         /// The receipts generated during execution of the transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeReport,
     },
 }
 
@@ -214,22 +221,28 @@ pub fn from_executor_to_status(
     let time = block.header().time();
     let block_id = block.id();
     match result {
-        TransactionExecutionResult::Success { result, receipts } => {
-            TransactionStatus::Success {
-                block_id,
-                time,
-                result,
-                receipts,
-            }
-        }
-        TransactionExecutionResult::Failed { result, receipts } => {
-            TransactionStatus::Failed {
-                block_id,
-                time,
-                result,
-                receipts,
-            }
-        }
+        TransactionExecutionResult::Success {
+            result,
+            receipts,
+            fee,
+        } => TransactionStatus::Success {
+            block_id,
+            time,
+            result,
+            receipts,
+            fee,
+        },
+        TransactionExecutionResult::Failed {
+            result,
+            receipts,
+            fee,
+        } => TransactionStatus::Failed {
+            block_id,
+            time,
+            result,
+            receipts,
+            fee,
+        },
     }
 }
 
@@ -297,11 +310,25 @@ pub enum Error {
     NotInsertedIoContractOutput,
     #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
     NotInsertedMaxDepth,
+    #[error("Transaction is not inserted. Rejected by the policy: {0}")]
+    NotInsertedRejectedByPolicy(String),
+    #[error("Transaction is not inserted. The script fails the static checks: {0}")]
+    NotInsertedInvalidScript(String),
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
+    #[error("Transaction replaced by the more priced transaction {0:#x} spending the same inputs.")]
+    Replaced(TxId),
+    #[error(
+        "Transaction pushed out of the full pool by the more priced transaction {0:#x}."
+    )]
+    PushedOut(TxId),
+    #[error(
+        "Transaction removed because the transaction {0:#x} it depends on was removed."
+    )]
+    DependencyRemoved(TxId),
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
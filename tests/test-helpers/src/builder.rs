@@ -121,6 +121,7 @@ impl TestSetupBuilder {
             ContractConfig {
                 contract_id,
                 code,
+                code_hash: None,
                 salt,
                 state: None,
                 balances,
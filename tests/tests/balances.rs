@@ -42,6 +42,8 @@ async fn balance() {
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
         contracts: None,
+        vesting: None,
+        codes: None,
         coins: Some(
             vec![
                 (owner, 50, asset_id),
@@ -205,6 +207,8 @@ async fn first_5_balances() {
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
         contracts: None,
+        vesting: None,
+        codes: None,
         coins: Some(coins),
         messages: Some(messages),
     });
@@ -1,5 +1,6 @@
 use fuel_core::{
     database::Database,
+    fuel_core_graphql_api::storage::da_compression::VersionedCompressedBlock,
     service::{
         Config,
         FuelService,
@@ -26,16 +27,22 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        header::PartialBlockHeader,
     },
     fuel_tx::*,
     fuel_types::ChainId,
     secrecy::ExposeSecret,
+    services::block_importer::Finality,
     tai64::Tai64,
 };
 use itertools::{
     rev,
     Itertools,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use rstest::rstest;
 use std::{
     ops::Deref,
@@ -123,6 +130,137 @@ async fn produce_block() {
     };
 }
 
+#[tokio::test]
+async fn verify_block_header() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = Config::local_node();
+    let srv = FuelService::from_database(Database::default(), config.clone())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let header = PartialBlockHeader::default().generate(&[], &[]);
+    let serialized_header = postcard::to_allocvec(&header).unwrap();
+    let consensus_key = config.consensus_key.unwrap();
+    let signature = Signature::sign(
+        consensus_key.expose_secret().deref(),
+        header.id().as_message(),
+    );
+    let foreign_signature =
+        Signature::sign(&SecretKey::random(&mut rng), header.id().as_message());
+
+    let verification = client
+        .verify_block_header(&serialized_header, &signature)
+        .await
+        .unwrap();
+    assert!(verification.valid);
+    assert_eq!(Bytes32::from(verification.id), Bytes32::from(header.id()));
+
+    let verification = client
+        .verify_block_header(&serialized_header, &foreign_signature)
+        .await
+        .unwrap();
+    assert!(!verification.valid);
+
+    let result = client.verify_block_header(&[1, 2, 3], &signature).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn da_compressed_block() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    let bytes = client.da_compressed_block(1).await.unwrap().unwrap();
+    let VersionedCompressedBlock::V0(block) =
+        VersionedCompressedBlock::decompress(&bytes).unwrap();
+    let header = client.block_by_height(1).await.unwrap().unwrap().header;
+    assert_eq!(*block.header.height(), 1u32.into());
+    assert_eq!(Bytes32::from(block.header.id()), header.id);
+    assert_eq!(block.transactions.len(), 2);
+    assert_eq!(
+        block.transactions[0].id(&ChainId::default()),
+        tx.id(&ChainId::default())
+    );
+
+    let missing = client.da_compressed_block(2).await.unwrap();
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn pruned_block_body_is_reported_as_pruned() {
+    let mut config = Config::local_node();
+    config.block_body_retention = Some(1);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+    // The block with the transaction is out of the retention after the next block.
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The off-chain worker prunes the block asynchronously.
+    let mut error = None;
+    for _ in 0..50 {
+        if let Err(e) = client.transaction(&tx_id).await {
+            error = Some(e);
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let error = error.expect("The transaction should be pruned");
+    assert!(error.to_string().contains("pruned"), "{error}");
+
+    // The header of the pruned block is available.
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    assert_eq!(block.header.height, 1);
+}
+
+#[tokio::test]
+async fn non_final_block_body_is_not_pruned() {
+    let mut config = Config::local_node();
+    config.block_body_retention = Some(1);
+    config.block_importer.finality = Finality::Confirmations(3);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The block with the transaction is out of the retention, but it is not final.
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(chain_info.finalized_height, None);
+    // Wait until the off-chain worker processes the latest block, which prunes the
+    // block bodies out of the retention.
+    let off_chain = srv.shared.database.off_chain();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while off_chain.latest_height().ok() != Some(2u32.into()) {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("The off-chain worker should process the block");
+    assert!(client.transaction(&tx_id).await.unwrap().is_some());
+
+    // The block becomes final after three blocks on top of it.
+    client.produce_blocks(2, None).await.unwrap();
+    let chain_info = client.chain_info().await.unwrap();
+    assert_eq!(chain_info.finalized_height, Some(1));
+}
+
 #[tokio::test]
 async fn produce_block_manually() {
     let db = Database::default();
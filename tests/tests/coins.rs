@@ -37,6 +37,8 @@ mod coin {
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
             contracts: None,
+            vesting: None,
+            codes: None,
             coins: Some(
                 vec![
                     (owner, 50, asset_id_a),
@@ -298,6 +300,8 @@ mod message_coin {
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
             contracts: None,
+            vesting: None,
+            codes: None,
             coins: None,
             messages: Some(
                 vec![(owner, 50), (owner, 100), (owner, 150)]
@@ -499,6 +503,8 @@ mod all_coins {
         config.chain_conf.initial_state = Some(StateConfig {
             height: None,
             contracts: None,
+            vesting: None,
+            codes: None,
             coins: Some(
                 vec![
                     (owner, 100, asset_id_a),
@@ -709,6 +715,8 @@ async fn empty_setup() -> TestContext {
     config.chain_conf.initial_state = Some(StateConfig {
         height: None,
         contracts: None,
+        vesting: None,
+        codes: None,
         coins: None,
         messages: None,
     });
@@ -296,3 +296,35 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
 }
+
+#[tokio::test]
+async fn contract_storage_stats_count_deployed_slots() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let bytecode: Witness = vec![op::ret(RegId::ONE)]
+        .into_iter()
+        .collect::<Vec<u8>>()
+        .into();
+    let salt = Salt::zeroed();
+    let slots = (1..=3)
+        .map(|i| StorageSlot::new(key(i), Bytes32::zeroed()))
+        .collect::<Vec<_>>();
+    let contract = Contract::from(bytecode.as_ref());
+    let state_root = Contract::initial_state_root(slots.iter());
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let tx = TransactionBuilder::create(bytecode, salt, slots)
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let stats = client
+        .contract_storage_stats(&contract_id)
+        .await
+        .unwrap()
+        .expect("The contract should exist");
+    assert_eq!(stats.slots, 3);
+    assert_eq!(stats.bytes, 192);
+}
@@ -44,3 +44,47 @@ async fn debugger_integration() {
     let result = client.end_session(session_id).await.unwrap();
     assert!(result);
 }
+
+/// Tests that breakpoints can be removed and all registers are readable
+#[tokio::test]
+async fn debugger_removes_breakpoints() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let session = client.start_session().await.unwrap();
+    let session_id = session.as_str();
+
+    let registers = client.registers(session_id).await.unwrap();
+    assert_eq!(registers.len(), 64);
+    assert_eq!(
+        registers[0x10],
+        client.register(session_id, 0x10).await.unwrap()
+    );
+
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 0)
+        .await
+        .unwrap();
+    client
+        .remove_breakpoint(session_id, ContractId::zeroed(), 0)
+        .await
+        .unwrap();
+
+    let tx: Transaction = serde_json::from_str(include_str!("example_tx.json"))
+        .expect("Invalid transaction JSON");
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    assert!(status.breakpoint.is_none());
+
+    client.reset(session_id).await.unwrap();
+    client
+        .set_breakpoint(session_id, ContractId::zeroed(), 0)
+        .await
+        .unwrap();
+    client.clear_breakpoints(session_id).await.unwrap();
+
+    let status = client.start_tx(session_id, &tx).await.unwrap();
+    assert!(status.breakpoint.is_none());
+
+    let result = client.end_session(session_id).await.unwrap();
+    assert!(result);
+}
@@ -15,6 +15,7 @@ use fuel_core_client::client::{
     types::TransactionStatus,
     FuelClient,
 };
+use fuel_core_producer::CoinbaseRecipient;
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -65,7 +66,7 @@ async fn setup(rng: &mut StdRng) -> TestContext {
     // Start up a node
     let mut config = Config::local_node();
     config.debug = true;
-    config.block_producer.coinbase_recipient = Some(contract_id);
+    config.block_producer.coinbase_recipient = CoinbaseRecipient::Static(contract_id);
     let node = FuelService::new_node(config).await.unwrap();
     let client = FuelClient::from(node.bound_address);
 
@@ -77,6 +78,8 @@ async fn setup(rng: &mut StdRng) -> TestContext {
     assert!(matches!(tx_status, TransactionStatus::Success { .. }));
     let bh = client.produce_blocks(1, None).await.unwrap();
     assert_eq!(bh, BlockHeight::new(2));
+    let block = client.block_by_height(2).await.unwrap().unwrap();
+    assert_eq!(block.coinbase_recipient, Some(contract_id));
 
     // No fees should have been collected yet
     let contract_balance = client.contract_balance(&(contract_id), None).await.unwrap();
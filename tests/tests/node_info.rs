@@ -105,3 +105,13 @@ async fn test_peer_info() {
         .unwrap();
     assert!(time_since_heartbeat < Duration::from_secs(10));
 }
+
+#[tokio::test]
+async fn sync_status_is_null_without_network() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let status = client.sync_status().await.unwrap();
+
+    assert!(status.is_none());
+}
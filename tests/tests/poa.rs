@@ -1,4 +1,5 @@
 use fuel_core::{
+    chain_config::ConsensusConfig,
     database::Database,
     fuel_core_graphql_api::ports::DatabaseBlocks,
     service::{
@@ -7,7 +8,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    types::{
+        Consensus as ClientConsensus,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_types::{
@@ -16,7 +20,10 @@ use fuel_core_types::{
         primitives::BlockId,
     },
     fuel_crypto::SecretKey,
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Input,
+        Transaction,
+    },
     secrecy::Secret,
 };
 use rand::{
@@ -88,11 +95,51 @@ async fn can_get_sealed_block_from_poa_produced_block() {
         .expect("failed to verify signature");
 }
 
+#[tokio::test]
+async fn produced_blocks_contain_beacon_if_enabled() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let poa_secret = SecretKey::random(&mut rng);
+    let poa_public = poa_secret.public_key();
+
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_key = Some(Secret::new(poa_secret.into()));
+    config.chain_conf.consensus = ConsensusConfig::PoA {
+        signing_key: Input::owner(&poa_public),
+        block_time: None,
+    };
+    config.poa_beacon = true;
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(2, None).await.unwrap();
+
+    for height in 1..=2u32 {
+        let sealed_block_header = db
+            .get_sealed_block_header(&height.into())
+            .unwrap()
+            .expect("expected sealed header to be available");
+        let beacon = sealed_block_header
+            .consensus
+            .beacon()
+            .expect("expected the beacon")
+            .clone();
+        assert!(beacon.verify(&height.into(), &Input::owner(&poa_public)));
+
+        let block = client.block_by_height(height).await.unwrap().unwrap();
+        let ClientConsensus::PoAConsensus(poa) = block.consensus else {
+            panic!("Not expected consensus")
+        };
+        assert_eq!(poa.beacon, Some(beacon.value().into()));
+    }
+}
+
 #[cfg(feature = "p2p")]
 mod p2p {
     use super::*;
     use fuel_core::{
-        chain_config::ConsensusConfig,
         p2p_test_helpers::{
             make_config,
             make_node,
@@ -101,7 +148,6 @@ mod p2p {
         service::ServiceTrait,
     };
     use fuel_core_poa::Trigger;
-    use fuel_core_types::fuel_tx::Input;
     use std::time::Duration;
 
     // Starts first_producer which creates some blocks
@@ -122,6 +168,7 @@ mod p2p {
         let mut config = Config::local_node();
         config.chain_conf.consensus = ConsensusConfig::PoA {
             signing_key: pub_key,
+            block_time: None,
         };
 
         let bootstrap_config = make_config("Bootstrap".to_string(), config.clone());
@@ -39,6 +39,7 @@ async fn snapshot_state_config() {
         contracts: Some(vec![ContractConfig {
             contract_id: [11; 32].into(),
             code: vec![8; 32],
+            code_hash: None,
             salt: Salt::new([9; 32]),
             state: Some(vec![
                 (Bytes32::new([5u8; 32]), Bytes32::new([8u8; 32])),
@@ -18,6 +18,7 @@ use std::{
         Hash,
         Hasher,
     },
+    time::Duration,
 };
 use test_case::test_case;
 
@@ -61,6 +62,92 @@ async fn test_producer_getting_own_blocks_back() {
     validator.consistency_10s(&expected).await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_validator_syncs_after_heal() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Create a producer and a validator that share the same key pair.
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(10).with_name("Alice"),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+        None,
+    )
+    .await;
+
+    let mut producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+
+    // Split the network between the producer and the validator.
+    partition(&[&producer], &[&validator]);
+
+    // Insert the transactions into the tx pool.
+    let expected = producer.insert_txs().await;
+
+    // Wait up to 10 seconds for the producer to commit their own blocks.
+    producer.consistency_10s(&expected).await;
+
+    // The validator doesn't hear about the blocks while the network is split.
+    let synced =
+        tokio::time::timeout(Duration::from_secs(2), validator.consistency(&expected))
+            .await;
+    assert!(synced.is_err());
+
+    heal(&[&producer, &validator]);
+
+    // Wait up to 20 seconds for the validator to sync with the producer.
+    validator.consistency_20s(&expected).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_validator_syncs_over_degraded_network() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Create a producer and a validator that share the same key pair.
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(10).with_name("Alice"),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+        None,
+    )
+    .await;
+
+    let mut producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+
+    // Delay and lose some of the messages on both sides.
+    for node in [&producer, &validator] {
+        node.network_conditions()
+            .set_latency(Duration::from_millis(50));
+        node.network_conditions().set_packet_loss(0.2);
+    }
+
+    // Insert the transactions into the tx pool.
+    let expected = producer.insert_txs().await;
+
+    // Wait up to 10 seconds for the producer to commit their own blocks.
+    producer.consistency_10s(&expected).await;
+
+    // Wait up to 20 seconds for the validator to sync with the producer.
+    validator.consistency_20s(&expected).await;
+}
+
 #[test_case(1; "partition with 1 tx")]
 #[test_case(10; "partition with 10 txs")]
 #[test_case(100; "partition with 100 txs")]
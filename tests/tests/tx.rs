@@ -240,6 +240,32 @@ async fn get_transaction_by_id() {
     }
 }
 
+#[tokio::test]
+async fn transaction_status_contains_fee_breakdown() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let status = client.submit_and_await_commit(&transaction).await.unwrap();
+
+    let TransactionStatus::Success {
+        fee, fee_recipient, ..
+    } = &status
+    else {
+        panic!("Unexpected status {status:?}");
+    };
+    assert_eq!(fee.gas_price, transaction.as_script().unwrap().price());
+    assert_eq!(fee.fee + fee.refunded, fee.max_fee);
+    // The local node doesn't collect fees.
+    assert_eq!(fee_recipient, &None);
+    let response = client.transaction(&id).await.unwrap().unwrap();
+    assert!(matches!(
+        response.status,
+        TransactionStatus::Success { fee: stored_fee, .. } if &stored_fee == fee
+    ));
+}
+
 #[tokio::test]
 async fn get_transparent_transaction_by_id() {
     let transaction = Transaction::default_test_tx();
@@ -4,11 +4,29 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_producer::{
+    FeeRateSelector,
+    TxSelector,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
     fuel_tx::*,
+    fuel_types::ChainId,
+    services::txpool::ArcPoolTx,
 };
 use itertools::Itertools;
 use rand::{
@@ -75,3 +93,75 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn pool_transactions_lists_pending_transactions() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = client.submit(&tx).await.unwrap();
+
+    let pending = client
+        .pool_transactions(PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(pending.results.len(), 1);
+    let pending = &pending.results[0];
+    assert_eq!(pending.id, tx_id);
+    assert_eq!(pending.transaction, tx);
+    assert_eq!(pending.gas_price, 1);
+    assert!(pending.dependencies.is_empty());
+}
+
+/// Includes the transactions with the lowest gas price first.
+#[derive(Debug)]
+struct LowestPriceFirst;
+
+impl TxSelector for LowestPriceFirst {
+    fn select(
+        &self,
+        includable_txs: &mut dyn Iterator<Item = ArcPoolTx>,
+        max_gas: u64,
+    ) -> Vec<ArcPoolTx> {
+        let mut txs = includable_txs.collect_vec();
+        txs.reverse();
+        FeeRateSelector.select(&mut txs.into_iter(), max_gas)
+    }
+}
+
+#[tokio::test]
+async fn block_producer_uses_configured_tx_selector() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.block_producer.tx_selector = Arc::new(LowestPriceFirst);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut tx_ids = vec![];
+    for gas_price in [3, 1, 2] {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+        tx_ids.push((gas_price, tx.id(&ChainId::default())));
+    }
+    client.produce_blocks(1, None).await.unwrap();
+
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    tx_ids.sort();
+    let expected = tx_ids.into_iter().map(|(_, id)| id).collect_vec();
+    // The last transaction is the coinbase.
+    assert_eq!(block.transactions[..expected.len()], expected);
+}